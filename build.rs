@@ -0,0 +1,51 @@
+//!
+//! Detects the minor version of the compiler, enabling the use of standard library items that
+//! are not available in all supported toolchains, see `src/compat.rs`. Also declares the
+//! configuration flags that may be set by the user, such as `flat_error_provider`.
+//!
+
+use std::{env, process::Command};
+
+///
+/// Configuration flags, and the minor version of Rust 1.x in which the items they guard were
+/// stabilized.
+///
+const VERSIONED_CFGS: &[(&str, u32)] =
+    &[("has_panic_hook_info", 81), ("has_fs_try_lock_error", 89)];
+
+///
+/// Configuration flags set by the user with `RUSTFLAGS`, rather than features, as they are not
+/// supported by all toolchains and so must not be enabled by `--all-features`.
+///
+const USER_CFGS: &[&str] = &["flat_error_provider", "flat_error_strip_type_names"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+
+    // If the version cannot be determined, assume a current toolchain.
+    let minor = rustc_minor_version().unwrap_or(u32::MAX);
+    for (cfg, since) in VERSIONED_CFGS {
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+        if minor >= *since {
+            println!("cargo:rustc-cfg={cfg}");
+        }
+    }
+    for cfg in USER_CFGS {
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    // For example "rustc 1.80.1 (3f5fd8dd4 2024-08-06)".
+    version
+        .split_whitespace()
+        .nth(1)?
+        .split('.')
+        .nth(1)?
+        .parse()
+        .ok()
+}
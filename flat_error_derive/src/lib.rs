@@ -0,0 +1,471 @@
+/*!
+Provides the `ExtendedError` derive macro for the [`flat_error`](https://docs.rs/flat_error)
+crate, which is re-exported by that crate when its **derive** feature is enabled; see the
+documentation of `flat_error::ExtendedError` for its use.
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse::ParseStream, parse_macro_input, parse_quote, punctuated::Punctuated, spanned::Spanned,
+    Attribute, Data, DeriveInput, Error, Fields, GenericParam, Generics, Ident, LitStr, Member,
+    Result, Token, Type,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Derive `Display`, `Error`, `Clone`, and `PartialEq`, and so `ExtendedError`, for a struct or
+/// enum; together with an implementation of `From` for each field marked `#[from]`.
+///
+#[proc_macro_derive(ExtendedError, attributes(error, source, from))]
+pub fn derive_extended_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A struct, or a single variant of an enum, with its fields and `#[error]` attribute.
+///
+struct Variant<'a> {
+    path: TokenStream2,
+    style: Style,
+    fields: Vec<Field<'a>>,
+    display: DisplayAttr,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+struct Field<'a> {
+    member: Member,
+    ty: &'a Type,
+    span: Span,
+    is_source: bool,
+    from: Option<FromAttr>,
+}
+
+///
+/// The `#[from]` attribute of a field; `Direct` converts from the type of the field itself,
+/// `Flattened` from each of the listed types by flattening them into a `FlatError`.
+///
+enum FromAttr {
+    Direct,
+    Flattened(Vec<Type>),
+}
+
+///
+/// The `#[error]` attribute of a struct or variant.
+///
+enum DisplayAttr {
+    Transparent,
+    Format { format: LitStr, args: TokenStream2 },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let variants = match &input.data {
+        Data::Struct(data) => vec![Variant::new(
+            quote!(Self),
+            &data.fields,
+            &input.attrs,
+            input.ident.span(),
+        )?],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                Variant::new(
+                    quote!(Self::#ident),
+                    &variant.fields,
+                    &variant.attrs,
+                    ident.span(),
+                )
+            })
+            .collect::<Result<_>>()?,
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "ExtendedError cannot be derived for a union",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // An enum without variants has no values to match, so each method matches `*self`.
+    // Comparing two values of an enum with more than one variant also needs a fallback arm.
+    let arms = |arm: fn(&Variant<'_>) -> TokenStream2, scrutinee: TokenStream2, pairs: bool| {
+        if variants.is_empty() {
+            return quote!(match *self {});
+        }
+        let arms = variants.iter().map(arm);
+        let fallback = (pairs && variants.len() > 1).then(|| quote!(_ => false,));
+        quote! {
+            match #scrutinee {
+                #(#arms)*
+                #fallback
+            }
+        }
+    };
+    let display_body = arms(|variant| variant.display_arm(), quote!(self), false);
+    let source_body = arms(|variant| variant.source_arm(), quote!(self), false);
+    let clone_body = arms(|variant| variant.clone_arm(), quote!(self), false);
+    let eq_body = arms(|variant| variant.eq_arm(), quote!((self, other)), true);
+    let impls_of_from = variants
+        .iter()
+        .map(|variant| variant.impls_of_from(&input.ident, &input.generics))
+        .collect::<Result<Vec<_>>>()?;
+
+    let clone_generics = bounded(&input.generics, quote!(::core::clone::Clone));
+    let (clone_impl_generics, _, clone_where_clause) = clone_generics.split_for_impl();
+    let eq_generics = bounded(&input.generics, quote!(::core::cmp::PartialEq));
+    let (eq_impl_generics, _, eq_where_clause) = eq_generics.split_for_impl();
+    let mut error_generics = input.generics.clone();
+    error_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(Self: ::core::fmt::Debug));
+    let (_, _, error_where_clause) = error_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fmt(&self, __formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #display_body
+            }
+        }
+
+        impl #impl_generics ::flat_error::__Error for #name #ty_generics #error_where_clause {
+            #[allow(unused_variables)]
+            fn source(&self) -> ::core::option::Option<&(dyn ::flat_error::__Error + 'static)> {
+                #source_body
+            }
+        }
+
+        impl #clone_impl_generics ::core::clone::Clone for #name #ty_generics #clone_where_clause {
+            fn clone(&self) -> Self {
+                #clone_body
+            }
+        }
+
+        impl #eq_impl_generics ::core::cmp::PartialEq for #name #ty_generics #eq_where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                #eq_body
+            }
+        }
+
+        #(#impls_of_from)*
+    })
+}
+
+///
+/// Return `generics` with `bound` added to each type parameter.
+///
+fn bounded(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+///
+/// Return `format` with each positional argument, such as `{0}` or `{1:?}`, replaced by the
+/// binding of the corresponding tuple field, `{_0}` or `{_1:?}`.
+///
+fn positional_to_bindings(format: &LitStr) -> LitStr {
+    let value = format.value();
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        if c == '{' {
+            if chars.peek() == Some(&'{') {
+                result.push(chars.next().unwrap());
+            } else if chars.peek().is_some_and(char::is_ascii_digit) {
+                result.push('_');
+            }
+        }
+    }
+    LitStr::new(&result, format.span())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Variant<'a> {
+    fn new(
+        path: TokenStream2,
+        fields: &'a Fields,
+        attrs: &[Attribute],
+        span: Span,
+    ) -> Result<Self> {
+        let style = match fields {
+            Fields::Named(_) => Style::Named,
+            Fields::Unnamed(_) => Style::Unnamed,
+            Fields::Unit => Style::Unit,
+        };
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| Field::new(index, field))
+            .collect::<Result<Vec<_>>>()?;
+        let display = attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("error"))
+            .ok_or_else(|| Error::new(span, "missing #[error(...)] attribute"))
+            .and_then(|attr| attr.parse_args_with(DisplayAttr::parse))?;
+        if matches!(display, DisplayAttr::Transparent) && fields.len() != 1 {
+            return Err(Error::new(
+                span,
+                "#[error(transparent)] requires exactly one field",
+            ));
+        }
+        if let Some(field) = fields.iter().filter(|field| field.is_source).nth(1) {
+            return Err(Error::new(field.span, "only one field may be a source"));
+        }
+        Ok(Self {
+            path,
+            style,
+            fields,
+            display,
+        })
+    }
+
+    ///
+    /// Return a pattern matching this variant, binding each field to the name returned by
+    /// `binding`.
+    ///
+    fn pattern(&self, binding: impl Fn(&Field<'a>) -> Ident) -> TokenStream2 {
+        let path = &self.path;
+        let bindings = self.fields.iter().map(&binding);
+        match self.style {
+            Style::Named => {
+                let fields = self
+                    .fields
+                    .iter()
+                    .zip(bindings)
+                    .map(|(field, binding)| match &field.member {
+                        Member::Named(ident) if *ident == binding => quote!(#binding),
+                        member => quote!(#member: #binding),
+                    });
+                quote!(#path { #(#fields),* })
+            }
+            Style::Unnamed => quote!(#path(#(#bindings),*)),
+            Style::Unit => quote!(#path),
+        }
+    }
+
+    ///
+    /// Return an expression constructing this variant from the field `values`.
+    ///
+    fn construct(&self, values: impl Iterator<Item = TokenStream2>) -> TokenStream2 {
+        let path = &self.path;
+        match self.style {
+            Style::Named => {
+                let members = self.fields.iter().map(|field| &field.member);
+                quote!(#path { #(#members: #values),* })
+            }
+            Style::Unnamed => quote!(#path(#(#values),*)),
+            Style::Unit => quote!(#path),
+        }
+    }
+
+    fn display_arm(&self) -> TokenStream2 {
+        let pattern = self.pattern(Field::binding);
+        let body = match &self.display {
+            DisplayAttr::Transparent => {
+                let binding = self.fields[0].binding();
+                quote!(::core::fmt::Display::fmt(#binding, __formatter))
+            }
+            DisplayAttr::Format { format, args } => {
+                let format = positional_to_bindings(format);
+                quote!(::core::write!(__formatter, #format #args))
+            }
+        };
+        quote!(#pattern => #body,)
+    }
+
+    fn source_arm(&self) -> TokenStream2 {
+        let pattern = self.pattern(Field::binding);
+        let body = match (&self.display, self.source_field()) {
+            (DisplayAttr::Transparent, _) => {
+                let binding = self.fields[0].binding();
+                quote!(::flat_error::__Error::source(#binding))
+            }
+            (_, Some(field)) => {
+                let binding = field.binding();
+                quote!(::core::option::Option::Some(
+                    #binding as &(dyn ::flat_error::__Error + 'static)
+                ))
+            }
+            (_, None) => quote!(::core::option::Option::None),
+        };
+        quote!(#pattern => #body,)
+    }
+
+    fn clone_arm(&self) -> TokenStream2 {
+        let pattern = self.pattern(Field::binding);
+        let value = self.construct(self.fields.iter().map(|field| {
+            let binding = field.binding();
+            quote!(::core::clone::Clone::clone(#binding))
+        }));
+        quote!(#pattern => #value,)
+    }
+
+    fn eq_arm(&self) -> TokenStream2 {
+        let lhs = self.pattern(|field| field.prefixed_binding("__self"));
+        let rhs = self.pattern(|field| field.prefixed_binding("__other"));
+        let comparisons = self.fields.iter().map(|field| {
+            let lhs = field.prefixed_binding("__self");
+            let rhs = field.prefixed_binding("__other");
+            quote!(&& #lhs == #rhs)
+        });
+        quote!((#lhs, #rhs) => true #(#comparisons)*,)
+    }
+
+    fn impls_of_from(&self, name: &Ident, generics: &Generics) -> Result<TokenStream2> {
+        let Some((field, from)) = self
+            .fields
+            .iter()
+            .find_map(|field| field.from.as_ref().map(|from| (field, from)))
+        else {
+            return Ok(TokenStream2::new());
+        };
+        if self.fields.len() != 1 {
+            return Err(Error::new(
+                field.span,
+                "#[from] requires the variant to have exactly one field",
+            ));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let impl_from = |ty: &Type, value: TokenStream2| {
+            let value = self.construct(core::iter::once(value));
+            quote! {
+                impl #impl_generics ::core::convert::From<#ty> for #name #ty_generics #where_clause {
+                    fn from(source: #ty) -> Self {
+                        #value
+                    }
+                }
+            }
+        };
+        Ok(match from {
+            FromAttr::Direct => impl_from(field.ty, quote!(source)),
+            FromAttr::Flattened(types) => types
+                .iter()
+                .map(|ty| impl_from(ty, quote!(::flat_error::FlatError::from_error(&source))))
+                .collect(),
+        })
+    }
+
+    ///
+    /// Return the field returned by `Error::source`; the field marked `#[source]` or `#[from]`,
+    /// or else a field named `source`.
+    ///
+    fn source_field(&self) -> Option<&Field<'a>> {
+        self.fields
+            .iter()
+            .find(|field| field.is_source)
+            .or_else(|| {
+                self.fields.iter().find(
+                    |field| matches!(&field.member, Member::Named(ident) if ident == "source"),
+                )
+            })
+    }
+}
+
+impl<'a> Field<'a> {
+    fn new(index: usize, field: &'a syn::Field) -> Result<Self> {
+        let mut is_source = false;
+        let mut from = None;
+        for attr in &field.attrs {
+            if attr.path().is_ident("source") {
+                attr.meta.require_path_only()?;
+                is_source = true;
+            } else if attr.path().is_ident("from") {
+                from = Some(match &attr.meta {
+                    syn::Meta::Path(_) => FromAttr::Direct,
+                    _ => FromAttr::Flattened(
+                        attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?
+                            .into_iter()
+                            .collect(),
+                    ),
+                });
+                is_source = true;
+            }
+        }
+        Ok(Self {
+            member: match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(index.into()),
+            },
+            ty: &field.ty,
+            span: field.span(),
+            is_source,
+            from,
+        })
+    }
+
+    ///
+    /// Return the name to which this field is bound in a pattern; its own name, or `_{index}`
+    /// for a tuple field.
+    ///
+    fn binding(&self) -> Ident {
+        match &self.member {
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(index) => format_ident!("_{}", index.index, span = self.span),
+        }
+    }
+
+    ///
+    /// Return the name to which this field is bound in a pattern that binds the fields of two
+    /// values, distinguished by `prefix`.
+    ///
+    fn prefixed_binding(&self, prefix: &str) -> Ident {
+        match &self.member {
+            Member::Named(ident) => format_ident!("{}_{}", prefix, ident),
+            Member::Unnamed(index) => format_ident!("{}_{}", prefix, index.index),
+        }
+    }
+}
+
+impl DisplayAttr {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident != "transparent" {
+                return Err(Error::new(
+                    ident.span(),
+                    "expected a format string or `transparent`",
+                ));
+            }
+            return Ok(Self::Transparent);
+        }
+        Ok(Self::Format {
+            format: input.parse()?,
+            args: input.parse()?,
+        })
+    }
+}
@@ -0,0 +1,71 @@
+/*!
+Provides an implementation of [`actix_web::ResponseError`](https://docs.rs/actix-web) for
+[`FlatError`], so that handlers may return `Result<_, FlatError>`.
+
+The response has the status code returned by [`FlatError::http_status`] and, so that no internal
+details are exposed to clients, a JSON body containing only the status code, its reason phrase,
+the class of the error, and its code if it has one.
+
+```rust
+use actix_web::{http::StatusCode, ResponseError};
+use flat_error::{ErrorClass, FlatError};
+
+let err = FlatError::new("no row for id 42 in users")
+    .with_code("E0404")
+    .with_class(ErrorClass::NotFound);
+
+assert_eq!(err.status_code(), StatusCode::NOT_FOUND);
+assert_eq!(
+    err.to_json_body(),
+    r#"{"class":"not_found","code":"E0404","error":"Not Found","status":404}"#
+);
+```
+
+*/
+
+use crate::FlatError;
+use ::actix_web::{
+    http::{header::ContentType, StatusCode},
+    HttpResponse, ResponseError,
+};
+use ::serde_json::{Map, Value};
+use alloc::string::{String, ToString};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ResponseError for FlatError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.http_status().as_u16())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(ContentType::json())
+            .body(self.to_json_body())
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return the JSON body used by [`ResponseError::error_response`], an object with the
+    /// members `status`, `error`, the reason phrase of the status code, `class`, and `code` if
+    /// this error has a code. The message and source chain of this error are not included.
+    ///
+    pub fn to_json_body(&self) -> String {
+        let status = self.http_status();
+        let mut body = Map::new();
+        body.insert("status".into(), Value::from(status.as_u16()));
+        body.insert(
+            "error".into(),
+            Value::from(status.canonical_reason().unwrap_or_default()),
+        );
+        body.insert("class".into(), Value::from(self.class().name()));
+        if let Some(code) = self.code() {
+            body.insert("code".into(), Value::from(code));
+        }
+        Value::Object(body).to_string()
+    }
+}
@@ -0,0 +1,142 @@
+/*!
+Provides [`FlatError::render_pretty`], rendering an error and its source chain for a terminal
+with ANSI colors.
+*/
+
+use crate::{FlatError, Severity};
+use alloc::string::String;
+use core::fmt::{Result as FmtResult, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const RESET: &str = "\x1b[0m";
+
+const BOLD: &str = "\x1b[1m";
+
+const BOLD_RED: &str = "\x1b[1;31m";
+
+const BOLD_YELLOW: &str = "\x1b[1;33m";
+
+const BOLD_CYAN: &str = "\x1b[1;36m";
+
+const BOLD_MAGENTA: &str = "\x1b[1;35m";
+
+const INDENT: &str = "  ";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Render this error, and its source chain, for a terminal. The first line is the severity
+    /// of the error, followed by its code if any, and its message; followed by a `-->` line with
+    /// the source span, see [`FlatError::with_span`], if any, a `caused by:` line, indented, for
+    /// each source, a `related:` line for each related error, see [`FlatError::with_related`], a
+    /// `help:` line if there is help text, see [`FlatError::with_help`], and a `see:` line if
+    /// there is a documentation URL, see [`FlatError::with_docs_url`]. Colors are used unless
+    /// the `NO_COLOR` environment variable is set to a non-empty value, see
+    /// <https://no-color.org>.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .with_code("E1042")
+    ///     .context("reading config file");
+    ///
+    /// eprintln!("{}", err.render_pretty());
+    /// assert_eq!(
+    ///     err.render_pretty_with(false),
+    ///     "error: reading config file\n  caused by: entity not found"
+    /// );
+    /// ```
+    ///
+    pub fn render_pretty(&self) -> String {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        self.render_pretty_with(!no_color)
+    }
+
+    ///
+    /// Render this error, and its source chain, for a terminal as [`FlatError::render_pretty`]
+    /// does, using colors only if `color` is `true`.
+    ///
+    pub fn render_pretty_with(&self, color: bool) -> String {
+        let mut rendered = String::new();
+        self.write_pretty(&mut rendered, color)
+            .expect("writing to a string cannot fail");
+        rendered
+    }
+
+    fn write_pretty<W: Write>(&self, w: &mut W, color: bool) -> FmtResult {
+        let paint = |w: &mut W, style: &str, text: &dyn core::fmt::Display| {
+            if color {
+                write!(w, "{style}{text}{RESET}")
+            } else {
+                write!(w, "{text}")
+            }
+        };
+        let severity = self.severity();
+        let style = match severity {
+            Severity::Info => BOLD_CYAN,
+            Severity::Warning => BOLD_YELLOW,
+            Severity::Error => BOLD_RED,
+            Severity::Critical => BOLD_MAGENTA,
+        };
+        match self.code() {
+            Some(code) => paint(w, style, &format_args!("{severity}[{code}]:"))?,
+            None => paint(w, style, &format_args!("{severity}:"))?,
+        }
+        w.write_char(' ')?;
+        write_indented(w, &self.inner.frame.message, INDENT)?;
+        if let Some(span) = self.span() {
+            write!(w, "\n{INDENT}")?;
+            paint(w, BOLD_CYAN, &"-->")?;
+            write!(w, " {span}")?;
+        }
+        for source in self.sources() {
+            write!(w, "\n{INDENT}")?;
+            paint(w, BOLD, &"caused by:")?;
+            w.write_char(' ')?;
+            write_indented(w, &source.message, INDENT)?;
+        }
+        for related in self.related() {
+            write!(w, "\n{INDENT}")?;
+            paint(w, BOLD_CYAN, &"related:")?;
+            w.write_char(' ')?;
+            write_indented(w, &related.inner.frame.message, INDENT)?;
+        }
+        if let Some(help) = self.help() {
+            write!(w, "\n{INDENT}")?;
+            paint(w, BOLD_CYAN, &"help:")?;
+            w.write_char(' ')?;
+            write_indented(w, help, INDENT)?;
+        }
+        if let Some(url) = self.docs_url() {
+            write!(w, "\n{INDENT}")?;
+            paint(w, BOLD_CYAN, &"see:")?;
+            write!(w, " {url}")?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Write `message`, indenting any lines after the first by `indent`.
+///
+fn write_indented<W: Write>(w: &mut W, message: &str, indent: &str) -> FmtResult {
+    for (i, line) in message.split('\n').enumerate() {
+        if i > 0 {
+            write!(w, "\n{indent}")?;
+        }
+        w.write_str(line)?;
+    }
+    Ok(())
+}
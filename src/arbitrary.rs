@@ -0,0 +1,74 @@
+/*!
+Implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for [`FlatError`] and
+[`Severity`](crate::Severity), for use in fuzz targets.
+
+Each generated error has a random message, severity, and optional code; the original type name
+is either one of a set of common error types or a random string; and the error has a source chain
+of up to four errors, each generated in the same way.
+
+```rust
+use arbitrary::{Arbitrary, Unstructured};
+use flat_error::FlatError;
+
+let data = [0x5a_u8; 64];
+let mut u = Unstructured::new(&data);
+let err = FlatError::arbitrary(&mut u).unwrap();
+assert!(std::iter::successors(Some(&err), |err| err.flat_source()).count() <= 5);
+```
+*/
+
+use crate::{
+    intern_type_name, FlatError, Frame, Severity, SharedStr, ARBITRARY_MAX_SOURCES,
+    ARBITRARY_TYPE_NAMES, FLAT_ERROR_TYPE_NAME,
+};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use alloc::{string::String, vec::Vec};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Arbitrary<'a> for FlatError {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let frame = arbitrary_frame(u)?;
+        let len = u.int_in_range(0..=ARBITRARY_MAX_SOURCES)?;
+        let sources = (0..len)
+            .map(|_| arbitrary_frame(u))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(FlatError::from_frames(frame, sources))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Severity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            Severity::Info,
+            Severity::Warning,
+            Severity::Error,
+            Severity::Critical,
+        ])?)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn arbitrary_frame(u: &mut Unstructured<'_>) -> Result<Frame> {
+    let type_name = if u.arbitrary()? {
+        intern_type_name(&String::arbitrary(u)?)
+    } else {
+        u.choose(ARBITRARY_TYPE_NAMES)?
+    };
+    let mut frame = Frame::new(FLAT_ERROR_TYPE_NAME, SharedStr::from(String::arbitrary(u)?));
+    frame.original_type_name = type_name;
+    frame.severity = u.arbitrary()?;
+    if let Some(code) = Option::<String>::arbitrary(u)? {
+        frame.details_mut().code = Some(code);
+    }
+    Ok(frame)
+}
@@ -0,0 +1,261 @@
+/*!
+Provides the [`FlatArena`] type, which flattens errors into a bump-allocated arena rather than
+allocating each message separately, returning a borrowed [`FlatErrorRef`]. All of the errors in
+an arena are freed together when it is dropped or [reset](FlatArena::reset), which is
+considerably cheaper than dropping each `FlatError` for pipelines that generate, and discard,
+large numbers of errors in each batch of records they process.
+
+```rust
+use flat_error::{FlatArena, FlatError};
+use std::io::{Error as IoError, ErrorKind};
+
+let mut arena = FlatArena::new();
+for _batch in 0..3 {
+    let errors: Vec<_> = ["a", "b", "c"]
+        .iter()
+        .map(|record| {
+            arena.flatten(&IoError::new(
+                ErrorKind::InvalidData,
+                FlatError::new(format!("bad record {record}")),
+            ))
+        })
+        .collect();
+    assert_eq!(errors[1].to_string(), "bad record b");
+
+    // Errors that must outlive the batch are converted into `FlatError`.
+    let kept = FlatError::from(errors[2]);
+    assert_eq!(kept.to_string(), "bad record c");
+
+    arena.reset();
+}
+```
+*/
+
+use crate::{
+    captured_type_name_of_val, compat::Error, count_sources, guarded_source, FlatError,
+    FlattenOptions, CYCLIC_SOURCE_MESSAGE, FLAT_ERROR_TYPE_NAME,
+};
+use ::bumpalo::{
+    collections::{String as BumpString, Vec as BumpVec},
+    Bump,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    ptr,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An arena into which errors are flattened, as [`FlatErrorRef`]s, so that they may be freed
+/// together. The errors flattened into an arena borrow it, and so must be dropped, or converted
+/// into [`FlatError`], before it is reset.
+///
+#[derive(Debug, Default)]
+pub struct FlatArena {
+    bump: Bump,
+}
+
+///
+/// A flattened error, and its source chain, held in a [`FlatArena`]. This is `Copy`, and may be
+/// converted into an owned [`FlatError`] with `From`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlatErrorRef<'a> {
+    frames: &'a [ArenaFrame<'a>],
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single error in the source chain of a [`FlatErrorRef`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ArenaFrame<'a> {
+    original_type_name: &'static str,
+    message: &'a str,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ FlatArena
+// ------------------------------------------------------------------------------------------------
+
+impl FlatArena {
+    ///
+    /// Construct a new, empty, arena.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Construct a new arena with at least `capacity` bytes allocated up-front.
+    ///
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bump: Bump::with_capacity(capacity),
+        }
+    }
+
+    ///
+    /// Flatten `error`, and its source chain, into this arena. As with
+    /// [`FlatError::from_any`], the source chain is limited to the default maximum depth, see
+    /// [`FlattenOptions::max_depth`], and any cycle in it is replaced by a single synthetic
+    /// error.
+    ///
+    pub fn flatten<E>(&self, error: &E) -> FlatErrorRef<'_>
+    where
+        E: Error + ?Sized,
+    {
+        let mut frames = BumpVec::new_in(&self.bump);
+        frames.push(ArenaFrame {
+            original_type_name: captured_type_name_of_val(error),
+            message: self.message(error),
+        });
+        let max_depth = FlattenOptions::new().max_depth();
+        let mut seen: BumpVec<'_, *const (dyn Error + 'static)> = BumpVec::new_in(&self.bump);
+        let mut current = guarded_source(error);
+        while let Some(source) = current {
+            let source_ptr = ptr::from_ref(source);
+            if seen.iter().any(|seen| ptr::eq(*seen, source_ptr)) {
+                frames.push(ArenaFrame::omitted(CYCLIC_SOURCE_MESSAGE));
+                break;
+            }
+            if seen.len() == max_depth {
+                frames.push(ArenaFrame::omitted(match count_sources(source) {
+                    Some(count) => {
+                        bumpalo::format!(in &self.bump, "… {} more", count).into_bump_str()
+                    }
+                    None => CYCLIC_SOURCE_MESSAGE,
+                }));
+                break;
+            }
+            seen.push(source_ptr);
+            frames.push(ArenaFrame {
+                original_type_name: captured_type_name_of_val(source),
+                message: self.message(source),
+            });
+            current = guarded_source(source);
+        }
+        FlatErrorRef {
+            frames: frames.into_bump_slice(),
+        }
+    }
+
+    ///
+    /// Return the number of bytes currently allocated by this arena.
+    ///
+    pub fn allocated_bytes(&self) -> usize {
+        self.bump.allocated_bytes()
+    }
+
+    ///
+    /// Free all of the errors flattened into this arena, retaining its largest allocation for
+    /// reuse.
+    ///
+    pub fn reset(&mut self) {
+        self.bump.reset();
+    }
+
+    ///
+    /// Write the message of `error` into this arena. With the **std** feature, if its `Display`
+    /// implementation panics the panic is caught and a placeholder message returned instead.
+    ///
+    fn message<E>(&self, error: &E) -> &str
+    where
+        E: Error + ?Sized,
+    {
+        let write = || {
+            let mut message = BumpString::new_in(&self.bump);
+            write!(message, "{error}")
+                .expect("a Display implementation returned an error unexpectedly");
+            message.into_bump_str()
+        };
+        #[cfg(feature = "std")]
+        {
+            use ::std::panic::{catch_unwind, AssertUnwindSafe};
+            catch_unwind(AssertUnwindSafe(write)).unwrap_or(crate::PANICKED_MESSAGE)
+        }
+        #[cfg(not(feature = "std"))]
+        write()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ FlatErrorRef
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatErrorRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.message())
+    }
+}
+
+impl From<FlatErrorRef<'_>> for FlatError {
+    fn from(error: FlatErrorRef<'_>) -> Self {
+        error
+            .frames
+            .iter()
+            .rev()
+            .fold(None, |source, frame| {
+                Some(FlatError::from_parts(
+                    frame.original_type_name,
+                    frame.message,
+                    source,
+                ))
+            })
+            .expect("a flattened error has at least one frame")
+    }
+}
+
+impl<'a> FlatErrorRef<'a> {
+    ///
+    /// Return the message of this error.
+    ///
+    pub fn message(&self) -> &'a str {
+        self.frames[0].message
+    }
+
+    ///
+    /// Return the name of the flattened error type, see [`FlatError::original_type_name`].
+    ///
+    pub fn original_type_name(&self) -> &'static str {
+        self.frames[0].original_type_name
+    }
+
+    ///
+    /// Return the source of this error, if any.
+    ///
+    pub fn flat_source(&self) -> Option<FlatErrorRef<'a>> {
+        (self.frames.len() > 1).then(|| FlatErrorRef {
+            frames: &self.frames[1..],
+        })
+    }
+
+    ///
+    /// Return the messages of this error and of each error in its source chain, in order.
+    ///
+    pub fn chain_messages(&self) -> impl ExactSizeIterator<Item = &'a str> {
+        self.frames.iter().map(|frame| frame.message)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ArenaFrame
+// ------------------------------------------------------------------------------------------------
+
+impl ArenaFrame<'_> {
+    ///
+    /// A synthetic frame standing in for errors omitted from a source chain.
+    ///
+    const fn omitted(message: &str) -> ArenaFrame<'_> {
+        ArenaFrame {
+            original_type_name: FLAT_ERROR_TYPE_NAME,
+            message,
+        }
+    }
+}
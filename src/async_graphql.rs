@@ -0,0 +1,50 @@
+/*!
+Provides an implementation of
+[`async_graphql::ErrorExtensions`](https://docs.rs/async-graphql) for [`FlatError`], so that the
+code and class of an error are surfaced in the extensions of a GraphQL error.
+
+As `async_graphql::Error` implements `From` for any type implementing `Display`, the `?` operator
+in a resolver produces an error with only the message of a `FlatError`; use
+[`ErrorExtensions::extend`] to include its extensions.
+
+The error has the message of the `FlatError`, not including its source chain, and the
+extensions `code`, if the error has a code, and `class`, the name of the class returned by
+[`FlatError::class`]. The `FlatError` itself is retained as the source of the GraphQL error.
+
+```rust
+use async_graphql::{ErrorExtensions, Value};
+use flat_error::{ErrorClass, FlatError};
+
+let err = FlatError::new("no such user")
+    .with_code("E0404")
+    .with_class(ErrorClass::NotFound)
+    .extend();
+
+assert_eq!(err.message, "no such user");
+let extensions = err.extensions.as_ref().unwrap();
+assert_eq!(extensions.get("code"), Some(&Value::from("E0404")));
+assert_eq!(extensions.get("class"), Some(&Value::from("not_found")));
+let source = err.source.as_ref().unwrap().downcast_ref::<FlatError>().unwrap();
+assert_eq!(source.code(), Some("E0404"));
+```
+
+*/
+
+use crate::FlatError;
+use ::async_graphql::{Error, ErrorExtensions};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ErrorExtensions for FlatError {
+    fn extend(&self) -> Error {
+        let mut error = Error::new_with_source(self.clone());
+        let extensions = error.extensions.get_or_insert_with(Default::default);
+        if let Some(code) = self.code() {
+            extensions.set("code", code);
+        }
+        extensions.set("class", self.class().name());
+        error
+    }
+}
@@ -0,0 +1,47 @@
+/*!
+Provides an implementation of [`axum::response::IntoResponse`](https://docs.rs/axum) for
+[`FlatError`], so that handlers may return `Result<_, FlatError>`.
+
+The response has the status code returned by [`FlatError::http_status`] and, so that no internal
+details are exposed to clients, a plain text body containing only the reason phrase of the status
+code. To include the error and its source chain use [`FlatError::into_detailed_response`].
+
+```rust
+use axum::response::IntoResponse;
+use flat_error::FlatError;
+use http::StatusCode;
+
+let response = FlatError::new("connecting to db-7.internal:5432").into_response();
+
+assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+```
+
+*/
+
+use crate::FlatError;
+use ::axum::response::{IntoResponse, Response};
+use alloc::string::ToString;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl IntoResponse for FlatError {
+    fn into_response(self) -> Response {
+        let status = self.http_status();
+        (status, status.canonical_reason().unwrap_or_default()).into_response()
+    }
+}
+
+impl FlatError {
+    ///
+    /// Convert this error into a response with the status code returned by
+    /// [`FlatError::http_status`] and a plain text body containing this error and its source
+    /// chain, in the form of [`FlatReport`](crate::FlatReport). This should only be used where
+    /// clients may see internal details, for example in development.
+    ///
+    pub fn into_detailed_response(self) -> Response {
+        let status = self.http_status();
+        (status, self.report().to_string()).into_response()
+    }
+}
@@ -0,0 +1,302 @@
+/*!
+Provides the [`FlatErrorBuilder`] type, for constructing a [`FlatError`] field by field.
+*/
+
+use crate::{
+    ErrorClass, FlatError, FlatFrame, Frame, Inner, Severity, SharedStr, SourceSpan, Sources,
+    FLAT_ERROR_TYPE_NAME,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt::Display, panic::Location};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A builder for [`FlatError`] values, allowing each captured value to be set individually
+/// rather than flattened from an existing error. This is useful when reconstructing an error
+/// that was flattened elsewhere, for example by a deserializer or across an FFI boundary.
+///
+/// A builder may also be created from an existing `FlatError`, using `From`, in which case all
+/// of its values and its source are retained.
+///
+/// ```rust
+/// use flat_error::{FlatError, Severity};
+///
+/// let err = FlatError::builder()
+///     .message("connection refused")
+///     .type_name("std::io::error::Error")
+///     .code("E1042")
+///     .severity(Severity::Critical)
+///     .field("host", "db.local")
+///     .source(FlatError::new("no route to host"))
+///     .build();
+///
+/// assert_eq!(err.to_string(), "connection refused");
+/// assert_eq!(err.original_type_name(), "std::io::error::Error");
+/// assert_eq!(err.code(), Some("E1042"));
+/// assert_eq!(err.field("host"), Some("db.local"));
+/// assert_eq!(err.flat_source().unwrap().to_string(), "no route to host");
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct FlatErrorBuilder {
+    frame: Frame,
+    source: Option<FlatError>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for FlatErrorBuilder {
+    fn default() -> Self {
+        Self {
+            frame: Frame::created(FLAT_ERROR_TYPE_NAME, SharedStr::Static("")),
+            source: None,
+        }
+    }
+}
+
+impl From<FlatError> for FlatErrorBuilder {
+    fn from(error: FlatError) -> Self {
+        let (frame, source) = error.into_frame_and_source();
+        Self { frame, source }
+    }
+}
+
+impl From<FlatErrorBuilder> for FlatError {
+    fn from(builder: FlatErrorBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl FlatErrorBuilder {
+    ///
+    /// Construct a new builder; unless set, the message is empty and the original type name is
+    /// that of `FlatError` itself.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Set the message of the error.
+    ///
+    pub fn message<S>(mut self, message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.frame.message = SharedStr::from(message.into());
+        self
+    }
+
+    ///
+    /// Set the original type name of the error.
+    ///
+    pub fn type_name(mut self, type_name: &'static str) -> Self {
+        self.frame.original_type_name = type_name;
+        self
+    }
+
+    ///
+    /// Set the source of the error, replacing any previously set.
+    ///
+    pub fn source(mut self, source: FlatError) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    ///
+    /// Set the error code of the error, see [`FlatError::with_code`].
+    ///
+    pub fn code<S>(mut self, code: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.frame.details_mut().code = Some(code.into());
+        self
+    }
+
+    ///
+    /// Set the class of the error, see [`FlatError::with_class`].
+    ///
+    pub fn class(mut self, class: ErrorClass) -> Self {
+        self.frame.details_mut().class = Some(class);
+        self
+    }
+
+    ///
+    /// Set the severity of the error, see [`FlatError::with_severity`].
+    ///
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.frame.severity = severity;
+        self
+    }
+
+    ///
+    /// Set the metadata field `key` to `value`, see [`FlatError::with_field`].
+    ///
+    pub fn field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Display,
+    {
+        self.frame.set_field(key.into(), value.to_string());
+        self
+    }
+
+    ///
+    /// Set the message key, and the arguments used to render it, see
+    /// [`FlatError::with_message_key`].
+    ///
+    pub fn message_key<K, A, N, V>(mut self, key: K, args: A) -> Self
+    where
+        K: Into<String>,
+        A: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Display,
+    {
+        self.frame.set_message_key(key.into(), args);
+        self
+    }
+
+    ///
+    /// Set the help text of the error, see [`FlatError::with_help`].
+    ///
+    pub fn help<S>(mut self, help: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.frame.details_mut().help = Some(help.into());
+        self
+    }
+
+    ///
+    /// Set the documentation URL of the error, see [`FlatError::with_docs_url`].
+    ///
+    pub fn docs_url<S>(mut self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.frame.details_mut().docs_url = Some(url.into());
+        self
+    }
+
+    ///
+    /// Add a related error, see [`FlatError::with_related`].
+    ///
+    pub fn related(mut self, related: FlatError) -> Self {
+        self.frame.details_mut().related.push(related);
+        self
+    }
+
+    ///
+    /// Set the span within some source text at which the error was detected, see
+    /// [`FlatError::with_span`].
+    ///
+    pub fn span(mut self, span: SourceSpan) -> Self {
+        self.frame.details_mut().span = Some(span);
+        self
+    }
+
+    ///
+    /// Set the source code location at which the error was raised, typically the value of
+    /// `Location::caller()`.
+    ///
+    pub fn location(mut self, location: &'static Location<'static>) -> Self {
+        self.frame.details_mut().location.0 = Some(location);
+        self
+    }
+
+    ///
+    /// Return a view of the values set so far, not including the source.
+    ///
+    pub fn as_frame(&self) -> FlatFrame<'_> {
+        FlatFrame::new(&self.frame)
+    }
+
+    ///
+    /// Construct the error from the values set.
+    ///
+    pub fn build(self) -> FlatError {
+        let error = FlatError::from_frames(self.frame, Vec::new());
+        match self.source {
+            Some(source) => error.caused_by(source),
+            None => error,
+        }
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return a new [`FlatErrorBuilder`].
+    ///
+    pub fn builder() -> FlatErrorBuilder {
+        FlatErrorBuilder::new()
+    }
+}
+
+impl<C> FlatError<C> {
+    ///
+    /// Return a copy of this error with each error in its source chain, including the error
+    /// itself, transformed by `f`. Each error is passed to `f`, in order, as a builder holding
+    /// its values but not its source; `f` returns the builder with any changes, or `None` to
+    /// remove the error from the chain. Any source set on a returned builder is ignored. If
+    /// every error is removed `None` is returned.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .context("querying accounts table")
+    ///     .context("loading profile");
+    ///
+    /// let public = err
+    ///     .map_frames(|builder| {
+    ///         let builder = builder.type_name("Error");
+    ///         match builder.as_frame().message() {
+    ///             "querying accounts table" => None,
+    ///             _ => Some(builder),
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(public.format_chain(": "), "loading profile: entity not found");
+    /// assert_eq!(public.chain_type_names().collect::<Vec<_>>(), ["Error", "Error"]);
+    /// ```
+    ///
+    pub fn map_frames<F>(self, mut f: F) -> Option<Self>
+    where
+        F: FnMut(FlatErrorBuilder) -> Option<FlatErrorBuilder>,
+    {
+        let Inner {
+            frame,
+            sources,
+            category,
+            ..
+        } = self.inner.into_inner();
+        let sources = sources.map(Sources::into_frames).unwrap_or_default();
+        let mut frames = ::core::iter::once(frame)
+            .chain(sources.into_iter().rev())
+            .filter_map(|frame| {
+                f(FlatErrorBuilder {
+                    frame,
+                    source: None,
+                })
+            })
+            .map(|builder| builder.frame)
+            .collect::<Vec<_>>();
+        if frames.is_empty() {
+            return None;
+        }
+        let frame = frames.remove(0);
+        frames.reverse();
+        Some(FlatError::from_frames(frame, frames).with_category(category))
+    }
+}
@@ -0,0 +1,277 @@
+/*!
+Provides the [`ErrorCatalog`] type, a registry of the errors an application may produce, so that
+each error is defined once, by a stable code, rather than by string literals scattered through
+the application.
+
+```rust
+use flat_error::{CatalogEntry, ErrorCatalog, ErrorClass, Severity};
+
+let catalog = ErrorCatalog::new()
+    .with_entry(
+        CatalogEntry::new("E1042", "no space left on {device}")
+            .with_class(ErrorClass::ResourceExhausted)
+            .with_severity(Severity::Critical)
+            .with_help("free some space and try again"),
+    )
+    .with_entry(CatalogEntry::new("E0404", "no user with id {id}"));
+
+let err = catalog.new_error("E1042", [("device", "/dev/sda1")]);
+
+assert_eq!(err.to_string(), "no space left on /dev/sda1");
+assert_eq!(err.code(), Some("E1042"));
+assert_eq!(err.class(), ErrorClass::ResourceExhausted);
+assert_eq!(err.severity(), Severity::Critical);
+assert_eq!(err.help(), Some("free some space and try again"));
+assert_eq!(err.message_key(), Some("E1042"));
+assert_eq!(err.message_template(), Some("no space left on {device}"));
+assert_eq!(err.message_args().collect::<Vec<_>>(), vec![("device", "/dev/sda1")]);
+```
+*/
+
+use crate::{ErrorClass, FlatError, Severity};
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt::Display;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A registry of [`CatalogEntry`] values, keyed by their code, used to construct errors with
+/// [`ErrorCatalog::new_error`].
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorCatalog {
+    entries: BTreeMap<String, CatalogEntry>,
+}
+
+///
+/// The definition of an error in an [`ErrorCatalog`]; a stable code, a template for the message,
+/// and the class, severity, help text, and documentation URL given to each error constructed
+/// from it.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogEntry {
+    code: String,
+    template: String,
+    class: Option<ErrorClass>,
+    severity: Severity,
+    help: Option<String>,
+    docs_url: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ErrorCatalog {
+    ///
+    /// Construct a new, empty, catalog.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Return this catalog with `entry` registered, see [`ErrorCatalog::register`].
+    ///
+    pub fn with_entry(mut self, entry: CatalogEntry) -> Self {
+        self.register(entry);
+        self
+    }
+
+    ///
+    /// Register `entry`, returning the entry it replaces if one with the same code was already
+    /// registered.
+    ///
+    pub fn register(&mut self, entry: CatalogEntry) -> Option<CatalogEntry> {
+        self.entries.insert(entry.code.clone(), entry)
+    }
+
+    ///
+    /// Return the entry registered with `code`, if any.
+    ///
+    pub fn get(&self, code: &str) -> Option<&CatalogEntry> {
+        self.entries.get(code)
+    }
+
+    ///
+    /// Return `true` if an entry is registered with `code`.
+    ///
+    pub fn contains(&self, code: &str) -> bool {
+        self.entries.contains_key(code)
+    }
+
+    ///
+    /// Return an iterator over the registered entries, in order of their codes.
+    ///
+    pub fn entries(&self) -> impl Iterator<Item = &CatalogEntry> {
+        self.entries.values()
+    }
+
+    ///
+    /// Return the number of registered entries.
+    ///
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    ///
+    /// Return `true` if no entries are registered.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///
+    /// Construct a new error from the entry registered with `code`, see
+    /// [`CatalogEntry::new_error`]. If no entry is registered with `code` the error has the
+    /// message "unknown error code" followed by `code`, the class [`ErrorClass::Internal`], and
+    /// no code, so that the mistake is not hidden.
+    ///
+    pub fn new_error<A, N, V>(&self, code: &str, args: A) -> FlatError
+    where
+        A: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Display,
+    {
+        match self.get(code) {
+            Some(entry) => entry.new_error(args),
+            None => FlatError::new(alloc::format!("unknown error code `{code}`"))
+                .with_class(ErrorClass::Internal),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl CatalogEntry {
+    ///
+    /// Construct a new entry with `code` and the message `template`, the class of errors
+    /// constructed from it is determined as for any error, and the severity is
+    /// [`Severity::Error`].
+    ///
+    pub fn new<C, T>(code: C, template: T) -> Self
+    where
+        C: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            code: code.into(),
+            template: template.into(),
+            class: None,
+            severity: Severity::default(),
+            help: None,
+            docs_url: None,
+        }
+    }
+
+    ///
+    /// Return this entry with the class given to errors constructed from it.
+    ///
+    pub fn with_class(self, class: ErrorClass) -> Self {
+        Self {
+            class: Some(class),
+            ..self
+        }
+    }
+
+    ///
+    /// Return this entry with the severity given to errors constructed from it.
+    ///
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self { severity, ..self }
+    }
+
+    ///
+    /// Return this entry with the help text given to errors constructed from it.
+    ///
+    pub fn with_help<S: Into<String>>(self, help: S) -> Self {
+        Self {
+            help: Some(help.into()),
+            ..self
+        }
+    }
+
+    ///
+    /// Return this entry with the documentation URL given to errors constructed from it.
+    ///
+    pub fn with_docs_url<S: Into<String>>(self, url: S) -> Self {
+        Self {
+            docs_url: Some(url.into()),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the code of this entry.
+    ///
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    ///
+    /// Return the message template of this entry.
+    ///
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    ///
+    /// Return the class given to errors constructed from this entry, if any.
+    ///
+    pub fn class(&self) -> Option<ErrorClass> {
+        self.class
+    }
+
+    ///
+    /// Return the severity given to errors constructed from this entry.
+    ///
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    ///
+    /// Return the help text given to errors constructed from this entry, if any.
+    ///
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    ///
+    /// Return the documentation URL given to errors constructed from this entry, if any.
+    ///
+    pub fn docs_url(&self) -> Option<&str> {
+        self.docs_url.as_deref()
+    }
+
+    ///
+    /// Construct a new error from the template of this entry and `args`, see
+    /// [`FlatError::templated`], with the code, class, severity, help text, and documentation
+    /// URL of this entry. The code is also attached as the message key, see
+    /// [`FlatError::message_key`].
+    ///
+    pub fn new_error<A, N, V>(&self, args: A) -> FlatError
+    where
+        A: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Display,
+    {
+        let mut error = FlatError::templated(self.template.clone(), args)
+            .with_code(self.code.clone())
+            .with_severity(self.severity);
+        error.inner.frame.details_mut().message_key = Some(self.code.clone());
+        if let Some(class) = self.class {
+            error = error.with_class(class);
+        }
+        if let Some(help) = &self.help {
+            error = error.with_help(help.clone());
+        }
+        if let Some(url) = &self.docs_url {
+            error = error.with_docs_url(url.clone());
+        }
+        error
+    }
+}
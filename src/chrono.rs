@@ -0,0 +1,30 @@
+/*!
+Provides an implementation of `From<chrono::ParseError>` for [`FlatError`]. The parse error does
+not record a position within the input, so the kind of the error is captured instead as the
+field `kind`.
+
+```rust
+use chrono::NaiveDate;
+use flat_error::FlatError;
+
+let err = FlatError::from(NaiveDate::parse_from_str("2024-02-30", "%Y-%m-%d").unwrap_err());
+
+assert_eq!(err.to_string(), "input is out of range");
+assert_eq!(err.field("kind"), Some("OutOfRange"));
+```
+
+*/
+
+use crate::FlatError;
+use ::chrono::ParseError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<ParseError> for FlatError {
+    #[track_caller]
+    fn from(e: ParseError) -> Self {
+        FlatError::from_error(&e).with_field("kind", format_args!("{:?}", e.kind()))
+    }
+}
@@ -0,0 +1,100 @@
+/*!
+Provides an implementation of `From<clap::Error>` for [`FlatError`]. The kind of the error is
+captured as the field `clap_kind`, and for the pseudo-errors used by `clap` to display help or
+version information, rather than report a usage error, the field `clap_display` is set to
+`true`, see [`FlatError::is_clap_display`].
+
+The exit code of an error flattened from a `clap::Error`, see [`FlatError::exit_code`], is that
+used by `clap` itself, `0` for help or version information and `2` for a usage error; and a
+[`FlatExit`](crate::FlatExit) holding help or version information writes it to standard output,
+rather than standard error, when returned from `main`.
+
+```rust
+use clap::{error::ErrorKind, Arg, Command};
+use flat_error::FlatError;
+
+let command = Command::new("app").arg(Arg::new("name").long("name"));
+
+let err = FlatError::from(command.clone().try_get_matches_from(["app", "--nmae"]).unwrap_err());
+assert_eq!(err.clap_kind(), Some("UnknownArgument"));
+assert!(!err.is_clap_display());
+assert_eq!(err.exit_code(), 2);
+
+let err = FlatError::from(command.try_get_matches_from(["app", "--help"]).unwrap_err());
+assert_eq!(err.clap_kind(), Some("DisplayHelp"));
+assert!(err.is_clap_display());
+assert_eq!(err.exit_code(), 0);
+```
+*/
+
+use crate::{ErrorClass, FlatError};
+use ::clap::error::{Error, ErrorFormatter};
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const CLAP_KIND_FIELD: &str = "clap_kind";
+
+const CLAP_DISPLAY_FIELD: &str = "clap_display";
+
+///
+/// The exit code used by `clap` for a usage error.
+///
+const USAGE_EXIT_CODE: u8 = 2;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<F> From<Error<F>> for FlatError
+where
+    F: ErrorFormatter + 'static,
+{
+    #[track_caller]
+    fn from(e: Error<F>) -> Self {
+        let flat =
+            FlatError::from_error(&e).with_field(CLAP_KIND_FIELD, format_args!("{:?}", e.kind()));
+        if e.use_stderr() {
+            flat.with_class(ErrorClass::InvalidInput)
+        } else {
+            flat.with_field(CLAP_DISPLAY_FIELD, true)
+        }
+    }
+}
+
+impl<C> FlatError<C> {
+    ///
+    /// Return the name of the `clap::error::ErrorKind` of this error, or of the first error in
+    /// its source chain flattened from a `clap::Error`, for example `"InvalidValue"`.
+    ///
+    pub fn clap_kind(&self) -> Option<&str> {
+        self.chain_frames()
+            .find_map(|frame| frame.field(CLAP_KIND_FIELD))
+    }
+
+    ///
+    /// Returns `true` if this error, or an error in its source chain, was flattened from a
+    /// `clap::Error` used to display help or version information rather than to report a usage
+    /// error. The message of such an error is the information to display, which should be
+    /// written to standard output.
+    ///
+    pub fn is_clap_display(&self) -> bool {
+        self.chain_frames()
+            .any(|frame| frame.field(CLAP_DISPLAY_FIELD).is_some())
+    }
+
+    ///
+    /// Return the exit code used by `clap` for this error, if it was flattened from a
+    /// `clap::Error`.
+    ///
+    pub(crate) fn clap_exit_code(&self) -> Option<u8> {
+        self.clap_kind().map(|_| {
+            if self.is_clap_display() {
+                0
+            } else {
+                USAGE_EXIT_CODE
+            }
+        })
+    }
+}
@@ -0,0 +1,396 @@
+/*!
+Provides the [`ErrorClass`] type, a coarse classification of a [`FlatError`](crate::FlatError)
+that may be used to route errors, or to map them onto protocol status codes.
+*/
+
+#[cfg(feature = "alloc")]
+use crate::{short_type_name, FlatError, Frame};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A coarse classification of an error, independent of its original type. The default is
+/// `Internal`, for errors that cannot otherwise be classified.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub enum ErrorClass {
+    /// A requested entity was not found.
+    NotFound,
+    /// The caller is not permitted to perform the operation.
+    PermissionDenied,
+    /// The caller could not be identified.
+    Unauthenticated,
+    /// An argument or input value was not valid.
+    InvalidInput,
+    /// The operation conflicts with the current state, for example an entity already exists.
+    Conflict,
+    /// The operation did not complete in time.
+    Timeout,
+    /// A service or resource is temporarily unavailable, the operation may be retried.
+    Unavailable,
+    /// A resource, such as memory, storage, or a quota, has been exhausted.
+    ResourceExhausted,
+    /// The operation was cancelled, or interrupted.
+    Cancelled,
+    /// The operation is not supported.
+    Unsupported,
+    /// An internal error, or one that could not be classified.
+    #[default]
+    Internal,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The short type names of errors, from `core` and `std`, that are classified as
+/// [`ErrorClass::InvalidInput`].
+///
+#[cfg(feature = "alloc")]
+const INVALID_INPUT_TYPE_NAMES: &[&str] = &[
+    "AddrParseError",
+    "CharTryFromError",
+    "FromUtf8Error",
+    "FromUtf16Error",
+    "ParseBoolError",
+    "ParseCharError",
+    "ParseFloatError",
+    "ParseIntError",
+    "TryFromIntError",
+    "Utf8Error",
+];
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ErrorClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+impl ErrorClass {
+    const ALL: [Self; 11] = [
+        Self::NotFound,
+        Self::PermissionDenied,
+        Self::Unauthenticated,
+        Self::InvalidInput,
+        Self::Conflict,
+        Self::Timeout,
+        Self::Unavailable,
+        Self::ResourceExhausted,
+        Self::Cancelled,
+        Self::Unsupported,
+        Self::Internal,
+    ];
+
+    ///
+    /// Return the name of this class, as used by `Display`.
+    ///
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::NotFound => "not_found",
+            Self::PermissionDenied => "permission_denied",
+            Self::Unauthenticated => "unauthenticated",
+            Self::InvalidInput => "invalid_input",
+            Self::Conflict => "conflict",
+            Self::Timeout => "timeout",
+            Self::Unavailable => "unavailable",
+            Self::ResourceExhausted => "resource_exhausted",
+            Self::Cancelled => "cancelled",
+            Self::Unsupported => "unsupported",
+            Self::Internal => "internal",
+        }
+    }
+
+    ///
+    /// Return the class with the given name, as returned by [`ErrorClass::name`].
+    ///
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|class| class.name() == name)
+    }
+
+    ///
+    /// Return the class corresponding to the kind of a `std::io::Error`, if any.
+    ///
+    #[cfg(feature = "std")]
+    pub fn from_io_kind(kind: ::std::io::ErrorKind) -> Option<Self> {
+        use ::std::io::ErrorKind;
+        Some(match kind {
+            ErrorKind::NotFound => Self::NotFound,
+            ErrorKind::PermissionDenied => Self::PermissionDenied,
+            ErrorKind::AlreadyExists | ErrorKind::AddrInUse => Self::Conflict,
+            ErrorKind::InvalidInput | ErrorKind::InvalidData => Self::InvalidInput,
+            ErrorKind::TimedOut | ErrorKind::WouldBlock => Self::Timeout,
+            ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::AddrNotAvailable
+            | ErrorKind::BrokenPipe => Self::Unavailable,
+            ErrorKind::OutOfMemory => Self::ResourceExhausted,
+            ErrorKind::Interrupted => Self::Cancelled,
+            ErrorKind::Unsupported => Self::Unsupported,
+            _ => return None,
+        })
+    }
+
+    ///
+    /// Return the kind of a `std::io::Error` that best corresponds to this class, if any.
+    ///
+    #[cfg(feature = "std")]
+    const fn io_kind(self) -> Option<::std::io::ErrorKind> {
+        use ::std::io::ErrorKind;
+        Some(match self {
+            Self::NotFound => ErrorKind::NotFound,
+            Self::PermissionDenied => ErrorKind::PermissionDenied,
+            Self::InvalidInput => ErrorKind::InvalidInput,
+            Self::Conflict => ErrorKind::AlreadyExists,
+            Self::Timeout => ErrorKind::TimedOut,
+            Self::Cancelled => ErrorKind::Interrupted,
+            Self::Unsupported => ErrorKind::Unsupported,
+            _ => return None,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl<C> FlatError<C> {
+    ///
+    /// Return a copy of this error with its class set to `class`; this is retained when the
+    /// error is serialized.
+    ///
+    pub fn with_class(mut self, class: ErrorClass) -> Self {
+        self.inner.frame.details_mut().class = Some(class);
+        self
+    }
+
+    ///
+    /// Return the class of this error. This is the class of the first error in the source
+    /// chain, starting with this error, that either has a class set by
+    /// [`FlatError::with_class`], or can be classified from its original type; the kind of a
+    /// `std::io::Error`, common parse errors, and panics are classified. If no error in the
+    /// chain can be classified the class is [`ErrorClass::Internal`].
+    ///
+    /// ```rust
+    /// use flat_error::{ErrorClass, FlatError};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("loading profile");
+    /// assert_eq!(err.class(), ErrorClass::NotFound);
+    ///
+    /// let err = FlatError::from("abc".parse::<u32>().unwrap_err());
+    /// assert_eq!(err.class(), ErrorClass::InvalidInput);
+    ///
+    /// let err = FlatError::new("database busy").with_class(ErrorClass::Unavailable);
+    /// assert_eq!(err.class(), ErrorClass::Unavailable);
+    /// ```
+    ///
+    pub fn class(&self) -> ErrorClass {
+        self.chain_class().unwrap_or_default()
+    }
+
+    ///
+    /// Return the class set on this error by [`FlatError::with_class`], if any, without
+    /// considering its original type or source chain.
+    ///
+    pub fn explicit_class(&self) -> Option<ErrorClass> {
+        self.inner.frame.details().class
+    }
+
+    ///
+    /// Return the class of the first error in the source chain, starting with this error, that
+    /// can be classified, if any; see [`FlatError::class`].
+    ///
+    pub(crate) fn chain_class(&self) -> Option<ErrorClass> {
+        self.chain_frames().find_map(Frame::class)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` with `message`, as [`FlatError::new`] does, and with its class
+    /// set to `class`. With the **std** feature, if the class corresponds to the kind of a
+    /// `std::io::Error` that kind is also set, see [`FlatError::from_io_kind`], so that the
+    /// error keeps its meaning when converted into a [`FlatIoError`](crate::FlatIoError).
+    ///
+    /// The shorthand constructors below, such as [`FlatError::not_found`], call this with the
+    /// corresponding class.
+    ///
+    /// ```rust
+    /// use flat_error::{ErrorClass, FlatError};
+    /// use std::io::ErrorKind;
+    ///
+    /// let err = FlatError::classified(ErrorClass::NotFound, "no such user");
+    /// assert_eq!(err.class(), ErrorClass::NotFound);
+    /// assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+    ///
+    /// let err = FlatError::unavailable("database busy");
+    /// assert_eq!(err.class(), ErrorClass::Unavailable);
+    /// assert_eq!(err.io_kind(), None);
+    /// ```
+    ///
+    pub fn classified<S>(class: ErrorClass, message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        #[cfg(feature = "std")]
+        let error = match class.io_kind() {
+            Some(kind) => Self::from_io_kind(kind, message),
+            None => Self::new(message),
+        };
+        #[cfg(not(feature = "std"))]
+        let error = Self::new(message);
+        error.with_class(class)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::NotFound`].
+    ///
+    pub fn not_found<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::NotFound, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::PermissionDenied`].
+    ///
+    pub fn permission_denied<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::PermissionDenied, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Unauthenticated`].
+    ///
+    pub fn unauthenticated<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Unauthenticated, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::InvalidInput`].
+    ///
+    pub fn invalid_input<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::InvalidInput, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Conflict`].
+    ///
+    pub fn conflict<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Conflict, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Timeout`].
+    ///
+    pub fn timeout<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Timeout, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Unavailable`].
+    ///
+    pub fn unavailable<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Unavailable, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::ResourceExhausted`].
+    ///
+    pub fn resource_exhausted<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::ResourceExhausted, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Cancelled`].
+    ///
+    pub fn cancelled<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Cancelled, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Unsupported`].
+    ///
+    pub fn unsupported<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Unsupported, message)
+    }
+
+    ///
+    /// Construct a new `FlatError` with `message`, and the class [`ErrorClass::Internal`].
+    ///
+    pub fn internal<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::classified(ErrorClass::Internal, message)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl Frame {
+    pub(crate) fn class(&self) -> Option<ErrorClass> {
+        if let Some(class) = self.details().class {
+            return Some(class);
+        }
+        #[cfg(feature = "std")]
+        if let Some(class) = self.details().io_kind.and_then(ErrorClass::from_io_kind) {
+            return Some(class);
+        }
+        #[cfg(feature = "std")]
+        if self.original_type_name == crate::PANIC_TYPE_NAME {
+            return Some(ErrorClass::Internal);
+        }
+        INVALID_INPUT_TYPE_NAMES
+            .contains(&short_type_name(self.original_type_name))
+            .then_some(ErrorClass::InvalidInput)
+    }
+}
@@ -0,0 +1,101 @@
+/*!
+Provides [`FlatError::to_codespan_diagnostic`], converting an error with attached source spans,
+see [`SourceSpan`](crate::SourceSpan), into a
+[`codespan_reporting::diagnostic::Diagnostic`](https://docs.rs/codespan-reporting) so that it
+may be printed with an annotated snippet of the source.
+
+```rust
+use codespan_reporting::diagnostic::{LabelStyle, Severity};
+use flat_error::{FlatError, SourceSpan};
+
+let source = "port: 80\nhost: [1, 2]\n";
+let err = FlatError::new_static("expected a string")
+    .with_span(SourceSpan::from_range(source, 6..12))
+    .context("invalid value for `host`")
+    .with_code("E0308");
+
+let diagnostic = err.to_codespan_diagnostic(0_usize);
+assert_eq!(diagnostic.severity, Severity::Error);
+assert_eq!(diagnostic.code.as_deref(), Some("E0308"));
+assert_eq!(diagnostic.message, "invalid value for `host`");
+assert_eq!(diagnostic.labels[0].style, LabelStyle::Primary);
+assert_eq!(diagnostic.labels[0].range, 6..12);
+assert_eq!(diagnostic.labels[0].message, "expected a string");
+```
+
+*/
+
+use crate::{FlatError, Severity};
+use ::codespan_reporting::diagnostic::{Diagnostic, Label, Severity as CodespanSeverity};
+use alloc::{format, string::ToString, vec::Vec};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Convert this error into a `codespan_reporting` diagnostic for the source file
+    /// `file_id`. The diagnostic has the message, code, and severity of this error and;
+    ///
+    /// * a primary label for the first span in the source chain, see [`FlatError::span`],
+    ///   with the message of the error it is attached to, if that is not this error,
+    /// * a secondary label for each further span in the source chain, and for each related
+    ///   error with a span, see [`FlatError::with_related`], with the message of that error,
+    /// * a note, `caused by: …`, for each source without a span,
+    /// * a note for any help text, `help: …`, and documentation URL, `see: …`.
+    ///
+    /// All spans are assumed to be within the same file.
+    ///
+    pub fn to_codespan_diagnostic<FileId>(&self, file_id: FileId) -> Diagnostic<FileId>
+    where
+        FileId: Copy,
+    {
+        let mut labels: Vec<Label<FileId>> = Vec::new();
+        let mut notes = Vec::new();
+        for (index, frame) in self.chain_frames().enumerate() {
+            match frame.details().span {
+                Some(span) => {
+                    let label = if labels.is_empty() {
+                        Label::primary(file_id, span.range())
+                    } else {
+                        Label::secondary(file_id, span.range())
+                    };
+                    labels.push(if index == 0 {
+                        label
+                    } else {
+                        label.with_message(&frame.message)
+                    });
+                }
+                None if index > 0 => notes.push(format!("caused by: {}", frame.message)),
+                None => {}
+            }
+        }
+        for related in self.related() {
+            if let Some(span) = related.span() {
+                labels.push(Label::secondary(file_id, span.range()).with_message(related));
+            } else {
+                notes.push(related.to_string());
+            }
+        }
+        if let Some(help) = self.help() {
+            notes.push(format!("help: {help}"));
+        }
+        if let Some(url) = self.docs_url() {
+            notes.push(format!("see: {url}"));
+        }
+
+        let diagnostic = Diagnostic::new(match self.severity() {
+            Severity::Info => CodespanSeverity::Note,
+            Severity::Warning => CodespanSeverity::Warning,
+            Severity::Error | Severity::Critical => CodespanSeverity::Error,
+        })
+        .with_message(&self.inner.frame.message)
+        .with_labels(labels)
+        .with_notes(notes);
+        match self.code() {
+            Some(code) => diagnostic.with_code(code),
+            None => diagnostic,
+        }
+    }
+}
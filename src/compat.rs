@@ -0,0 +1,22 @@
+/*!
+Items whose location depends on the version of the compiler, so that the crate builds with
+toolchains that predate them when the `std` feature is enabled; the version is detected by the
+build script.
+
+The `Error` trait was moved into `core` in Rust 1.81, and remains available from `std`, so it is
+taken from `std` whenever that is available. Without `std` it is taken from `core`, requiring
+Rust 1.81 or later.
+*/
+
+#[cfg(not(feature = "std"))]
+pub use core::error::Error;
+#[cfg(feature = "std")]
+pub use std::error::Error;
+
+///
+/// The type passed to a panic hook, named `PanicInfo` before Rust 1.81.
+///
+#[cfg(all(feature = "std", has_panic_hook_info))]
+pub(crate) use std::panic::PanicHookInfo;
+#[cfg(all(feature = "std", not(has_panic_hook_info)))]
+pub(crate) use std::panic::PanicInfo as PanicHookInfo;
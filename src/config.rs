@@ -0,0 +1,400 @@
+/*!
+Provides the [`FlatErrorConfig`] type, a single process-wide configuration of the defaults used
+as errors are flattened, captured, and rendered. The configuration is installed once, early in
+the life of the application, and is read thereafter without locking.
+
+```rust
+use flat_error::{DisplayStyle, FlatError, FlatErrorConfig};
+use std::io::Error as IoError;
+
+FlatErrorConfig::new()
+    .with_max_message_len(16)
+    .with_location_capture(true)
+    .with_display_style(DisplayStyle::CausedBy)
+    .install()
+    .expect("the configuration is installed once");
+
+let err = FlatError::from_any(&IoError::other("a very long message indeed"));
+assert_eq!(err.to_string(), "a very long mess…");
+assert_eq!(err.location().unwrap().file(), file!());
+assert_eq!(DisplayStyle::configured(), DisplayStyle::CausedBy);
+```
+
+This is the only global state affecting how errors are flattened. Each setting is taken from the
+first of the following that provides it:
+
+1. the [`FlattenOptions`](crate::FlattenOptions) in use, where the setting is one of its options,
+   or the redactor given to [`FlatError::from_any_redacted`](crate::FlatError::from_any_redacted);
+2. the installed configuration;
+3. the default configuration, as returned by [`FlatErrorConfig::new`].
+*/
+
+use crate::{DisplayStyle, FlattenHook, Frame, Redactor, BACKTRACE_FIELD, DEFAULT_MAX_DEPTH};
+use alloc::{string::ToString, sync::Arc};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    panic::Location,
+};
+use std::{backtrace::Backtrace, sync::OnceLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The process-wide defaults used as errors are flattened, captured, and rendered; see
+/// [`FlatErrorConfig::install`]. Until a configuration is installed the defaults returned by
+/// [`FlatErrorConfig::new`] are used.
+///
+#[derive(Clone)]
+pub struct FlatErrorConfig {
+    max_depth: usize,
+    max_message_len: Option<usize>,
+    capture_backtraces: bool,
+    capture_locations: bool,
+    capture_timestamps: bool,
+    sanitize_messages: bool,
+    intern_messages: bool,
+    redactor: Option<Arc<dyn Redactor + Send + Sync>>,
+    flatten_hook: Option<FlattenHook>,
+    display_style: DisplayStyle,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+static INSTALLED_CONFIG: OnceLock<FlatErrorConfig> = OnceLock::new();
+
+static DEFAULT_CONFIG: FlatErrorConfig = FlatErrorConfig::new();
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for FlatErrorConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("FlatErrorConfig")
+            .field("max_depth", &self.max_depth)
+            .field("max_message_len", &self.max_message_len)
+            .field("capture_backtraces", &self.capture_backtraces)
+            .field("capture_locations", &self.capture_locations)
+            .field("capture_timestamps", &self.capture_timestamps)
+            .field("sanitize_messages", &self.sanitize_messages)
+            .field("intern_messages", &self.intern_messages)
+            .field("redactor", &self.redactor.is_some())
+            .field("flatten_hook", &self.flatten_hook.is_some())
+            .field("display_style", &self.display_style)
+            .finish()
+    }
+}
+
+impl Default for FlatErrorConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlatErrorConfig {
+    ///
+    /// Construct a new configuration with default values; the maximum depth is
+    /// [`DEFAULT_MAX_DEPTH`], messages are not truncated, timestamps are captured with the
+    /// **timestamp** feature, backtraces and locations are not captured, messages are neither
+    /// sanitized nor interned, there is neither a redactor nor a flatten hook, and the display
+    /// style is [`DisplayStyle::Compact`].
+    ///
+    pub const fn new() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_message_len: None,
+            capture_backtraces: false,
+            capture_locations: false,
+            capture_timestamps: true,
+            sanitize_messages: false,
+            intern_messages: false,
+            redactor: None,
+            flatten_hook: None,
+            display_style: DisplayStyle::Compact,
+        }
+    }
+
+    ///
+    /// Install this configuration, returning it as an error if a configuration has already been
+    /// installed. A configuration may only be installed once, and should be installed before any
+    /// error is flattened.
+    ///
+    pub fn install(self) -> Result<(), Self> {
+        INSTALLED_CONFIG.set(self)
+    }
+
+    ///
+    /// Return the installed configuration, or the default configuration if none has been
+    /// installed.
+    ///
+    pub fn global() -> &'static Self {
+        INSTALLED_CONFIG.get().unwrap_or(&DEFAULT_CONFIG)
+    }
+
+    ///
+    /// Return a copy of this configuration with the maximum number of errors in the source chain
+    /// that will be flattened set to `max_depth`, when not set on the [`FlattenOptions`](crate::FlattenOptions) in use.
+    ///
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    ///
+    /// Return the maximum number of errors in the source chain that will be flattened.
+    ///
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    ///
+    /// Return a copy of this configuration with the maximum length, in bytes, of each captured
+    /// message set to `max_len`, when not set on the [`FlattenOptions`](crate::FlattenOptions) in use; see
+    /// [`FlattenOptions::with_max_message_len`](crate::FlattenOptions::with_max_message_len).
+    ///
+    pub fn with_max_message_len(self, max_len: usize) -> Self {
+        Self {
+            max_message_len: Some(max_len),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the maximum length, in bytes, of each captured message, if any.
+    ///
+    pub const fn max_message_len(&self) -> Option<usize> {
+        self.max_message_len
+    }
+
+    ///
+    /// Return a copy of this configuration with backtrace capture set to `capture`. When
+    /// enabled, a backtrace is captured as the field [`BACKTRACE_FIELD`] of each error as it is
+    /// flattened and of each panic, whether or not backtraces are enabled by the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables. Capturing a backtrace is
+    /// expensive, and this is best reserved for diagnosing problems.
+    ///
+    pub fn with_backtrace_capture(self, capture: bool) -> Self {
+        Self {
+            capture_backtraces: capture,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if backtraces are captured.
+    ///
+    pub const fn capture_backtraces(&self) -> bool {
+        self.capture_backtraces
+    }
+
+    ///
+    /// Return a copy of this configuration with location capture set to `capture`. When
+    /// enabled, the location of the call that flattened each error, such as a call to
+    /// [`FlatError::from_any`](crate::FlatError::from_any), is captured, see
+    /// [`FlatError::location`](crate::FlatError::location).
+    ///
+    pub fn with_location_capture(self, capture: bool) -> Self {
+        Self {
+            capture_locations: capture,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if locations are captured.
+    ///
+    pub const fn capture_locations(&self) -> bool {
+        self.capture_locations
+    }
+
+    ///
+    /// Return a copy of this configuration with timestamp capture set to `capture`. This has no
+    /// effect without the **timestamp** feature, which captures the time at which each error is
+    /// created unless disabled here.
+    ///
+    pub fn with_timestamp_capture(self, capture: bool) -> Self {
+        Self {
+            capture_timestamps: capture,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if timestamps are captured, with the **timestamp** feature.
+    ///
+    pub const fn capture_timestamps(&self) -> bool {
+        self.capture_timestamps
+    }
+
+    ///
+    /// Return a copy of this configuration with message sanitization set to `sanitize`, when not
+    /// set on the [`FlattenOptions`](crate::FlattenOptions) in use; see
+    /// [`FlattenOptions::with_sanitized_messages`](crate::FlattenOptions::with_sanitized_messages).
+    ///
+    pub fn with_sanitized_messages(self, sanitize: bool) -> Self {
+        Self {
+            sanitize_messages: sanitize,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if messages are sanitized.
+    ///
+    pub const fn sanitize_messages(&self) -> bool {
+        self.sanitize_messages
+    }
+
+    ///
+    /// Return a copy of this configuration with message interning set to `intern`, when not set
+    /// on the [`FlattenOptions`](crate::FlattenOptions) in use; see
+    /// [`FlattenOptions::with_interned_messages`](crate::FlattenOptions::with_interned_messages).
+    ///
+    pub fn with_interned_messages(self, intern: bool) -> Self {
+        Self {
+            intern_messages: intern,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if messages are interned.
+    ///
+    pub const fn intern_messages(&self) -> bool {
+        self.intern_messages
+    }
+
+    ///
+    /// Return a copy of this configuration with `redactor` applied to the message, and any
+    /// captured `Debug` form, of each error, and of each error in its source chain, as it is
+    /// flattened by [`FlatError::from_any`](crate::FlatError::from_any) and the other
+    /// constructors and `From` implementations that flatten an error; but not by
+    /// [`FlatError::from_any_redacted`](crate::FlatError::from_any_redacted), which uses the
+    /// redactor it is given.
+    ///
+    pub fn with_redactor<R>(self, redactor: R) -> Self
+    where
+        R: Redactor + Send + Sync + 'static,
+    {
+        Self {
+            redactor: Some(Arc::new(redactor)),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the redactor applied to each error as it is flattened, if any.
+    ///
+    pub fn redactor(&self) -> Option<&(dyn Redactor + Send + Sync)> {
+        self.redactor.as_deref()
+    }
+
+    ///
+    /// Return a copy of this configuration with `hook` called each time an error is flattened by
+    /// [`FlatError::from_any`](crate::FlatError::from_any),
+    /// [`FlatError::from_error`](crate::FlatError::from_error), and the other constructors and
+    /// `From` implementations that flatten an error; it is not called by constructors such as
+    /// [`FlatError::new`](crate::FlatError::new) or for the errors in a source chain. The hook is
+    /// called once the messages are redacted, sanitized, and truncated.
+    ///
+    /// This allows values such as request identifiers to be attached to all errors in one place,
+    /// or messages to be redacted. The hook should not itself flatten errors, as it would be
+    /// called recursively.
+    ///
+    /// As the error being flattened need not be `'static` the hook cannot downcast it, although
+    /// the errors in its source chain may be downcast.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlatErrorConfig};
+    /// use std::{
+    ///     io::{Error as IoError, ErrorKind},
+    ///     mem,
+    /// };
+    ///
+    /// FlatErrorConfig::new()
+    ///     .with_flatten_hook(|error, builder| {
+    ///         *builder = mem::take(builder).field("service", "billing");
+    ///         if error.to_string().contains("password") {
+    ///             *builder = mem::take(builder).message("[redacted]");
+    ///         }
+    ///     })
+    ///     .install()
+    ///     .unwrap();
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    /// assert_eq!(err.field("service"), Some("billing"));
+    ///
+    /// let err = FlatError::from(IoError::other("bad password: hunter2"));
+    /// assert_eq!(err.to_string(), "[redacted]");
+    /// ```
+    ///
+    pub fn with_flatten_hook(self, hook: FlattenHook) -> Self {
+        Self {
+            flatten_hook: Some(hook),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the hook called each time an error is flattened, if any.
+    ///
+    pub const fn flatten_hook(&self) -> Option<FlattenHook> {
+        self.flatten_hook
+    }
+
+    ///
+    /// Return a copy of this configuration with the display style returned by
+    /// [`DisplayStyle::configured`] set to `style`.
+    ///
+    pub fn with_display_style(self, style: DisplayStyle) -> Self {
+        Self {
+            display_style: style,
+            ..self
+        }
+    }
+
+    ///
+    /// Return the configured display style.
+    ///
+    pub const fn display_style(&self) -> DisplayStyle {
+        self.display_style
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl DisplayStyle {
+    ///
+    /// Return the display style of the installed [`FlatErrorConfig`], or
+    /// [`DisplayStyle::Compact`] if none has been installed. This is independent of
+    /// `DisplayStyle::default()`, which is always [`DisplayStyle::Compact`].
+    ///
+    pub fn configured() -> Self {
+        FlatErrorConfig::global().display_style()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Frame {
+    ///
+    /// Capture the location of the caller and a backtrace, if enabled by the installed
+    /// configuration.
+    ///
+    #[track_caller]
+    pub(crate) fn capture_configured(&mut self) {
+        let config = FlatErrorConfig::global();
+        if config.capture_locations {
+            self.details_mut().location.0 = Some(Location::caller());
+        }
+        if config.capture_backtraces {
+            self.set_field(
+                BACKTRACE_FIELD.into(),
+                Backtrace::force_capture().to_string(),
+            );
+        }
+    }
+}
@@ -0,0 +1,144 @@
+/*!
+Provides [`FlatError::write_crash_report`], writing an error, its source chain and metadata, any
+backtrace, and a summary of the environment in which it occurred to a new, timestamped, file;
+so that an application may tell the user where a report was written rather than print the
+details of an error they cannot act on.
+
+```rust,no_run
+use flat_error::FlatError;
+use std::{env, process::ExitCode};
+
+fn run() -> Result<(), FlatError> {
+    Err(FlatError::new("unexpected state").context("saving document"))
+}
+
+fn main() -> ExitCode {
+    if let Err(e) = run() {
+        match e.write_crash_report(&env::temp_dir()) {
+            Ok(path) => eprintln!("{e}, a report was written to {}", path.display()),
+            Err(_) => eprintln!("{e}"),
+        }
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+```
+*/
+
+use crate::{FlatError, BACKTRACE_FIELD};
+use alloc::string::{String, ToString};
+use core::fmt::{Result as FmtResult, Write as _};
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    env,
+    fs::OpenOptions,
+    io::{Result as IoResult, Write as _},
+    path::{Path, PathBuf},
+    process, thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Write a crash report for this error to a new file in `dir`, returning the path of the
+    /// file. The file is named `crash-report-{timestamp}-{process id}.txt`, where the timestamp
+    /// is in milliseconds since the Unix epoch, and an existing file is never overwritten.
+    ///
+    /// The report includes the time, the executable, process id, thread, operating system, and
+    /// architecture, followed by this error and each error in its source chain and branches with
+    /// their type names, codes, locations, and metadata fields. Finally, any backtrace captured
+    /// as the field [`BACKTRACE_FIELD`] is written, or if there is none and backtraces are
+    /// enabled, a backtrace of the call to this method.
+    ///
+    pub fn write_crash_report(&self, dir: &Path) -> IoResult<PathBuf> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let path = dir.join(alloc::format!("crash-report-{now}-{}.txt", process::id()));
+        let mut report = String::new();
+        self.render_crash_report(&mut report, now)
+            .expect("writing to a String cannot fail");
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        file.write_all(report.as_bytes())?;
+        file.sync_all()?;
+        Ok(path)
+    }
+
+    ///
+    /// Write the content of a crash report, created at `now`, to `out`.
+    ///
+    fn render_crash_report(&self, out: &mut String, now: u128) -> FmtResult {
+        writeln!(out, "Crash Report")?;
+        writeln!(out, "============")?;
+        writeln!(out)?;
+        writeln!(out, "Time:         {now} ms since the Unix epoch")?;
+        if let Ok(executable) = env::current_exe() {
+            writeln!(out, "Executable:   {}", executable.display())?;
+        }
+        writeln!(out, "Process id:   {}", process::id())?;
+        writeln!(
+            out,
+            "Thread:       {}",
+            thread::current().name().unwrap_or("<unnamed>")
+        )?;
+        writeln!(
+            out,
+            "OS:           {} {} ({})",
+            env::consts::OS,
+            env::consts::ARCH,
+            env::consts::FAMILY
+        )?;
+
+        writeln!(out)?;
+        writeln!(out, "Error")?;
+        writeln!(out, "-----")?;
+        writeln!(out)?;
+        let mut backtrace = None;
+        for (depth, frame) in self.tree_frames() {
+            let indent = "  ".repeat(depth);
+            let mut lines = frame.message().lines();
+            writeln!(out, "{indent}- {}", lines.next().unwrap_or_default())?;
+            for line in lines {
+                writeln!(out, "{indent}  {line}")?;
+            }
+            writeln!(out, "{indent}  type: {}", frame.original_type_name())?;
+            writeln!(out, "{indent}  severity: {}", frame.severity())?;
+            if let Some(code) = frame.code() {
+                writeln!(out, "{indent}  code: {code}")?;
+            }
+            if let Some(location) = frame.location() {
+                writeln!(out, "{indent}  location: {location}")?;
+            }
+            for (key, value) in frame.fields() {
+                if key == BACKTRACE_FIELD {
+                    backtrace.get_or_insert(value);
+                } else {
+                    writeln!(out, "{indent}  {key}: {value}")?;
+                }
+            }
+        }
+
+        let captured = match backtrace {
+            Some(_) => None,
+            None => Some(Backtrace::capture())
+                .filter(|captured| captured.status() == BacktraceStatus::Captured)
+                .map(|captured| captured.to_string()),
+        };
+        if let Some(backtrace) = backtrace.or(captured.as_deref()) {
+            writeln!(out)?;
+            writeln!(out, "Backtrace")?;
+            writeln!(out, "---------")?;
+            writeln!(out)?;
+            writeln!(out, "{}", backtrace.trim_end())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+/*!
+Provides implementations of `From` for the [`crossbeam_channel`](https://docs.rs/crossbeam-channel)
+send, receive, and select errors. The values carried by the send errors are dropped, as for the
+standard library's `SendError`.
+
+```rust
+use flat_error::FlatError;
+use std::time::Duration;
+
+let (_tx, rx) = crossbeam_channel::unbounded::<String>();
+let err = FlatError::from(rx.recv_timeout(Duration::from_millis(1)).unwrap_err());
+
+assert_eq!(err.to_string(), "timed out waiting on receive operation");
+```
+
+*/
+
+use crate::FlatError;
+use ::crossbeam_channel::{
+    RecvError, RecvTimeoutError, SelectTimeoutError, SendError, SendTimeoutError, TryRecvError,
+    TrySelectError, TrySendError,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+flat_from_impls!(
+    RecvError,
+    RecvTimeoutError,
+    SelectTimeoutError,
+    TryRecvError,
+    TrySelectError,
+);
+
+impl<T> From<SendError<T>> for FlatError
+where
+    T: Send,
+{
+    #[track_caller]
+    fn from(e: SendError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
+
+impl<T> From<SendTimeoutError<T>> for FlatError
+where
+    T: Send,
+{
+    #[track_caller]
+    fn from(e: SendTimeoutError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
+
+impl<T> From<TrySendError<T>> for FlatError
+where
+    T: Send,
+{
+    #[track_caller]
+    fn from(e: TrySendError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
@@ -0,0 +1,90 @@
+/*!
+Provides the [`FlattenCustom`] trait, allowing an error type to control how it is flattened by
+[`FlatError::from_custom`].
+
+```rust
+use flat_error::{ErrorClass, FlatError, FlatErrorBuilder, FlattenCustom};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[derive(Debug)]
+struct QuotaExceeded {
+    tenant: u32,
+    limit: u64,
+}
+
+impl Display for QuotaExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "tenant {} exceeded quota of {} bytes", self.tenant, self.limit)
+    }
+}
+
+impl std::error::Error for QuotaExceeded {}
+
+impl FlattenCustom for QuotaExceeded {
+    fn flatten_custom(&self, builder: FlatErrorBuilder) -> FlatErrorBuilder {
+        builder
+            .message("storage quota exceeded")
+            .code("E0429")
+            .class(ErrorClass::ResourceExhausted)
+            .field("tenant", self.tenant)
+            .field("limit", self.limit)
+    }
+}
+
+let err = FlatError::from_custom(&QuotaExceeded { tenant: 7, limit: 1 << 30 });
+
+assert_eq!(err.to_string(), "storage quota exceeded");
+assert_eq!(err.code(), Some("E0429"));
+assert_eq!(err.class(), ErrorClass::ResourceExhausted);
+assert_eq!(err.field("tenant"), Some("7"));
+assert!(err.is_exact::<QuotaExceeded>());
+```
+*/
+
+use crate::{compat::Error, FlatError, FlatErrorBuilder, FlattenOptions, Redactor};
+use core::any::TypeId;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by error types that control how they are flattened by
+/// [`FlatError::from_custom`], for example to provide a message other than that of their
+/// `Display` implementation, or to attach a code, class, or metadata fields.
+///
+/// As Rust does not allow [`FlatError::from_any`] and `From` to detect this trait, an error is
+/// only flattened this way by calling [`FlatError::from_custom`], and errors in a source chain
+/// are always flattened in the usual way.
+///
+pub trait FlattenCustom: Error + 'static {
+    ///
+    /// Return `builder`, initialized from the usual flattening of this error, with any changes
+    /// this error requires.
+    ///
+    fn flatten_custom(&self, builder: FlatErrorBuilder) -> FlatErrorBuilder;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, as
+    /// [`FlatError::from_error`] does, and then applying [`FlattenCustom::flatten_custom`]. The
+    /// configured redaction, sanitization, message length limit, flatten hook, and interning are
+    /// applied after the error has made its changes.
+    ///
+    #[track_caller]
+    pub fn from_custom<E>(error: &E) -> Self
+    where
+        E: FlattenCustom,
+    {
+        let options = FlattenOptions::new();
+        let mut flat = Self::flatten(error, options).with_std_details(error);
+        flat.inner.frame.type_id = Some(TypeId::of::<E>());
+        let flat = error.flatten_custom(FlatErrorBuilder::from(flat)).build();
+        Self::finish_flatten(flat, error, options, None::<&dyn Redactor>)
+    }
+}
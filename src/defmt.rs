@@ -0,0 +1,39 @@
+/*!
+Provides implementations of [`defmt::Format`](https://docs.rs/defmt) for [`FlatErrorFixed`] and,
+with the `alloc` feature, [`FlatError`](crate::FlatError).
+
+The format used is the same as the alternate `Display` format, including the original type name.
+*/
+
+use crate::FlatErrorFixed;
+use ::defmt::{write, Format, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl Format for crate::FlatError {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} (", &*self.inner.frame.message);
+        if let Some(code) = self.code() {
+            write!(f, "code: `{=str}`, ", code);
+        }
+        if let Some(source) = self.sources().next() {
+            write!(f, "source: {=str}, ", &*source.message);
+        }
+        write!(f, "original type: `{=str}`)", self.original_type_name());
+    }
+}
+
+impl<const N: usize> Format for FlatErrorFixed<N> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=str}{=str} (original type: `{=str}`)",
+            self.message(),
+            if self.is_truncated() { "…" } else { "" },
+            self.original_type_name()
+        );
+    }
+}
@@ -0,0 +1,275 @@
+/*!
+Provides [`FlatError::diff`], and the [`FlatErrorDiff`] report it returns, describing which
+values of which errors in two source chains differ. This is intended for reporting test failures,
+where the `Debug` output of two long chains makes a single differing value hard to find.
+
+```rust
+use flat_error::FlatError;
+
+let expected = FlatError::new("disk full")
+    .with_code("E1042")
+    .context("saving file")
+    .context("closing editor");
+let actual = FlatError::new("disk full")
+    .with_code("E1043")
+    .context("saving file")
+    .context("closing editor");
+
+let diff = actual.diff(&expected);
+assert_eq!(diff.len(), 1);
+assert_eq!(
+    diff.to_string(),
+    r#"frame 2, code: expected Some("E1042"), found Some("E1043")"#
+);
+assert!(expected.diff(&expected).is_empty());
+```
+*/
+
+use crate::{FlatError, Frame};
+use alloc::{format, string::String, vec::Vec};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    slice::Iter,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The differences between an error and the error it was expected to equal, returned by
+/// [`FlatError::diff`]. The report is empty if, and only if, the two errors are equal.
+///
+/// The `Display` implementation writes each difference on its own line, or `no differences` if
+/// there are none.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlatErrorDiff {
+    differences: Vec<Difference>,
+}
+
+///
+/// A single difference in a [`FlatErrorDiff`]; either a value of the same error in each chain,
+/// an error present in only one chain, or the category of the two errors. Values are written
+/// in their `Debug` form.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Difference {
+    frame_index: Option<usize>,
+    field: Option<&'static str>,
+    actual: Option<String>,
+    expected: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C: Debug + PartialEq> FlatError<C> {
+    ///
+    /// Compare this error with `expected`, returning a report of the differences. Errors in the
+    /// two source chains are compared in order, starting with the errors themselves, and each
+    /// value considered by `PartialEq` that differs is reported with the index of the error, as
+    /// used by [`FlatError::frame`]. An error present in only one chain is reported once, rather
+    /// than by value.
+    ///
+    pub fn diff(&self, expected: &FlatError<C>) -> FlatErrorDiff {
+        let mut diff = FlatErrorDiff::default();
+        let mut actual_frames = self.chain_frames();
+        let mut expected_frames = expected.chain_frames();
+        let mut index = 0;
+        loop {
+            match (actual_frames.next(), expected_frames.next()) {
+                (Some(actual), Some(expected)) => diff.compare_frames(index, actual, expected),
+                (Some(actual), None) => diff.push_frame(index, Some(actual), None),
+                (None, Some(expected)) => diff.push_frame(index, None, Some(expected)),
+                (None, None) => break,
+            }
+            index += 1;
+        }
+        diff.compare(
+            None,
+            "category",
+            &self.inner.category,
+            &expected.inner.category,
+        );
+        diff
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatErrorDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.differences.is_empty() {
+            return write!(f, "no differences");
+        }
+        for (i, difference) in self.differences.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{difference}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a FlatErrorDiff {
+    type Item = &'a Difference;
+    type IntoIter = Iter<'a, Difference>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FlatErrorDiff {
+    ///
+    /// Returns `true` if there are no differences, that is the errors are equal.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    ///
+    /// Return the number of differences.
+    ///
+    pub fn len(&self) -> usize {
+        self.differences.len()
+    }
+
+    ///
+    /// Return an iterator over the differences, in chain order, with any difference in category
+    /// last.
+    ///
+    pub fn iter(&self) -> Iter<'_, Difference> {
+        self.differences.iter()
+    }
+
+    fn compare_frames(&mut self, index: usize, actual: &Frame, expected: &Frame) {
+        if actual == expected {
+            return;
+        }
+        let index = Some(index);
+        let (a, e) = (actual.details(), expected.details());
+        self.compare(
+            index,
+            "original_type_name",
+            &actual.original_type_name,
+            &expected.original_type_name,
+        );
+        self.compare(index, "message", &actual.message, &expected.message);
+        self.compare(index, "severity", &actual.severity, &expected.severity);
+        self.compare(index, "code", &a.code, &e.code);
+        self.compare(index, "class", &a.class, &e.class);
+        self.compare(index, "fields", &a.fields, &e.fields);
+        self.compare(
+            index,
+            "original_message_len",
+            &a.original_message_len,
+            &e.original_message_len,
+        );
+        self.compare(index, "message_key", &a.message_key, &e.message_key);
+        self.compare(index, "message_args", &a.message_args, &e.message_args);
+        self.compare(
+            index,
+            "message_template",
+            &a.message_template,
+            &e.message_template,
+        );
+        self.compare(index, "debug_repr", &a.debug_repr, &e.debug_repr);
+        self.compare(index, "repeat_count", &a.repeat_count, &e.repeat_count);
+        self.compare(index, "help", &a.help, &e.help);
+        self.compare(index, "docs_url", &a.docs_url, &e.docs_url);
+        self.compare(index, "span", &a.span, &e.span);
+        self.compare(index, "branches", &a.branches, &e.branches);
+        self.compare(index, "related", &a.related, &e.related);
+        self.compare(index, "num_kind", &a.num_kind, &e.num_kind);
+        #[cfg(feature = "std")]
+        {
+            self.compare(index, "io_kind", &a.io_kind, &e.io_kind);
+            self.compare(index, "os_error_code", &a.os_error_code, &e.os_error_code);
+        }
+    }
+
+    fn compare<T>(
+        &mut self,
+        frame_index: Option<usize>,
+        field: &'static str,
+        actual: &T,
+        expected: &T,
+    ) where
+        T: Debug + PartialEq + ?Sized,
+    {
+        if actual != expected {
+            self.differences.push(Difference {
+                frame_index,
+                field: Some(field),
+                actual: Some(format!("{actual:?}")),
+                expected: Some(format!("{expected:?}")),
+            });
+        }
+    }
+
+    fn push_frame(&mut self, index: usize, actual: Option<&Frame>, expected: Option<&Frame>) {
+        let describe =
+            |frame: &Frame| format!("{:?} ({})", frame.message, frame.original_type_name);
+        self.differences.push(Difference {
+            frame_index: Some(index),
+            field: None,
+            actual: actual.map(describe),
+            expected: expected.map(describe),
+        });
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.frame_index, self.field) {
+            (Some(index), Some(field)) => write!(f, "frame {index}, {field}: ")?,
+            (Some(index), None) => write!(f, "frame {index}: ")?,
+            (None, Some(field)) => write!(f, "{field}: ")?,
+            (None, None) => {}
+        }
+        write!(
+            f,
+            "expected {}, found {}",
+            self.expected.as_deref().unwrap_or("none"),
+            self.actual.as_deref().unwrap_or("none")
+        )
+    }
+}
+
+impl Difference {
+    ///
+    /// Return the index of the error in the source chain that differs, as used by
+    /// [`FlatError::frame`], or `None` if this is a difference in category.
+    ///
+    pub fn frame_index(&self) -> Option<usize> {
+        self.frame_index
+    }
+
+    ///
+    /// Return the name of the value that differs, or `None` if the error is present in only
+    /// one of the chains.
+    ///
+    pub fn field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    ///
+    /// Return the actual value, or `None` if the error is not present in the actual chain.
+    ///
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+
+    ///
+    /// Return the expected value, or `None` if the error is not present in the expected chain.
+    ///
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+}
@@ -0,0 +1,399 @@
+/*!
+Provides alternative renderings of a [`FlatError`] and its source chain, see
+[`FlatError::display_with`], [`FlatError::format_chain`], and [`FlatError::to_logfmt`].
+*/
+
+use crate::{normalize_type_name, FlatError};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The style used to render a [`FlatError`] and its source chain. The default style is
+/// [`DisplayStyle::Compact`]; with the **std** feature, the style set by the installed
+/// `FlatErrorConfig` is returned by `DisplayStyle::configured()`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DisplayStyle {
+    ///
+    /// All messages on a single line separated by `": "`, for example:
+    ///
+    /// ```text
+    /// starting server: reading config file: entity not found
+    /// ```
+    ///
+    #[default]
+    Compact,
+    ///
+    /// The error message on the first line, followed by each source on its own line, for example:
+    ///
+    /// ```text
+    /// starting server
+    /// caused by: reading config file
+    /// caused by: entity not found
+    /// ```
+    ///
+    CausedBy,
+    ///
+    /// The error message on the first line, followed by each source indented below its parent,
+    /// for example:
+    ///
+    /// ```text
+    /// starting server
+    /// └─ reading config file
+    ///    └─ entity not found
+    /// ```
+    ///
+    /// Any branches of an error, see [`FlatError::join`], are written below it after its
+    /// source.
+    ///
+    Tree,
+    ///
+    /// A stable, multi-line rendering intended for snapshot tests. Each error in the chain is
+    /// followed by its attributes, one per line, and attributes that vary between builds or
+    /// toolchains are either normalized or omitted:
+    ///
+    /// * type names have each path reduced to its final segment, as by
+    ///   [`FlattenOptions::with_normalized_type_names`](crate::FlattenOptions::with_normalized_type_names),
+    ///   after removing any symbol hash segments;
+    /// * metadata fields and message arguments are sorted by key;
+    /// * the location, original error, and `TypeId` are omitted.
+    ///
+    /// For example:
+    ///
+    /// ```text
+    /// reading config file
+    ///   type: FlatError
+    ///   severity: error
+    ///   field.path: /etc/app.toml
+    /// caused by: entity not found
+    ///   type: Error
+    ///   severity: error
+    /// ```
+    ///
+    Snapshot,
+}
+
+///
+/// An adapter that implements `Display` for a [`FlatError`] using a specific [`DisplayStyle`],
+/// returned by [`FlatError::display_with`].
+///
+#[derive(Clone, Copy, Debug)]
+pub struct StyledDisplay<'a> {
+    error: &'a FlatError,
+    style: DisplayStyle,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for StyledDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.style == DisplayStyle::Snapshot {
+            return write_snapshot(f, self.error);
+        }
+        if self.style == DisplayStyle::Tree {
+            return write_tree(f, self.error);
+        }
+        write!(f, "{}", self.error)?;
+        for source in self.error.sources() {
+            let message = &source.message;
+            match self.style {
+                DisplayStyle::Compact => write!(f, ": {message}")?,
+                DisplayStyle::CausedBy => write!(f, "\ncaused by: {message}")?,
+                DisplayStyle::Tree | DisplayStyle::Snapshot => {
+                    unreachable!("trees and snapshots are written above")
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return an adapter that renders this error, and its source chain, in the given `style`.
+    ///
+    /// ```rust
+    /// use flat_error::{DisplayStyle, FlatError};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .context("reading config file");
+    ///
+    /// assert_eq!(
+    ///     err.display_with(DisplayStyle::Compact).to_string(),
+    ///     "reading config file: entity not found"
+    /// );
+    /// assert_eq!(
+    ///     err.display_with(DisplayStyle::CausedBy).to_string(),
+    ///     "reading config file\ncaused by: entity not found"
+    /// );
+    /// ```
+    ///
+    pub fn display_with(&self, style: DisplayStyle) -> StyledDisplay<'_> {
+        StyledDisplay { error: self, style }
+    }
+
+    ///
+    /// Return the messages of this error, and each error in its source chain, joined by
+    /// `separator`; see [`FlatError::write_chain`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .context("reading config file")
+    ///     .context("starting server");
+    ///
+    /// assert_eq!(
+    ///     err.format_chain(" -> "),
+    ///     "starting server -> reading config file -> entity not found"
+    /// );
+    /// ```
+    ///
+    pub fn format_chain(&self, separator: &str) -> String {
+        let mut buffer = String::new();
+        self.write_chain(&mut buffer, separator)
+            .expect("writing to a String cannot fail");
+        buffer
+    }
+
+    ///
+    /// Write the messages of this error, and each error in its source chain, to `w` separated
+    /// by `separator`. The [`DisplayStyle::Compact`] style is equivalent to a separator of
+    /// `": "`.
+    ///
+    pub fn write_chain<W>(&self, w: &mut W, separator: &str) -> FmtResult
+    where
+        W: Write + ?Sized,
+    {
+        for (depth, frame) in self.chain_frames().enumerate() {
+            if depth > 0 {
+                w.write_str(separator)?;
+            }
+            w.write_str(&frame.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return this error, and its source chain, rendered as a single line of
+    /// [logfmt](https://brandur.org/logfmt) key/value pairs, see [`FlatError::write_logfmt`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .with_field("path", "/etc/app.toml")
+    ///     .context("reading config file");
+    ///
+    /// assert_eq!(
+    ///     err.to_logfmt(),
+    ///     "msg=\"reading config file\" type=flat_error::FlatError severity=error \
+    ///      source.msg=\"entity not found\" source.type=std::io::error::Error \
+    ///      source.severity=error source.field.path=/etc/app.toml"
+    /// );
+    /// ```
+    ///
+    pub fn to_logfmt(&self) -> String {
+        let mut buffer = String::new();
+        self.write_logfmt(&mut buffer)
+            .expect("writing to a String cannot fail");
+        buffer
+    }
+
+    ///
+    /// Write this error, and its source chain, to `w` as a single line of logfmt key/value pairs.
+    ///
+    /// Each error in the chain writes the keys `msg`, `type`, `code` (if present), `severity`,
+    /// and `field.<key>` for each metadata field. Keys for the first source are prefixed with
+    /// `source.`, for its source `source.source.`, and so on. Values are quoted only if they are
+    /// empty or contain spaces, quotes, `=`, or control characters.
+    ///
+    pub fn write_logfmt<W>(&self, w: &mut W) -> FmtResult
+    where
+        W: Write + ?Sized,
+    {
+        for (depth, frame) in self.chain_frames().enumerate() {
+            let prefix = LogfmtPrefix(depth);
+            if depth > 0 {
+                w.write_char(' ')?;
+            }
+            write!(w, "{prefix}msg=")?;
+            write_logfmt_value(w, &frame.message)?;
+            write!(w, " {prefix}type=")?;
+            write_logfmt_value(w, frame.original_type_name)?;
+            if let Some(code) = frame.code() {
+                write!(w, " {prefix}code=")?;
+                write_logfmt_value(w, code)?;
+            }
+            write!(w, " {prefix}severity={}", frame.severity)?;
+            for (key, value) in frame.fields() {
+                write!(w, " {prefix}field.{key}=")?;
+                write_logfmt_value(w, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct LogfmtPrefix(usize);
+
+impl Display for LogfmtPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for _ in 0..self.0 {
+            f.write_str("source.")?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn write_tree(f: &mut Formatter<'_>, error: &FlatError) -> FmtResult {
+    write!(f, "{error}")?;
+    let mut tree = error.tree_frames();
+    tree.next_node();
+    // For each ancestor below the root, whether it has further siblings to be written below.
+    let mut continues: Vec<bool> = Vec::new();
+    while let Some(node) = tree.next_node() {
+        continues.truncate(node.depth - 1);
+        f.write_str("\n")?;
+        for continues in &continues {
+            f.write_str(if *continues { "│  " } else { "   " })?;
+        }
+        let branch = if node.is_last { '└' } else { '├' };
+        write!(f, "{branch}─ {}", node.frame.message)?;
+        continues.push(!node.is_last);
+    }
+    Ok(())
+}
+
+fn write_snapshot(f: &mut Formatter<'_>, error: &FlatError) -> FmtResult {
+    for (depth, frame) in error.chain_frames().enumerate() {
+        if depth > 0 {
+            write!(f, "\ncaused by: ")?;
+        }
+        write!(f, "{}", frame.message)?;
+        write!(
+            f,
+            "\n  type: {}",
+            snapshot_type_name(frame.original_type_name)
+        )?;
+        if let Some(code) = frame.code() {
+            write!(f, "\n  code: {code}")?;
+        }
+        write!(f, "\n  severity: {}", frame.severity)?;
+        let details = frame.details();
+        if let Some(key) = &details.message_key {
+            write!(f, "\n  message_key: {key}")?;
+        }
+        if let Some(template) = &details.message_template {
+            write!(f, "\n  message_template: {template}")?;
+        }
+        if let Some(repr) = &details.debug_repr {
+            write!(f, "\n  debug_repr: {repr}")?;
+        }
+        if let Some(count) = details.repeat_count {
+            write!(f, "\n  repeat_count: {count}")?;
+        }
+        if let Some(help) = &details.help {
+            write!(f, "\n  help: {help}")?;
+        }
+        if let Some(url) = &details.docs_url {
+            write!(f, "\n  docs_url: {url}")?;
+        }
+        if let Some(span) = &details.span {
+            write!(
+                f,
+                "\n  span: {span} [{}..{}]",
+                span.offset,
+                span.offset + span.len
+            )?;
+        }
+        for branch in &details.branches {
+            write!(f, "\n  branch: {branch}")?;
+        }
+        for related in &details.related {
+            write!(f, "\n  related: {related}")?;
+        }
+        let mut args: Vec<_> = details.message_args.iter().collect();
+        args.sort();
+        for (key, value) in args {
+            write!(f, "\n  message_arg.{key}: {value}")?;
+        }
+        let mut fields: Vec<_> = frame.fields().collect();
+        fields.sort();
+        for (key, value) in fields {
+            write!(f, "\n  field.{key}: {value}")?;
+        }
+    }
+    Ok(())
+}
+
+///
+/// Normalize `type_name` for [`DisplayStyle::Snapshot`], removing any symbol hash segments,
+/// such as `::h0123456789abcdef`, before reducing each path to its final segment.
+///
+fn snapshot_type_name(type_name: &str) -> String {
+    let mut stripped = String::with_capacity(type_name.len());
+    let mut rest = type_name;
+    while let Some(index) = rest.find("::h") {
+        let (before, after) = rest.split_at(index);
+        let hash_len = after[3..].bytes().take_while(u8::is_ascii_hexdigit).count();
+        let is_segment_end = after[3 + hash_len..]
+            .chars()
+            .next()
+            .map_or(true, |c| !(c.is_alphanumeric() || c == '_'));
+        stripped.push_str(before);
+        if hash_len == 16 && is_segment_end {
+            rest = &after[3 + hash_len..];
+        } else {
+            stripped.push_str("::h");
+            rest = &after[3..];
+        }
+    }
+    stripped.push_str(rest);
+    normalize_type_name(&stripped)
+}
+
+fn write_logfmt_value<W>(w: &mut W, value: &str) -> FmtResult
+where
+    W: Write + ?Sized,
+{
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c == ' ' || c == '"' || c == '=' || c == '\\' || c.is_control());
+    if needs_quotes {
+        w.write_char('"')?;
+        for c in value.chars() {
+            match c {
+                '"' => w.write_str("\\\"")?,
+                '\\' => w.write_str("\\\\")?,
+                '\n' => w.write_str("\\n")?,
+                '\r' => w.write_str("\\r")?,
+                '\t' => w.write_str("\\t")?,
+                c => w.write_char(c)?,
+            }
+        }
+        w.write_char('"')
+    } else {
+        w.write_str(value)
+    }
+}
@@ -0,0 +1,108 @@
+/*!
+Provides the [`DynExtendedError`] trait, an object-safe companion to
+[`ExtendedError`](crate::ExtendedError).
+*/
+
+use crate::{compat::Error, ExtendedError};
+use alloc::boxed::Box;
+use core::any::Any;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An object-safe form of [`ExtendedError`], which cannot itself be used as a trait object as
+/// both `Clone` and `PartialEq` require `Sized`. Cloning is provided by boxing, and equality by
+/// comparing values only if they are of the same type.
+///
+/// There is a blanket implementation for any `'static` type that implements `ExtendedError`;
+/// `dyn DynExtendedError` implements `PartialEq`, and `Box<dyn DynExtendedError>` implements
+/// `Clone`, so that collections of heterogeneous errors may be cloned and compared. Note that,
+/// due to [a limitation](https://github.com/rust-lang/rust/issues/31740) of the compiler, two
+/// boxed errors are compared with `==` by dereferencing them, as in `*a == *b`.
+///
+/// ```rust
+/// use flat_error::{DynExtendedError, FlatError};
+/// use std::fmt::Error as FmtError;
+///
+/// let errors: Vec<Box<dyn DynExtendedError>> = vec![
+///     Box::new(FlatError::new("disk full")),
+///     Box::new(FmtError),
+/// ];
+///
+/// let copies = errors.clone();
+/// assert_eq!(copies, errors);
+/// assert!(*errors[0] != *errors[1]);
+/// assert!(errors[1].is::<FmtError>());
+/// assert_eq!(errors[0].downcast_ref::<FlatError>().unwrap().to_string(), "disk full");
+/// ```
+///
+pub trait DynExtendedError: Error {
+    ///
+    /// Return a boxed clone of this error.
+    ///
+    fn clone_boxed(&self) -> Box<dyn DynExtendedError>;
+
+    ///
+    /// Returns `true` if `other` is of the same type as this error and the two are equal.
+    ///
+    fn dyn_eq(&self, other: &dyn DynExtendedError) -> bool;
+
+    ///
+    /// Return this error as `Any`, allowing it to be downcast to its concrete type.
+    ///
+    fn as_any(&self) -> &dyn Any;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<E> DynExtendedError for E
+where
+    E: ExtendedError + 'static,
+{
+    fn clone_boxed(&self) -> Box<dyn DynExtendedError> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn DynExtendedError) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<E>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl dyn DynExtendedError {
+    ///
+    /// Returns `true` if the concrete type of this error is `E`.
+    ///
+    pub fn is<E: Any>(&self) -> bool {
+        self.as_any().is::<E>()
+    }
+
+    ///
+    /// Return a reference to the concrete error if it is of type `E`.
+    ///
+    pub fn downcast_ref<E: Any>(&self) -> Option<&E> {
+        self.as_any().downcast_ref::<E>()
+    }
+}
+
+impl PartialEq for dyn DynExtendedError {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other)
+    }
+}
+
+impl Clone for Box<dyn DynExtendedError> {
+    fn clone(&self) -> Self {
+        (**self).clone_boxed()
+    }
+}
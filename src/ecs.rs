@@ -0,0 +1,122 @@
+/*!
+Provides the [`EcsDocument`] type, describing a [`FlatError`] with the fields of the Elastic
+Common Schema (ECS), so that flattened errors may be indexed by Elasticsearch or OpenSearch
+using their existing field mappings, see [`FlatError::ecs_document`].
+
+```rust
+use flat_error::FlatError;
+
+let document = FlatError::new_static("entity not found")
+    .context("reading config file")
+    .with_code("E1042")
+    .with_field("config.path", "/etc/app.toml")
+    .ecs_document();
+
+assert_eq!(
+    serde_json::to_string(&document).unwrap(),
+    concat!(
+        r#"{"error":{"type":"flat_error::FlatError","message":"reading config file","#,
+        r#""code":"E1042","stack_trace":"reading config file\ncaused by: entity not found"},"#,
+        r#""labels":{"config_path":"/etc/app.toml"}}"#
+    )
+);
+```
+*/
+
+use crate::{DisplayStyle, FlatError};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An ECS document describing an error, serialized as a JSON object with the members `error`
+/// and, if there are any, `labels`. This may be merged into an application's own log events,
+/// which will commonly add `@timestamp`, `message`, and `log.level`.
+///
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct EcsDocument {
+    /// The ECS `error` field set.
+    pub error: EcsError,
+    /// Custom key/value pairs, the ECS field `labels`; keys may not contain dots.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+///
+/// The ECS `error` field set.
+///
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct EcsError {
+    /// The type of the error, the field `error.type`.
+    #[serde(rename = "type")]
+    pub error_type: String,
+    /// The error message, the field `error.message`.
+    pub message: String,
+    /// The error code, the field `error.code`, optional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The stack trace of the error in plain text, the field `error.stack_trace`, optional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_trace: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The field holding a backtrace, which is included in the stack trace rather than the labels.
+///
+const BACKTRACE_FIELD: &str = "backtrace";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<&FlatError> for EcsDocument {
+    fn from(error: &FlatError) -> Self {
+        error.ecs_document()
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return an ECS document describing this error. The document includes:
+    ///
+    /// * `error.type`; the original type name of this error.
+    /// * `error.message`; the message of this error, not including its source chain.
+    /// * `error.code`; the code of this error, if it has one.
+    /// * `error.stack_trace`; this error and its source chain in the
+    ///   [`DisplayStyle::CausedBy`] style, followed by the backtrace captured with a panic, if
+    ///   any.
+    /// * `labels`; each metadata field of this error, other than the backtrace, with any `.` in
+    ///   its key replaced by `_`.
+    ///
+    pub fn ecs_document(&self) -> EcsDocument {
+        let mut stack_trace = self.display_with(DisplayStyle::CausedBy).to_string();
+        if let Some(backtrace) = self.field(BACKTRACE_FIELD) {
+            stack_trace.push('\n');
+            stack_trace.push_str(backtrace);
+        }
+        EcsDocument {
+            error: EcsError {
+                error_type: self.original_type_name().into(),
+                message: self.to_string(),
+                code: self.code().map(Into::into),
+                stack_trace: Some(stack_trace),
+            },
+            labels: self
+                .fields()
+                .filter(|(key, _)| *key != BACKTRACE_FIELD)
+                .map(|(key, value)| (key.replace('.', "_"), value.into()))
+                .collect(),
+        }
+    }
+}
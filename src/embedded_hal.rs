@@ -0,0 +1,219 @@
+/*!
+Provides conversions from the error kinds of the `embedded-hal` I²C, SPI, and digital I/O traits
+into [`FlatError`], preserving the kind as a metadata field so that it may be recovered with
+[`FlatError::i2c_error_kind`], [`FlatError::spi_error_kind`], or
+[`FlatError::digital_error_kind`]. The `embedded_hal` `Error` traits are implemented for
+`FlatError` using these kinds, so that a driver may use `FlatError` as its associated error
+type rather than define an enum over the errors of each bus it uses.
+
+```rust
+use embedded_hal::i2c::{self, ErrorKind, NoAcknowledgeSource};
+use flat_error::FlatError;
+
+let err = FlatError::from(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))
+    .context("reading temperature sensor");
+
+assert_eq!(
+    err.i2c_error_kind(),
+    Some(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))
+);
+assert_eq!(
+    i2c::Error::kind(&err),
+    ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+);
+```
+
+Driver errors that implement one of the `Error` traits, but not `core::error::Error`, can be
+flattened with [`FlatError::from_i2c_error`], [`FlatError::from_spi_error`], or
+[`FlatError::from_digital_error`], which use the `Debug` form of the error as the message.
+*/
+
+use crate::FlatError;
+use ::embedded_hal::{digital, i2c, spi};
+use core::fmt::Debug;
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const I2C_KIND_FIELD: &str = "hal.i2c_kind";
+
+const SPI_KIND_FIELD: &str = "hal.spi_kind";
+
+const DIGITAL_KIND_FIELD: &str = "hal.digital_kind";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<i2c::ErrorKind> for FlatError {
+    #[track_caller]
+    fn from(kind: i2c::ErrorKind) -> Self {
+        FlatError::from_display(&kind).with_field(I2C_KIND_FIELD, i2c_kind_name(kind))
+    }
+}
+
+impl From<spi::ErrorKind> for FlatError {
+    #[track_caller]
+    fn from(kind: spi::ErrorKind) -> Self {
+        FlatError::from_display(&kind).with_field(SPI_KIND_FIELD, spi_kind_name(kind))
+    }
+}
+
+impl From<digital::ErrorKind> for FlatError {
+    #[track_caller]
+    fn from(kind: digital::ErrorKind) -> Self {
+        FlatError::from_display(&kind).with_field(DIGITAL_KIND_FIELD, digital_kind_name(kind))
+    }
+}
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` from an I²C driver error, with the `Debug` form of `error` as
+    /// its message and its kind preserved, see [`FlatError::i2c_error_kind`].
+    ///
+    pub fn from_i2c_error<E>(error: &E) -> Self
+    where
+        E: i2c::Error + ?Sized,
+    {
+        FlatError::from_debug(error).with_field(I2C_KIND_FIELD, i2c_kind_name(error.kind()))
+    }
+
+    ///
+    /// Construct a new `FlatError` from an SPI driver error, with the `Debug` form of `error` as
+    /// its message and its kind preserved, see [`FlatError::spi_error_kind`].
+    ///
+    pub fn from_spi_error<E>(error: &E) -> Self
+    where
+        E: spi::Error + ?Sized,
+    {
+        FlatError::from_debug(error).with_field(SPI_KIND_FIELD, spi_kind_name(error.kind()))
+    }
+
+    ///
+    /// Construct a new `FlatError` from a digital I/O driver error, with the `Debug` form of
+    /// `error` as its message and its kind preserved, see [`FlatError::digital_error_kind`].
+    ///
+    pub fn from_digital_error<E>(error: &E) -> Self
+    where
+        E: digital::Error + ?Sized,
+    {
+        FlatError::from_debug(error).with_field(DIGITAL_KIND_FIELD, digital_kind_name(error.kind()))
+    }
+}
+
+impl<C> FlatError<C> {
+    ///
+    /// Return the `embedded_hal::i2c::ErrorKind` of this error, or of the first error in its
+    /// source chain flattened from an I²C error.
+    ///
+    pub fn i2c_error_kind(&self) -> Option<i2c::ErrorKind> {
+        self.chain_frames()
+            .find_map(|frame| frame.field(I2C_KIND_FIELD))
+            .map(i2c_kind_from_name)
+    }
+
+    ///
+    /// Return the `embedded_hal::spi::ErrorKind` of this error, or of the first error in its
+    /// source chain flattened from an SPI error.
+    ///
+    pub fn spi_error_kind(&self) -> Option<spi::ErrorKind> {
+        self.chain_frames()
+            .find_map(|frame| frame.field(SPI_KIND_FIELD))
+            .map(spi_kind_from_name)
+    }
+
+    ///
+    /// Return the `embedded_hal::digital::ErrorKind` of this error, or of the first error in its
+    /// source chain flattened from a digital I/O error.
+    ///
+    pub fn digital_error_kind(&self) -> Option<digital::ErrorKind> {
+        self.chain_frames()
+            .find_map(|frame| frame.field(DIGITAL_KIND_FIELD))
+            .map(|_| digital::ErrorKind::Other)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<C: Debug> i2c::Error for FlatError<C> {
+    ///
+    /// Return the I²C error kind of this error, see [`FlatError::i2c_error_kind`], or
+    /// `ErrorKind::Other` if it has none.
+    ///
+    fn kind(&self) -> i2c::ErrorKind {
+        self.i2c_error_kind().unwrap_or(i2c::ErrorKind::Other)
+    }
+}
+
+impl<C: Debug> spi::Error for FlatError<C> {
+    ///
+    /// Return the SPI error kind of this error, see [`FlatError::spi_error_kind`], or
+    /// `ErrorKind::Other` if it has none.
+    ///
+    fn kind(&self) -> spi::ErrorKind {
+        self.spi_error_kind().unwrap_or(spi::ErrorKind::Other)
+    }
+}
+
+impl<C: Debug> digital::Error for FlatError<C> {
+    fn kind(&self) -> digital::ErrorKind {
+        digital::ErrorKind::Other
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn i2c_kind_name(kind: i2c::ErrorKind) -> &'static str {
+    use i2c::{ErrorKind, NoAcknowledgeSource};
+    match kind {
+        ErrorKind::Bus => "Bus",
+        ErrorKind::ArbitrationLoss => "ArbitrationLoss",
+        ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address) => "NoAcknowledge(Address)",
+        ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => "NoAcknowledge(Data)",
+        ErrorKind::NoAcknowledge(_) => "NoAcknowledge(Unknown)",
+        ErrorKind::Overrun => "Overrun",
+        _ => "Other",
+    }
+}
+
+fn i2c_kind_from_name(name: &str) -> i2c::ErrorKind {
+    use i2c::{ErrorKind, NoAcknowledgeSource};
+    match name {
+        "Bus" => ErrorKind::Bus,
+        "ArbitrationLoss" => ErrorKind::ArbitrationLoss,
+        "NoAcknowledge(Address)" => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+        "NoAcknowledge(Data)" => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data),
+        "NoAcknowledge(Unknown)" => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown),
+        "Overrun" => ErrorKind::Overrun,
+        _ => ErrorKind::Other,
+    }
+}
+
+fn spi_kind_name(kind: spi::ErrorKind) -> &'static str {
+    use spi::ErrorKind;
+    match kind {
+        ErrorKind::Overrun => "Overrun",
+        ErrorKind::ModeFault => "ModeFault",
+        ErrorKind::FrameFormat => "FrameFormat",
+        ErrorKind::ChipSelectFault => "ChipSelectFault",
+        _ => "Other",
+    }
+}
+
+fn spi_kind_from_name(name: &str) -> spi::ErrorKind {
+    use spi::ErrorKind;
+    match name {
+        "Overrun" => ErrorKind::Overrun,
+        "ModeFault" => ErrorKind::ModeFault,
+        "FrameFormat" => ErrorKind::FrameFormat,
+        "ChipSelectFault" => ErrorKind::ChipSelectFault,
+        _ => ErrorKind::Other,
+    }
+}
+
+fn digital_kind_name(_: digital::ErrorKind) -> &'static str {
+    "Other"
+}
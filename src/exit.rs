@@ -0,0 +1,212 @@
+/*!
+Provides the [`FlatExit`] type, for returning a [`FlatError`] from `main`, and a mapping from a
+[`FlatError`] to the BSD `sysexits.h` exit codes, see [`FlatError::exit_code`].
+
+| Class               | Exit code               |
+|---------------------|-------------------------|
+| `NotFound`          | `EX_NOINPUT` (66)       |
+| `PermissionDenied`  | `EX_NOPERM` (77)        |
+| `Unauthenticated`   | `EX_NOPERM` (77)        |
+| `InvalidInput`      | `EX_DATAERR` (65)       |
+| `Conflict`          | `EX_CANTCREAT` (73)     |
+| `Timeout`           | `EX_TEMPFAIL` (75)      |
+| `Unavailable`       | `EX_UNAVAILABLE` (69)   |
+| `ResourceExhausted` | `EX_TEMPFAIL` (75)      |
+| `Cancelled`         | `EX_TEMPFAIL` (75)      |
+| `Unsupported`       | `EX_UNAVAILABLE` (69)   |
+| `Internal`          | `EX_SOFTWARE` (70)      |
+
+```rust
+use flat_error::{ErrorClass, FlatError};
+use std::io::{Error as IoError, ErrorKind};
+
+let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("reading config file");
+assert_eq!(err.exit_code(), 66);
+
+let err = FlatError::from(IoError::from(ErrorKind::UnexpectedEof));
+assert_eq!(err.exit_code(), 74);
+
+assert_eq!(FlatError::new("failed").exit_code(), 1);
+assert_eq!(ErrorClass::Timeout.exit_code(), 75);
+```
+*/
+
+use crate::{ErrorClass, FlatError, FlatReport};
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::process::{ExitCode, Termination};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `FlatExit` wraps a [`FlatError`] so that it may be returned from `main`, either directly or
+/// as the error of a `Result`. The error and its source chain are written to standard error,
+/// using the pretty form of [`FlatReport`], and the process exits with the exit code of the
+/// `FlatExit`, which is that returned by [`FlatError::exit_code`] unless set by
+/// [`FlatExit::with_exit_code`].
+///
+/// ```rust,no_run
+/// use flat_error::{FlatError, FlatExit};
+///
+/// fn main() -> Result<(), FlatExit> {
+///     std::fs::read_to_string("config.toml")
+///         .map_err(|e| FlatError::from(e).context("reading config file"))?;
+///     Ok(())
+/// }
+/// ```
+///
+/// When returned in a `Result` the exit code is always `1`, as it is chosen by the standard
+/// library, to use the exit code of the `FlatExit` return it directly.
+///
+/// ```rust,no_run
+/// use flat_error::{FlatError, FlatExit};
+/// use std::process::{ExitCode, Termination};
+///
+/// fn run() -> Result<(), FlatError> {
+///     Err(FlatError::new("no input files"))
+/// }
+///
+/// fn main() -> ExitCode {
+///     match run() {
+///         Ok(()) => ExitCode::SUCCESS,
+///         Err(e) => FlatExit::from(e).with_exit_code(2).report(),
+///     }
+/// }
+/// ```
+///
+#[derive(Clone, PartialEq)]
+pub struct FlatExit {
+    error: FlatError,
+    exit_code: u8,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The exit code for an error that cannot be classified, a general failure.
+///
+const EXIT_FAILURE: u8 = 1;
+
+///
+/// The `sysexits.h` exit code for an input/output error, `EX_IOERR`.
+///
+const EX_IOERR: u8 = 74;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatExit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.error.clone().report().pretty(true), f)
+    }
+}
+
+impl Debug for FlatExit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+impl From<FlatError> for FlatExit {
+    fn from(error: FlatError) -> Self {
+        let exit_code = error.exit_code();
+        Self { error, exit_code }
+    }
+}
+
+impl From<FlatReport> for FlatExit {
+    fn from(report: FlatReport) -> Self {
+        Self::from(report.into_error())
+    }
+}
+
+impl Termination for FlatExit {
+    fn report(self) -> ExitCode {
+        #[cfg(feature = "clap")]
+        if self.error.is_clap_display() {
+            ::std::print!("{}", self.error);
+            return ExitCode::from(self.exit_code);
+        }
+        ::std::eprintln!("Error: {self}");
+        ExitCode::from(self.exit_code)
+    }
+}
+
+impl FlatExit {
+    ///
+    /// Set the exit code used when this value is returned from `main`.
+    ///
+    pub fn with_exit_code(self, exit_code: u8) -> Self {
+        Self { exit_code, ..self }
+    }
+
+    ///
+    /// Return the exit code used when this value is returned from `main`.
+    ///
+    pub fn exit_code(&self) -> u8 {
+        self.exit_code
+    }
+
+    ///
+    /// Return the error to be reported.
+    ///
+    pub fn error(&self) -> &FlatError {
+        &self.error
+    }
+
+    ///
+    /// Consume this value and return the error to be reported.
+    ///
+    pub fn into_error(self) -> FlatError {
+        self.error
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ErrorClass {
+    ///
+    /// Return the BSD `sysexits.h` exit code corresponding to this class.
+    ///
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::NotFound => 66,
+            Self::PermissionDenied | Self::Unauthenticated => 77,
+            Self::InvalidInput => 65,
+            Self::Conflict => 73,
+            Self::Timeout | Self::ResourceExhausted | Self::Cancelled => 75,
+            Self::Unavailable | Self::Unsupported => 69,
+            Self::Internal => 70,
+        }
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return the BSD `sysexits.h` exit code to be used when this error ends the process. This
+    /// is the code corresponding to the class of the first error in the source chain that can
+    /// be classified, see [`FlatError::class`], or `EX_IOERR` (74) if the chain includes a
+    /// flattened `std::io::Error` that cannot be classified, otherwise it is `1`. With the
+    /// **clap** feature, an error flattened from a `clap::Error` uses the exit code of `clap`.
+    ///
+    pub fn exit_code(&self) -> u8 {
+        #[cfg(feature = "clap")]
+        if let Some(exit_code) = self.clap_exit_code() {
+            return exit_code;
+        }
+        if let Some(class) = self.chain_class() {
+            class.exit_code()
+        } else if self
+            .chain_frames()
+            .any(|frame| frame.details().io_kind.is_some())
+        {
+            EX_IOERR
+        } else {
+            EXIT_FAILURE
+        }
+    }
+}
@@ -0,0 +1,231 @@
+/*!
+Extension traits for standard library types.
+*/
+
+use crate::{compat::Error, FlatError, FlatErrorGroup};
+use alloc::{string::ToString, vec::Vec};
+use core::fmt::Display;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Extension methods on `Result` that convert the error into a [`FlatError`] and layer a context
+/// message over it, see [`FlatError::context`].
+///
+/// ```rust
+/// use flat_error::{FlatError, ResultExt};
+/// use std::fs::File;
+///
+/// fn open_config(path: &str) -> Result<File, FlatError> {
+///     File::open(path).with_context(|| format!("opening config file {path}"))
+/// }
+///
+/// let err = open_config("/no/such/file").unwrap_err();
+/// assert_eq!(err.to_string(), "opening config file /no/such/file");
+/// ```
+///
+pub trait ResultExt<T> {
+    ///
+    /// Convert any error into a [`FlatError`] with `context` as its message.
+    ///
+    fn context<C>(self, context: C) -> Result<T, FlatError>
+    where
+        C: Display;
+
+    ///
+    /// Convert any error into a [`FlatError`] with a message returned by the closure `f`, which
+    /// is only called in the error case.
+    ///
+    fn with_context<C, F>(self, f: F) -> Result<T, FlatError>
+    where
+        C: Display,
+        F: FnOnce() -> C;
+}
+
+///
+/// Extension methods on `Option` that convert a `None` into a [`FlatError`] with the given
+/// message, see [`FlatError::new`].
+///
+/// ```rust
+/// use flat_error::{FlatError, OptionExt};
+/// use std::collections::HashMap;
+///
+/// fn port(settings: &HashMap<&str, u16>) -> Result<u16, FlatError> {
+///     settings.get("port").copied().ok_or_flat("missing field port")
+/// }
+///
+/// let err = port(&HashMap::new()).unwrap_err();
+/// assert_eq!(err.to_string(), "missing field port");
+/// ```
+///
+pub trait OptionExt<T> {
+    ///
+    /// Convert a `None` into a [`FlatError`] with `message` as its message.
+    ///
+    fn ok_or_flat<M>(self, message: M) -> Result<T, FlatError>
+    where
+        M: Display;
+
+    ///
+    /// Convert a `None` into a [`FlatError`] with a message returned by the closure `f`, which
+    /// is only called if this is `None`.
+    ///
+    fn ok_or_flat_with<M, F>(self, f: F) -> Result<T, FlatError>
+    where
+        M: Display,
+        F: FnOnce() -> M;
+}
+
+///
+/// Extension methods on iterators of `Result`s, that collect every failure rather than stopping
+/// at the first as collecting into a `Result` does.
+///
+/// ```rust
+/// use flat_error::IteratorExt;
+///
+/// let values = ["1", "2", "3"].iter().map(|s| s.parse::<u8>()).collect_flat();
+/// assert_eq!(values, Ok(vec![1, 2, 3]));
+///
+/// let errors = ["1", "x", "300"]
+///     .iter()
+///     .map(|s| s.parse::<u8>())
+///     .collect_flat()
+///     .unwrap_err();
+/// assert_eq!(
+///     errors.to_string(),
+///     "invalid digit found in string; number too large to fit in target type"
+/// );
+/// ```
+///
+pub trait IteratorExt<T, E>: Iterator<Item = Result<T, E>> {
+    ///
+    /// Collect the values of this iterator, or if any item is an error, consume the remainder
+    /// of the iterator and return every error converted into a [`FlatError`] in a
+    /// [`FlatErrorGroup`].
+    ///
+    fn collect_flat(self) -> Result<Vec<T>, FlatErrorGroup>
+    where
+        Self: Sized;
+}
+
+///
+/// Extension methods on any error that flatten it into a [`FlatError`], these read more naturally
+/// than [`FlatError::from_any`] at the end of a chain of calls, or in `map_err`.
+///
+/// ```rust
+/// use flat_error::{FlatError, Flatten};
+/// use std::fs::File;
+///
+/// fn open_config(path: &str) -> Result<File, FlatError> {
+///     File::open(path).map_err(|e| e.flatten())
+/// }
+///
+/// let err = open_config("/no/such/file").unwrap_err();
+/// assert_eq!(err.original_type_name(), "std::io::error::Error");
+/// ```
+///
+pub trait Flatten: Error {
+    ///
+    /// Flatten this error into a [`FlatError`], see [`FlatError::from_any`].
+    ///
+    fn flatten(&self) -> FlatError;
+
+    ///
+    /// Flatten this error into a [`FlatError`], retaining the original error value, see
+    /// [`FlatError::from_any_retaining`].
+    ///
+    fn flatten_retaining(self) -> FlatError
+    where
+        Self: Sized + Send + Sync + 'static;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Into<FlatError>,
+{
+    fn context<C>(self, context: C) -> Result<T, FlatError>
+    where
+        C: Display,
+    {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, FlatError>
+    where
+        C: Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T> OptionExt<T> for Option<T> {
+    fn ok_or_flat<M>(self, message: M) -> Result<T, FlatError>
+    where
+        M: Display,
+    {
+        self.ok_or_else(|| FlatError::new(message.to_string()))
+    }
+
+    fn ok_or_flat_with<M, F>(self, f: F) -> Result<T, FlatError>
+    where
+        M: Display,
+        F: FnOnce() -> M,
+    {
+        self.ok_or_else(|| FlatError::new(f().to_string()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<I, T, E> IteratorExt<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Into<FlatError>,
+{
+    fn collect_flat(self) -> Result<Vec<T>, FlatErrorGroup>
+    where
+        Self: Sized,
+    {
+        let mut values = Vec::new();
+        let mut errors = FlatErrorGroup::new();
+        for item in self {
+            match item {
+                Ok(value) if errors.is_empty() => values.push(value),
+                Ok(_) => {}
+                Err(error) => errors.push(error.into()),
+            }
+        }
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<E> Flatten for E
+where
+    E: Error + ?Sized,
+{
+    fn flatten(&self) -> FlatError {
+        FlatError::from_any(self)
+    }
+
+    fn flatten_retaining(self) -> FlatError
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        FlatError::from_any_retaining(self)
+    }
+}
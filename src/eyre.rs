@@ -0,0 +1,54 @@
+/*!
+Provides interoperability with the [`eyre`](https://docs.rs/eyre) crate, and so also with
+[`color-eyre`](https://docs.rs/color-eyre) which uses the same `Report` type.
+
+An `eyre::Report` may be flattened into a [`FlatError`] using `From`, and a `FlatError` may be
+turned back into a report using the [`IntoEyre`] trait. In both directions the source chain is
+preserved.
+
+```rust
+use flat_error::{FlatError, eyre::IntoEyre};
+
+let report = eyre::eyre!("disk full").wrap_err("could not save file");
+let flat = FlatError::from(report);
+assert_eq!(flat.to_string(), "could not save file");
+assert_eq!(flat.flat_source().unwrap().to_string(), "disk full");
+
+let report = flat.into_eyre();
+assert_eq!(report.chain().count(), 2);
+```
+
+*/
+
+use crate::FlatError;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Convert a value into an `eyre::Report`, preserving any source chain.
+///
+pub trait IntoEyre {
+    ///
+    /// Convert `self` into a new `eyre::Report`.
+    ///
+    fn into_eyre(self) -> ::eyre::Report;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<::eyre::Report> for FlatError {
+    #[track_caller]
+    fn from(e: ::eyre::Report) -> Self {
+        FlatError::from_any(AsRef::<dyn ::std::error::Error + Send + Sync>::as_ref(&e))
+    }
+}
+
+impl IntoEyre for FlatError {
+    fn into_eyre(self) -> ::eyre::Report {
+        ::eyre::Report::new(self)
+    }
+}
@@ -0,0 +1,234 @@
+/*!
+Provides a C compatible representation of a [`FlatError`] and its source chain, for libraries
+that expose a C API.
+
+A [`FlatErrorChain`] is created from a `FlatError` by [`FlatError::to_ffi`], which returns an
+owned pointer to be handed to the C caller. The caller reads the frames of the chain, starting
+with the error itself and followed by each of its sources, either directly from the `#[repr(C)]`
+structures or using the accessor functions [`flat_error_chain_len`] and
+[`flat_error_chain_frame`], and must release the chain with [`flat_error_chain_free`].
+
+All strings are NUL terminated UTF-8; any NUL characters within a message are replaced with
+`U+FFFD`.
+
+```rust
+use flat_error::{
+    ffi::{flat_error_chain_frame, flat_error_chain_free, flat_error_chain_len},
+    FlatError,
+};
+use std::ffi::CStr;
+
+let chain = FlatError::new("disk full").context("saving file").to_ffi();
+unsafe {
+    assert_eq!(flat_error_chain_len(chain), 2);
+    let frame = &*flat_error_chain_frame(chain, 1);
+    assert_eq!(CStr::from_ptr(frame.message).to_str(), Ok("disk full"));
+    flat_error_chain_free(chain);
+}
+```
+
+*/
+
+#![allow(unsafe_code)]
+
+use crate::{FlatError, Frame, Severity};
+use alloc::{boxed::Box, ffi::CString, vec::Vec};
+use core::{
+    ffi::c_char,
+    ptr::{self, null},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single error within a [`FlatErrorChain`].
+///
+#[derive(Debug)]
+#[repr(C)]
+pub struct FlatErrorFrame {
+    ///
+    /// The message of the error.
+    ///
+    pub message: *const c_char,
+    ///
+    /// The original type name of the error.
+    ///
+    pub type_name: *const c_char,
+    ///
+    /// The error code, or null if the error has no code.
+    ///
+    pub code: *const c_char,
+    ///
+    /// The severity of the error, one of the `FLAT_ERROR_SEVERITY_*` constants.
+    ///
+    pub severity: u8,
+}
+
+///
+/// A C compatible representation of a [`FlatError`] and its source chain.
+///
+#[derive(Debug)]
+#[repr(C)]
+pub struct FlatErrorChain {
+    ///
+    /// The frames of the chain, the error itself followed by each of its sources.
+    ///
+    pub frames: *const FlatErrorFrame,
+    ///
+    /// The number of frames in the chain, this is always at least 1.
+    ///
+    pub len: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The value of [`FlatErrorFrame::severity`] for [`Severity::Info`].
+///
+pub const FLAT_ERROR_SEVERITY_INFO: u8 = 0;
+
+///
+/// The value of [`FlatErrorFrame::severity`] for [`Severity::Warning`].
+///
+pub const FLAT_ERROR_SEVERITY_WARNING: u8 = 1;
+
+///
+/// The value of [`FlatErrorFrame::severity`] for [`Severity::Error`].
+///
+pub const FLAT_ERROR_SEVERITY_ERROR: u8 = 2;
+
+///
+/// The value of [`FlatErrorFrame::severity`] for [`Severity::Critical`].
+///
+pub const FLAT_ERROR_SEVERITY_CRITICAL: u8 = 3;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the number of frames in `chain`, or `0` if `chain` is null.
+///
+/// # Safety
+///
+/// `chain` must be null or a pointer returned by [`FlatError::to_ffi`] that has not been freed.
+///
+#[no_mangle]
+pub unsafe extern "C" fn flat_error_chain_len(chain: *const FlatErrorChain) -> usize {
+    // SAFETY: the caller guarantees `chain` is null or valid.
+    match unsafe { chain.as_ref() } {
+        Some(chain) => chain.len,
+        None => 0,
+    }
+}
+
+///
+/// Return the frame at `index` in `chain`, or null if `chain` is null or `index` is out of
+/// bounds. The frame is owned by `chain`.
+///
+/// # Safety
+///
+/// `chain` must be null or a pointer returned by [`FlatError::to_ffi`] that has not been freed.
+///
+#[no_mangle]
+pub unsafe extern "C" fn flat_error_chain_frame(
+    chain: *const FlatErrorChain,
+    index: usize,
+) -> *const FlatErrorFrame {
+    // SAFETY: the caller guarantees `chain` is null or valid.
+    match unsafe { chain.as_ref() } {
+        // SAFETY: `frames` holds `len` frames, and `index` is within bounds.
+        Some(chain) if index < chain.len => unsafe { chain.frames.add(index) },
+        _ => null(),
+    }
+}
+
+///
+/// Release `chain`, and all of the frames and strings it owns. Does nothing if `chain` is null.
+///
+/// # Safety
+///
+/// `chain` must be null or a pointer returned by [`FlatError::to_ffi`] that has not been freed;
+/// it must not be used after this call.
+///
+#[no_mangle]
+pub unsafe extern "C" fn flat_error_chain_free(chain: *mut FlatErrorChain) {
+    if chain.is_null() {
+        return;
+    }
+    // SAFETY: `chain` was allocated by `to_ffi` as a box, and has not been freed.
+    let chain = unsafe { Box::from_raw(chain) };
+    // SAFETY: `frames` and `len` are the pointer and length of the `Box<[FlatErrorFrame]>`
+    // released by `Box::into_raw` in `to_ffi`, allocated by the same global allocator; the
+    // chain is freed only once, so the slice has not already been reclaimed.
+    let frames = unsafe {
+        Box::from_raw(ptr::slice_from_raw_parts_mut(
+            chain.frames.cast_mut(),
+            chain.len,
+        ))
+    };
+    for frame in frames.iter() {
+        for string in [frame.message, frame.type_name, frame.code] {
+            if !string.is_null() {
+                // SAFETY: each non-null string was created by `CString::into_raw`.
+                drop(unsafe { CString::from_raw(string.cast_mut()) });
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Return a new [`FlatErrorChain`] representing this error and its source chain. The
+    /// returned pointer is owned by the caller and must be released with
+    /// [`flat_error_chain_free`].
+    ///
+    pub fn to_ffi(&self) -> *mut FlatErrorChain {
+        let frames: Box<[FlatErrorFrame]> = self.chain_frames().map(FlatErrorFrame::from).collect();
+        let len = frames.len();
+        Box::into_raw(Box::new(FlatErrorChain {
+            frames: Box::into_raw(frames).cast_const().cast(),
+            len,
+        }))
+    }
+}
+
+impl From<&Frame> for FlatErrorFrame {
+    fn from(frame: &Frame) -> Self {
+        Self {
+            message: c_string(&frame.message),
+            type_name: c_string(frame.original_type_name),
+            code: frame.code().map_or(null(), c_string),
+            severity: match frame.severity {
+                Severity::Info => FLAT_ERROR_SEVERITY_INFO,
+                Severity::Warning => FLAT_ERROR_SEVERITY_WARNING,
+                Severity::Error => FLAT_ERROR_SEVERITY_ERROR,
+                Severity::Critical => FLAT_ERROR_SEVERITY_CRITICAL,
+            },
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn c_string(value: &str) -> *const c_char {
+    let bytes: Vec<u8> = if value.contains('\0') {
+        value.replace('\0', "\u{FFFD}").into_bytes()
+    } else {
+        value.as_bytes().to_vec()
+    };
+    CString::new(bytes)
+        .expect("NUL characters were replaced")
+        .into_raw()
+        .cast_const()
+}
@@ -0,0 +1,216 @@
+/*!
+Provides [`FlatError::fingerprint`] and [`FlatError::normalized_message`], for grouping errors
+with the same cause, for example in alerting, telemetry, or dashboards.
+*/
+
+use crate::{normalize_type_name, FlatError};
+use alloc::string::String;
+use core::net::{IpAddr, SocketAddr};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The 64-bit FNV-1a hash function. Unlike the hashers in `std` its output is specified, and so
+/// stable across runs, platforms, and releases.
+///
+struct Fnv1a(u64);
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+const NUMBER_PLACEHOLDER: &str = "<n>";
+
+const PATH_PLACEHOLDER: &str = "<path>";
+
+const UUID_PLACEHOLDER: &str = "<uuid>";
+
+const ADDRESS_PLACEHOLDER: &str = "<addr>";
+
+///
+/// Characters removed from the start of a word before it is classified, and retained in the
+/// normalized message. This excludes `[`, which begins an IPv6 socket address.
+///
+const WORD_PREFIXES: &[char] = &['"', '\'', '`', '(', '{', '<'];
+
+///
+/// Characters removed from the end of a word before it is classified, and retained in the
+/// normalized message.
+///
+const WORD_SUFFIXES: &[char] = &[
+    '"', '\'', '`', ')', ']', '}', '>', ',', '.', ';', ':', '!', '?',
+];
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return a fingerprint of this error, and its source chain, suitable as a key for grouping
+    /// errors with the same cause. This is computed from the original type name, with any module
+    /// paths removed, and the normalized message, see [`FlatError::normalized_message`], of each
+    /// error in the chain; so that errors differing only in a count, path, or similar value share
+    /// a fingerprint.
+    ///
+    /// The fingerprint is stable across runs, platforms, and releases of this crate, although it
+    /// will change if an error's message or type name changes.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let first = FlatError::from(IoError::from(ErrorKind::ConnectionRefused))
+    ///     .context("connecting to port 8080");
+    /// let second = FlatError::from(IoError::from(ErrorKind::ConnectionRefused))
+    ///     .context("connecting to port 9090");
+    /// let third = FlatError::from(IoError::from(ErrorKind::TimedOut))
+    ///     .context("connecting to port 8080");
+    ///
+    /// assert_eq!(first.fingerprint(), second.fingerprint());
+    /// assert_ne!(first.fingerprint(), third.fingerprint());
+    /// ```
+    ///
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a(FNV_OFFSET_BASIS);
+        for frame in self.chain_frames() {
+            hasher.write(normalize_type_name(frame.original_type_name).as_bytes());
+            hasher.write(&[0]);
+            hasher.write(normalize_message(&frame.message).as_bytes());
+            hasher.write(&[0]);
+        }
+        hasher.0
+    }
+
+    ///
+    /// Return the message of this error with volatile values replaced by placeholders, so that
+    /// messages created from the same template may be grouped. Each word of the message that is
+    /// a UUID is replaced by `<uuid>`, a file system path by `<path>`, and an IP or socket
+    /// address, or a hexadecimal value such as a memory address, by `<addr>`; any other numbers
+    /// that do not follow a letter, as in `utf8`, are replaced by `<n>`.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new("failed to read /tmp/a.txt after 3 attempts");
+    /// assert_eq!(err.normalized_message(), "failed to read <path> after <n> attempts");
+    ///
+    /// let err = FlatError::new("connection to 10.0.0.1:8080 refused (session 'b4c1e2a0-5e9f-4d3c-8a71-0c2d9e6f1a3b')");
+    /// assert_eq!(err.normalized_message(), "connection to <addr> refused (session '<uuid>')");
+    /// ```
+    ///
+    pub fn normalized_message(&self) -> String {
+        normalize_message(&self.inner.frame.message)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Normalize `message`, see [`FlatError::normalized_message`].
+///
+fn normalize_message(message: &str) -> String {
+    let mut normalized = String::with_capacity(message.len());
+    let mut rest = message;
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(end);
+        normalize_word(word, &mut normalized);
+        let spaces = tail.len() - tail.trim_start().len();
+        normalized.push_str(&tail[..spaces]);
+        rest = &tail[spaces..];
+    }
+    normalized
+}
+
+fn normalize_word(word: &str, normalized: &mut String) {
+    let start = word.len() - word.trim_start_matches(WORD_PREFIXES).len();
+    let (prefix, rest) = word.split_at(start);
+    let (core, suffix) = rest.split_at(rest.trim_end_matches(WORD_SUFFIXES).len());
+    normalized.push_str(prefix);
+    if is_uuid(core) {
+        normalized.push_str(UUID_PLACEHOLDER);
+    } else if is_path(core) {
+        normalized.push_str(PATH_PLACEHOLDER);
+    } else if is_address(core) {
+        normalized.push_str(ADDRESS_PLACEHOLDER);
+    } else {
+        mask_numbers(core, normalized);
+    }
+    normalized.push_str(suffix);
+}
+
+fn is_uuid(word: &str) -> bool {
+    word.len() == 36
+        && word.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+fn is_path(word: &str) -> bool {
+    let bytes = word.as_bytes();
+    (word.starts_with('/') && word.len() > 1)
+        || ["./", "../", "~/", "\\\\"]
+            .iter()
+            .any(|prefix| word.starts_with(prefix))
+        || (bytes.len() > 2
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && matches!(bytes[2], b'\\' | b'/'))
+}
+
+fn is_address(word: &str) -> bool {
+    word.parse::<IpAddr>().is_ok()
+        || word.parse::<SocketAddr>().is_ok()
+        || word
+            .strip_prefix("0x")
+            .or_else(|| word.strip_prefix("0X"))
+            .is_some_and(|hex| !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+///
+/// Replace each number in `word`, a run of ASCII digits optionally separated by `.`, that does
+/// not follow a letter, digit, or underscore, with [`NUMBER_PLACEHOLDER`].
+///
+fn mask_numbers(word: &str, normalized: &mut String) {
+    let bytes = word.as_bytes();
+    let mut follows_word = false;
+    let mut i = 0;
+    while let Some(c) = word[i..].chars().next() {
+        if c.is_ascii_digit() && !follows_word {
+            i += 1;
+            while i < bytes.len()
+                && (bytes[i].is_ascii_digit()
+                    || (bytes[i] == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)))
+            {
+                i += 1;
+            }
+            normalized.push_str(NUMBER_PLACEHOLDER);
+            follows_word = true;
+        } else {
+            normalized.push(c);
+            follows_word = c.is_alphanumeric() || c == '_';
+            i += c.len_utf8();
+        }
+    }
+}
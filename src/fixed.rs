@@ -0,0 +1,178 @@
+/*!
+Provides the [`FlatErrorFixed`] type, a fixed-capacity flattened error that requires no heap
+allocation.
+*/
+
+use crate::{captured_type_name, captured_type_name_of_val, compat::Error};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    hash::{Hash, Hasher},
+    str::from_utf8,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `FlatErrorFixed` captures the message and type name of an error, as [`FlatError`] does,
+/// but stores the message inline in a buffer of `N` bytes so that no heap allocation is required.
+/// This type is therefore available without the `alloc` feature.
+///
+/// Messages longer than `N` bytes are truncated, on a character boundary, and
+/// [`FlatErrorFixed::is_truncated`] will return `true`. As there is no allocation the source
+/// chain of the original error is not captured.
+///
+/// ```rust
+/// use flat_error::{ExtendedError, FlatErrorFixed};
+///
+/// let err = "12a".parse::<u8>().unwrap_err();
+/// let flat: FlatErrorFixed<16> = FlatErrorFixed::from_any(&err);
+///
+/// assert_eq!(flat.message(), "invalid digit fo");
+/// assert!(flat.is_truncated());
+///
+/// fn takes_extended(_: impl ExtendedError) {}
+/// takes_extended(flat);
+/// ```
+///
+/// [`FlatError`]: crate::FlatError
+///
+#[derive(Clone, Copy)]
+pub struct FlatErrorFixed<const N: usize> {
+    original_type_name: &'static str,
+    pub(crate) message: [u8; N],
+    pub(crate) length: usize,
+    pub(crate) truncated: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<const N: usize> Display for FlatErrorFixed<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(
+                f,
+                "{}{} (original type: `{}`)",
+                self.message(),
+                if self.truncated { "…" } else { "" },
+                self.original_type_name
+            )
+        } else {
+            write!(f, "{}", self.message())
+        }
+    }
+}
+
+impl<const N: usize> Debug for FlatErrorFixed<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("FlatErrorFixed")
+            .field("original_type_name", &self.original_type_name)
+            .field("message", &self.message())
+            .field("truncated", &self.truncated)
+            .finish()
+    }
+}
+
+impl<const N: usize> PartialEq for FlatErrorFixed<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.original_type_name == other.original_type_name
+            && self.message() == other.message()
+            && self.truncated == other.truncated
+    }
+}
+
+impl<const N: usize> Eq for FlatErrorFixed<N> {}
+
+impl<const N: usize> Hash for FlatErrorFixed<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.original_type_name.hash(state);
+        self.message().hash(state);
+        self.truncated.hash(state);
+    }
+}
+
+impl<const N: usize> Error for FlatErrorFixed<N> {}
+
+impl<const N: usize> Write for FlatErrorFixed<N> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for c in s.chars() {
+            let width = c.len_utf8();
+            if self.truncated || self.length + width > N {
+                self.truncated = true;
+                break;
+            }
+            c.encode_utf8(&mut self.message[self.length..self.length + width]);
+            self.length += width;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> FlatErrorFixed<N> {
+    ///
+    /// Construct a new `FlatErrorFixed` by flattening the provided `error`.
+    ///
+    pub fn from_any<E>(error: &E) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        let mut flat = Self::empty(captured_type_name_of_val(error));
+        // Writing to the fixed buffer truncates rather than failing, so any error here is
+        // returned by the error's own Display implementation and can be ignored.
+        let _ = write!(flat, "{error}");
+        flat
+    }
+
+    ///
+    /// Construct a new `FlatErrorFixed` with the provided `message`, the original type name will be
+    /// that of `FlatErrorFixed` itself.
+    ///
+    pub fn new(message: &str) -> Self {
+        let mut flat = Self::empty(captured_type_name::<Self>());
+        let _ = flat.write_str(message);
+        flat
+    }
+
+    ///
+    /// Return the captured, and possibly truncated, message.
+    ///
+    pub fn message(&self) -> &str {
+        // Only complete characters are ever written to the buffer.
+        from_utf8(&self.message[..self.length]).unwrap_or_default()
+    }
+
+    ///
+    /// Returns `true` if the message was truncated to fit within `N` bytes.
+    ///
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    ///
+    /// Return the name of the flattened error type, see [`FlatError::original_type_name`].
+    ///
+    /// [`FlatError::original_type_name`]: crate::FlatError::original_type_name
+    ///
+    pub fn original_type_name(&self) -> &'static str {
+        self.original_type_name
+    }
+
+    ///
+    /// Return the capacity, in bytes, of the message buffer.
+    ///
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    const fn empty(original_type_name: &'static str) -> Self {
+        Self {
+            original_type_name,
+            message: [0; N],
+            length: 0,
+            truncated: false,
+        }
+    }
+}
@@ -0,0 +1,483 @@
+/*!
+Provides the [`FlatFrame`] type, a view of a single error in the source chain of a
+[`FlatError`], the [`Frames`] iterator returned by [`FlatError::frames`], and the [`TreeFrames`]
+iterator returned by [`FlatError::tree_frames`].
+*/
+
+use crate::{short_type_name, DebugChain, FlatError, Frame, Severity, SourceSpan};
+use alloc::{vec, vec::Vec};
+use core::{
+    any::{type_name, TypeId},
+    fmt::{Debug, Formatter, Result as FmtResult},
+    iter::{FusedIterator, Rev},
+    panic::Location,
+    slice::Iter,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A view of a single error in the source chain of a [`FlatError`], providing the values
+/// captured for that error without constructing a `FlatError` for it.
+///
+#[derive(Clone, Copy)]
+pub struct FlatFrame<'a> {
+    frame: &'a Frame,
+}
+
+///
+/// An iterator over the errors in the source chain of a [`FlatError`], starting with the error
+/// itself, returned by [`FlatError::frames`].
+///
+#[derive(Clone, Debug)]
+pub struct Frames<'a> {
+    first: Option<&'a Frame>,
+    sources: Rev<Iter<'a, Frame>>,
+}
+
+///
+/// An iterator over the errors in the tree formed by a [`FlatError`], its source chain, and
+/// any branches of the errors in that chain, returned by [`FlatError::tree_frames`]. Each
+/// error is returned with its depth in the tree, where `0` is the error itself.
+///
+#[derive(Clone, Debug)]
+pub struct TreeFrames<'a> {
+    stack: Vec<TreeNode<'a>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An error in a [`TreeFrames`] iteration, along with the source chain that follows it.
+///
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TreeNode<'a> {
+    pub(crate) depth: usize,
+    pub(crate) is_last: bool,
+    pub(crate) frame: &'a Frame,
+    sources: &'a [Frame],
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return an iterator over this error and each error in its source chain, in order. Unlike
+    /// walking the chain with [`FlatError::flat_source`] this does not construct a `FlatError`
+    /// for each source, and the iterator knows its length.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .with_code("E1042")
+    ///     .context("reading config file");
+    ///
+    /// let frames = err.frames();
+    /// assert_eq!(frames.len(), 2);
+    /// assert_eq!(
+    ///     frames.map(|frame| frame.message()).collect::<Vec<_>>(),
+    ///     ["reading config file", "entity not found"]
+    /// );
+    ///
+    /// let root = err.frame(1).unwrap();
+    /// assert_eq!(root.original_type_name(), "std::io::error::Error");
+    /// assert_eq!(root.code(), Some("E1042"));
+    /// ```
+    ///
+    pub fn frames(&self) -> Frames<'_> {
+        Frames {
+            first: Some(&self.inner.frame),
+            sources: self.sources(),
+        }
+    }
+
+    ///
+    /// Return an iterator over the messages of this error and each error in its source chain,
+    /// in order.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("reading config file");
+    ///
+    /// assert_eq!(
+    ///     err.chain_messages().collect::<Vec<_>>(),
+    ///     ["reading config file", "entity not found"]
+    /// );
+    /// assert_eq!(
+    ///     err.chain_type_names().collect::<Vec<_>>(),
+    ///     ["flat_error::FlatError", "std::io::error::Error"]
+    /// );
+    /// ```
+    ///
+    pub fn chain_messages(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + FusedIterator {
+        self.frames().map(|frame| frame.message())
+    }
+
+    ///
+    /// Return an iterator over the original type names of this error and each error in its
+    /// source chain, in order.
+    ///
+    pub fn chain_type_names(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + FusedIterator {
+        self.frames().map(|frame| frame.original_type_name())
+    }
+
+    ///
+    /// Return the first of this error and the errors in its source chain for which `predicate`
+    /// returns `true`, if any.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::ConnectionRefused))
+    ///     .with_code("E111")
+    ///     .context("fetching profile");
+    ///
+    /// let coded = err.find(|frame| frame.code().is_some()).unwrap();
+    /// assert_eq!(coded.message(), "connection refused");
+    ///
+    /// assert!(err.any_message_contains("connection refused"));
+    /// assert_eq!(err.position_of_type::<IoError>(), Some(1));
+    /// assert_eq!(err.position_of_type::<std::fmt::Error>(), None);
+    /// ```
+    ///
+    pub fn find<P>(&self, mut predicate: P) -> Option<FlatFrame<'_>>
+    where
+        P: FnMut(&FlatFrame<'_>) -> bool,
+    {
+        self.frames().find(|frame| predicate(frame))
+    }
+
+    ///
+    /// Returns `true` if the message of this error, or of any error in its source chain,
+    /// contains `pattern`.
+    ///
+    pub fn any_message_contains(&self, pattern: &str) -> bool {
+        self.chain_frames()
+            .any(|frame| frame.message.contains(pattern))
+    }
+
+    ///
+    /// Return the index of the first of this error and the errors in its source chain with the
+    /// original type name of `E`, as used by [`FlatError::frame`]; see [`FlatError::is`] and
+    /// [`FlatError::chain_matches`].
+    ///
+    pub fn position_of_type<E>(&self) -> Option<usize>
+    where
+        E: ?Sized,
+    {
+        let type_name = type_name::<E>();
+        self.chain_frames()
+            .position(|frame| frame.original_type_name == type_name)
+    }
+
+    ///
+    /// Return the error at `index` in the source chain, where `0` is this error, `1` its
+    /// immediate source, and so on.
+    ///
+    pub fn frame(&self, index: usize) -> Option<FlatFrame<'_>> {
+        match index {
+            0 => Some(&self.inner.frame),
+            _ => {
+                let sources = self.source_frames();
+                sources
+                    .len()
+                    .checked_sub(index)
+                    .map(|index| &sources[index])
+            }
+        }
+        .map(FlatFrame::new)
+    }
+
+    ///
+    /// Return an iterator over this error, each error in its source chain, and each error in the
+    /// branches of those errors, see [`FlatError::join`], depth first. The children of each
+    /// error are its source, followed by its branches.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::join(FlatError::new_static("a"), FlatError::new_static("b"))
+    ///     .context("both failed");
+    ///
+    /// assert_eq!(
+    ///     err.tree_frames()
+    ///         .map(|(depth, frame)| (depth, frame.message()))
+    ///         .collect::<Vec<_>>(),
+    ///     [(0, "both failed"), (1, "a; b"), (2, "a"), (2, "b")]
+    /// );
+    /// ```
+    ///
+    pub fn tree_frames(&self) -> TreeFrames<'_> {
+        TreeFrames {
+            stack: vec![TreeNode {
+                depth: 0,
+                is_last: true,
+                frame: &self.inner.frame,
+                sources: self.source_frames(),
+            }],
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for FlatFrame<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(
+            &DebugChain {
+                frame: self.frame,
+                sources: &[],
+                category: None,
+            },
+            f,
+        )
+    }
+}
+
+impl PartialEq for FlatFrame<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.frame == other.frame
+    }
+}
+
+impl<'a> FlatFrame<'a> {
+    pub(crate) const fn new(frame: &'a Frame) -> Self {
+        Self { frame }
+    }
+
+    ///
+    /// Return the message of this error.
+    ///
+    pub fn message(&self) -> &'a str {
+        &self.frame.message
+    }
+
+    ///
+    /// Return the original type name of this error, see [`FlatError::original_type_name`].
+    ///
+    pub fn original_type_name(&self) -> &'static str {
+        self.frame.original_type_name
+    }
+
+    ///
+    /// Return the original type name of this error without any module path or generic
+    /// parameters, see [`FlatError::original_type_short_name`].
+    ///
+    pub fn original_type_short_name(&self) -> &'a str {
+        short_type_name(self.frame.original_type_name)
+    }
+
+    ///
+    /// Return the `TypeId` of the original error, if it was captured, see
+    /// [`FlatError::original_type_id`].
+    ///
+    pub fn original_type_id(&self) -> Option<TypeId> {
+        self.frame.type_id
+    }
+
+    ///
+    /// Return the error code of this error, if any.
+    ///
+    pub fn code(&self) -> Option<&'a str> {
+        self.frame.code()
+    }
+
+    ///
+    /// Return the severity of this error.
+    ///
+    pub fn severity(&self) -> Severity {
+        self.frame.severity
+    }
+
+    ///
+    /// Return the location at which this error was created, if it was captured.
+    ///
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.frame.details().location.0
+    }
+
+    ///
+    /// Return an iterator over the metadata fields of this error, in the order they were added.
+    ///
+    pub fn fields(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.frame.fields()
+    }
+
+    ///
+    /// Return the value of the metadata field `key`, if present.
+    ///
+    pub fn field(&self, key: &str) -> Option<&'a str> {
+        self.frame.field(key)
+    }
+
+    ///
+    /// Return the message key of this error, if any, see [`FlatError::message_key`].
+    ///
+    pub fn message_key(&self) -> Option<&'a str> {
+        self.frame.details().message_key.as_deref()
+    }
+
+    ///
+    /// Return the `Debug` form of the original error, if it was captured, see
+    /// [`FlatError::debug_repr`].
+    ///
+    pub fn debug_repr(&self) -> Option<&'a str> {
+        self.frame.details().debug_repr.as_deref()
+    }
+
+    ///
+    /// Return the message template of this error, if any, see [`FlatError::message_template`].
+    ///
+    pub fn message_template(&self) -> Option<&'a str> {
+        self.frame.details().message_template.as_deref()
+    }
+
+    ///
+    /// Return the help text attached to this error, if any, see [`FlatError::with_help`].
+    ///
+    pub fn help(&self) -> Option<&'a str> {
+        self.frame.details().help.as_deref()
+    }
+
+    ///
+    /// Return the documentation URL attached to this error, if any, see
+    /// [`FlatError::with_docs_url`].
+    ///
+    pub fn docs_url(&self) -> Option<&'a str> {
+        self.frame.details().docs_url.as_deref()
+    }
+
+    ///
+    /// Return the source span attached to this error, if any, see [`FlatError::with_span`].
+    ///
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.frame.details().span
+    }
+
+    ///
+    /// Return the number of consecutive identical errors collapsed into this error, see
+    /// [`FlatError::repeat_count`].
+    ///
+    pub fn repeat_count(&self) -> usize {
+        self.frame.repeat_count()
+    }
+
+    ///
+    /// Return an iterator over the branches of this error, see [`FlatError::branches`].
+    ///
+    pub fn branches(&self) -> Iter<'a, FlatError> {
+        self.frame.details().branches.iter()
+    }
+
+    ///
+    /// Return an iterator over the related errors of this error, see [`FlatError::related`].
+    ///
+    pub fn related(&self) -> Iter<'a, FlatError> {
+        self.frame.details().related.iter()
+    }
+
+    ///
+    /// Return an iterator over the arguments used to render the message of this error, see
+    /// [`FlatError::message_args`].
+    ///
+    pub fn message_args(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.frame
+            .details()
+            .message_args
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = FlatFrame<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first
+            .take()
+            .or_else(|| self.sources.next())
+            .map(FlatFrame::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Frames<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.sources
+            .next_back()
+            .or_else(|| self.first.take())
+            .map(FlatFrame::new)
+    }
+}
+
+impl ExactSizeIterator for Frames<'_> {
+    fn len(&self) -> usize {
+        usize::from(self.first.is_some()) + self.sources.len()
+    }
+}
+
+impl FusedIterator for Frames<'_> {}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Iterator for TreeFrames<'a> {
+    type Item = (usize, FlatFrame<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node()
+            .map(|node| (node.depth, FlatFrame::new(node.frame)))
+    }
+}
+
+impl FusedIterator for TreeFrames<'_> {}
+
+impl<'a> TreeFrames<'a> {
+    ///
+    /// Return the next error in the tree, pushing its children so that the first is returned
+    /// next.
+    ///
+    pub(crate) fn next_node(&mut self) -> Option<TreeNode<'a>> {
+        let node = self.stack.pop()?;
+        let depth = node.depth + 1;
+        let branches = node.frame.details().branches.iter().map(|branch| TreeNode {
+            depth,
+            is_last: false,
+            frame: &branch.inner.frame,
+            sources: branch.source_frames(),
+        });
+        let source = node.sources.split_last().map(|(frame, sources)| TreeNode {
+            depth,
+            is_last: false,
+            frame,
+            sources,
+        });
+        let start = self.stack.len();
+        self.stack.extend(source.into_iter().chain(branches));
+        if let Some(last) = self.stack[start..].last_mut() {
+            last.is_last = true;
+        }
+        self.stack[start..].reverse();
+        Some(node)
+    }
+}
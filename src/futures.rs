@@ -0,0 +1,280 @@
+/*!
+Provides extension traits for the [`futures`](https://docs.rs/futures) traits `TryFuture` and
+`TryStream`, converting their errors into [`FlatError`] as [`ResultExt`](crate::ResultExt) does
+for `Result`.
+
+```rust
+use flat_error::{futures::TryFutureFlatExt, FlatError};
+use std::io::{Error as IoError, ErrorKind};
+
+async fn load(path: &str) -> Result<String, IoError> {
+    Err(IoError::from(ErrorKind::NotFound))
+}
+
+let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+let err: FlatError = runtime
+    .block_on(load("profile.toml").flat_context("loading profile"))
+    .unwrap_err();
+
+assert_eq!(err.to_string(), "loading profile");
+assert_eq!(err.flat_source().unwrap().io_kind(), Some(ErrorKind::NotFound));
+```
+*/
+
+use crate::FlatError;
+use ::futures_core::{
+    future::{FusedFuture, TryFuture},
+    stream::{FusedStream, Stream, TryStream},
+};
+use core::{
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use pin_project_lite::pin_project;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Extension methods on any `TryFuture` whose error may be converted into a [`FlatError`].
+///
+pub trait TryFutureFlatExt: TryFuture + Sized {
+    ///
+    /// Return a future which converts the error of this future into a [`FlatError`].
+    ///
+    fn flat_err(self) -> FlatErr<Self>
+    where
+        Self::Error: Into<FlatError>,
+    {
+        FlatErr { inner: self }
+    }
+
+    ///
+    /// Return a future which converts the error of this future into a [`FlatError`] with
+    /// `context` as its message, see [`FlatError::context`].
+    ///
+    fn flat_context<C>(self, context: C) -> FlatContext<Self, C>
+    where
+        Self::Error: Into<FlatError>,
+        C: Display,
+    {
+        FlatContext {
+            inner: self,
+            context,
+        }
+    }
+}
+
+///
+/// Extension methods on any `TryStream` whose error may be converted into a [`FlatError`].
+///
+/// ```rust
+/// use flat_error::futures::TryStreamFlatExt;
+/// use futures_util::{stream, StreamExt};
+/// use std::num::ParseIntError;
+///
+/// let parsed = stream::iter(["1", "x"].map(str::parse::<u32>)).flat_context("parsing line");
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let results = runtime.block_on(parsed.collect::<Vec<_>>());
+///
+/// assert_eq!(results[0], Ok(1));
+/// assert_eq!(results[1].as_ref().unwrap_err().to_string(), "parsing line");
+/// assert!(results[1].as_ref().unwrap_err().chain_matches::<ParseIntError>());
+/// ```
+///
+pub trait TryStreamFlatExt: TryStream + Sized {
+    ///
+    /// Return a stream which converts each error of this stream into a [`FlatError`].
+    ///
+    fn flat_err(self) -> FlatErr<Self>
+    where
+        Self::Error: Into<FlatError>,
+    {
+        FlatErr { inner: self }
+    }
+
+    ///
+    /// Return a stream which converts each error of this stream into a [`FlatError`] with
+    /// `context` as its message, see [`FlatError::context`].
+    ///
+    fn flat_context<C>(self, context: C) -> FlatContext<Self, C>
+    where
+        Self::Error: Into<FlatError>,
+        C: Display,
+    {
+        FlatContext {
+            inner: self,
+            context,
+        }
+    }
+}
+
+pin_project! {
+    ///
+    /// The future, or stream, returned by [`TryFutureFlatExt::flat_err`] and
+    /// [`TryStreamFlatExt::flat_err`].
+    ///
+    #[derive(Clone, Debug)]
+    #[must_use = "futures and streams do nothing unless polled"]
+    pub struct FlatErr<T> {
+        #[pin]
+        inner: T,
+    }
+}
+
+pin_project! {
+    ///
+    /// The future, or stream, returned by [`TryFutureFlatExt::flat_context`] and
+    /// [`TryStreamFlatExt::flat_context`].
+    ///
+    #[derive(Clone, Debug)]
+    #[must_use = "futures and streams do nothing unless polled"]
+    pub struct FlatContext<T, C> {
+        #[pin]
+        inner: T,
+        context: C,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<F: TryFuture> TryFutureFlatExt for F {}
+
+impl<S: TryStream> TryStreamFlatExt for S {}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T> FlatErr<T> {
+    ///
+    /// Consume this future, or stream, returning the inner one.
+    ///
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<F> Future for FlatErr<F>
+where
+    F: TryFuture,
+    F::Error: Into<FlatError>,
+{
+    type Output = Result<F::Ok, FlatError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        Poll::Ready(ready!(this.inner.try_poll(cx)).map_err(Into::into))
+    }
+}
+
+impl<F> FusedFuture for FlatErr<F>
+where
+    F: TryFuture + FusedFuture,
+    F::Error: Into<FlatError>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S> Stream for FlatErr<S>
+where
+    S: TryStream,
+    S::Error: Into<FlatError>,
+{
+    type Item = Result<S::Ok, FlatError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(ready!(this.inner.try_poll_next(cx)).map(|item| item.map_err(Into::into)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> FusedStream for FlatErr<S>
+where
+    S: TryStream + FusedStream,
+    S::Error: Into<FlatError>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T, C> FlatContext<T, C> {
+    ///
+    /// Consume this future, or stream, returning the inner one.
+    ///
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<F, C> Future for FlatContext<F, C>
+where
+    F: TryFuture,
+    F::Error: Into<FlatError>,
+    C: Display,
+{
+    type Output = Result<F::Ok, FlatError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let context = &*this.context;
+        Poll::Ready(ready!(this.inner.try_poll(cx)).map_err(|e| e.into().context(context)))
+    }
+}
+
+impl<F, C> FusedFuture for FlatContext<F, C>
+where
+    F: TryFuture + FusedFuture,
+    F::Error: Into<FlatError>,
+    C: Display,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S, C> Stream for FlatContext<S, C>
+where
+    S: TryStream,
+    S::Error: Into<FlatError>,
+    C: Display,
+{
+    type Item = Result<S::Ok, FlatError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let context = &*this.context;
+        Poll::Ready(
+            ready!(this.inner.try_poll_next(cx))
+                .map(|item| item.map_err(|e| e.into().context(context))),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, C> FusedStream for FlatContext<S, C>
+where
+    S: TryStream + FusedStream,
+    S::Error: Into<FlatError>,
+    C: Display,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
@@ -0,0 +1,294 @@
+/*!
+Provides the [`FlatErrorGroup`] aggregate error type.
+*/
+
+use crate::{compat::Error, FlatError};
+use alloc::{vec, vec::Vec};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    slice::Iter,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `FlatErrorGroup` holds a number of [`FlatError`]s, allowing an operation to report all of
+/// its failures rather than only the first. As it holds only flattened errors it also meets the
+/// requirements of [`ExtendedError`](crate::ExtendedError).
+///
+/// ```rust
+/// use flat_error::{FlatError, FlatErrorGroup};
+///
+/// let errors: FlatErrorGroup = ["1x", "22", "three"]
+///     .iter()
+///     .filter_map(|s| s.parse::<u8>().err())
+///     .map(|e| FlatError::from_any(&e))
+///     .collect();
+///
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(
+///     errors.to_string(),
+///     "invalid digit found in string; invalid digit found in string"
+/// );
+/// ```
+///
+/// The `Display` implementation writes the message of each error, separated by `"; "`; in
+/// alternate mode each error is written in alternate mode on its own line.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct FlatErrorGroup {
+    errors: Vec<FlatError>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatErrorGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (i, error) in self.errors.iter().enumerate() {
+            if f.alternate() {
+                if i > 0 {
+                    writeln!(f)?;
+                }
+                write!(f, "{error:#}")?;
+            } else {
+                if i > 0 {
+                    write!(f, "; ")?;
+                }
+                write!(f, "{error}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Error for FlatErrorGroup {}
+
+impl From<FlatError> for FlatErrorGroup {
+    fn from(error: FlatError) -> Self {
+        Self {
+            errors: vec![error],
+        }
+    }
+}
+
+impl From<Vec<FlatError>> for FlatErrorGroup {
+    fn from(errors: Vec<FlatError>) -> Self {
+        Self { errors }
+    }
+}
+
+impl From<FlatErrorGroup> for Vec<FlatError> {
+    fn from(group: FlatErrorGroup) -> Self {
+        group.errors
+    }
+}
+
+impl FromIterator<FlatError> for FlatErrorGroup {
+    fn from_iter<I: IntoIterator<Item = FlatError>>(iter: I) -> Self {
+        Self {
+            errors: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl Extend<FlatError> for FlatErrorGroup {
+    fn extend<I: IntoIterator<Item = FlatError>>(&mut self, iter: I) {
+        self.errors.extend(iter)
+    }
+}
+
+impl IntoIterator for FlatErrorGroup {
+    type Item = FlatError;
+    type IntoIter = <Vec<FlatError> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FlatErrorGroup {
+    type Item = &'a FlatError;
+    type IntoIter = Iter<'a, FlatError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl FlatErrorGroup {
+    ///
+    /// Construct a new, empty, group.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Add `error` to the end of this group.
+    ///
+    pub fn push(&mut self, error: FlatError) {
+        self.errors.push(error)
+    }
+
+    ///
+    /// Add `error` to this group unless an equal error is already present, in which case the
+    /// [`FlatError::repeat_count`] of that error is increased by that of `error`. Errors are
+    /// compared ignoring their repeat counts.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlatErrorGroup};
+    ///
+    /// let mut errors = FlatErrorGroup::new();
+    /// for record in 0..10_000 {
+    ///     errors.push_dedup(FlatError::new("missing field `id`"));
+    ///     if record % 5_000 == 0 {
+    ///         errors.push_dedup(FlatError::new("invalid date"));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors.as_slice()[0].repeat_count(), 10_000);
+    /// assert_eq!(errors.as_slice()[1].repeat_count(), 2);
+    /// assert_eq!(errors.total_count(), 10_002);
+    /// ```
+    ///
+    pub fn push_dedup(&mut self, error: FlatError) {
+        match self
+            .errors
+            .iter_mut()
+            .find(|existing| is_repeat(existing, &error))
+        {
+            Some(existing) => existing.add_repeats(error.repeat_count()),
+            None => self.errors.push(error),
+        }
+    }
+
+    ///
+    /// Collapse equal errors in this group, wherever they occur, into the first of them, whose
+    /// [`FlatError::repeat_count`] becomes the sum of the repeat counts of those collapsed.
+    /// Errors are compared ignoring their repeat counts, and the order of the first occurrence
+    /// of each error is retained.
+    ///
+    pub fn dedup(&mut self) {
+        self.dedup_with(is_repeat)
+    }
+
+    ///
+    /// Collapse errors in this group with the same [`FlatError::fingerprint`], wherever they
+    /// occur, into the first of them, as [`FlatErrorGroup::dedup`] does. Errors differing only in
+    /// volatile values, such as numbers and paths, are collapsed even though they are not equal.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlatErrorGroup};
+    ///
+    /// let mut errors: FlatErrorGroup = (1..=3)
+    ///     .map(|line| FlatError::new(format!("unexpected end of input at line {line}")))
+    ///     .collect();
+    ///
+    /// errors.dedup();
+    /// assert_eq!(errors.len(), 3);
+    ///
+    /// errors.dedup_by_fingerprint();
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors.to_string(), "unexpected end of input at line 1");
+    /// assert_eq!(errors.as_slice()[0].repeat_count(), 3);
+    /// ```
+    ///
+    pub fn dedup_by_fingerprint(&mut self) {
+        self.dedup_with(|existing, error| existing.fingerprint() == error.fingerprint())
+    }
+
+    ///
+    /// Return the number of errors added to this group, including those collapsed into another,
+    /// that is the sum of the [`FlatError::repeat_count`] of each error.
+    ///
+    pub fn total_count(&self) -> usize {
+        self.errors.iter().map(FlatError::repeat_count).sum()
+    }
+
+    ///
+    /// Return an iterator over the errors in this group, in the order they were added.
+    ///
+    pub fn iter(&self) -> Iter<'_, FlatError> {
+        self.errors.iter()
+    }
+
+    ///
+    /// Return the number of errors in this group.
+    ///
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    ///
+    /// Returns `true` if this group contains no errors.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    ///
+    /// Return a slice containing the errors in this group.
+    ///
+    pub fn as_slice(&self) -> &[FlatError] {
+        self.errors.as_slice()
+    }
+
+    ///
+    /// Collapse each error for which `is_repeat` returns `true`, given an earlier retained
+    /// error, into that error.
+    ///
+    fn dedup_with<F>(&mut self, mut is_repeat: F)
+    where
+        F: FnMut(&FlatError, &FlatError) -> bool,
+    {
+        let mut retained: Vec<FlatError> = Vec::with_capacity(self.errors.len());
+        for error in self.errors.drain(..) {
+            match retained
+                .iter_mut()
+                .find(|existing| is_repeat(existing, &error))
+            {
+                Some(existing) => existing.add_repeats(error.repeat_count()),
+                None => retained.push(error),
+            }
+        }
+        self.errors = retained;
+    }
+}
+
+impl FlatError {
+    ///
+    /// Increase the repeat count of this error by `count`.
+    ///
+    fn add_repeats(&mut self, count: usize) {
+        let count = self.repeat_count() + count;
+        self.inner.frame.details_mut().repeat_count = Some(count);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns `true` if `error` is equal to `existing`, ignoring their repeat counts.
+///
+fn is_repeat(existing: &FlatError, error: &FlatError) -> bool {
+    if existing.repeat_count() == error.repeat_count() {
+        existing == error
+    } else {
+        let mut error = error.clone();
+        error.inner.frame.details_mut().repeat_count = existing.inner.frame.details().repeat_count;
+        *existing == error
+    }
+}
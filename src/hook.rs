@@ -0,0 +1,80 @@
+/*!
+Provides the [`FlattenHook`] type, a function called whenever an error is flattened, see
+[`FlatErrorConfig::with_flatten_hook`].
+*/
+
+use crate::{FlatError, FlatErrorBuilder, FlatErrorConfig};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A function called whenever an error is flattened, with the error being flattened and a
+/// builder initialized from the flattened error. Any changes made to the builder are reflected
+/// in the resulting [`FlatError`].
+///
+pub type FlattenHook = fn(&dyn Error, &mut FlatErrorBuilder);
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Allows an error that may not be `Sized` to be passed to the hook as a `&dyn Error`.
+///
+pub(crate) struct ErrorRef<'a, E: ?Sized>(pub(crate) &'a E);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<E> Display for ErrorRef<'_, E>
+where
+    E: Error + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.0, f)
+    }
+}
+
+impl<E> Debug for ErrorRef<'_, E>
+where
+    E: Error + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl<E> Error for ErrorRef<'_, E>
+where
+    E: Error + ?Sized,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Apply the flatten hook of the installed configuration, if any, to `flat` which was flattened
+/// from `error`.
+///
+pub(crate) fn apply_flatten_hook(error: &dyn Error, flat: FlatError) -> FlatError {
+    match FlatErrorConfig::global().flatten_hook() {
+        Some(hook) => {
+            let mut builder = FlatErrorBuilder::from(flat);
+            hook(error, &mut builder);
+            builder.build()
+        }
+        None => flat,
+    }
+}
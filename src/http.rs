@@ -0,0 +1,72 @@
+/*!
+Provides a mapping from a [`FlatError`] to an [`http::StatusCode`](https://docs.rs/http), see
+[`FlatError::http_status`].
+
+```rust
+use flat_error::FlatError;
+use http::StatusCode;
+use std::io::{Error as IoError, ErrorKind};
+
+let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("loading profile");
+assert_eq!(err.http_status(), StatusCode::NOT_FOUND);
+
+let err = err.with_http_status(StatusCode::GONE);
+assert_eq!(err.http_status(), StatusCode::GONE);
+```
+
+*/
+
+use crate::{ErrorClass, FlatError};
+use ::http::StatusCode;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Return a copy of this error with the HTTP status code to be used when it is returned in a
+    /// response, this is captured as the field `http_status`.
+    ///
+    pub fn with_http_status(self, status: StatusCode) -> Self {
+        self.with_field("http_status", status.as_u16())
+    }
+
+    ///
+    /// Return the HTTP status code to be used when this error is returned in a response. This is
+    /// the code set by [`FlatError::with_http_status`], if any, or one corresponding to the class
+    /// of the first error in the source chain that can be classified, see [`FlatError::class`],
+    /// otherwise it is `500 Internal Server Error`.
+    ///
+    pub fn http_status(&self) -> StatusCode {
+        if let Some(status) = self
+            .field("http_status")
+            .and_then(|status| status.parse::<u16>().ok())
+            .and_then(|status| StatusCode::from_u16(status).ok())
+        {
+            return status;
+        }
+        self.chain_class()
+            .map_or(StatusCode::INTERNAL_SERVER_ERROR, StatusCode::from)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<ErrorClass> for StatusCode {
+    fn from(class: ErrorClass) -> Self {
+        match class {
+            ErrorClass::NotFound => StatusCode::NOT_FOUND,
+            ErrorClass::PermissionDenied => StatusCode::FORBIDDEN,
+            ErrorClass::Unauthenticated => StatusCode::UNAUTHORIZED,
+            ErrorClass::InvalidInput => StatusCode::BAD_REQUEST,
+            ErrorClass::Conflict => StatusCode::CONFLICT,
+            ErrorClass::Timeout => StatusCode::GATEWAY_TIMEOUT,
+            ErrorClass::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorClass::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+            ErrorClass::Cancelled => StatusCode::REQUEST_TIMEOUT,
+            ErrorClass::Unsupported => StatusCode::NOT_IMPLEMENTED,
+            ErrorClass::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
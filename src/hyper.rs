@@ -0,0 +1,33 @@
+/*!
+Provides an implementation of `From<hyper::Error>` for [`FlatError`]. Each of the fields
+`parse`, `user`, `canceled`, `closed`, `incomplete_message`, `body_write_aborted`, and `timeout`
+is set to `true` if the corresponding `is_*` method of the error returns `true`.
+*/
+
+use crate::FlatError;
+use ::hyper::Error;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let kinds = [
+            ("parse", e.is_parse()),
+            ("user", e.is_user()),
+            ("canceled", e.is_canceled()),
+            ("closed", e.is_closed()),
+            ("incomplete_message", e.is_incomplete_message()),
+            ("body_write_aborted", e.is_body_write_aborted()),
+            ("timeout", e.is_timeout()),
+        ];
+        kinds
+            .into_iter()
+            .filter(|(_, is_kind)| *is_kind)
+            .fold(FlatError::from_error(&e), |flat, (kind, _)| {
+                flat.with_field(kind, true)
+            })
+    }
+}
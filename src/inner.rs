@@ -0,0 +1,130 @@
+/*!
+Provides [`InnerBox`], the pointer from a [`FlatError`](crate::FlatError) to its contents. This
+either owns the contents, as a `Box` would, or holds only the static message of an error
+constructed by [`FlatError::new_static`](crate::FlatError::new_static), so that such an error may
+be constructed in a `const` context without allocation.
+*/
+
+use crate::{Frame, Inner, SharedStr, FLAT_ERROR_TYPE_NAME};
+use alloc::boxed::Box;
+use core::ops::{Deref, DerefMut};
+use once_cell::race::OnceBox;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A pointer to the contents of a [`FlatError`](crate::FlatError), either owned or static. The
+/// contents of a static error are allocated when first read, and copied to an owned `Inner` when
+/// first modified, or when taken by [`InnerBox::into_inner`].
+///
+pub(crate) enum InnerBox<C> {
+    Owned(Box<Inner<C>>),
+    Static {
+        message: &'static str,
+        category: fn() -> C,
+        inner: OnceBox<Inner<C>>,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> Deref for InnerBox<C> {
+    type Target = Inner<C>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Owned(inner) => inner,
+            Self::Static {
+                message,
+                category,
+                inner,
+            } => inner.get_or_init(|| Box::new(static_inner(message, category()))),
+        }
+    }
+}
+
+impl<C> DerefMut for InnerBox<C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        if let Self::Static {
+            message, category, ..
+        } = self
+        {
+            *self = Self::new(static_inner(message, category()));
+        }
+        match self {
+            Self::Owned(inner) => inner,
+            Self::Static { .. } => unreachable!(),
+        }
+    }
+}
+
+impl<C: Clone> Clone for InnerBox<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Owned(inner) => Self::new(Inner {
+                frame: inner.frame.clone(),
+                sources: inner.sources.clone(),
+                category: inner.category.clone(),
+            }),
+            Self::Static {
+                message, category, ..
+            } => Self::Static {
+                message,
+                category: *category,
+                inner: OnceBox::new(),
+            },
+        }
+    }
+}
+
+impl<C> InnerBox<C> {
+    ///
+    /// Construct a new pointer owning `inner`.
+    ///
+    pub(crate) fn new(inner: Inner<C>) -> Self {
+        Self::Owned(Box::new(inner))
+    }
+
+    ///
+    /// Return the contents, constructing them if static.
+    ///
+    pub(crate) fn into_inner(self) -> Inner<C> {
+        match self {
+            Self::Owned(inner) => *inner,
+            Self::Static {
+                message, category, ..
+            } => static_inner(message, category()),
+        }
+    }
+}
+
+impl InnerBox<()> {
+    ///
+    /// Construct a new pointer holding only the static `message`.
+    ///
+    pub(crate) const fn from_static(message: &'static str) -> Self {
+        Self::Static {
+            message,
+            category: unit_category,
+            inner: OnceBox::new(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn static_inner<C>(message: &'static str, category: C) -> Inner<C> {
+    Inner {
+        frame: Frame::new(FLAT_ERROR_TYPE_NAME, SharedStr::Static(message)),
+        sources: None,
+        category,
+    }
+}
+
+fn unit_category() {}
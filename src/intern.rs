@@ -0,0 +1,137 @@
+/*!
+Provides a global pool of interned messages, so that errors flattened repeatedly with the same
+message, such as those emitted by a hot loop retrying the same failing operation, share a single
+allocation rather than each holding their own copy. Interning is enabled for an error by
+[`FlatError::from_any_interned`], or by [`FlattenOptions::with_interned_messages`].
+
+```rust
+use flat_error::FlatError;
+use std::io::Error as IoError;
+
+let first = FlatError::from_any_interned(&IoError::other("connection refused by remote host"));
+let second = FlatError::from_any_interned(&IoError::other("connection refused by remote host"));
+
+let message = |err: &FlatError| err.chain_messages().next().unwrap().as_ptr();
+assert_eq!(message(&first), message(&second));
+```
+
+Messages stored inline with the **inline-messages** feature are not interned, as they already
+require no allocation.
+
+The pool holds at most [`MAX_INTERNED_MESSAGES`] messages; when it is full, messages no longer
+held by any error are removed and, if it is still full, new messages are not interned. The pool
+may be emptied with [`clear_interned_messages`].
+*/
+
+use crate::{compat::Error, FlatError, FlattenOptions, SharedStr};
+use alloc::sync::Arc;
+use core::iter;
+use std::{
+    collections::HashSet,
+    sync::{Mutex, PoisonError},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The maximum number of messages held in the global pool of interned messages.
+///
+pub const MAX_INTERNED_MESSAGES: usize = 1024;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Remove all messages from the global pool of interned messages. Errors holding an interned
+/// message are unaffected, although they no longer share it with errors flattened later.
+///
+/// ```rust
+/// use flat_error::{clear_interned_messages, FlatError};
+/// use std::io::Error as IoError;
+///
+/// let first = FlatError::from_any_interned(&IoError::other("connection refused by remote host"));
+/// clear_interned_messages();
+/// let second = FlatError::from_any_interned(&IoError::other("connection refused by remote host"));
+///
+/// let message = |err: &FlatError| err.chain_messages().next().unwrap().as_ptr();
+/// assert_eq!(first, second);
+/// assert_ne!(message(&first), message(&second));
+/// ```
+///
+pub fn clear_interned_messages() {
+    *MESSAGE_POOL.lock().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+static MESSAGE_POOL: Mutex<Option<HashSet<Arc<str>>>> = Mutex::new(None);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, as [`FlatError::from_any`]
+    /// does, with the message of the error and of each error in its source chain taken from the
+    /// global pool of interned messages.
+    ///
+    #[track_caller]
+    pub fn from_any_interned<E>(error: &E) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        Self::from_any_with(error, FlattenOptions::new().with_interned_messages(true))
+    }
+
+    ///
+    /// Return this error with the message of each frame replaced by the identical message in
+    /// the global pool, adding those not already present.
+    ///
+    pub(crate) fn interned(self) -> Self {
+        let (mut frame, mut sources) = self.into_frames();
+        {
+            let mut pool = MESSAGE_POOL.lock().unwrap_or_else(PoisonError::into_inner);
+            let pool = pool.get_or_insert_with(HashSet::new);
+            for frame in iter::once(&mut frame).chain(&mut sources) {
+                intern(pool, &mut frame.message);
+            }
+        }
+        Self::from_frames(frame, sources)
+    }
+
+    ///
+    /// Return this error with its messages interned, as by `interned`, if enabled by `options`.
+    ///
+    pub(crate) fn interned_with(self, options: FlattenOptions) -> Self {
+        if options.intern_messages() {
+            self.interned()
+        } else {
+            self
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn intern(pool: &mut HashSet<Arc<str>>, message: &mut SharedStr) {
+    if let SharedStr::Shared(shared) = message {
+        if let Some(pooled) = pool.get(&**shared) {
+            *shared = Arc::clone(pooled);
+            return;
+        }
+        if pool.len() >= MAX_INTERNED_MESSAGES {
+            pool.retain(|pooled| Arc::strong_count(pooled) > 1);
+        }
+        if pool.len() < MAX_INTERNED_MESSAGES {
+            let _ = pool.insert(Arc::clone(shared));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*!
+Provides the [`IntoFlat`] trait, a conversion into [`FlatError`] that consumes the error, so that
+an error type may move the data it owns into the new error rather than have it copied by
+formatting, and may attach extra data as it is converted.
+
+```rust
+use flat_error::{FlatError, IntoFlat};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[derive(Debug)]
+struct ScriptError {
+    message: String,
+    line: u32,
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} (line {})", self.message, self.line)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl IntoFlat for ScriptError {
+    fn into_flat(self) -> FlatError {
+        FlatError::new(self.message).with_field("line", self.line)
+    }
+}
+
+let err = ScriptError { message: "undefined variable `x`".to_string(), line: 3 }.into_flat();
+
+assert_eq!(err.to_string(), "undefined variable `x`");
+assert_eq!(err.field("line"), Some("3"));
+```
+*/
+
+use crate::{compat::Error, FlatError};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by error types that may be converted into a [`FlatError`] by value. The provided
+/// implementation of [`IntoFlat::into_flat`] flattens the error as [`FlatError::from_error`]
+/// does, so an error type may opt in with an empty implementation, or override it to move its
+/// own data into the new error.
+///
+/// As Rust does not allow [`FlatError::from_any`] and `From` to detect this trait, an error is
+/// only converted this way by calling [`IntoFlat::into_flat`], for example with
+/// `result.map_err(IntoFlat::into_flat)`.
+///
+pub trait IntoFlat: Error + Sized + 'static {
+    ///
+    /// Convert this error into a [`FlatError`], consuming it.
+    ///
+    fn into_flat(self) -> FlatError {
+        FlatError::from_error(&self)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl IntoFlat for FlatError {
+    ///
+    /// Return this error unchanged.
+    ///
+    fn into_flat(self) -> FlatError {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl IntoFlat for ::std::io::Error {}
+
+#[cfg(feature = "std")]
+impl IntoFlat for crate::FlatIoError {
+    ///
+    /// Convert this error into a [`FlatError`] as `From` does, moving its inner error and
+    /// adding its path, if present, as the field [`FILE_PATH_FIELD`](crate::FILE_PATH_FIELD).
+    ///
+    fn into_flat(self) -> FlatError {
+        FlatError::from(self)
+    }
+}
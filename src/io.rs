@@ -0,0 +1,249 @@
+/*!
+Provides the [`FlatIoError`] type, a flattened `std::io::Error` that retains its `ErrorKind`, any
+operating system error code, and optionally the path of the file on which the operation failed.
+
+```rust
+use flat_error::{FlatError, FlatIoError, FILE_PATH_FIELD};
+use std::{fs::File, io::ErrorKind, path::Path};
+
+let err = File::open("/no/such/file")
+    .map_err(|e| FlatIoError::from(e).with_path("/no/such/file"))
+    .unwrap_err();
+
+assert_eq!(err.kind(), ErrorKind::NotFound);
+assert!(err.raw_os_error().is_some());
+assert_eq!(err.path(), Some(Path::new("/no/such/file")));
+
+let flat = FlatError::from(err.clone());
+assert_eq!(flat.field(FILE_PATH_FIELD), Some("/no/such/file"));
+assert_eq!(FlatIoError::from(flat), err);
+```
+*/
+
+use crate::{compat::Error, FlatError, Frame, SharedStr, FLAT_ERROR_TYPE_NAME};
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    io::{Error as IoError, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of the metadata field holding the path of a [`FlatIoError`] when it is converted
+/// into a [`FlatError`].
+///
+pub const FILE_PATH_FIELD: &str = "file.path";
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A flattened `std::io::Error`, which unlike the original is `Clone` and `PartialEq`, and so
+/// implements [`ExtendedError`](crate::ExtendedError). It retains the `ErrorKind` and any
+/// operating system error code of the original, along with its message and source chain as a
+/// [`FlatError`], and may also hold the path of the file on which the operation failed.
+///
+/// When converted into a `std::io::Error` the `FlatIoError` becomes its inner error, so that a
+/// `FlatIoError` converted back from that error is equal to the original.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FlatIoError {
+    error: FlatError,
+    kind: ErrorKind,
+    raw_os_error: Option<i32>,
+    path: Option<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatIoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.path {
+            Some(path) => write!(f, "{}: {}", self.error, path.display()),
+            None => Display::fmt(&self.error, f),
+        }
+    }
+}
+
+impl Error for FlatIoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl From<IoError> for FlatIoError {
+    fn from(error: IoError) -> Self {
+        match error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<Self>())
+        {
+            Some(flat) => flat.clone(),
+            None => Self::from_io_error(&error),
+        }
+    }
+}
+
+impl From<FlatIoError> for IoError {
+    fn from(error: FlatIoError) -> Self {
+        IoError::new(error.kind, error)
+    }
+}
+
+impl From<FlatError> for FlatIoError {
+    ///
+    /// Convert `error` into a `FlatIoError`, taking the kind and operating system error code of
+    /// the first error in its chain that has a kind, see [`FlatError::io_kind`], or
+    /// `ErrorKind::Other` if none has; and the path from the field [`FILE_PATH_FIELD`], which is
+    /// removed from the error.
+    ///
+    fn from(mut error: FlatError) -> Self {
+        let (kind, raw_os_error) = error
+            .chain_frames()
+            .find_map(|frame| {
+                let details = frame.details();
+                details.io_kind.map(|kind| (kind, details.os_error_code))
+            })
+            .unwrap_or((ErrorKind::Other, None));
+        let path = error.field(FILE_PATH_FIELD).map(PathBuf::from);
+        if path.is_some() {
+            let fields = &mut error.inner.frame.details_mut().fields;
+            fields.retain(|(key, _)| key != FILE_PATH_FIELD);
+        }
+        Self {
+            error,
+            kind,
+            raw_os_error,
+            path,
+        }
+    }
+}
+
+impl From<FlatIoError> for FlatError {
+    ///
+    /// Convert `error` into a `FlatError`, adding its path, if present, as the field
+    /// [`FILE_PATH_FIELD`].
+    ///
+    fn from(error: FlatIoError) -> Self {
+        match error.path {
+            Some(path) => error.error.with_field(FILE_PATH_FIELD, path.display()),
+            None => error.error,
+        }
+    }
+}
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` with `message`, as [`FlatError::new`] does, and with the
+    /// provided `kind` as though it had been flattened from a `std::io::Error`; the kind is
+    /// returned by [`FlatError::io_kind`], determines the [class](FlatError::class) of the
+    /// error, and is retained when it is converted into a [`FlatIoError`].
+    ///
+    /// ```rust
+    /// use flat_error::{ErrorClass, FlatError};
+    /// use std::io::ErrorKind;
+    ///
+    /// let err = FlatError::from_io_kind(ErrorKind::TimedOut, "no response from server");
+    /// assert_eq!(err.io_kind(), Some(ErrorKind::TimedOut));
+    /// assert_eq!(err.class(), ErrorClass::Timeout);
+    /// assert_eq!(err.to_string(), "no response from server");
+    /// ```
+    ///
+    pub fn from_io_kind<S>(kind: ErrorKind, message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let mut error = Self::new(message);
+        error.inner.frame.details_mut().io_kind = Some(kind);
+        error
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl FlatIoError {
+    ///
+    /// Construct a new `FlatIoError` of the provided `kind` with `message`, as
+    /// `std::io::Error::new` does.
+    ///
+    /// ```rust
+    /// use flat_error::FlatIoError;
+    /// use std::io::ErrorKind;
+    ///
+    /// let err = FlatIoError::new(ErrorKind::InvalidData, "bad header");
+    /// assert_eq!(err.kind(), ErrorKind::InvalidData);
+    /// assert_eq!(err.to_string(), "bad header");
+    /// ```
+    ///
+    pub fn new<S>(kind: ErrorKind, message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let mut frame = Frame::created(FLAT_ERROR_TYPE_NAME, SharedStr::from(message.into()));
+        frame.details_mut().io_kind = Some(kind);
+        Self {
+            error: FlatError::from_inner(frame, None, ()),
+            kind,
+            raw_os_error: None,
+            path: None,
+        }
+    }
+
+    ///
+    /// Construct a new `FlatIoError` by flattening the provided `error`.
+    ///
+    pub fn from_io_error(error: &IoError) -> Self {
+        Self {
+            error: FlatError::from_error(error),
+            kind: error.kind(),
+            raw_os_error: error.raw_os_error(),
+            path: None,
+        }
+    }
+
+    ///
+    /// Return this error with `path` as the path of the file on which the operation failed.
+    ///
+    pub fn with_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.path = Some(path.into());
+        self
+    }
+
+    ///
+    /// Return the kind of this error.
+    ///
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    ///
+    /// Return the operating system error code of this error, if it was created from an OS error.
+    ///
+    pub fn raw_os_error(&self) -> Option<i32> {
+        self.raw_os_error
+    }
+
+    ///
+    /// Return the path of the file on which the operation failed, if known.
+    ///
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    ///
+    /// Return the flattened message and source chain of this error, which does not include its
+    /// path.
+    ///
+    pub fn as_flat(&self) -> &FlatError {
+        &self.error
+    }
+}
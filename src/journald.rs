@@ -0,0 +1,243 @@
+/*!
+Provides support for the structured logging of system daemons, rendering a [`FlatError`] and its
+source chain as the fields of a `systemd` journal entry, see [`FlatError::journald_fields`] and
+[`FlatError::send_to_journald`], or as RFC 5424 syslog structured data, see
+[`FlatError::syslog_structured_data`].
+
+```rust
+use flat_error::FlatError;
+use std::io::Error as IoError;
+
+let err = FlatError::from(IoError::from_raw_os_error(2))
+    .context("reading config file")
+    .with_code("E1042");
+
+let fields = err.journald_fields();
+assert!(fields.contains(&("ERRNO".to_string(), "2".to_string())));
+assert!(fields.contains(&("FLAT_CODE".to_string(), "E1042".to_string())));
+
+assert!(err
+    .syslog_structured_data("flat@32473")
+    .starts_with(r#"[flat@32473 message="reading config file" type="flat_error::FlatError""#));
+```
+*/
+
+use crate::{DisplayStyle, FlatError, Severity};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The maximum length of the name of a journal field.
+///
+const MAX_FIELD_NAME_LEN: usize = 64;
+
+///
+/// The maximum length of the name of a syslog structured data parameter.
+///
+const MAX_PARAM_NAME_LEN: usize = 32;
+
+///
+/// The socket on which the journal receives entries using its native protocol.
+///
+#[cfg(unix)]
+const JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Return this error as the fields of a `systemd` journal entry, in the order they should be
+    /// sent:
+    ///
+    /// * `MESSAGE`; the error and its source chain in the [`DisplayStyle::Compact`] style.
+    /// * `PRIORITY`; the syslog priority corresponding to the severity of the error.
+    /// * `ERRNO`; the operating system error code of the first error in the chain with one, if
+    ///   any, see [`FlatError::os_error_code`].
+    /// * `FLAT_MESSAGE`, `FLAT_TYPE`, `FLAT_CODE`, `FLAT_SEVERITY`, and `FLAT_DEPTH`; the message,
+    ///   original type name, code if present, and severity of the error, and the number of errors
+    ///   in its source chain, including itself.
+    /// * `FLAT_SOURCE_{n}_MESSAGE` and `FLAT_SOURCE_{n}_TYPE`; the message and original type name
+    ///   of the `n`th error in the source chain, starting from `1`.
+    /// * `FLAT_FIELD_{key}`; each metadata field of the error.
+    ///
+    /// Field names are upper case, with any character other than an ASCII letter or digit
+    /// replaced with `_`, and are truncated to 64 characters.
+    ///
+    pub fn journald_fields(&self) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        fields.push((
+            "MESSAGE".to_string(),
+            self.display_with(DisplayStyle::Compact).to_string(),
+        ));
+        fields.push((
+            "PRIORITY".to_string(),
+            syslog_priority(self.severity()).to_string(),
+        ));
+        if let Some(errno) = self
+            .chain_frames()
+            .find_map(|frame| frame.details().os_error_code)
+        {
+            fields.push(("ERRNO".to_string(), errno.to_string()));
+        }
+        fields.push(("FLAT_MESSAGE".to_string(), self.to_string()));
+        fields.push((
+            "FLAT_TYPE".to_string(),
+            self.original_type_name().to_string(),
+        ));
+        if let Some(code) = self.code() {
+            fields.push(("FLAT_CODE".to_string(), code.to_string()));
+        }
+        fields.push(("FLAT_SEVERITY".to_string(), self.severity().to_string()));
+        fields.push((
+            "FLAT_DEPTH".to_string(),
+            self.chain_frames().count().to_string(),
+        ));
+        for (index, frame) in self.sources().enumerate() {
+            let index = index + 1;
+            fields.push((
+                format!("FLAT_SOURCE_{index}_MESSAGE"),
+                frame.message.to_string(),
+            ));
+            fields.push((
+                format!("FLAT_SOURCE_{index}_TYPE"),
+                frame.original_type_name.to_string(),
+            ));
+        }
+        for (key, value) in self.fields() {
+            fields.push((
+                journald_field_name(&format!("FLAT_FIELD_{key}")),
+                value.to_string(),
+            ));
+        }
+        fields
+    }
+
+    ///
+    /// Send this error to the `systemd` journal, as the fields returned by
+    /// [`FlatError::journald_fields`], using the journal's native protocol. An error is returned
+    /// if the journal is not running, or if the entry is too large to be sent as a single
+    /// datagram.
+    ///
+    #[cfg(unix)]
+    pub fn send_to_journald(&self) -> ::std::io::Result<()> {
+        let mut entry = Vec::new();
+        for (name, value) in self.journald_fields() {
+            entry.extend_from_slice(name.as_bytes());
+            if value.contains('\n') {
+                entry.push(b'\n');
+                entry.extend_from_slice(&(value.len() as u64).to_le_bytes());
+            } else {
+                entry.push(b'=');
+            }
+            entry.extend_from_slice(value.as_bytes());
+            entry.push(b'\n');
+        }
+        let socket = ::std::os::unix::net::UnixDatagram::unbound()?;
+        socket.send_to(&entry, JOURNALD_SOCKET)?;
+        Ok(())
+    }
+
+    ///
+    /// Return this error as an RFC 5424 syslog structured data element with the identifier
+    /// `sd_id`, which should be of the form `name@enterprise-number`. The element has the
+    /// parameters `message`, `type`, `code` if present, `severity`, `errno` if present, each
+    /// source as `source.{n}` and `source.{n}.type`, and each metadata field as `field.{key}`.
+    ///
+    /// Parameter names are truncated to 32 characters, with any character not permitted by
+    /// RFC 5424 replaced with `_`, and the characters `"`, `\`, and `]` in values are escaped.
+    ///
+    pub fn syslog_structured_data(&self, sd_id: &str) -> String {
+        let mut element = format!("[{sd_id}");
+        let mut push_param = |name: &str, value: &str| {
+            element.push(' ');
+            element.push_str(&syslog_param_name(name));
+            element.push_str("=\"");
+            for c in value.chars() {
+                if matches!(c, '"' | '\\' | ']') {
+                    element.push('\\');
+                }
+                element.push(c);
+            }
+            element.push('"');
+        };
+        push_param("message", &self.inner.frame.message);
+        push_param("type", self.original_type_name());
+        if let Some(code) = self.code() {
+            push_param("code", code);
+        }
+        push_param("severity", &self.severity().to_string());
+        if let Some(errno) = self
+            .chain_frames()
+            .find_map(|frame| frame.details().os_error_code)
+        {
+            push_param("errno", &errno.to_string());
+        }
+        for (index, frame) in self.sources().enumerate() {
+            let index = index + 1;
+            push_param(&format!("source.{index}"), &frame.message);
+            push_param(&format!("source.{index}.type"), frame.original_type_name);
+        }
+        for (key, value) in self.fields() {
+            push_param(&format!("field.{key}"), value);
+        }
+        element.push(']');
+        element
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the syslog priority, as used by the journal, for `severity`.
+///
+const fn syslog_priority(severity: Severity) -> u8 {
+    match severity {
+        Severity::Info => 6,
+        Severity::Warning => 4,
+        Severity::Error => 3,
+        Severity::Critical => 2,
+    }
+}
+
+///
+/// Return `name` as a valid journal field name.
+///
+fn journald_field_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .take(MAX_FIELD_NAME_LEN)
+        .collect()
+}
+
+///
+/// Return `name` as a valid syslog structured data parameter name.
+///
+fn syslog_param_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_graphic() && !matches!(c, '=' | ']' | '"') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(MAX_PARAM_NAME_LEN)
+        .collect()
+}
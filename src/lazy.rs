@@ -0,0 +1,158 @@
+/*!
+Provides the [`LazyFlat`] type, which defers flattening an error until its flattened form is
+required.
+*/
+
+use crate::{captured_type_name, compat::Error, FlatError};
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    any::TypeId,
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
+use once_cell::race::OnceBox;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `LazyFlat` holds an error and only flattens it into a [`FlatError`] when the flattened
+/// form is first required, by `Display`, `Debug`, comparison, or [`LazyFlat::get`]. The
+/// flattened error is then cached, and retains the original error.
+///
+/// This avoids the cost of capturing the message of every error in the source chain for errors
+/// that are handled and discarded without being displayed.
+///
+/// ```rust
+/// use flat_error::LazyFlat;
+/// use std::io::{Error as IoError, ErrorKind};
+///
+/// let err = LazyFlat::new(IoError::from(ErrorKind::NotFound));
+/// assert!(!err.is_flattened());
+///
+/// assert_eq!(err.to_string(), "entity not found");
+/// assert!(err.is_flattened());
+/// assert_eq!(err.get().io_kind(), Some(ErrorKind::NotFound));
+/// ```
+///
+#[derive(Clone)]
+pub struct LazyFlat {
+    error: Arc<dyn Error + Send + Sync>,
+    original_type_name: &'static str,
+    type_id: TypeId,
+    flat: OnceBox<FlatError>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for LazyFlat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.get(), f)
+    }
+}
+
+impl Debug for LazyFlat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(self.get(), f)
+    }
+}
+
+impl PartialEq for LazyFlat {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for LazyFlat {}
+
+impl PartialOrd for LazyFlat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LazyFlat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(other.get())
+    }
+}
+
+impl Hash for LazyFlat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+impl Error for LazyFlat {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.get().source()
+    }
+}
+
+impl From<LazyFlat> for FlatError {
+    fn from(lazy: LazyFlat) -> Self {
+        lazy.into_flat()
+    }
+}
+
+impl LazyFlat {
+    ///
+    /// Construct a new `LazyFlat` holding `error`, which is not flattened until required.
+    ///
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Self {
+            error: Arc::new(error),
+            original_type_name: captured_type_name::<E>(),
+            type_id: TypeId::of::<E>(),
+            flat: OnceBox::new(),
+        }
+    }
+
+    ///
+    /// Returns `true` if the held error has already been flattened.
+    ///
+    pub fn is_flattened(&self) -> bool {
+        self.flat.get().is_some()
+    }
+
+    ///
+    /// Return a reference to the held error.
+    ///
+    pub fn inner(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.error
+    }
+
+    ///
+    /// Return the flattened form of the held error, flattening it if this has not already been
+    /// done.
+    ///
+    pub fn get(&self) -> &FlatError {
+        self.flat.get_or_init(|| Box::new(self.flatten()))
+    }
+
+    ///
+    /// Consume this value and return the flattened form of the held error.
+    ///
+    pub fn into_flat(self) -> FlatError {
+        match self.flat.get() {
+            Some(flat) => flat.clone(),
+            None => self.flatten(),
+        }
+    }
+
+    fn flatten(&self) -> FlatError {
+        let error: &(dyn Error + 'static) = &*self.error;
+        let mut flat = FlatError::from_any(error).with_std_details(error);
+        flat.inner.frame.original_type_name = self.original_type_name;
+        flat.inner.frame.type_id = Some(self.type_id);
+        flat.inner.frame.original = Some(self.error.clone());
+        flat
+    }
+}
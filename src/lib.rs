@@ -67,8 +67,132 @@ pub enum MyError {
 # Features
 
 - **std**; Uses the `std` library. This is only really relevant for implementing `From` for errors in the `std`
-  crate.
-- **alloc**; Uses the `alloc` and `core` libraries.
+  crate. With this feature the crate builds with toolchains earlier than Rust 1.81, in which the
+  `Error` trait was moved into `core`; without it Rust 1.81 or later is required.
+- **alloc**; Uses the `alloc` and `core` libraries, this is required for `FlatError` and all related types. Without
+  this feature only [`FlatErrorFixed`], [`ErrorClass`], [`Severity`], and [`SourceSpan`] are
+  available.
+- **actix-web**; Implements `actix_web::ResponseError` for `FlatError`, with a JSON body that does
+  not expose internal details.
+- **ansi**; Adds [`FlatError::render_pretty`] to render an error and its source chain for a
+  terminal with ANSI colors, respecting the `NO_COLOR` environment variable.
+- **arbitrary**; Implements `arbitrary::Arbitrary` for `FlatError` and `Severity`, for use in fuzz
+  targets.
+- **arena**; Adds [`FlatArena`], which flattens errors into a bump-allocated arena as
+  [`FlatErrorRef`]s that are freed together, for pipelines that discard many errors per batch.
+- **async-graphql**; Implements `async_graphql::ErrorExtensions` for `FlatError`, surfacing its
+  code and class in the extensions of a GraphQL error.
+- **axum**; Implements `axum::response::IntoResponse` for `FlatError`, without exposing internal
+  details by default.
+- **chrono**; Implements `From<chrono::ParseError>` for `FlatError`, capturing the kind of the
+  error as the field `kind`.
+- **clap**; Implements `From<clap::Error>` for `FlatError`, capturing the kind of the error and
+  whether it displays help or version information, and using the exit code of `clap` for it, see
+  [`FlatError::is_clap_display`].
+- **codespan-reporting**; Provides `FlatError::to_codespan_diagnostic`, converting an error with
+  source spans into a `codespan_reporting` diagnostic to print annotated source snippets.
+- **crossbeam-channel**; Implements `From` for the `crossbeam_channel` send, receive, and select
+  errors.
+- **defmt**; Implements `defmt::Format` for `FlatError` and `FlatErrorFixed`, for logging on embedded targets.
+- **derive**; Adds [`derive@ExtendedError`], a derive macro in the style of `thiserror` that
+  implements `Display`, `Error`, `Clone`, and `PartialEq`, flattening sources that cannot be cloned.
+- **embedded-hal**; Implements `From` for the `embedded-hal` I²C, SPI, and digital I/O error kinds,
+  and their `Error` traits for `FlatError`, see [`FlatError::i2c_error_kind`].
+- **eyre**; Adds conversions between `FlatError` and `eyre::Report`, see the [`eyre`](crate::eyre) module.
+- **ffi**; Adds a C compatible representation of a `FlatError` chain, and `extern "C"` functions to
+  access and free it, see the [`ffi`](crate::ffi) module.
+- **futures**; Adds extension traits converting the errors of a `TryFuture` or `TryStream` into
+  `FlatError`, see the [`futures`](crate::futures) module.
+- **http**; Adds a mapping from `FlatError` to `http::StatusCode`; with the **serde** feature also
+  adds [`FlatError::problem_details`], producing an RFC 7807 [`ProblemDetails`] document.
+- **hyper**; Implements `From<hyper::Error>` for `FlatError`, capturing the kind of the error as
+  fields.
+- **inline-messages**; Stores messages of up to 22 bytes, the majority of those of the standard
+  library's errors, inline rather than on the heap, so that flattening such an error requires no
+  allocation for its message.
+- **journald**; Adds [`FlatError::journald_fields`] and [`FlatError::send_to_journald`], to
+  write an error and its source chain to the `systemd` journal as fields, and
+  [`FlatError::syslog_structured_data`] to render them as RFC 5424 structured data.
+- **log**; Implements the `log` key-value traits `ToValue` and `Source` for `FlatError`, and adds the
+  [`log_error!`] macro to log an error with its source chain as key-value pairs.
+- **metrics**; Adds [`FlatError::record_metric`] to increment a `metrics` counter labelled with
+  the class, code, and root cause type name of an error.
+- **miette**; Implements `miette::Diagnostic` for `FlatError`, and so also `From<FlatError>` for
+  `miette::Report`.
+- **napi**; Adds conversions between `FlatError` and `napi::Error`, for Node.js native modules,
+  preserving the N-API status, see [`FlatError::napi_status`].
+- **nom**; Implements `From<nom::Err<E>>` for `FlatError`, and adds [`FlatError::from_nom_error`]
+  capturing the position in the input at which a parser failed as a [`SourceSpan`].
+- **origin**; Captures the id of the process, and the name of the host, in which an error was
+  flattened as the metadata fields `process.id` and `host.name`, once enabled with
+  [`set_capture_origin`]; see [`FlatError::process_id`].
+- **pest**; Implements `From<pest::error::Error<R>>` for `FlatError`, capturing the position of
+  the error as a [`SourceSpan`] and the rules expected at that position.
+- **proptest**; Implements `proptest::arbitrary::Arbitrary` for `FlatError` and `Severity`, for use
+  in property tests.
+- **regex**; Adds [`FlatError::matches_pattern`] and [`FlatError::chain_matches_pattern`] to match
+  messages against a `regex::Regex`, and [`FlatErrorMatcher::message_matches`].
+- **reqwest**; Implements `From<reqwest::Error>` for `FlatError`, capturing the HTTP status and
+  whether the request timed out or failed to connect as fields.
+- **rusqlite**; Implements `From<rusqlite::Error>` for `FlatError`, capturing the SQLite error
+  codes and any constraint as fields.
+- **schemars**; Implements `schemars::JsonSchema` for `FlatError`, `FlatErrorGroup`, `ErrorClass`,
+  `Severity`, and `SourceSpan`, describing the form used by the **serde** feature.
+- **secrecy**; Adds [`FlatError::with_secret_field`] to record a `secrecy` secret as a redacted
+  metadata field, without copying the secret into the error.
+- **serde**; Implements `serde::Serialize` and `serde::Deserialize` for `FlatError`,
+  `FlatErrorGroup`, `ErrorClass`, `Severity`, and `SourceSpan`, see the [`serde`](crate::serde)
+  module; also adds [`FlatError::ecs_document`], producing an Elastic Common Schema
+  [`EcsDocument`].
+- **serde_json**; Implements `From<serde_json::Error>` for `FlatError`, capturing the line, column,
+  and category of the error as fields.
+- **serde_yaml**; Implements `From<serde_yaml::Error>` for `FlatError`, capturing the location of
+  the error as fields.
+- **slog**; Implements `slog::Value` for `FlatError`, emitting the error and its source chain as a
+  nested value.
+- **sqlx**; Implements `From<sqlx::Error>` for `FlatError`, capturing the SQLSTATE, constraint, and
+  table of a database error as fields.
+- **timestamp**; Captures the time at which an error was created, see [`FlatError::timestamp`];
+  this is included in the **log**, **serde**, and **tracing** renderings.
+- **tokio**; Implements `From` for the `tokio` task and synchronization errors, capturing whether
+  a task panicked or was cancelled as fields.
+- **toml**; Implements `From` for the `toml` deserialization and serialization errors, capturing
+  the span of a deserialization error as fields.
+- **tonic**; Adds conversions between `FlatError` and `tonic::Status`, preserving the gRPC status
+  code.
+- **tower**; Adds a `tower` middleware, [`FlattenErrorLayer`](crate::tower::FlattenErrorLayer),
+  converting the errors of any service into `FlatError`, see the [`tower`](crate::tower) module.
+- **tracing**; Adds [`FlatError::trace`] to emit a `tracing` event with the error and its source
+  chain as structured fields.
+- **tracing-error**; Captures the `tracing` span trace in which an error was flattened, see
+  [`FlatError::span_trace_str`]; this is included in the pretty form of a [`FlatReport`].
+- **ufmt**; Implements `ufmt::uDisplay` and `ufmt::uDebug` for `FlatError` and `FlatErrorFixed`,
+  for formatting on embedded targets without the cost of `core::fmt`.
+- **url**; Implements `From<url::ParseError>` for `FlatError`, capturing the kind of the error
+  as the field `kind`.
+- **uuid**; Implements `From<uuid::Error>` for `FlatError`, capturing the position of an invalid
+  character in the input as the field `position`.
+- **wasm**; Adds conversions between `FlatError` and the `wasm-bindgen` types `JsValue` and
+  `JsError` when compiling for `wasm32`.
+- **wire**; Adds a compact, versioned, binary encoding of a `FlatError` and its source chain, see
+  the [`wire`](crate::wire) module.
+- **zeroize**; Implements `zeroize::Zeroize` and `zeroize::ZeroizeOnDrop` for `FlatError`, wiping
+  its messages and metadata when dropped, and `Zeroize` for `FlatErrorFixed`.
+
+# Configuration Flags
+
+- **flat_error_provider**; Implements `Error::provide` for `FlatError`, this requires a nightly
+  compiler as it uses the unstable `error_generic_member_access` feature, and so is enabled with
+  `RUSTFLAGS="--cfg flat_error_provider"` rather than a feature. A `FlatError` provides a
+  reference to itself, its `Severity`, and `io::ErrorKind` if present, and forwards the request to
+  the original error if it was retained.
+- **flat_error_strip_type_names**; Records [`STRIPPED_TYPE_NAME`] as the original type name of
+  every flattened error, rather than the name of its type, so that the names of those types are
+  not included in the binary; for size-constrained embedded and `wasm32` targets. This is enabled
+  with `RUSTFLAGS="--cfg flat_error_strip_type_names"` rather than a feature as it is not
+  additive, [`FlatError::is`], [`FlatError::chain_matches`], and anything else that relies on
+  type names, such as the default [`ErrorClass`] of a parse error, no longer distinguish between
+  error types; [`FlatError::is_exact`] is unaffected.
 
 */
 
@@ -110,33 +234,55 @@ pub enum MyError {
     drop_bounds,
     dyn_drop,
 )]
-#![cfg_attr(all(feature = "alloc", not(feature = "std")), no_std)]
+#![no_std]
+#![cfg_attr(flat_error_provider, feature(error_generic_member_access))]
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[cfg(feature = "alloc")]
 extern crate alloc;
-/*
- * The following is no longer supported as there is no known external crate named
- * `std` after approximately edition 2018. However, it does make a nice logical
- * follow-on from the line above for the `alloc` crate.
- *
- *  ```
- * #[cfg(any(test, feature = "std"))]
- * extern crate std;
- * ```
- */
-
-#[cfg(all(feature = "alloc", not(feature = "std")))]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
 use alloc::{
+    borrow::Cow,
     boxed::Box,
     string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
 };
+use compat::Error;
+#[cfg(feature = "alloc")]
+use core::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "alloc")]
 use core::{
-    any::type_name_of_val,
-    clone::Clone,
-    cmp::PartialEq,
-    error::Error,
-    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    any::{type_name, TypeId},
+    ptr,
+};
+use core::{clone::Clone, cmp::PartialEq, fmt::Debug};
+#[cfg(feature = "alloc")]
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    iter::Rev,
+    mem,
+    ops::{Deref, DerefMut},
+    panic::Location,
+    slice::Iter as SliceIter,
 };
+#[cfg(feature = "alloc")]
+use once_cell::race::OnceBox;
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The original type name recorded for a flattened error when the `flat_error_strip_type_names`
+/// configuration flag is set, in place of the name of its type.
+///
+pub const STRIPPED_TYPE_NAME: &str = "?";
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -147,7 +293,8 @@ use core::{
 /// and `PartialEq`.
 ///
 /// There is a blanket implementation for any type that implements all of these requirements and may
-/// then be used as a type constraint, as shown in the following example.
+/// then be used as a type constraint, as shown in the following example. The trait also provides
+/// a small number of methods that are useful in generic code.
 ///
 /// ```rust
 /// use flat_error::ExtendedError;
@@ -158,7 +305,85 @@ use core::{
 /// }
 /// ```
 ///
-pub trait ExtendedError: Error + Clone + Debug + PartialEq {}
+/// As `Clone` and `PartialEq` require `Sized` this trait cannot be used as a trait object, see
+/// [`DynExtendedError`] for an object-safe alternative.
+///
+pub trait ExtendedError: Error + Clone + Debug + PartialEq {
+    ///
+    /// Return the name of this error's type, as returned by `type_name`. For a [`FlatError`]
+    /// this is the name of `FlatError` itself, see [`FlatError::original_type_name`] for the
+    /// name of the type it was flattened from.
+    ///
+    /// ```rust
+    /// use flat_error::ExtendedError;
+    /// use std::fmt::Error as FmtError;
+    ///
+    /// assert_eq!(FmtError.type_name(), "core::fmt::Error");
+    /// ```
+    ///
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
+    ///
+    /// Return this error boxed as a `dyn Error`, for use where a boxed error is expected.
+    ///
+    #[cfg(feature = "alloc")]
+    fn boxed(self) -> Box<dyn Error + Send + Sync>
+    where
+        Self: Send + Sync + 'static,
+    {
+        Box::new(self)
+    }
+
+    ///
+    /// Return the `Display` form of this error, followed by that of each error in its source
+    /// chain.
+    ///
+    /// ```rust
+    /// use flat_error::{ExtendedError, FlatError};
+    ///
+    /// let err = FlatError::new("entity not found").context("reading config file");
+    ///
+    /// assert_eq!(
+    ///     err.chain_to_strings(),
+    ///     ["reading config file", "entity not found"]
+    /// );
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    fn chain_to_strings(&self) -> Vec<String> {
+        let mut strings = vec![self.to_string()];
+        let mut current = self.source();
+        while let Some(source) = current {
+            strings.push(source.to_string());
+            current = source.source();
+        }
+        strings
+    }
+}
+
+///
+/// This trait extends [`ExtendedError`] with the `Send`, `Sync`, and `'static` bounds required
+/// of errors passed between threads or held across an `.await` in multi-threaded async code, so
+/// that generic code need not spell them out.
+///
+/// As with `ExtendedError` there is a blanket implementation for any type that meets these
+/// requirements; [`FlatError`] does so.
+///
+/// ```rust
+/// use flat_error::{ExtendedErrorSync, FlatError};
+/// use std::thread;
+///
+/// fn report_later<E: ExtendedErrorSync>(err: E) -> thread::JoinHandle<String> {
+///     thread::spawn(move || err.to_string())
+/// }
+///
+/// let err = FlatError::new("entity not found");
+/// assert_eq!(report_later(err).join().unwrap(), "entity not found");
+/// ```
+///
+pub trait ExtendedErrorSync: ExtendedError + Send + Sync + 'static {}
 
 ///
 /// A `FlatError` is used to capture an error that does not meet the requirements of the trait
@@ -168,171 +393,3359 @@ pub trait ExtendedError: Error + Clone + Debug + PartialEq {}
 /// own `Display` implementation. It will also flatten any error returned by `Error::source`.
 /// Finally, it captures the type name of the original error for debugging.
 ///
-/// Note also that the blanket implementation for `ExtendedError` applies to `FlatError`.
+/// The source chain is flattened iteratively and stored as a single vector of frames, rather than
+/// as nested errors, so that neither flattening nor dropping a long chain is recursive. The
+/// length of the chain is determined first, so that the vector is allocated once, and each
+/// message is written to a single buffer reused for the whole chain before being copied into a
+/// string of exactly its length. The
+/// `FlatError` returned by [`FlatError::flat_source`], and so by `Error::source`, is created from
+/// these frames on first use and cached.
 ///
-#[derive(Clone, Debug, PartialEq)]
-pub struct FlatError {
-    original_type_name: &'static str,
-    message: String,
-    source: Option<Box<Self>>,
+/// Messages, other captured values, and the source chain are shared between clones, so that
+/// cloning a `FlatError` requires a single allocation and reference count updates, however long
+/// its source chain.
+///
+/// Optionally, the original error may also be retained, see [`FlatError::from_any_retaining`];
+/// this allows the original to be recovered with [`FlatError::downcast_ref`]. The retained error
+/// is shared between clones and is **not** considered when comparing or hashing values; nor is
+/// the `TypeId` of the original error, see [`FlatError::original_type_id`].
+///
+/// The alternate `Display` format, `{:#}`, includes the error code, the message of the source,
+/// and the original type name; this format may be parsed back into a `FlatError` using
+/// `FromStr`. The grammar of this format is as follows, where the message, source message, and
+/// type name are not escaped and codes and type names may not contain a backtick.
+///
+/// ```text
+/// flat-error = message " (" [ code ", " ] [ source ", " ] type-name ")"
+/// code       = "code: `" code-text "`"
+/// source     = "source: " source-message
+/// type-name  = "original type: `" type-text "`"
+/// ```
+///
+/// ```rust
+/// use flat_error::FlatError;
+///
+/// let err = FlatError::new("disk full").context("saving file").with_code("E1042");
+/// let text = format!("{err:#}");
+/// assert_eq!(
+///     text,
+///     "saving file (code: `E1042`, source: disk full, original type: `flat_error::FlatError`)"
+/// );
+///
+/// let parsed: FlatError = text.parse().unwrap();
+/// assert_eq!(parsed, err);
+/// assert_eq!(format!("{parsed:#}"), text);
+/// ```
+///
+/// Only the values included in this format are recovered, the original type names of sources,
+/// and any sources beyond the first, are lost.
+///
+/// `FlatError` values are ordered by their original type name, then their message, then their
+/// source chain, and finally by any values attached to them such as code and severity. This
+/// ordering is intended to be deterministic, for stable reports and tests, rather than
+/// meaningful.
+///
+/// `FlatError` implements `Eq` and `Hash` and so may be used as the key of a map or set; the
+/// cached source view, the captured location and backtrace, and the time captured with the
+/// **timestamp** feature, are not used when comparing or hashing. Clippy's `mutable_key_type`
+/// lint cannot determine this, and so the type may be added to its `ignore-interior-mutability`
+/// configuration.
+///
+/// A `FlatError` may also carry a typed category, such as an application's own error kind
+/// enum, which defaults to `()`; see [`FlatError::with_category`]. The category is considered
+/// when comparing, ordering, and hashing values, and is included in the `Debug` output unless it
+/// is zero-sized. Constructors and integrations with other crates produce and accept errors with
+/// the default category; [`FlatError::into_category`] returns such an error and the category.
+///
+/// A `FlatError` is four pointers in size, whatever its category, so that a `Result` with a
+/// `FlatError` error remains small; as a result constructing one allocates, other than by
+/// [`FlatError::new_static`].
+///
+/// Note also that the blanket implementations for `ExtendedError` and [`ExtendedErrorSync`]
+/// apply to `FlatError`.
+///
+#[cfg(feature = "alloc")]
+pub struct FlatError<C = ()> {
+    inner: InnerBox<C>,
 }
 
 // ------------------------------------------------------------------------------------------------
-// Implementations ❱ ExtendedError
+// Private Types
 // ------------------------------------------------------------------------------------------------
 
-impl<E: Error + Clone + Debug + PartialEq> ExtendedError for E {}
-
-// ------------------------------------------------------------------------------------------------
-// Implementations ❱ FlatError
-// ------------------------------------------------------------------------------------------------
+///
+/// The contents of a [`FlatError`], boxed so that a `Result` containing one remains small; or,
+/// for an error constructed by [`FlatError::new_static`], allocated when first read, see
+/// [`InnerBox`].
+///
+#[cfg(feature = "alloc")]
+struct Inner<C> {
+    frame: Frame,
+    sources: Option<Arc<Sources>>,
+    category: C,
+}
 
-impl Display for FlatError {
-    #[allow(clippy::format_in_format_args)]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if f.alternate() {
-            write!(
-                f,
-                "{} ({}{})",
-                self.message,
-                self.source
-                    .as_ref()
-                    .map(|e| format!("source: {e}, "))
-                    .unwrap_or_default(),
-                format!("original type: `{}`", self.original_type_name),
-            )
-        } else {
-            write!(f, "{}", self.message)
-        }
-    }
+///
+/// A single flattened error; a [`FlatError`] holds the frame for the error itself and one frame
+/// for each error in its source chain, in order.
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+struct Frame {
+    original_type_name: &'static str,
+    message: SharedStr,
+    severity: Severity,
+    details: Option<Arc<Details>>,
+    original: Option<Arc<dyn Error + Send + Sync>>,
+    type_id: Option<TypeId>,
 }
 
-impl Error for FlatError {
-    #[allow(trivial_casts)]
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        // Casts to trait objects can be a pain, this is perfectly legal but will generate
-        // a warning; however, removing it generates an error.
-        self.flat_source().map(|err| err as &dyn Error)
-    }
+///
+/// The frames for the source chain of a [`FlatError`], along with the `FlatError` created from
+/// them by [`FlatError::flat_source`]. The frames are stored in reverse order, starting with the
+/// root cause, so that [`FlatError::context`] need only push a single frame. These are shared
+/// between clones, so that cloning an error does not copy its source chain, and are only copied
+/// if a shared chain is modified. An error without a source requires no further allocation.
+///
+/// The source view shares the frames of this chain, as the frames of its own source chain are
+/// those before its frame; `len` is the number of frames in the chain, so that walking the chain
+/// through [`FlatError::flat_source`] copies no frames.
+///
+#[cfg(feature = "alloc")]
+struct Sources {
+    frames: Arc<Vec<Frame>>,
+    len: usize,
+    view: OnceBox<FlatError>,
 }
 
-impl FlatError {
-    ///
-    /// Construct a new `FlatError` by flattening the provided `error`.
-    ///
-    pub fn from_any<E>(error: &E) -> Self
-    where
-        E: Error + ?Sized,
-    {
-        Self {
-            original_type_name: type_name_of_val(error),
-            message: error.to_string(),
-            source: error.source().map(|err| Box::new(FlatError::from_any(err))),
-        }
-    }
+///
+/// Renders a [`Frame`], and the source chain that follows it, as nested `FlatError` structs for
+/// the `Debug` implementation of [`FlatError`]. The `sources` are stored root cause first, as in
+/// [`Sources`].
+///
+#[cfg(feature = "alloc")]
+struct DebugChain<'a> {
+    frame: &'a Frame,
+    sources: &'a [Frame],
+    category: Option<&'a dyn Debug>,
+}
 
-    ///
-    /// A concrete version of `Error::source` that returns the flattened source.
-    ///
-    pub fn flat_source(&self) -> Option<&FlatError> {
-        self.source.as_ref().map(|source| source.as_ref())
-    }
+///
+/// Renders key-value pairs as a map, in order, for [`DebugChain`].
+///
+#[cfg(feature = "alloc")]
+struct DebugPairs<'a>(&'a [(String, String)]);
 
-    ///
-    /// Return the name of the flattened error type. This uses the `type_name_of_val` function from
-    /// `std::any`, with the warning:
-    ///
-    /// > Like type_name, this is intended for diagnostic use and the exact output is not guaranteed.
-    /// > It provides a best-effort description, but the output may change between versions of the
-    /// > compiler.
-    ///
-    pub fn original_type_name(&self) -> &'static str {
-        self.original_type_name
-    }
+///
+/// A string captured in a [`Frame`], either a static string or one shared between clones; or,
+/// with the **inline-messages** feature, a short string stored inline.
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+enum SharedStr {
+    Static(&'static str),
+    Shared(Arc<str>),
+    #[cfg(feature = "inline-messages")]
+    Inline(InlineStr),
 }
 
-// ------------------------------------------------------------------------------------------------
-// Implementations ❱ core
-// ------------------------------------------------------------------------------------------------
+///
+/// A string of up to [`INLINE_MESSAGE_CAPACITY`] bytes stored within a [`SharedStr`], so that it
+/// requires no allocation. This is no larger than the other variants of `SharedStr`.
+///
+#[cfg(feature = "inline-messages")]
+#[derive(Clone, Copy, Default)]
+struct InlineStr {
+    length: u8,
+    bytes: [u8; INLINE_MESSAGE_CAPACITY],
+}
 
-impl From<::core::array::TryFromSliceError> for FlatError {
-    fn from(e: ::core::array::TryFromSliceError) -> Self {
-        FlatError::from_any(&e)
-    }
+///
+/// Writes the `Display` form of a value to an [`InlineStr`] until it exceeds the capacity, and
+/// then to a `String`, so that the value is only formatted once.
+///
+#[cfg(feature = "inline-messages")]
+#[derive(Default)]
+struct SharedStrWriter {
+    inline: InlineStr,
+    spilled: Option<String>,
 }
 
-impl From<::core::cell::BorrowError> for FlatError {
-    fn from(e: ::core::cell::BorrowError) -> Self {
-        FlatError::from_any(&e)
-    }
+///
+/// Details attached to a [`Frame`] after flattening, these are kept behind a pointer to keep the
+/// size of `FlatError` itself, and so any `Result` containing one, small; they are shared between
+/// clones until modified.
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Details {
+    code: Option<String>,
+    class: Option<ErrorClass>,
+    fields: Fields,
+    location: Uncompared<Option<&'static Location<'static>>>,
+    original_message_len: Option<usize>,
+    message_key: Option<String>,
+    message_args: Vec<(String, String)>,
+    message_template: Option<String>,
+    debug_repr: Option<String>,
+    repeat_count: Option<usize>,
+    help: Option<String>,
+    docs_url: Option<String>,
+    span: Option<SourceSpan>,
+    branches: Vec<FlatError>,
+    related: Vec<FlatError>,
+    num_kind: Option<num::NumErrorKind>,
+    #[cfg(feature = "std")]
+    io_kind: Option<::std::io::ErrorKind>,
+    #[cfg(feature = "std")]
+    os_error_code: Option<i32>,
+    #[cfg(feature = "timestamp")]
+    timestamp: timestamp::Timestamp,
+    #[cfg(feature = "tracing-error")]
+    span_trace: tracing_error::SpanTrace,
 }
 
+///
+/// The metadata fields of a [`Frame`], in the order they were set. A captured backtrace, held
+/// in the field `BACKTRACE_FIELD` with the **std** feature, differs between otherwise identical
+/// errors and so is not considered when comparing, ordering, or hashing fields.
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+struct Fields(Vec<(String, String)>);
+
+///
+/// A value held in [`Details`] that records how an error was captured, such as its location,
+/// rather than what the error is; as for the timestamp, it is not considered when comparing,
+/// ordering, or hashing errors, and so all values compare as equal and hash to nothing.
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default)]
+struct Uncompared<T>(T);
+
+///
+/// The type name used for errors created by this crate, rather than flattened from another
+/// error. This is the value of `type_name::<FlatError>()`, which cannot be called in a `const`
+/// context.
+///
+#[cfg(feature = "alloc")]
+const FLAT_ERROR_TYPE_NAME: &str = "flat_error::FlatError";
+
+///
+/// The maximum length, in bytes, of a message stored inline with the **inline-messages**
+/// feature. Most messages of the standard library's errors are no longer than this.
+///
+#[cfg(feature = "inline-messages")]
+const INLINE_MESSAGE_CAPACITY: usize = 22;
+
+///
+/// The maximum number of distinct original type names held by [`intern_type_name`].
+///
+#[cfg(feature = "alloc")]
+const MAX_INTERNED_TYPE_NAMES: usize = 1024;
+
+///
+/// The original type names interned by [`intern_type_name`], filled in order and never emptied.
+///
+#[cfg(feature = "alloc")]
+static TYPE_NAME_POOL: [OnceBox<String>; MAX_INTERNED_TYPE_NAMES] =
+    [const { OnceBox::new() }; MAX_INTERNED_TYPE_NAMES];
+
+///
+/// The message of the synthetic frame added when a source chain contains a cycle.
+///
+#[cfg(feature = "alloc")]
+const CYCLIC_SOURCE_MESSAGE: &str = "… cyclic source chain";
+
+///
+/// The message of an error whose `Display` implementation panicked while it was flattened.
+///
+#[cfg(feature = "std")]
+const PANICKED_MESSAGE: &str = "… panicked while formatting message";
+
+///
+/// The maximum number of sources in the chain of a [`FlatError`] generated by the **arbitrary**
+/// and **proptest** features.
+///
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+const ARBITRARY_MAX_SOURCES: usize = 4;
+
+///
+/// The original type names chosen from when generating a [`FlatError`] by the **arbitrary** and
+/// **proptest** features.
+///
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+const ARBITRARY_TYPE_NAMES: &[&str] = &[
+    FLAT_ERROR_TYPE_NAME,
+    "core::fmt::Error",
+    "core::num::error::ParseIntError",
+    "core::str::error::Utf8Error",
+    "std::io::error::Error",
+];
+
+#[cfg(feature = "alloc")]
+static NO_DETAILS: Details = Details {
+    code: None,
+    class: None,
+    fields: Fields(Vec::new()),
+    location: Uncompared(None),
+    original_message_len: None,
+    message_key: None,
+    message_args: Vec::new(),
+    message_template: None,
+    debug_repr: None,
+    repeat_count: None,
+    help: None,
+    docs_url: None,
+    span: None,
+    branches: Vec::new(),
+    related: Vec::new(),
+    num_kind: None,
+    #[cfg(feature = "std")]
+    io_kind: None,
+    #[cfg(feature = "std")]
+    os_error_code: None,
+    #[cfg(feature = "timestamp")]
+    timestamp: timestamp::Timestamp(None),
+    #[cfg(feature = "tracing-error")]
+    span_trace: tracing_error::SpanTrace(None),
+};
+
 // ------------------------------------------------------------------------------------------------
-// Implementations ❱ std
+// Modules
 // ------------------------------------------------------------------------------------------------
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl From<::std::env::JoinPathsError> for FlatError {
-    fn from(e: ::std::env::JoinPathsError) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+#[macro_use]
+mod macros;
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl From<::std::fs::TryLockError> for FlatError {
-    fn from(e: ::std::fs::TryLockError) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+mod compat;
+#[doc(hidden)]
+pub use compat::Error as __Error;
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl From<::std::io::Error> for FlatError {
-    fn from(e: ::std::io::Error) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "alloc")]
+pub use builder::FlatErrorBuilder;
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl<W> From<::std::io::IntoInnerError<W>> for FlatError
-where
-    W: ::std::fmt::Debug + ::std::marker::Send,
-{
-    fn from(e: ::std::io::IntoInnerError<W>) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+#[cfg(feature = "alloc")]
+mod catalog;
+#[cfg(feature = "alloc")]
+pub use catalog::{CatalogEntry, ErrorCatalog};
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl From<::std::io::WriterPanicked> for FlatError {
-    fn from(e: ::std::io::WriterPanicked) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "std")]
+pub use config::FlatErrorConfig;
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl From<::std::string::FromUtf16Error> for FlatError {
-    fn from(e: ::std::string::FromUtf16Error) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+#[cfg(feature = "std")]
+mod crash;
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl<T> From<::std::sync::PoisonError<T>> for FlatError {
-    fn from(e: ::std::sync::PoisonError<T>) -> Self {
-        FlatError::from_any(&e)
-    }
-}
+#[cfg(feature = "alloc")]
+mod custom;
+#[cfg(feature = "alloc")]
+pub use custom::FlattenCustom;
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl<T> From<::std::sync::TryLockError<T>> for FlatError {
-    fn from(e: ::std::sync::TryLockError<T>) -> Self {
+mod class;
+pub use class::ErrorClass;
+
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+pub use diff::{Difference, FlatErrorDiff};
+
+#[cfg(feature = "alloc")]
+mod display;
+#[cfg(feature = "alloc")]
+pub use display::{DisplayStyle, StyledDisplay};
+
+#[cfg(feature = "alloc")]
+mod dyn_error;
+#[cfg(feature = "alloc")]
+pub use dyn_error::DynExtendedError;
+
+#[cfg(feature = "serde")]
+mod ecs;
+#[cfg(feature = "serde")]
+pub use ecs::{EcsDocument, EcsError};
+
+#[cfg(feature = "std")]
+mod exit;
+#[cfg(feature = "std")]
+pub use exit::FlatExit;
+
+#[cfg(feature = "alloc")]
+mod ext;
+#[cfg(feature = "alloc")]
+pub use ext::{Flatten, IteratorExt, OptionExt, ResultExt};
+
+#[cfg(feature = "alloc")]
+mod fingerprint;
+
+mod fixed;
+pub use fixed::FlatErrorFixed;
+
+#[cfg(feature = "alloc")]
+mod frames;
+#[cfg(feature = "alloc")]
+pub use frames::{FlatFrame, Frames, TreeFrames};
+
+#[cfg(feature = "alloc")]
+mod group;
+#[cfg(feature = "alloc")]
+pub use group::FlatErrorGroup;
+
+#[cfg(feature = "std")]
+mod hook;
+#[cfg(feature = "std")]
+pub use hook::FlattenHook;
+
+#[cfg(feature = "alloc")]
+mod inner;
+#[cfg(feature = "alloc")]
+use inner::InnerBox;
+
+#[cfg(feature = "std")]
+mod intern;
+#[cfg(feature = "std")]
+pub use intern::{clear_interned_messages, MAX_INTERNED_MESSAGES};
+
+#[cfg(feature = "alloc")]
+mod into_flat;
+#[cfg(feature = "alloc")]
+pub use into_flat::IntoFlat;
+
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+pub use io::{FlatIoError, FILE_PATH_FIELD};
+
+#[cfg(feature = "alloc")]
+mod lazy;
+#[cfg(feature = "alloc")]
+pub use lazy::LazyFlat;
+
+#[cfg(feature = "alloc")]
+mod matcher;
+#[cfg(feature = "alloc")]
+pub use matcher::FlatErrorMatcher;
+
+#[cfg(feature = "alloc")]
+mod num;
+
+#[cfg(feature = "alloc")]
+mod options;
+#[cfg(feature = "alloc")]
+pub use options::{FlattenOptions, DEFAULT_MAX_DEPTH};
+
+#[cfg(feature = "std")]
+mod panic;
+#[cfg(feature = "std")]
+pub use panic::{
+    catch_flat, install_flat_panic_hook, join_flat, BACKTRACE_FIELD, PANIC_LOCATION_FIELD,
+    PANIC_TYPE_NAME, THREAD_NAME_FIELD,
+};
+
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "alloc")]
+pub use parse::FlatErrorParseError;
+
+#[cfg(feature = "std")]
+mod process;
+#[cfg(feature = "std")]
+pub use process::MAX_COMMAND_STDERR_LEN;
+
+#[cfg(all(feature = "http", feature = "serde"))]
+mod problem;
+#[cfg(all(feature = "http", feature = "serde"))]
+pub use problem::ProblemDetails;
+
+#[cfg(feature = "alloc")]
+mod redact;
+#[cfg(feature = "alloc")]
+pub use redact::Redactor;
+
+#[cfg(feature = "alloc")]
+mod report;
+
+#[cfg(feature = "alloc")]
+mod sanitize;
+#[cfg(feature = "alloc")]
+pub use report::FlatReport;
+
+mod severity;
+pub use severity::Severity;
+
+mod span;
+pub use span::SourceSpan;
+
+#[cfg(feature = "alloc")]
+mod template;
+
+#[cfg(feature = "std")]
+mod thread;
+
+#[cfg(feature = "alloc")]
+mod value;
+
+#[cfg(feature = "alloc")]
+mod visit;
+#[cfg(feature = "alloc")]
+pub use visit::FlatErrorVisitor;
+
+#[cfg(feature = "actix-web")]
+mod actix_web;
+
+#[cfg(feature = "ansi")]
+mod ansi;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "arena")]
+mod arena;
+#[cfg(feature = "arena")]
+pub use arena::{FlatArena, FlatErrorRef};
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+
+#[cfg(feature = "axum")]
+mod axum;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "clap")]
+mod clap;
+
+#[cfg(feature = "codespan-reporting")]
+mod codespan_reporting;
+
+#[cfg(feature = "crossbeam-channel")]
+mod crossbeam_channel;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+///
+/// Derive `Display`, `Error`, `Clone`, and `PartialEq` for a struct or enum, and so make it an
+/// [`ExtendedError`], in the style of the `thiserror` crate. `Debug` is not derived, and must be
+/// derived or implemented as usual.
+///
+/// * `#[error("...")]` on a struct, or on each variant of an enum, provides the format string
+///   for `Display`; fields are referred to by name, as `{name}`, or by position for tuple
+///   fields, as `{0}`, and any further arguments are passed to `write!` as they are.
+/// * `#[error(transparent)]` forwards `Display` and `Error::source` to the only field.
+/// * `#[source]` marks the field returned by `Error::source`, otherwise a field named `source`
+///   is returned, if there is one.
+/// * `#[from]` marks the only field of a struct or variant as its source, and implements
+///   `From` for the type of the field.
+/// * `#[from(Type, ...)]` marks the only field, which must be a [`FlatError`], as the source,
+///   and implements `From` for each listed type by flattening it with
+///   [`FlatError::from_error`]; so that error types that are not `Clone` or `PartialEq` can
+///   still be a source. This requires the **alloc** feature.
+///
+/// The derived implementations of `Clone` and `PartialEq` compare and clone each field, and
+/// so should not also be derived.
+///
+/// ```rust
+/// use flat_error::{ExtendedError, FlatError};
+/// use std::{error::Error, io::{Error as IoError, ErrorKind}};
+///
+/// #[derive(Debug, ExtendedError)]
+/// enum ConfigError {
+///     #[error("could not read {path}")]
+///     Read {
+///         path: String,
+///         #[source]
+///         cause: FlatError,
+///     },
+///     #[error("invalid port {0}")]
+///     Port(#[from] std::num::ParseIntError),
+///     #[error("io error")]
+///     Io(#[from(IoError)] FlatError),
+/// }
+///
+/// let err = ConfigError::from(IoError::from(ErrorKind::NotFound));
+/// assert_eq!(err.to_string(), "io error");
+/// assert_eq!(err.source().unwrap().to_string(), "entity not found");
+/// assert_eq!(err.clone(), err);
+///
+/// let err = ConfigError::from("80x".parse::<u16>().unwrap_err());
+/// assert_eq!(err.to_string(), "invalid port invalid digit found in string");
+/// ```
+///
+#[cfg(feature = "derive")]
+pub use ::flat_error_derive::ExtendedError;
+
+#[cfg(feature = "embedded-hal")]
+mod embedded_hal;
+
+#[cfg(feature = "eyre")]
+pub mod eyre;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "futures")]
+pub mod futures;
+
+#[cfg(feature = "http")]
+mod http;
+
+#[cfg(feature = "hyper")]
+mod hyper;
+
+#[cfg(feature = "journald")]
+mod journald;
+
+#[cfg(feature = "log")]
+mod log;
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub use ::log as __log;
+
+#[cfg(feature = "metrics")]
+mod metrics;
+
+#[cfg(feature = "miette")]
+mod miette;
+
+#[cfg(feature = "napi")]
+mod napi;
+
+#[cfg(feature = "nom")]
+mod nom;
+
+#[cfg(feature = "origin")]
+mod origin;
+#[cfg(feature = "origin")]
+pub use origin::{set_capture_origin, HOST_NAME_FIELD, PROCESS_ID_FIELD};
+
+#[cfg(feature = "pest")]
+mod pest;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+
+#[cfg(feature = "regex")]
+mod regex;
+
+#[cfg(feature = "reqwest")]
+mod reqwest;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "secrecy")]
+mod secrecy;
+#[cfg(feature = "secrecy")]
+pub use secrecy::REDACTED_FIELD_VALUE;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "serde_json")]
+mod serde_json;
+
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
+
+#[cfg(feature = "slog")]
+mod slog;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "timestamp")]
+mod timestamp;
+
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(feature = "toml")]
+mod toml;
+
+#[cfg(feature = "tonic")]
+mod tonic;
+
+#[cfg(feature = "tower")]
+pub mod tower;
+
+#[cfg(feature = "tracing")]
+mod tracing;
+
+#[cfg(feature = "tracing-error")]
+mod tracing_error;
+
+#[cfg(feature = "ufmt")]
+mod ufmt;
+
+#[cfg(feature = "url")]
+mod url;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+mod wasm;
+
+#[cfg(feature = "wire")]
+pub mod wire;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ExtendedError
+// ------------------------------------------------------------------------------------------------
+
+impl<E: Error + Clone + Debug + PartialEq> ExtendedError for E {}
+
+impl<E: ExtendedError + Send + Sync + 'static> ExtendedErrorSync for E {}
+
+///
+/// Fails to compile if `E` does not implement [`ExtendedErrorSync`], used to assert that the
+/// error types of this crate may be passed between threads.
+///
+const fn assert_extended_error_sync<E: ExtendedErrorSync>() {}
+
+const _: () = assert_extended_error_sync::<FlatErrorFixed<64>>();
+
+#[cfg(feature = "alloc")]
+const _: () = {
+    assert_extended_error_sync::<FlatError>();
+    assert_extended_error_sync::<FlatErrorGroup>();
+};
+
+#[cfg(feature = "std")]
+const _: () = assert_extended_error_sync::<FlatIoError>();
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ FlatError
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The `Display` implementation writes the error message; in alternate mode it also writes the
+/// code, the message of the immediate source, and the original type name. The width, fill,
+/// alignment, and precision flags are honored, precision truncating the output to at most that
+/// many characters.
+///
+/// ```rust
+/// use flat_error::FlatError;
+///
+/// let err = FlatError::new("entity not found");
+///
+/// assert_eq!(format!("[{err:>20}]"), "[    entity not found]");
+/// assert_eq!(format!("[{err:-^8.6}]"), "[-entity-]");
+/// ```
+///
+#[cfg(feature = "alloc")]
+impl<C> Display for FlatError<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            if f.width().is_none() && f.precision().is_none() {
+                return self.write_alternate(f);
+            }
+            let mut buffer = String::new();
+            self.write_alternate(&mut buffer)?;
+            f.pad(&buffer)
+        } else {
+            f.pad(&self.inner.frame.message)
+        }
+    }
+}
+
+///
+/// The `Debug` implementation writes the error, and each error in its source chain, as nested
+/// structs with the fields `message`, `type`, and then `category`, `location`, `code`,
+/// `severity` (if not `Error`), `fields`, `message_key`, `message_template`, `debug_repr`,
+/// `repeat_count`, `help`, `docs_url`, `span`, `branches`, and `related` only when present,
+/// followed by the `source`.
+///
+/// ```rust
+/// use flat_error::FlatError;
+///
+/// let err = FlatError::new("entity not found")
+///     .with_code("E1042")
+///     .context("reading config file");
+///
+/// assert_eq!(
+///     format!("{err:#?}"),
+///     r#"FlatError {
+///     message: "reading config file",
+///     type: "flat_error::FlatError",
+///     source: FlatError {
+///         message: "entity not found",
+///         type: "flat_error::FlatError",
+///         code: "E1042",
+///     },
+/// }"#
+/// );
+/// ```
+///
+#[cfg(feature = "alloc")]
+impl<C: Debug> Debug for FlatError<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let category: &dyn Debug = &self.inner.category;
+        Debug::fmt(
+            &DebugChain {
+                frame: &self.inner.frame,
+                sources: self.source_frames(),
+                category: (size_of::<C>() != 0).then_some(category),
+            },
+            f,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Clone> Clone for FlatError<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: PartialEq> PartialEq for FlatError<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.frame == other.inner.frame
+            && self.source_frames() == other.source_frames()
+            && self.inner.category == other.inner.category
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Eq> Eq for FlatError<C> {}
+
+#[cfg(feature = "alloc")]
+impl<C> PartialEq<str> for FlatError<C> {
+    fn eq(&self, other: &str) -> bool {
+        *self.inner.frame.message == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C> PartialEq<&str> for FlatError<C> {
+    fn eq(&self, other: &&str) -> bool {
+        *self.inner.frame.message == **other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<FlatError> for str {
+    fn eq(&self, other: &FlatError) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<FlatError> for &str {
+    fn eq(&self, other: &FlatError) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Hash> Hash for FlatError<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.frame.hash(state);
+        self.source_frames().hash(state);
+        self.inner.category.hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Ord> PartialOrd for FlatError<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Ord> Ord for FlatError<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner
+            .frame
+            .original_type_name
+            .cmp(other.inner.frame.original_type_name)
+            .then_with(|| self.inner.frame.message.cmp(&other.inner.frame.message))
+            .then_with(|| self.sources().cmp(other.sources()))
+            .then_with(|| self.inner.frame.cmp(&other.inner.frame))
+            .then_with(|| self.inner.category.cmp(&other.inner.category))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Debug + 'static> Error for FlatError<C> {
+    #[allow(trivial_casts)]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // Casts to trait objects can be a pain, this is perfectly legal but will generate
+        // a warning; however, removing it generates an error.
+        self.flat_source().map(|err| err as &dyn Error)
+    }
+
+    #[cfg(flat_error_provider)]
+    fn provide<'a>(&'a self, request: &mut ::core::error::Request<'a>) {
+        request
+            .provide_ref::<FlatError<C>>(self)
+            .provide_ref::<C>(&self.inner.category)
+            .provide_value::<Severity>(self.inner.frame.severity);
+        #[cfg(feature = "std")]
+        if let Some(kind) = self.io_kind() {
+            request.provide_value::<::std::io::ErrorKind>(kind);
+        }
+        if let Some(original) = &self.inner.frame.original {
+            original.provide(request);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for FlatError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&str> for FlatError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, using the default
+    /// [`FlattenOptions`].
+    ///
+    #[track_caller]
+    pub fn from_any<E>(error: &E) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        Self::from_any_with(error, FlattenOptions::new())
+    }
+
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, as [`FlatError::from_any`]
+    /// does. As `error` is known to be `'static` this also captures its `TypeId`, see
+    /// [`FlatError::is_exact`], and additional details for some standard error types such as
+    /// the kind of a `std::io::Error`.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from_error(&IoError::from(ErrorKind::NotFound));
+    ///
+    /// assert!(err.is_exact::<IoError>());
+    /// assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+    /// ```
+    ///
+    #[track_caller]
+    pub fn from_error<E>(error: &E) -> Self
+    where
+        E: Error + 'static,
+    {
+        let options = FlattenOptions::new();
+        let mut flat = Self::flatten(error, options).with_std_details(error);
+        flat.inner.frame.type_id = Some(TypeId::of::<E>());
+        Self::finish_flatten(flat, error, options, None::<&dyn Redactor>)
+    }
+
+    ///
+    /// Construct a new `FlatError` by flattening the provided trait object, as
+    /// [`FlatError::from_error`] does, although the original type name is only known as a
+    /// `dyn Error`.
+    ///
+    #[track_caller]
+    fn from_dyn_error(error: &(dyn Error + 'static)) -> Self {
+        let options = FlattenOptions::new();
+        let flat = Self::flatten(error, options).with_std_details(error);
+        Self::finish_flatten(flat, error, options, None::<&dyn Redactor>)
+    }
+
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error` according to `options`.
+    ///
+    /// The source chain is truncated after [`FlattenOptions::max_depth`] errors, and if an error
+    /// in the chain is its own source, directly or indirectly, the chain is truncated at the
+    /// first repeated source. In either case a final source is added with a message describing
+    /// what was omitted.
+    ///
+    /// With the **std** feature, if the `Display` implementation of an error in the chain panics
+    /// its message is replaced by a marker, while its type name is retained, and if its `source`
+    /// implementation panics the chain ends at that error; the panic is still reported by the
+    /// panic hook.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlattenOptions};
+    /// use std::{error::Error, fmt};
+    ///
+    /// #[derive(Debug)]
+    /// struct Loop(u32);
+    ///
+    /// impl fmt::Display for Loop {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "loop")
+    ///     }
+    /// }
+    ///
+    /// static LOOP: Loop = Loop(1);
+    ///
+    /// impl Error for Loop {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&LOOP)
+    ///     }
+    /// }
+    ///
+    /// let err = FlatError::from_any_with(&LOOP, FlattenOptions::new());
+    /// assert_eq!(
+    ///     err.display_with(Default::default()).to_string(),
+    ///     "loop: loop: … cyclic source chain"
+    /// );
+    /// ```
+    ///
+    #[track_caller]
+    pub fn from_any_with<E>(error: &E, options: FlattenOptions) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        Self::finish_flatten(
+            Self::flatten(error, options),
+            error,
+            options,
+            None::<&dyn Redactor>,
+        )
+    }
+
+    ///
+    /// Flatten `error` and its source chain according to `options`, without applying any
+    /// flatten hook.
+    ///
+    #[track_caller]
+    fn flatten<E>(error: &E, options: FlattenOptions) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        // The length of the source chain, or `None` if it is cyclic, so that the frames are
+        // allocated once and sources need only be tracked if there is a cycle to detect.
+        let max_depth = options.max_depth();
+        let chain_len = guarded_source(error).map_or(Some(0), count_sources);
+        // Sources are compared by address and type, as a `dyn Error`; the error itself is not
+        // compared as its source may be a field at the same address.
+        let mut seen: Vec<*const (dyn Error + 'static)> = Vec::new();
+        let mut sources = Vec::with_capacity(chain_len.unwrap_or_default().min(max_depth) + 1);
+        // Each message is written to this buffer, which is reused for the whole chain, and then
+        // copied once into a string of exactly its length.
+        let mut buffer = String::new();
+        let mut current = guarded_source(error);
+        while let Some(source) = current {
+            let source_ptr = ptr::from_ref(source);
+            if chain_len.is_none() && seen.iter().any(|seen| ptr::eq(*seen, source_ptr)) {
+                sources.push(Frame::omitted(SharedStr::Static(CYCLIC_SOURCE_MESSAGE)));
+                break;
+            }
+            if sources.len() == max_depth {
+                sources.push(Frame::omitted(match chain_len {
+                    Some(len) => {
+                        SharedStr::from(alloc::format!("… {} more", len.saturating_sub(max_depth)))
+                    }
+                    None => SharedStr::Static(CYCLIC_SOURCE_MESSAGE),
+                }));
+                break;
+            }
+            if chain_len.is_none() {
+                seen.push(source_ptr);
+            }
+            let mut frame = Frame::from_dyn(source, &mut buffer);
+            if options.capture_debug() {
+                frame.capture_debug(source);
+            }
+            sources.push(frame);
+            current = guarded_source(source);
+        }
+        sources.reverse();
+        let mut frame = Frame::from_any(error, &mut buffer);
+        if options.capture_debug() {
+            frame.capture_debug(error);
+        }
+        if options.dedup_frames() {
+            sources.push(frame);
+            sources.dedup_by(|outer, inner| {
+                let is_repeat = outer.original_type_name == inner.original_type_name
+                    && outer.message == inner.message;
+                if is_repeat {
+                    let count = inner.repeat_count() + outer.repeat_count();
+                    inner.details_mut().repeat_count = Some(count);
+                }
+                is_repeat
+            });
+            frame = sources.pop().expect("the error itself was pushed");
+        }
+        #[cfg(feature = "timestamp")]
+        frame.capture_timestamp();
+        #[cfg(feature = "std")]
+        frame.capture_configured();
+        #[cfg(feature = "origin")]
+        frame.capture_origin();
+        #[cfg(feature = "tracing-error")]
+        frame.capture_span_trace();
+        if options.normalize_type_names() {
+            for frame in ::core::iter::once(&mut frame).chain(&mut sources) {
+                frame.original_type_name =
+                    intern_type_name(&normalize_type_name(frame.original_type_name));
+            }
+        }
+        Self::from_frames(frame, sources)
+    }
+
+    ///
+    /// Complete the flattening of `error` into `flat`, with the steps common to every constructor
+    /// that flattens an error: the messages are redacted by `redactor`, or else by the redactor
+    /// of the installed configuration, then sanitized and truncated as set by `options`; the
+    /// flatten hook is called, and finally the messages are interned as set by `options`.
+    ///
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn finish_flatten<E, R>(
+        flat: Self,
+        error: &E,
+        options: FlattenOptions,
+        redactor: Option<&R>,
+    ) -> Self
+    where
+        E: Error + ?Sized,
+        R: Redactor + ?Sized,
+    {
+        let flat = match redactor {
+            Some(redactor) => flat.redacted(redactor),
+            #[cfg(feature = "std")]
+            None => match FlatErrorConfig::global().redactor() {
+                Some(redactor) => flat.redacted(redactor),
+                None => flat,
+            },
+            #[cfg(not(feature = "std"))]
+            None => flat,
+        };
+        let flat = if options.sanitize_messages() {
+            flat.sanitized()
+        } else {
+            flat
+        };
+        let flat = flat.truncated(options.max_message_len());
+        #[cfg(feature = "std")]
+        let flat = hook::apply_flatten_hook(&hook::ErrorRef(error), flat).interned_with(options);
+        flat
+    }
+
+    ///
+    /// Construct a new `FlatError` with the provided `message`, without the need for an
+    /// underlying error value. The original type name of the new error is that of `FlatError`
+    /// itself.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new(format!("parse failed at line {}", 12));
+    ///
+    /// assert_eq!(err.to_string(), "parse failed at line 12");
+    /// assert_eq!(err, FlatError::from("parse failed at line 12"));
+    /// ```
+    ///
+    pub fn new<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::from_frames(
+            Frame::created(FLAT_ERROR_TYPE_NAME, SharedStr::from(message.into())),
+            Vec::new(),
+        )
+    }
+
+    ///
+    /// Construct a new `FlatError` with the static string `message`; this requires no allocation
+    /// and may be used in `const` contexts. The contents of the error are allocated when first
+    /// read, and copied when it is modified, for example by [`FlatError::with_code`]. The
+    /// original type name of the new error is that of `FlatError` itself.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// const NOT_READY: FlatError = FlatError::new_static("service not ready");
+    ///
+    /// assert_eq!(NOT_READY.to_string(), "service not ready");
+    /// assert_eq!(NOT_READY.original_type_name(), "flat_error::FlatError");
+    /// ```
+    ///
+    pub const fn new_static(message: &'static str) -> Self {
+        Self {
+            inner: InnerBox::from_static(message),
+        }
+    }
+
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, and retain the original
+    /// error value so that it may be recovered later.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound));
+    ///
+    /// let original = err.downcast_ref::<IoError>().unwrap();
+    /// assert_eq!(original.kind(), ErrorKind::NotFound);
+    /// ```
+    ///
+    #[track_caller]
+    pub fn from_any_retaining<E>(error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let mut flat = Self::from_error(&error);
+        flat.inner.frame.original = Some(Arc::new(error));
+        flat
+    }
+
+    ///
+    /// Construct a new `FlatError` from its constituent parts, the inverse of
+    /// [`FlatError::into_parts`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::new("reading config file")
+    ///     .caused_by(FlatError::from(IoError::from(ErrorKind::NotFound)));
+    ///
+    /// let (type_name, message, source) = err.clone().into_parts();
+    /// assert_eq!(type_name, "flat_error::FlatError");
+    /// assert_eq!(message, "reading config file");
+    ///
+    /// assert_eq!(FlatError::from_parts(type_name, message, source), err);
+    /// ```
+    ///
+    /// Values attached to an error, such as its code, severity, or fields, are not included in
+    /// its parts and so must be set separately, see [`FlatErrorBuilder`].
+    ///
+    pub fn from_parts<S>(type_name: &'static str, message: S, source: Option<FlatError>) -> Self
+    where
+        S: Into<String>,
+    {
+        let error = Self::from_frames(
+            Frame::created(type_name, SharedStr::from(message.into())),
+            Vec::new(),
+        );
+        match source {
+            Some(source) => error.caused_by(source),
+            None => error,
+        }
+    }
+
+    ///
+    /// Construct a new `FlatError` with the message `context`, with `self` as its source. This
+    /// allows higher-level descriptions to be layered over a lower-level error.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .context("reading config file");
+    ///
+    /// assert_eq!(err.to_string(), "reading config file");
+    /// assert_eq!(err.flat_source().unwrap().to_string(), "entity not found");
+    /// ```
+    ///
+    /// The original type name of the new error is that of `FlatError` itself.
+    ///
+    pub fn context<C>(self, context: C) -> Self
+    where
+        C: Display,
+    {
+        let (frame, mut sources) = self.into_frames();
+        sources.push(frame);
+        Self::from_frames(
+            Frame::created(FLAT_ERROR_TYPE_NAME, SharedStr::from(context.to_string())),
+            sources,
+        )
+    }
+
+    ///
+    /// Return a copy of this error with `cause` attached as the cause of the last error in its
+    /// source chain, or of this error itself if it has no source. This allows a chain to be
+    /// assembled by hand, in order from the highest-level error to the root cause.
+    ///
+    /// ```rust
+    /// use flat_error::{DisplayStyle, FlatError};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::new("request failed")
+    ///     .caused_by(FlatError::new("connection reset"))
+    ///     .caused_by(FlatError::from(IoError::from(ErrorKind::ConnectionReset)));
+    ///
+    /// assert_eq!(
+    ///     err.display_with(DisplayStyle::Compact).to_string(),
+    ///     "request failed: connection reset: connection reset"
+    /// );
+    /// ```
+    ///
+    pub fn caused_by(self, cause: FlatError) -> Self {
+        let (cause, mut sources) = cause.into_frames();
+        sources.push(cause);
+        let (frame, existing) = self.into_frames();
+        sources.extend(existing);
+        Self::from_frames(frame, sources)
+    }
+
+    ///
+    /// Construct a new error for an operation that failed for two independent reasons, `first`
+    /// and `second`. These become the branches of the new error, see [`FlatError::branches`],
+    /// rather than its source, and its message is their messages separated by `"; "`. Further
+    /// branches may be added with [`FlatError::with_branch`].
+    ///
+    /// ```rust
+    /// use flat_error::{DisplayStyle, FlatError};
+    ///
+    /// let err = FlatError::join(
+    ///     FlatError::new_static("connection refused").context("connecting to primary"),
+    ///     FlatError::new_static("timed out").context("connecting to replica"),
+    /// )
+    /// .context("all endpoints failed");
+    ///
+    /// assert_eq!(
+    ///     err.display_with(DisplayStyle::Tree).to_string(),
+    ///     [
+    ///         "all endpoints failed",
+    ///         "└─ connecting to primary; connecting to replica",
+    ///         "   ├─ connecting to primary",
+    ///         "   │  └─ connection refused",
+    ///         "   └─ connecting to replica",
+    ///         "      └─ timed out",
+    ///     ]
+    ///     .join("\n")
+    /// );
+    /// ```
+    ///
+    pub fn join(first: FlatError, second: FlatError) -> Self {
+        let mut frame = Frame::created(
+            FLAT_ERROR_TYPE_NAME,
+            SharedStr::from(alloc::format!("{first}; {second}")),
+        );
+        frame.details_mut().branches = vec![first, second];
+        Self::from_frames(frame, Vec::new())
+    }
+
+    ///
+    /// Return a copy of this error with `source` flattened and attached as the cause of the last
+    /// error in its source chain, see [`FlatError::caused_by`].
+    ///
+    pub fn with_source<E>(self, source: &E) -> Self
+    where
+        E: Error + 'static,
+    {
+        self.caused_by(Self::from_error(source))
+    }
+
+    ///
+    /// Return a copy of this error with its message replaced by the result of calling `f` with
+    /// the current message. The messages of errors in the source chain are unchanged, see
+    /// [`FlatError::map_messages`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new("Connection Refused").map_message(|m| m.to_lowercase());
+    /// assert_eq!(err.to_string(), "connection refused");
+    /// ```
+    ///
+    pub fn map_message<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&str) -> String,
+    {
+        self.inner.frame.message = SharedStr::from(f(&self.inner.frame.message));
+        self
+    }
+
+    ///
+    /// Return a copy of this error with the message of this error, and of each error in its
+    /// source chain, replaced by the result of calling `f` with the current message.
+    ///
+    /// ```rust
+    /// use flat_error::{DisplayStyle, FlatError};
+    ///
+    /// let err = FlatError::new("disk full")
+    ///     .context("saving file")
+    ///     .map_messages(|m| format!("[req-42] {m}"));
+    ///
+    /// assert_eq!(
+    ///     err.display_with(DisplayStyle::Compact).to_string(),
+    ///     "[req-42] saving file: [req-42] disk full"
+    /// );
+    /// ```
+    ///
+    pub fn map_messages<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(&str) -> String,
+    {
+        let (mut frame, mut sources) = self.into_frames();
+        frame.message = SharedStr::from(f(&frame.message));
+        for source in sources.iter_mut().rev() {
+            source.message = SharedStr::from(f(&source.message));
+        }
+        Self::from_frames(frame, sources)
+    }
+
+    ///
+    /// Return a copy of this error with `prefix` added to the start of its message.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new("disk full").prefix_message("step 3: ");
+    /// assert_eq!(err.to_string(), "step 3: disk full");
+    /// ```
+    ///
+    pub fn prefix_message<P>(self, prefix: P) -> Self
+    where
+        P: Display,
+    {
+        self.map_message(|message| alloc::format!("{prefix}{message}"))
+    }
+
+    ///
+    /// Return a copy of this error with `suffix` added to the end of its message.
+    ///
+    pub fn suffix_message<S>(self, suffix: S) -> Self
+    where
+        S: Display,
+    {
+        self.map_message(|message| alloc::format!("{message}{suffix}"))
+    }
+
+    ///
+    /// Returns `true` if this error is equal to the result of flattening `other`, as by
+    /// `FlatError::from`; this includes the source chain and original type name of `other`.
+    ///
+    /// Note that comparing a `FlatError` with a string, using `==`, only compares the message
+    /// of this error.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    ///
+    /// assert_eq!(err, "entity not found");
+    /// assert!(err.eq_error(&IoError::from(ErrorKind::NotFound)));
+    /// assert!(!err.eq_error(&IoError::other("entity not found")));
+    /// ```
+    ///
+    pub fn eq_error<E>(&self, other: &E) -> bool
+    where
+        E: Error + 'static,
+    {
+        *self == Self::from_error(other)
+    }
+
+    ///
+    /// Reconstruct a `std::io::Error` from this error. If this error was flattened from an OS
+    /// error the result is created from its OS error code, see [`FlatError::os_error_code`];
+    /// otherwise it wraps a copy of this error, with the kind of the first error in the source
+    /// chain for which one was captured, see [`FlatError::io_kind`], or `ErrorKind::Other`.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("opening file");
+    ///
+    /// let io_error = err.to_io_error();
+    /// assert_eq!(io_error.kind(), ErrorKind::NotFound);
+    /// assert_eq!(io_error.to_string(), "opening file");
+    ///
+    /// let io_error = FlatError::from(IoError::from_raw_os_error(2)).to_io_error();
+    /// assert_eq!(io_error.raw_os_error(), Some(2));
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn to_io_error(&self) -> ::std::io::Error {
+        self.clone().into()
+    }
+
+    fn from_frames(frame: Frame, sources: Vec<Frame>) -> Self {
+        Self::from_inner(frame, Sources::new(sources), ())
+    }
+
+    ///
+    /// Truncate the message of this error, and of each error in its source chain, to at most
+    /// `max_len` bytes followed by an ellipsis; the ellipsis is not counted in `max_len`.
+    ///
+    fn truncated(self, max_len: Option<usize>) -> Self {
+        match max_len {
+            Some(max_len) => {
+                let (mut frame, mut sources) = self.into_frames();
+                for frame in ::core::iter::once(&mut frame).chain(&mut sources) {
+                    frame.truncate_message(max_len);
+                }
+                Self::from_frames(frame, sources)
+            }
+            None => self,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C> FlatError<C> {
+    ///
+    /// Return this error with `category` as its category, replacing any existing category and
+    /// possibly changing the category type.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// enum AppErrorKind {
+    ///     Config,
+    ///     Network,
+    /// }
+    ///
+    /// let err: FlatError<AppErrorKind> = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .context("reading config file")
+    ///     .with_category(AppErrorKind::Config);
+    ///
+    /// assert_eq!(err.category(), &AppErrorKind::Config);
+    /// assert_eq!(err.to_string(), "reading config file");
+    ///
+    /// let (err, kind) = err.into_category();
+    /// assert_eq!(kind, AppErrorKind::Config);
+    /// assert_eq!(err.flat_source().unwrap().to_string(), "entity not found");
+    /// ```
+    ///
+    pub fn with_category<D>(self, category: D) -> FlatError<D> {
+        self.map_category(|_| category)
+    }
+
+    ///
+    /// Return this error with its category replaced by the result of calling `f` with it.
+    ///
+    pub fn map_category<D, F>(self, f: F) -> FlatError<D>
+    where
+        F: FnOnce(C) -> D,
+    {
+        let Inner {
+            frame,
+            sources,
+            category,
+            ..
+        } = self.inner.into_inner();
+        FlatError::from_inner(frame, sources, f(category))
+    }
+
+    ///
+    /// Return the category of this error.
+    ///
+    pub fn category(&self) -> &C {
+        &self.inner.category
+    }
+
+    ///
+    /// Split this error into an uncategorized `FlatError` and its category.
+    ///
+    pub fn into_category(self) -> (FlatError, C) {
+        let Inner {
+            frame,
+            sources,
+            category,
+            ..
+        } = self.inner.into_inner();
+        (FlatError::from_inner(frame, sources, ()), category)
+    }
+
+    fn from_inner(frame: Frame, sources: Option<Arc<Sources>>, category: C) -> Self {
+        Self {
+            inner: InnerBox::new(Inner {
+                frame,
+                sources,
+                category,
+            }),
+        }
+    }
+
+    ///
+    /// Return the `ErrorKind` of the original error, if it was a `std::io::Error`.
+    ///
+    /// The kind is captured when flattening via `From<std::io::Error>`, via
+    /// [`FlatError::from_any_retaining`], or for any `std::io::Error` found in the source chain;
+    /// it cannot be captured by [`FlatError::from_any`] for the top-level error as that does not
+    /// require it to be `'static`.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    /// assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn io_kind(&self) -> Option<::std::io::ErrorKind> {
+        self.inner.frame.details().io_kind
+    }
+
+    ///
+    /// Return the operating system error code (`errno` on Unix, `GetLastError` on Windows) of the
+    /// original error, if it was a `std::io::Error` created from an OS error. This is captured
+    /// under the same conditions as [`FlatError::io_kind`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::Error as IoError;
+    ///
+    /// let err = FlatError::from(IoError::from_raw_os_error(2));
+    /// assert_eq!(err.os_error_code(), Some(2));
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn os_error_code(&self) -> Option<i32> {
+        self.inner.frame.details().os_error_code
+    }
+
+    ///
+    /// Return a copy of this error with the error code set to `code`. Codes are intended to be
+    /// stable identifiers for an error condition, suitable for keying documentation and alerting,
+    /// and are included in the alternate `Display` output and when comparing errors.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound)).with_code("E1042");
+    ///
+    /// assert_eq!(err.code(), Some("E1042"));
+    /// assert_eq!(
+    ///     format!("{err:#}"),
+    ///     "entity not found (code: `E1042`, original type: `std::io::error::Error`)"
+    /// );
+    /// ```
+    ///
+    pub fn with_code<S>(mut self, code: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.inner.frame.details_mut().code = Some(code.into());
+        self
+    }
+
+    ///
+    /// Return the error code attached to this error, if any.
+    ///
+    pub fn code(&self) -> Option<&str> {
+        self.inner.frame.code()
+    }
+
+    ///
+    /// Return the source code location attached to this error, if any, see
+    /// [`FlatErrorBuilder::location`].
+    ///
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.inner.frame.details().location.0
+    }
+
+    ///
+    /// Return the length, in bytes, of the original message of this error if it was truncated
+    /// when flattened, see [`FlattenOptions::with_max_message_len`].
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlattenOptions};
+    /// use std::io::Error as IoError;
+    ///
+    /// let options = FlattenOptions::new().with_max_message_len(8);
+    /// let err = FlatError::from_any_with(&IoError::other("request body: {\"id\": 1}"), options);
+    ///
+    /// assert_eq!(err.to_string(), "request …");
+    /// assert_eq!(err.original_message_len(), Some(23));
+    /// ```
+    ///
+    pub fn original_message_len(&self) -> Option<usize> {
+        self.inner.frame.details().original_message_len
+    }
+
+    ///
+    /// Return a copy of this error with the severity set to `severity`; by default all errors
+    /// have the severity [`Severity::Error`].
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, Severity};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    /// assert_eq!(err.severity(), Severity::Error);
+    ///
+    /// let err = err.with_severity(Severity::Warning);
+    /// assert_eq!(err.severity(), Severity::Warning);
+    /// ```
+    ///
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.inner.frame.severity = severity;
+        self
+    }
+
+    ///
+    /// Return the severity of this error.
+    ///
+    pub fn severity(&self) -> Severity {
+        self.inner.frame.severity
+    }
+
+    ///
+    /// Return a copy of this error with the metadata field `key` set to `value`, replacing any
+    /// existing value for `key`. Fields are included when comparing errors.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .with_field("path", "/etc/app.toml")
+    ///     .with_field("attempt", 3);
+    ///
+    /// assert_eq!(err.field("attempt"), Some("3"));
+    /// assert_eq!(
+    ///     err.fields().collect::<Vec<_>>(),
+    ///     vec![("path", "/etc/app.toml"), ("attempt", "3")]
+    /// );
+    /// ```
+    ///
+    pub fn with_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Display,
+    {
+        self.inner.frame.set_field(key.into(), value.to_string());
+        self
+    }
+
+    ///
+    /// Return an iterator over the metadata fields attached to this error, as key/value pairs in
+    /// the order they were first added.
+    ///
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.inner.frame.fields()
+    }
+
+    ///
+    /// Return the value of the metadata field `key`, if present.
+    ///
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.inner.frame.field(key)
+    }
+
+    ///
+    /// Return a copy of this error with a message key, and the arguments used to render it, in
+    /// addition to the rendered message. This allows a user interface to look up a localized
+    /// message by key, rather than displaying the English message. Any existing key and
+    /// arguments are replaced, and both are included when comparing errors.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    ///     .with_message_key("fs.open_failed", [("path", "/etc/app.toml")]);
+    ///
+    /// assert_eq!(err.to_string(), "entity not found");
+    /// assert_eq!(err.message_key(), Some("fs.open_failed"));
+    /// assert_eq!(
+    ///     err.message_args().collect::<Vec<_>>(),
+    ///     vec![("path", "/etc/app.toml")]
+    /// );
+    /// ```
+    ///
+    pub fn with_message_key<K, A, N, V>(mut self, key: K, args: A) -> Self
+    where
+        K: Into<String>,
+        A: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Display,
+    {
+        self.inner.frame.set_message_key(key.into(), args);
+        self
+    }
+
+    ///
+    /// Return a copy of this error with `help` attached, text suggesting how the user may
+    /// resolve the error. This is included in the pretty form of a [`FlatReport`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new_static("destination exists")
+    ///     .with_help("try running with --force")
+    ///     .context("copying file");
+    ///
+    /// assert_eq!(err.help(), Some("try running with --force"));
+    /// assert_eq!(
+    ///     err.report().pretty(true).to_string(),
+    ///     "copying file\n\nCaused by:\n      destination exists\n\nHelp: try running with --force"
+    /// );
+    /// ```
+    ///
+    pub fn with_help<S>(mut self, help: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.inner.frame.details_mut().help = Some(help.into());
+        self
+    }
+
+    ///
+    /// Return the help text attached to this error or, if none, to the first error in its
+    /// source chain with help text.
+    ///
+    pub fn help(&self) -> Option<&str> {
+        self.chain_frames()
+            .find_map(|frame| frame.details().help.as_deref())
+    }
+
+    ///
+    /// Return a copy of this error with `url` attached, the address of documentation, or a
+    /// runbook, describing the error. This is included in the pretty form of a [`FlatReport`]
+    /// and when serialized.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new_static("replica lag exceeded")
+    ///     .with_docs_url("https://runbooks.example.com/replica-lag")
+    ///     .context("serving query");
+    ///
+    /// assert_eq!(err.docs_url(), Some("https://runbooks.example.com/replica-lag"));
+    /// ```
+    ///
+    pub fn with_docs_url<S>(mut self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.inner.frame.details_mut().docs_url = Some(url.into());
+        self
+    }
+
+    ///
+    /// Return the documentation URL attached to this error or, if none, to the first error in
+    /// its source chain with a documentation URL.
+    ///
+    pub fn docs_url(&self) -> Option<&str> {
+        self.chain_frames()
+            .find_map(|frame| frame.details().docs_url.as_deref())
+    }
+
+    ///
+    /// Return the `Debug` form of the original error, if it was captured; see
+    /// [`FlattenOptions::with_debug_capture`].
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlattenOptions};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let options = FlattenOptions::new().with_debug_capture(true);
+    /// let err = FlatError::from_any_with(&IoError::from(ErrorKind::NotFound), options);
+    ///
+    /// assert_eq!(err.to_string(), "entity not found");
+    /// assert_eq!(err.debug_repr(), Some("Kind(NotFound)"));
+    /// assert_eq!(FlatError::new("failed").debug_repr(), None);
+    /// ```
+    ///
+    pub fn debug_repr(&self) -> Option<&str> {
+        self.inner.frame.details().debug_repr.as_deref()
+    }
+
+    ///
+    /// Return the message key attached to this error, if any.
+    ///
+    pub fn message_key(&self) -> Option<&str> {
+        self.inner.frame.details().message_key.as_deref()
+    }
+
+    ///
+    /// Return an iterator over the arguments of the message key attached to this error, as
+    /// name/value pairs in the order they were provided.
+    ///
+    pub fn message_args(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.inner
+            .frame
+            .details()
+            .message_args
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    ///
+    /// Return the number of consecutive identical errors in the source chain that were collapsed
+    /// into this error when flattened, see [`FlattenOptions::with_dedup_frames`]; this is `1`
+    /// unless errors were collapsed.
+    ///
+    pub fn repeat_count(&self) -> usize {
+        self.inner.frame.repeat_count()
+    }
+
+    ///
+    /// Return a copy of this error with `branch` added as a further independent cause of this
+    /// error, alongside its source, see [`FlatError::join`].
+    ///
+    pub fn with_branch(mut self, branch: FlatError) -> Self {
+        self.inner.frame.details_mut().branches.push(branch);
+        self
+    }
+
+    ///
+    /// Return an iterator over the branches of this error, the independent causes of this error
+    /// other than its source, see [`FlatError::join`]. Branches are not returned by
+    /// `Error::source`, and are only rendered by [`DisplayStyle::Tree`].
+    ///
+    pub fn branches(&self) -> SliceIter<'_, FlatError> {
+        self.inner.frame.details().branches.iter()
+    }
+
+    ///
+    /// Return a copy of this error with `related` added as a related error; a secondary error,
+    /// or note, reported alongside this error that is not a cause of it. For example, a
+    /// validation tool may report a duplicate definition as the primary error, and the original
+    /// definition as a related error.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new_static("duplicate key `name` at line 12")
+    ///     .with_related(FlatError::new_static("`name` first defined at line 3"));
+    ///
+    /// assert_eq!(err.related().count(), 1);
+    /// assert!(std::error::Error::source(&err).is_none());
+    /// ```
+    ///
+    pub fn with_related(mut self, related: FlatError) -> Self {
+        self.inner.frame.details_mut().related.push(related);
+        self
+    }
+
+    ///
+    /// Return an iterator over the related errors of this error, see
+    /// [`FlatError::with_related`]. Related errors are not returned by `Error::source`.
+    ///
+    pub fn related(&self) -> SliceIter<'_, FlatError> {
+        self.inner.frame.details().related.iter()
+    }
+
+    ///
+    /// Returns `true` if this error retained the original error value when flattened.
+    ///
+    pub fn has_original(&self) -> bool {
+        self.inner.frame.original.is_some()
+    }
+
+    ///
+    /// Return a reference to the original error, if it was retained.
+    ///
+    pub fn original(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
+        self.inner.frame.original.as_deref()
+    }
+
+    ///
+    /// Return a reference to the original error if it was retained *and* it is of type `E`.
+    ///
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: Error + 'static,
+    {
+        self.inner
+            .frame
+            .original
+            .as_deref()
+            .and_then(|original| original.downcast_ref::<E>())
+    }
+
+    ///
+    /// Return a reference to the first error of type `E` found in the original errors retained
+    /// by this error and the errors in its source chain. The source chain of each original,
+    /// from `Error::source`, is also searched, so an error may be found even if it was wrapped
+    /// by another error type before being flattened and retained.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::{error::Error, fmt, num::ParseIntError};
+    ///
+    /// #[derive(Debug)]
+    /// struct ConfigError(ParseIntError);
+    ///
+    /// impl fmt::Display for ConfigError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "invalid port")
+    ///     }
+    /// }
+    ///
+    /// impl Error for ConfigError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let cause = "http".parse::<u16>().unwrap_err();
+    /// let err = FlatError::from_any_retaining(ConfigError(cause.clone())).context("starting server");
+    ///
+    /// assert!(err.downcast_ref::<ParseIntError>().is_none());
+    /// assert_eq!(err.source_of_type::<ParseIntError>(), Some(&cause));
+    /// ```
+    ///
+    pub fn source_of_type<E>(&self) -> Option<&E>
+    where
+        E: Error + 'static,
+    {
+        self.chain_frames()
+            .filter_map(|frame| frame.original.as_deref())
+            .flat_map(|original| {
+                let original: &(dyn Error + 'static) = original;
+                ::core::iter::successors(Some(original), |&error| error.source())
+            })
+            .find_map(|error| error.downcast_ref::<E>())
+    }
+
+    ///
+    /// Consume this error and return the original error, if it was retained. As the original is
+    /// shared between clones it is returned as an `Arc`.
+    ///
+    pub fn into_original(self) -> Option<Arc<dyn Error + Send + Sync>> {
+        self.inner.into_inner().frame.original
+    }
+
+    ///
+    /// Consume this error and return its original type name, message, and source, see
+    /// [`FlatError::from_parts`].
+    ///
+    pub fn into_parts(self) -> (&'static str, String, Option<FlatError>) {
+        let (frame, source) = self.into_frame_and_source();
+        (frame.original_type_name, frame.message.to_string(), source)
+    }
+
+    ///
+    /// A concrete version of `Error::source` that returns the flattened source.
+    ///
+    pub fn flat_source(&self) -> Option<&FlatError> {
+        let sources = self.inner.sources.as_deref()?;
+        Some(sources.view.get_or_init(|| {
+            let (source, _) = sources
+                .as_slice()
+                .split_last()
+                .expect("sources are never empty");
+            Box::new(FlatError::from_inner(
+                source.clone(),
+                Sources::prefix(&sources.frames, sources.len - 1),
+                (),
+            ))
+        }))
+    }
+
+    ///
+    /// Return a copy of this error with at most `max_depth` errors retained in its source chain,
+    /// as if it had been flattened with [`FlattenOptions::with_max_depth`]. If the chain is
+    /// longer, the outermost errors are retained and a final source is added with a message
+    /// describing how many were omitted.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new_static("disk full")
+    ///     .context("writing block")
+    ///     .context("flushing cache")
+    ///     .context("saving file")
+    ///     .truncate_chain(1);
+    ///
+    /// assert_eq!(err.format_chain(": "), "saving file: flushing cache: … 2 more");
+    /// ```
+    ///
+    pub fn truncate_chain(self, max_depth: usize) -> Self {
+        self.map_source_frames(|frames| {
+            if frames.len() > max_depth {
+                let omitted = frames.len() - max_depth;
+                frames.drain(..omitted);
+                frames.insert(
+                    0,
+                    Frame::omitted(SharedStr::from(alloc::format!("… {omitted} more"))),
+                );
+            }
+        })
+    }
+
+    ///
+    /// Return a copy of this error with only its root cause retained in its source chain, the
+    /// errors between this error and its root cause are removed.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new_static("disk full")
+    ///     .context("writing block")
+    ///     .context("saving file")
+    ///     .keep_root();
+    ///
+    /// assert_eq!(err.format_chain(": "), "saving file: disk full");
+    /// ```
+    ///
+    pub fn keep_root(self) -> Self {
+        self.map_source_frames(|frames| frames.truncate(1))
+    }
+
+    ///
+    /// Return a copy of this error with its source chain removed, so that only its own message
+    /// and other values remain.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new_static("disk full")
+    ///     .context("saving file")
+    ///     .strip_sources();
+    ///
+    /// assert_eq!(err.format_chain(": "), "saving file");
+    /// assert!(err.flat_source().is_none());
+    /// ```
+    ///
+    pub fn strip_sources(self) -> Self {
+        let Inner {
+            frame, category, ..
+        } = self.inner.into_inner();
+        FlatError::from_inner(frame, None, category)
+    }
+
+    ///
+    /// Returns `true` if this error is equal to `other` without considering their source
+    /// chains; that is their messages, original type names, categories, and any other values
+    /// attached to them are equal.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let first = FlatError::new_static("disk full").context("saving file");
+    /// let second = FlatError::new_static("quota exceeded").context("saving file");
+    ///
+    /// assert!(first.eq_shallow(&second));
+    /// assert_ne!(first, second);
+    /// ```
+    ///
+    pub fn eq_shallow(&self, other: &Self) -> bool
+    where
+        C: PartialEq,
+    {
+        self.inner.frame == other.inner.frame && self.inner.category == other.inner.category
+    }
+
+    ///
+    /// Return the name of the flattened error type. This uses the `type_name_of_val` function from
+    /// `std::any`, with the warning:
+    ///
+    /// > Like type_name, this is intended for diagnostic use and the exact output is not guaranteed.
+    /// > It provides a best-effort description, but the output may change between versions of the
+    /// > compiler.
+    ///
+    /// With the `flat_error_strip_type_names` configuration flag this is [`STRIPPED_TYPE_NAME`]
+    /// for any flattened error.
+    ///
+    /// Type names read back by parsing or deserializing an error are interned in a process-wide
+    /// pool of up to 1024 distinct names, which are never freed; once the pool is full any new
+    /// name is recorded as [`STRIPPED_TYPE_NAME`].
+    ///
+    pub fn original_type_name(&self) -> &'static str {
+        self.inner.frame.original_type_name
+    }
+
+    ///
+    /// Returns `true` if the original type name of this error is that of `E`.
+    ///
+    /// This compares type names, as returned by `type_name`, and so is subject to the same
+    /// caveats as [`FlatError::original_type_name`]. Note also that, apart from some types in
+    /// the standard library such as `std::io::Error`, the type of an error in a source chain is
+    /// not known when it is flattened and so its type name is that of `dyn Error`.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("loading");
+    ///
+    /// assert!(err.is::<FlatError>());
+    /// assert!(!err.is::<IoError>());
+    /// assert!(err.chain_matches::<IoError>());
+    /// ```
+    ///
+    pub fn is<E>(&self) -> bool
+    where
+        E: ?Sized,
+    {
+        self.inner.frame.original_type_name == type_name::<E>()
+    }
+
+    ///
+    /// Returns `true` if the `TypeId` of the original error was captured, and is that of `E`.
+    /// Unlike [`FlatError::is`] this is exact, but returns `false` if the `TypeId` was not
+    /// captured, see [`FlatError::original_type_id`].
+    ///
+    pub fn is_exact<E>(&self) -> bool
+    where
+        E: ?Sized + 'static,
+    {
+        self.inner.frame.type_id == Some(TypeId::of::<E>())
+    }
+
+    ///
+    /// Return the `TypeId` of the original error, if it was captured. This requires the original
+    /// error to be `'static` and so is captured by [`FlatError::from_error`], by
+    /// [`FlatError::from_any_retaining`], by the `From` implementations provided by this crate,
+    /// and for a `std::io::Error` in a source chain; it is not captured by
+    /// [`FlatError::from_any`].
+    ///
+    pub fn original_type_id(&self) -> Option<TypeId> {
+        self.inner.frame.type_id
+    }
+
+    ///
+    /// Returns `true` if the original type name of this error, or of any error in its source
+    /// chain, is that of `E`; see [`FlatError::is`].
+    ///
+    pub fn chain_matches<E>(&self) -> bool
+    where
+        E: ?Sized,
+    {
+        let type_name = type_name::<E>();
+        self.chain_frames()
+            .any(|frame| frame.original_type_name == type_name)
+    }
+
+    ///
+    /// Return the name of the flattened error type without any module path or generic
+    /// parameters, for example `Error` rather than `std::io::error::Error`. This is intended for
+    /// user-facing messages, see [`FlatError::original_type_name`].
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    /// assert_eq!(err.original_type_short_name(), "Error");
+    /// ```
+    ///
+    pub fn original_type_short_name(&self) -> &str {
+        short_type_name(self.inner.frame.original_type_name)
+    }
+
+    ///
+    /// Write the alternate `Display` form of this error, which [`FromStr`](core::str::FromStr)
+    /// parses.
+    ///
+    fn write_alternate<W>(&self, w: &mut W) -> FmtResult
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        write!(w, "{} (", self.inner.frame.message)?;
+        if let Some(code) = self.code() {
+            write!(w, "code: `{code}`, ")?;
+        }
+        if let Some(source) = self.sources().next() {
+            write!(w, "source: {}, ", source.message)?;
+        }
+        write!(
+            w,
+            "original type: `{}`)",
+            self.inner.frame.original_type_name
+        )
+    }
+
+    ///
+    /// Capture additional details from `error`, which is the error flattened into this error's
+    /// own frame.
+    ///
+    fn with_std_details(mut self, error: &(dyn Error + 'static)) -> Self {
+        let frame = mem::replace(
+            &mut self.inner.frame,
+            Frame::new(FLAT_ERROR_TYPE_NAME, SharedStr::Static("")),
+        );
+        self.inner.frame = frame.with_std_details(error);
+        self
+    }
+
+    ///
+    /// Split this error into its own frame and the frames of its source chain, as stored.
+    ///
+    fn into_frames(self) -> (Frame, Vec<Frame>) {
+        let Inner { frame, sources, .. } = self.inner.into_inner();
+        (frame, sources.map(Sources::into_frames).unwrap_or_default())
+    }
+
+    ///
+    /// Split this error into its own frame and its source, if any.
+    ///
+    fn into_frame_and_source(self) -> (Frame, Option<FlatError>) {
+        let Inner { frame, sources, .. } = self.inner.into_inner();
+        let source = sources.and_then(|sources| {
+            let mut frames = sources.into_frames();
+            frames
+                .pop()
+                .map(|source| FlatError::from_frames(source, frames))
+        });
+        (frame, source)
+    }
+
+    ///
+    /// Apply `f` to the frames of the source chain of this error, as stored starting with the
+    /// root cause.
+    ///
+    fn map_source_frames<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Vec<Frame>),
+    {
+        let Inner {
+            frame,
+            sources,
+            category,
+            ..
+        } = self.inner.into_inner();
+        let mut frames = sources.map(Sources::into_frames).unwrap_or_default();
+        f(&mut frames);
+        FlatError::from_frames(frame, frames).with_category(category)
+    }
+
+    ///
+    /// Return an iterator over the frames of the source chain of this error, in order.
+    ///
+    fn sources(&self) -> Rev<SliceIter<'_, Frame>> {
+        self.source_frames().iter().rev()
+    }
+
+    ///
+    /// Return the frames of the source chain of this error as stored, starting with the root
+    /// cause.
+    ///
+    fn source_frames(&self) -> &[Frame] {
+        self.inner
+            .sources
+            .as_deref()
+            .map(Sources::as_slice)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Return an iterator over the frames of this error, starting with its own.
+    ///
+    fn chain_frames(&self) -> impl Iterator<Item = &Frame> {
+        ::core::iter::once(&self.inner.frame).chain(self.sources())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Sources
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl Drop for Sources {
+    fn drop(&mut self) {
+        self.drop_view();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Sources {
+    ///
+    /// Return new sources with all of `frames`, or `None` if there are none.
+    ///
+    fn new(frames: Vec<Frame>) -> Option<Arc<Self>> {
+        let len = frames.len();
+        (len != 0).then(|| {
+            Arc::new(Self {
+                frames: Arc::new(frames),
+                len,
+                view: OnceBox::new(),
+            })
+        })
+    }
+
+    ///
+    /// Return new sources with the first `len` of `frames`, or `None` if `len` is zero.
+    ///
+    fn prefix(frames: &Arc<Vec<Frame>>, len: usize) -> Option<Arc<Self>> {
+        (len != 0).then(|| {
+            Arc::new(Self {
+                frames: frames.clone(),
+                len,
+                view: OnceBox::new(),
+            })
+        })
+    }
+
+    ///
+    /// Return the frames of this chain, starting with the root cause.
+    ///
+    fn as_slice(&self) -> &[Frame] {
+        &self.frames[..self.len]
+    }
+
+    ///
+    /// Return the frames, copying them only if they are shared with another error.
+    ///
+    fn into_frames(self: Arc<Self>) -> Vec<Frame> {
+        match Arc::try_unwrap(self) {
+            Ok(mut sources) => {
+                // The view shares the frames, and so is dropped first.
+                sources.drop_view();
+                let len = sources.len;
+                match Arc::get_mut(&mut sources.frames) {
+                    Some(frames) => {
+                        frames.truncate(len);
+                        mem::take(frames)
+                    }
+                    None => sources.as_slice().to_vec(),
+                }
+            }
+            Err(sources) => sources.as_slice().to_vec(),
+        }
+    }
+
+    ///
+    /// Drop the source view, if any, and the views nested within it. The views are dropped in
+    /// turn, rather than recursively, so that dropping an error whose chain has been walked
+    /// through [`FlatError::flat_source`] cannot overflow the stack however long the chain.
+    ///
+    fn drop_view(&mut self) {
+        let mut view = mem::take(&mut self.view);
+        loop {
+            // Retain the sources of the view, so that dropping the view does not drop them.
+            let next = view.get().and_then(|view| view.inner.sources.clone());
+            drop(view);
+            match next.map(Arc::try_unwrap) {
+                Some(Ok(mut sources)) => view = mem::take(&mut sources.view),
+                _ => break,
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Fields
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl Deref for Fields {
+    type Target = Vec<(String, String)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DerefMut for Fields {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Fields {
+    fn eq(&self, other: &Self) -> bool {
+        self.compared().eq(other.compared())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for Fields {}
+
+#[cfg(feature = "alloc")]
+impl PartialOrd for Fields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ord for Fields {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compared().cmp(other.compared())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Hash for Fields {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for field in self.compared() {
+            field.hash(state);
+        }
+        self.compared().count().hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Fields {
+    ///
+    /// Return the fields considered when comparing, ordering, and hashing.
+    ///
+    fn compared(&self) -> impl Iterator<Item = &(String, String)> {
+        #[cfg(feature = "std")]
+        let is_compared = |(key, _): &&(String, String)| key != BACKTRACE_FIELD;
+        #[cfg(not(feature = "std"))]
+        let is_compared = |_: &&(String, String)| true;
+        self.0.iter().filter(is_compared)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Uncompared
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl<T> PartialEq for Uncompared<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Eq for Uncompared<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> PartialOrd for Uncompared<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Ord for Uncompared<T> {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Hash for Uncompared<T> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Frame
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Frame {
+    fn eq(&self, other: &Self) -> bool {
+        self.original_type_name == other.original_type_name
+            && self.message == other.message
+            && self.severity == other.severity
+            && self.details() == other.details()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for Frame {}
+
+#[cfg(feature = "alloc")]
+impl Hash for Frame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.original_type_name.hash(state);
+        self.message.hash(state);
+        self.severity.hash(state);
+        self.details().hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialOrd for Frame {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ord for Frame {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.original_type_name
+            .cmp(other.original_type_name)
+            .then_with(|| self.message.cmp(&other.message))
+            .then_with(|| self.severity.cmp(&other.severity))
+            .then_with(|| self.details().cmp(other.details()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Frame {
+    const fn new(original_type_name: &'static str, message: SharedStr) -> Self {
+        Self {
+            original_type_name,
+            message,
+            severity: Severity::Error,
+            details: None,
+            original: None,
+            type_id: None,
+        }
+    }
+
+    ///
+    /// Construct a new frame for an error created by a constructor, rather than flattened,
+    /// capturing the time with the **timestamp** feature as flattening does.
+    ///
+    fn created(original_type_name: &'static str, message: SharedStr) -> Self {
+        #[allow(unused_mut)]
+        let mut frame = Self::new(original_type_name, message);
+        #[cfg(feature = "timestamp")]
+        frame.capture_timestamp();
+        frame
+    }
+
+    ///
+    /// Flatten an error, writing its message to `buffer` first, see [`guarded_message`].
+    ///
+    fn from_any<E>(error: &E, buffer: &mut String) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        Self::new(
+            captured_type_name_of_val(error),
+            guarded_message(error, buffer),
+        )
+    }
+
+    ///
+    /// A synthetic frame standing in for errors omitted from a source chain.
+    ///
+    const fn omitted(message: SharedStr) -> Self {
+        Self::new(FLAT_ERROR_TYPE_NAME, message)
+    }
+
+    ///
+    /// Flatten an error from a source chain, these are known to be `'static` which allows the
+    /// capture of additional details for some error types by downcasting.
+    ///
+    fn from_dyn(error: &(dyn Error + 'static), buffer: &mut String) -> Self {
+        Self::from_any(error, buffer).with_std_details(error)
+    }
+
+    fn with_std_details(mut self, error: &(dyn Error + 'static)) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(io_error) = error.downcast_ref::<::std::io::Error>() {
+            // Errors in a source chain are only known as `dyn Error`.
+            self.original_type_name = captured_type_name::<::std::io::Error>();
+            self.type_id = Some(TypeId::of::<::std::io::Error>());
+            let details = self.details_mut();
+            details.io_kind = Some(io_error.kind());
+            details.os_error_code = io_error.raw_os_error();
+        }
+        self.capture_num_kind(error);
+        self
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.details().code.as_deref()
+    }
+
+    fn capture_debug<E>(&mut self, error: &E)
+    where
+        E: Debug + ?Sized,
+    {
+        if let Some(repr) = guarded_debug(error) {
+            self.details_mut().debug_repr = Some(repr);
+        }
+    }
+
+    fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.details()
+            .fields
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    fn field(&self, key: &str) -> Option<&str> {
+        self.details()
+            .fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn repeat_count(&self) -> usize {
+        self.details().repeat_count.unwrap_or(1)
+    }
+
+    fn set_field(&mut self, key: String, value: String) {
+        let fields = &mut self.details_mut().fields;
+        match fields.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = value,
+            None => fields.push((key, value)),
+        }
+    }
+
+    fn set_message_key<A, N, V>(&mut self, key: String, args: A)
+    where
+        A: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Display,
+    {
+        let details = self.details_mut();
+        details.message_key = Some(key);
+        details.message_args = args
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.to_string()))
+            .collect();
+    }
+
+    fn truncate_message(&mut self, max_len: usize) {
+        let len = self.message.len();
+        if len > max_len {
+            let end = floor_char_boundary(&self.message, max_len);
+            self.message = SharedStr::from_display(&format_args!("{}…", &self.message[..end]));
+            self.details_mut().original_message_len = Some(len);
+        }
+        if let Some(repr) = self
+            .details()
+            .debug_repr
+            .as_deref()
+            .filter(|repr| repr.len() > max_len)
+        {
+            let end = floor_char_boundary(repr, max_len);
+            let repr = alloc::format!("{}…", &repr[..end]);
+            self.details_mut().debug_repr = Some(repr);
+        }
+    }
+
+    fn details(&self) -> &Details {
+        self.details.as_deref().unwrap_or(&NO_DETAILS)
+    }
+
+    fn details_mut(&mut self) -> &mut Details {
+        Arc::make_mut(self.details.get_or_insert_with(Default::default))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ DebugChain
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl Debug for DebugChain<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let frame = self.frame;
+        let details = frame.details();
+        let mut debug = f.debug_struct("FlatError");
+        debug
+            .field("message", &&*frame.message)
+            .field("type", &frame.original_type_name);
+        if let Some(category) = self.category {
+            debug.field("category", category);
+        }
+        if let Some(location) = details.location.0 {
+            debug.field("location", &format_args!("{location}"));
+        }
+        if let Some(code) = &details.code {
+            debug.field("code", code);
+        }
+        if frame.severity != Severity::Error {
+            debug.field("severity", &frame.severity);
+        }
+        if !details.fields.is_empty() {
+            debug.field("fields", &DebugPairs(&details.fields));
+        }
+        if let Some(key) = &details.message_key {
+            debug.field("message_key", key);
+        }
+        if let Some(template) = &details.message_template {
+            debug.field("message_template", template);
+        }
+        if let Some(repr) = &details.debug_repr {
+            debug.field("debug_repr", repr);
+        }
+        if let Some(count) = details.repeat_count {
+            debug.field("repeat_count", &count);
+        }
+        if let Some(help) = &details.help {
+            debug.field("help", help);
+        }
+        if let Some(url) = &details.docs_url {
+            debug.field("docs_url", url);
+        }
+        if let Some(span) = &details.span {
+            debug.field("span", span);
+        }
+        if !details.branches.is_empty() {
+            debug.field("branches", &details.branches);
+        }
+        if !details.related.is_empty() {
+            debug.field("related", &details.related);
+        }
+        if let Some((source, sources)) = self.sources.split_last() {
+            debug.field(
+                "source",
+                &DebugChain {
+                    frame: source,
+                    sources,
+                    category: None,
+                },
+            );
+        }
+        debug.finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for DebugPairs<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ SharedStr
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl SharedStr {
+    ///
+    /// Return the `Display` form of `value`; with the **inline-messages** feature this is stored
+    /// inline, without allocation, if it is no longer than [`INLINE_MESSAGE_CAPACITY`].
+    ///
+    fn from_display<T>(value: &T) -> Self
+    where
+        T: Display + ?Sized,
+    {
+        #[cfg(feature = "inline-messages")]
+        {
+            use core::fmt::Write;
+            let mut writer = SharedStrWriter::default();
+            write!(writer, "{value}")
+                .expect("a Display implementation returned an error unexpectedly");
+            match writer.spilled {
+                Some(spilled) => Self::from(spilled),
+                None => Self::Inline(writer.inline),
+            }
+        }
+        #[cfg(not(feature = "inline-messages"))]
+        Self::from(value.to_string())
+    }
+
+    ///
+    /// Return the `Display` form of `value`, written to `buffer`, which is cleared first, and
+    /// then copied into a string of exactly its length, or stored inline as for
+    /// [`SharedStr::from_display`]. Reusing `buffer` for a number of values avoids growing a new
+    /// string for each.
+    ///
+    fn from_display_in<T>(value: &T, buffer: &mut String) -> Self
+    where
+        T: Display + ?Sized,
+    {
+        use core::fmt::Write;
+        buffer.clear();
+        write!(buffer, "{value}").expect("a Display implementation returned an error unexpectedly");
+        #[cfg(feature = "inline-messages")]
+        if buffer.len() <= INLINE_MESSAGE_CAPACITY {
+            let mut inline = InlineStr::default();
+            inline.bytes[..buffer.len()].copy_from_slice(buffer.as_bytes());
+            inline.length = buffer.len() as u8;
+            return Self::Inline(inline);
+        }
+        Self::Shared(Arc::from(buffer.as_str()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for SharedStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Static(message) => message,
+            Self::Shared(message) => message,
+            #[cfg(feature = "inline-messages")]
+            Self::Inline(message) => message.as_str(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for SharedStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for SharedStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for SharedStr {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for SharedStr {}
+
+#[cfg(feature = "alloc")]
+impl Hash for SharedStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialOrd for SharedStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ord for SharedStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for SharedStr {
+    fn from(value: String) -> Self {
+        Self::Shared(Arc::from(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Cow<'static, str>> for SharedStr {
+    fn from(value: Cow<'static, str>) -> Self {
+        match value {
+            Cow::Borrowed(value) => Self::Static(value),
+            Cow::Owned(value) => Self::from(value),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<SharedStr> for Cow<'static, str> {
+    fn from(value: SharedStr) -> Self {
+        match &value {
+            SharedStr::Static(value) => Cow::Borrowed(*value),
+            SharedStr::Shared(value) => Cow::Owned(value.to_string()),
+            #[cfg(feature = "inline-messages")]
+            SharedStr::Inline(value) => Cow::Owned(value.as_str().to_string()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ InlineStr
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "inline-messages")]
+impl InlineStr {
+    fn as_str(&self) -> &str {
+        // Only complete strings are ever written to the buffer.
+        core::str::from_utf8(&self.bytes[..usize::from(self.length)]).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "inline-messages")]
+impl core::fmt::Write for SharedStrWriter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        if let Some(spilled) = &mut self.spilled {
+            spilled.push_str(s);
+            return Ok(());
+        }
+        let start = usize::from(self.inline.length);
+        let end = start + s.len();
+        if end <= INLINE_MESSAGE_CAPACITY {
+            self.inline.bytes[start..end].copy_from_slice(s.as_bytes());
+            self.inline.length = end as u8;
+        } else {
+            let mut spilled = String::with_capacity(end);
+            spilled.push_str(self.inline.as_str());
+            spilled.push_str(s);
+            self.spilled = Some(spilled);
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the largest index no greater than `index` that is on a character boundary of `s`.
+///
+#[cfg(feature = "alloc")]
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut end = index.min(s.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    end
+}
+
+///
+/// Remove any generic parameters, and then any module path, from `type_name`.
+///
+#[cfg(feature = "alloc")]
+fn short_type_name(type_name: &str) -> &str {
+    let type_name = type_name
+        .split_once('<')
+        .map_or(type_name, |(base, _)| base);
+    type_name
+        .rsplit_once("::")
+        .map_or(type_name, |(_, name)| name)
+}
+
+///
+/// Return the interned copy of `type_name`, for an original type name that is not known
+/// statically, such as one that is parsed, deserialized, or normalized. The pool holds at most
+/// [`MAX_INTERNED_TYPE_NAMES`] names, which are never removed; once it is full, names not
+/// already present are recorded as [`STRIPPED_TYPE_NAME`].
+///
+#[cfg(feature = "alloc")]
+fn intern_type_name(type_name: &str) -> &'static str {
+    for slot in &TYPE_NAME_POOL {
+        let interned = slot.get_or_init(|| Box::new(type_name.to_string()));
+        if interned == type_name {
+            return interned;
+        }
+    }
+    STRIPPED_TYPE_NAME
+}
+
+///
+/// Reduce each path within `type_name` to its final segment, retaining generic parameters.
+///
+#[cfg(feature = "alloc")]
+fn normalize_type_name(type_name: &str) -> String {
+    let mut normalized = String::with_capacity(type_name.len());
+    let mut segment_start = 0;
+    let mut chars = type_name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            let _ = chars.next();
+            normalized.truncate(segment_start);
+        } else {
+            normalized.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = normalized.len();
+            }
+        }
+    }
+    normalized
+}
+
+///
+/// Return the message of `error`, written to `buffer`, which is cleared first, and then copied.
+/// With the **std** feature, if its `Display` implementation panics the panic is caught and
+/// [`PANICKED_MESSAGE`] is returned instead, so that flattening a misbehaving error does not
+/// itself panic.
+///
+#[cfg(feature = "alloc")]
+fn guarded_message<E>(error: &E, buffer: &mut String) -> SharedStr
+where
+    E: Error + ?Sized,
+{
+    #[cfg(feature = "std")]
+    {
+        use ::std::panic::{catch_unwind, AssertUnwindSafe};
+        catch_unwind(AssertUnwindSafe(|| {
+            SharedStr::from_display_in(error, buffer)
+        }))
+        .unwrap_or(SharedStr::Static(PANICKED_MESSAGE))
+    }
+    #[cfg(not(feature = "std"))]
+    SharedStr::from_display_in(error, buffer)
+}
+
+///
+/// Return the `Debug` form of `error`. With the **std** feature, if its `Debug` implementation
+/// panics the panic is caught and `None` is returned.
+///
+#[cfg(feature = "alloc")]
+fn guarded_debug<E>(error: &E) -> Option<String>
+where
+    E: Debug + ?Sized,
+{
+    #[cfg(feature = "std")]
+    {
+        use ::std::panic::{catch_unwind, AssertUnwindSafe};
+        catch_unwind(AssertUnwindSafe(|| alloc::format!("{error:?}"))).ok()
+    }
+    #[cfg(not(feature = "std"))]
+    Some(alloc::format!("{error:?}"))
+}
+
+///
+/// Return the source of `error`. With the **std** feature, if its `source` implementation
+/// panics the panic is caught and the chain ends at `error`.
+///
+#[cfg(feature = "alloc")]
+fn guarded_source<E>(error: &E) -> Option<&(dyn Error + 'static)>
+where
+    E: Error + ?Sized,
+{
+    #[cfg(feature = "std")]
+    {
+        use ::std::panic::{catch_unwind, AssertUnwindSafe};
+        catch_unwind(AssertUnwindSafe(|| error.source())).unwrap_or_default()
+    }
+    #[cfg(not(feature = "std"))]
+    error.source()
+}
+
+///
+/// Count the errors in the chain starting at `error`, or return `None` if the chain contains a
+/// cycle. This uses Brent's algorithm so that no memory is required for chains of any length.
+///
+#[cfg(feature = "alloc")]
+fn count_sources(error: &(dyn Error + 'static)) -> Option<usize> {
+    let mut count = 1;
+    let mut saved = ptr::from_ref(error);
+    let mut steps = 0;
+    let mut limit = 1;
+    let mut current = guarded_source(error);
+    while let Some(source) = current {
+        let source_ptr = ptr::from_ref(source);
+        if ptr::eq(source_ptr, saved) {
+            return None;
+        }
+        count += 1;
+        steps += 1;
+        if steps == limit {
+            saved = source_ptr;
+            steps = 0;
+            limit *= 2;
+        }
+        current = guarded_source(source);
+    }
+    Some(count)
+}
+
+///
+/// Return the name of the type `T` to record as the original type name of an error, or
+/// [`STRIPPED_TYPE_NAME`] if the `flat_error_strip_type_names` configuration flag is set so that
+/// the name is not included in the binary.
+///
+#[cfg_attr(
+    flat_error_strip_type_names,
+    allow(clippy::extra_unused_type_parameters)
+)]
+fn captured_type_name<T: ?Sized>() -> &'static str {
+    #[cfg(not(flat_error_strip_type_names))]
+    let type_name = core::any::type_name::<T>();
+    #[cfg(flat_error_strip_type_names)]
+    let type_name = STRIPPED_TYPE_NAME;
+    type_name
+}
+
+///
+/// Return the name of the type of `value` to record as the original type name of an error, see
+/// [`captured_type_name`].
+///
+fn captured_type_name_of_val<T: ?Sized>(_value: &T) -> &'static str {
+    captured_type_name::<T>()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Trait Objects
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl From<Box<dyn Error + Send + Sync>> for FlatError {
+    #[track_caller]
+    fn from(e: Box<dyn Error + Send + Sync>) -> Self {
+        FlatError::from_dyn_error(&*e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Box<dyn Error>> for FlatError {
+    #[track_caller]
+    fn from(e: Box<dyn Error>) -> Self {
+        FlatError::from_dyn_error(&*e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&dyn Error> for FlatError {
+    #[track_caller]
+    fn from(e: &dyn Error) -> Self {
+        FlatError::from_any(e)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ core
+// ------------------------------------------------------------------------------------------------
+
+flat_from_impls!(
+    #[cfg(feature = "alloc")]
+    ::core::array::TryFromSliceError,
+    #[cfg(feature = "alloc")]
+    ::core::cell::BorrowError,
+    #[cfg(feature = "alloc")]
+    ::core::char::CharTryFromError,
+    #[cfg(feature = "alloc")]
+    ::core::char::DecodeUtf16Error,
+    #[cfg(feature = "alloc")]
+    ::core::char::ParseCharError,
+    #[cfg(feature = "alloc")]
+    ::core::ffi::FromBytesUntilNulError,
+    #[cfg(feature = "alloc")]
+    ::core::fmt::Error,
+    #[cfg(feature = "alloc")]
+    ::core::ffi::FromBytesWithNulError,
+    #[cfg(feature = "alloc")]
+    ::core::net::AddrParseError,
+    #[cfg(feature = "alloc")]
+    ::core::num::ParseFloatError,
+    #[cfg(feature = "alloc")]
+    ::core::num::ParseIntError,
+    #[cfg(feature = "alloc")]
+    ::core::num::TryFromIntError,
+    #[cfg(feature = "alloc")]
+    ::core::str::ParseBoolError,
+    #[cfg(feature = "alloc")]
+    ::core::str::Utf8Error,
+    #[cfg(feature = "alloc")]
+    ::core::time::TryFromFloatSecsError,
+);
+
+#[cfg(feature = "alloc")]
+impl From<::core::convert::Infallible> for FlatError {
+    fn from(e: ::core::convert::Infallible) -> Self {
+        match e {}
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ alloc
+// ------------------------------------------------------------------------------------------------
+
+flat_from_impls!(
+    #[cfg(feature = "alloc")]
+    ::alloc::collections::TryReserveError,
+    #[cfg(feature = "alloc")]
+    ::alloc::ffi::FromVecWithNulError,
+    #[cfg(feature = "alloc")]
+    ::alloc::ffi::IntoStringError,
+    #[cfg(feature = "alloc")]
+    ::alloc::ffi::NulError,
+    #[cfg(feature = "alloc")]
+    ::alloc::string::FromUtf8Error,
+    #[cfg(feature = "alloc")]
+    ::core::alloc::LayoutError,
+);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ std
+// ------------------------------------------------------------------------------------------------
+
+flat_from_impls!(
+    #[cfg(feature = "std")]
+    ::std::env::JoinPathsError,
+    #[cfg(feature = "std")]
+    ::std::env::VarError,
+    #[cfg(all(feature = "std", has_fs_try_lock_error))]
+    #[allow(clippy::incompatible_msrv)]
+    ::std::fs::TryLockError,
+    #[cfg(feature = "std")]
+    ::std::io::WriterPanicked,
+    #[cfg(feature = "std")]
+    ::std::path::StripPrefixError,
+    #[cfg(feature = "std")]
+    ::std::string::FromUtf16Error,
+    #[cfg(feature = "std")]
+    ::std::sync::mpsc::RecvError,
+    #[cfg(feature = "std")]
+    ::std::sync::mpsc::RecvTimeoutError,
+    #[cfg(feature = "std")]
+    ::std::sync::mpsc::TryRecvError,
+    #[cfg(feature = "std")]
+    ::std::thread::AccessError,
+    #[cfg(feature = "std")]
+    ::std::time::SystemTimeError,
+);
+
+#[cfg(feature = "std")]
+impl From<::std::io::Error> for FlatError {
+    #[track_caller]
+    fn from(e: ::std::io::Error) -> Self {
+        FlatError::from_error(&e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FlatError> for ::std::io::Error {
+    fn from(e: FlatError) -> Self {
+        if let Some(code) = e.os_error_code() {
+            return ::std::io::Error::from_raw_os_error(code);
+        }
+        let kind = e
+            .chain_frames()
+            .find_map(|frame| frame.details().io_kind)
+            .unwrap_or(::std::io::ErrorKind::Other);
+        ::std::io::Error::new(kind, e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> From<::std::io::IntoInnerError<W>> for FlatError
+where
+    W: ::std::fmt::Debug + ::std::marker::Send,
+{
+    #[track_caller]
+    fn from(e: ::std::io::IntoInnerError<W>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<::std::sync::PoisonError<T>> for FlatError {
+    #[track_caller]
+    fn from(e: ::std::sync::PoisonError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<::std::sync::TryLockError<T>> for FlatError {
+    #[track_caller]
+    fn from(e: ::std::sync::TryLockError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<::std::sync::mpsc::SendError<T>> for FlatError {
+    #[track_caller]
+    fn from(e: ::std::sync::mpsc::SendError<T>) -> Self {
         FlatError::from_any(&e)
     }
 }
 
-#[cfg(any(not(feature = "alloc"), feature = "std"))]
-impl From<::std::time::SystemTimeError> for FlatError {
-    fn from(e: ::std::time::SystemTimeError) -> Self {
+#[cfg(feature = "std")]
+impl<T> From<::std::sync::mpsc::TrySendError<T>> for FlatError {
+    #[track_caller]
+    fn from(e: ::std::sync::mpsc::TrySendError<T>) -> Self {
         FlatError::from_any(&e)
     }
 }
@@ -0,0 +1,188 @@
+/*!
+Provides support for the structured key-value API of the [`log`](https://docs.rs/log) crate.
+
+[`FlatError`] implements `log::kv::ToValue`, so that it may be used as the value of a key-value
+pair, and `log::kv::Source`, so that it may be used as the key-value pairs of a record. The
+[`log_error!`](crate::log_error) macro logs an error with these pairs, and the compact rendering
+of the source chain, at the `Error` level.
+
+```rust
+use flat_error::{log_error, FlatError};
+
+let err = FlatError::new("disk full")
+    .with_code("E1042")
+    .context("saving file");
+
+log_error!(err);
+log_error!(target: "storage", err);
+```
+*/
+
+use crate::{DisplayStyle, FlatError};
+use ::log::{
+    kv::{Error as KvError, Key, Source, ToValue, Value, VisitSource},
+    Level, Metadata, Record,
+};
+use alloc::string::{String, ToString};
+use core::panic::Location;
+
+// ------------------------------------------------------------------------------------------------
+// Public Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Log a [`FlatError`] at the `Error` level, with the error and its source chain as key-value
+/// pairs, see [`FlatError::log`]. The target defaults to the current module path, and may be
+/// specified in the same manner as the `log` macros.
+///
+/// ```rust
+/// use flat_error::{log_error, FlatError};
+///
+/// let err = FlatError::new("connection refused");
+/// log_error!(err);
+/// log_error!(target: "network", err);
+/// ```
+///
+#[macro_export]
+macro_rules! log_error {
+    (target: $target:expr, $error:expr $(,)?) => {
+        $crate::FlatError::log(&$error, $crate::__log::Level::Error, $target)
+    };
+    ($error:expr $(,)?) => {
+        $crate::FlatError::log(&$error, $crate::__log::Level::Error, ::core::module_path!())
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct ChainSource<'a> {
+    error: &'a FlatError,
+    chain: String,
+    timestamp: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Log this error at `level` to `target`, if enabled. The message of the record is the error
+    /// message, and the key-value pairs are those of the `Source` implementation for
+    /// [`FlatError`], with the **timestamp** feature `error.timestamp`, the time the error was
+    /// flattened in the RFC 3339 form, and finally `error.chain`, the error and its source chain
+    /// in the [`DisplayStyle::Compact`] style. The file and line of the record are those of the
+    /// caller.
+    ///
+    /// The `Source` implementation provides the following keys:
+    ///
+    /// * `error.message`; the error message.
+    /// * `error.type`; the original type name of the error.
+    /// * `error.code`; the error code, if present.
+    /// * `error.severity`; the severity of the error.
+    /// * `error.depth`; the number of errors in the source chain, including this error.
+    /// * `error.source`; the message of the immediate source, if present.
+    /// * `error.root`; the message of the root cause, if different from the immediate source.
+    ///
+    #[track_caller]
+    pub fn log(&self, level: Level, target: &str) {
+        if level > ::log::max_level() {
+            return;
+        }
+        let logger = ::log::logger();
+        let metadata = Metadata::builder().level(level).target(target).build();
+        if !logger.enabled(&metadata) {
+            return;
+        }
+        let location = Location::caller();
+        let source = ChainSource {
+            error: self,
+            chain: self.display_with(DisplayStyle::Compact).to_string(),
+            #[cfg(feature = "timestamp")]
+            timestamp: self.timestamp().map(crate::timestamp::format_rfc3339),
+            #[cfg(not(feature = "timestamp"))]
+            timestamp: None,
+        };
+        logger.log(
+            &Record::builder()
+                .metadata(metadata)
+                .args(format_args!("{}", self))
+                .file_static(Some(location.file()))
+                .line(Some(location.line()))
+                .key_values(&source)
+                .build(),
+        );
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ToValue for FlatError {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+impl Source for FlatError {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), KvError> {
+        visitor.visit_pair(
+            Key::from_str("error.message"),
+            Value::from(&*self.inner.frame.message),
+        )?;
+        visitor.visit_pair(
+            Key::from_str("error.type"),
+            Value::from(self.original_type_name()),
+        )?;
+        if let Some(code) = self.code() {
+            visitor.visit_pair(Key::from_str("error.code"), Value::from(code))?;
+        }
+        visitor.visit_pair(
+            Key::from_str("error.severity"),
+            Value::from_display(&self.inner.frame.severity),
+        )?;
+        visitor.visit_pair(
+            Key::from_str("error.depth"),
+            Value::from(self.chain_frames().count()),
+        )?;
+        let sources = self.source_frames();
+        if let Some(source) = sources.last() {
+            visitor.visit_pair(Key::from_str("error.source"), Value::from(&*source.message))?;
+        }
+        if sources.len() > 1 {
+            visitor.visit_pair(
+                Key::from_str("error.root"),
+                Value::from(&*sources[0].message),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn count(&self) -> usize {
+        let sources = self.source_frames().len();
+        4 + usize::from(self.code().is_some()) + sources.min(2)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Source for ChainSource<'_> {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), KvError> {
+        self.error.visit(visitor)?;
+        if let Some(timestamp) = &self.timestamp {
+            visitor.visit_pair(
+                Key::from_str("error.timestamp"),
+                Value::from(timestamp.as_str()),
+            )?;
+        }
+        visitor.visit_pair(
+            Key::from_str("error.chain"),
+            Value::from(self.chain.as_str()),
+        )
+    }
+
+    fn count(&self) -> usize {
+        self.error.count() + 1 + usize::from(self.timestamp.is_some())
+    }
+}
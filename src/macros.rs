@@ -0,0 +1,340 @@
+/*!
+Declarative macros provided by this crate.
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate an implementation of `From<T> for FlatError` for each of the listed error types, each
+/// implementation simply calls [`FlatError::from_error`](crate::FlatError::from_error).
+///
+/// This crate cannot provide a blanket `From<E: Error>` implementation as it would conflict with
+/// the reflexive `From<T> for T` implementation in `core`; this macro allows a client to generate
+/// the implementations they require in a single line. Note that Rust's orphan rules mean that a
+/// client crate may only generate implementations for error types defined within that crate.
+///
+/// Each type may be preceded by attributes, such as `cfg`, which are copied onto the generated
+/// implementation.
+///
+/// ```rust
+/// use flat_error::{flat_from_impls, FlatError};
+/// use std::fmt::{Display, Formatter, Result as FmtResult};
+///
+/// #[derive(Debug)]
+/// pub struct ParseError;
+///
+/// impl Display for ParseError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+///         write!(f, "could not parse input")
+///     }
+/// }
+///
+/// impl std::error::Error for ParseError {}
+///
+/// #[derive(Debug)]
+/// pub struct ValidationError;
+///
+/// impl Display for ValidationError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+///         write!(f, "input is not valid")
+///     }
+/// }
+///
+/// impl std::error::Error for ValidationError {}
+///
+/// flat_from_impls!(ParseError, ValidationError);
+///
+/// let err: FlatError = ParseError.into();
+/// assert_eq!(err.to_string(), "could not parse input");
+/// ```
+///
+#[macro_export]
+macro_rules! flat_from_impls {
+    ($($(#[$meta:meta])* $error_type:ty),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl ::core::convert::From<$error_type> for $crate::FlatError {
+                #[track_caller]
+                fn from(e: $error_type) -> Self {
+                    $crate::FlatError::from_error(&e)
+                }
+            }
+        )+
+    };
+}
+
+///
+/// Generate an error enum, together with its `Display`, `Error`, and `From` implementations.
+///
+/// Each variant holds a single value; a variant written as `Name(from Type)` holds a
+/// [`FlatError`](crate::FlatError) and an implementation of `From<Type>` is generated which
+/// flattens the error with [`FlatError::from_error`](crate::FlatError::from_error). Any other
+/// variant, written as `Name(Type)`, holds a value of the given type which must implement
+/// `Clone`, `Debug`, `Display`, and `PartialEq`.
+///
+/// The generated enum derives `Clone`, `Debug`, and `PartialEq`, and so is itself an
+/// [`ExtendedError`](crate::ExtendedError). Its `Display` implementation writes the value of the
+/// variant, and its `Error::source` implementation returns the source of a `FlatError` value, or
+/// `None` for any other variant.
+///
+/// ```rust
+/// use flat_error::flat_enum;
+/// use std::{error::Error, io::{Error as IoError, ErrorKind}};
+///
+/// flat_enum! {
+///     /// Errors returned by this application.
+///     pub enum AppError {
+///         Io(from std::io::Error),
+///         Parse(from std::num::ParseIntError),
+///         Custom(String),
+///     }
+/// }
+///
+/// fn parse(s: &str) -> Result<u32, AppError> {
+///     Ok(s.parse::<u32>()?)
+/// }
+///
+/// let err = parse("x").unwrap_err();
+/// assert!(matches!(&err, AppError::Parse(flat) if flat.original_type_short_name() == "ParseIntError"));
+/// assert_eq!(err.to_string(), "invalid digit found in string");
+///
+/// let err = AppError::from(IoError::from(ErrorKind::NotFound));
+/// assert_eq!(err.to_string(), "entity not found");
+///
+/// let err = AppError::Custom("not ready".to_string());
+/// assert_eq!(err.to_string(), "not ready");
+/// assert!(err.source().is_none());
+/// ```
+///
+#[macro_export]
+macro_rules! flat_enum {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($body:tt)* }) => {
+        $crate::__flat_enum! {
+            @munch [$(#[$meta])* $vis enum $name] $name [] [] $($body)*
+        }
+    };
+}
+
+///
+/// Assert, at compile time, that each of the listed types meets the requirements of
+/// [`ExtendedError`](crate::ExtendedError). Each requirement is checked separately so that a
+/// missing implementation is reported by name, as a bound on a function such as
+/// `type_must_implement_clone`, rather than as an unsatisfied bound at some distant use of the
+/// type.
+///
+/// ```rust
+/// use flat_error::assert_extended_error;
+/// use std::fmt::{Display, Formatter, Result as FmtResult};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// pub struct ParseError;
+///
+/// impl Display for ParseError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+///         write!(f, "could not parse input")
+///     }
+/// }
+///
+/// impl std::error::Error for ParseError {}
+///
+/// assert_extended_error!(ParseError, std::fmt::Error);
+/// ```
+///
+/// The following fails to compile, reporting that `ParseError` does not satisfy the bound on
+/// `type_must_implement_partial_eq`.
+///
+/// ```rust,compile_fail
+/// use flat_error::assert_extended_error;
+/// use std::fmt::{Display, Formatter, Result as FmtResult};
+///
+/// #[derive(Clone, Debug)]
+/// pub struct ParseError;
+///
+/// impl Display for ParseError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+///         write!(f, "could not parse input")
+///     }
+/// }
+///
+/// impl std::error::Error for ParseError {}
+///
+/// assert_extended_error!(ParseError);
+/// ```
+///
+#[macro_export]
+macro_rules! assert_extended_error {
+    ($($error_type:ty),+ $(,)?) => {
+        const _: () = {
+            fn type_must_implement_clone<T: ::core::clone::Clone>() {}
+            fn type_must_implement_debug<T: ::core::fmt::Debug + ?Sized>() {}
+            fn type_must_implement_display<T: ::core::fmt::Display + ?Sized>() {}
+            fn type_must_implement_partial_eq<T: ::core::cmp::PartialEq + ?Sized>() {}
+            fn type_must_implement_error<T: $crate::__Error + ?Sized>() {}
+            #[allow(dead_code)]
+            fn assert_extended_error() {
+                $(
+                    type_must_implement_clone::<$error_type>();
+                    type_must_implement_debug::<$error_type>();
+                    type_must_implement_display::<$error_type>();
+                    type_must_implement_partial_eq::<$error_type>();
+                    type_must_implement_error::<$error_type>();
+                )+
+            }
+        };
+    };
+}
+
+///
+/// Assert that a [`FlatError`](crate::FlatError) meets a set of criteria, using a
+/// [`FlatErrorMatcher`](crate::FlatErrorMatcher). On failure the panic message includes the
+/// alternate `Display` form of the error and a description of the criteria.
+///
+/// The first argument is an expression that evaluates to a `FlatError`, or a reference to one,
+/// followed by one or more comma-separated criteria:
+///
+/// * `type: expr`; the original type name, compared as described for
+///   [`FlatErrorMatcher`](crate::FlatErrorMatcher).
+/// * `message: expr`, `message contains expr`, `message starts_with expr`, or
+///   `message ends_with expr`; the message. With the **regex** feature, also
+///   `message matches expr`, where the expression is a `regex::Regex`.
+/// * `code: expr`; the error code.
+/// * `depth: expr`; the number of errors in the source chain, including the error itself.
+/// * `in chain`; apply the type, message, and code criteria to any error in the source chain.
+///
+/// ```rust
+/// use flat_error::{assert_flat_matches, FlatError};
+/// use std::io::{Error as IoError, ErrorKind};
+///
+/// let err = FlatError::from(IoError::new(ErrorKind::PermissionDenied, "access denied"));
+///
+/// assert_flat_matches!(err, type: "std::io::Error", message contains "denied");
+/// assert_flat_matches!(
+///     err.context("opening config"),
+///     message: "opening config",
+///     depth: 2,
+/// );
+/// ```
+///
+#[macro_export]
+macro_rules! assert_flat_matches {
+    ($error:expr, $($criteria:tt)+) => {{
+        let matcher = $crate::__flat_matcher!(@ $crate::FlatErrorMatcher::new(); $($criteria)+);
+        match $error {
+            ref error => {
+                let error: &$crate::FlatError = ::core::borrow::Borrow::borrow(error);
+                assert!(
+                    matcher.matches(error),
+                    "assertion failed: error does not match\n  error: {:#}\nmatcher: {}",
+                    error,
+                    matcher
+                );
+            }
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flat_matcher {
+    (@ $matcher:expr;) => {
+        $matcher
+    };
+    (@ $matcher:expr; type: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.type_name($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; message: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.message($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; message contains $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.message_contains($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; message starts_with $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.message_starts_with($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; message ends_with $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.message_ends_with($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; message matches $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.message_matches($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; code: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.code($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; depth: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.depth($value); $($($rest)*)?)
+    };
+    (@ $matcher:expr; in chain $(, $($rest:tt)*)?) => {
+        $crate::__flat_matcher!(@ $matcher.in_chain(); $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flat_enum {
+    (
+        @munch $header:tt $name:ident [$($variants:tt)*] [$($froms:tt)*]
+        $(#[$vmeta:meta])* $variant:ident (from $from_type:ty) $(, $($rest:tt)*)?
+    ) => {
+        $crate::__flat_enum! {
+            @munch $header $name
+            [$($variants)* [$(#[$vmeta])* $variant ($crate::FlatError) from]]
+            [$($froms)* [$variant ($from_type)]]
+            $($($rest)*)?
+        }
+    };
+    (
+        @munch $header:tt $name:ident [$($variants:tt)*] $froms:tt
+        $(#[$vmeta:meta])* $variant:ident ($field_type:ty) $(, $($rest:tt)*)?
+    ) => {
+        $crate::__flat_enum! {
+            @munch $header $name
+            [$($variants)* [$(#[$vmeta])* $variant ($field_type) value]]
+            $froms
+            $($($rest)*)?
+        }
+    };
+    (
+        @munch [$($header:tt)*] $name:ident
+        [$([$(#[$vmeta:meta])* $variant:ident ($field_type:ty) $kind:ident])*]
+        [$([$from_variant:ident ($from_type:ty)])*]
+    ) => {
+        #[derive(Clone, Debug, PartialEq)]
+        $($header)* {
+            $($(#[$vmeta])* $variant($field_type),)*
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$variant(value) => ::core::fmt::Display::fmt(value, f),)*
+                }
+            }
+        }
+
+        impl $crate::__Error for $name {
+            fn source(&self) -> ::core::option::Option<&(dyn $crate::__Error + 'static)> {
+                match self {
+                    $(Self::$variant(value) => $crate::__flat_enum!(@source $kind value),)*
+                }
+            }
+        }
+
+        $(
+            impl ::core::convert::From<$from_type> for $name {
+                #[track_caller]
+                fn from(e: $from_type) -> Self {
+                    Self::$from_variant($crate::FlatError::from_error(&e))
+                }
+            }
+        )*
+    };
+    (@source from $value:ident) => {
+        $crate::__Error::source($value)
+    };
+    (@source value $value:ident) => {{
+        let _ = $value;
+        ::core::option::Option::None
+    }};
+}
@@ -0,0 +1,262 @@
+/*!
+Provides the [`FlatErrorMatcher`] type, used to make assertions about a [`FlatError`] in tests,
+see also the [`assert_flat_matches!`](crate::assert_flat_matches) macro.
+*/
+
+use crate::{short_type_name, FlatError, Frame};
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A set of criteria that a [`FlatError`] may be matched against, all of which must hold for
+/// the error to match. By default the message, type name, and code criteria are applied to the
+/// error itself; if [`FlatErrorMatcher::in_chain`] is set they must instead all hold for a
+/// single error in the source chain, including the error itself.
+///
+/// Type names are compared leniently, as the output of `type_name` includes private module
+/// names; an expected type name matches if it is equal to the original type name, if it is a
+/// single segment equal to the last segment of the original type name, or if its segments
+/// appear in order within the original type name with the same first and last segments. For
+/// example, `"std::io::Error"` and `"Error"` both match `"std::io::error::Error"`.
+///
+/// ```rust
+/// use flat_error::{FlatError, FlatErrorMatcher};
+/// use std::io::{Error as IoError, ErrorKind};
+///
+/// let err = FlatError::from(IoError::new(ErrorKind::PermissionDenied, "access denied"))
+///     .context("opening config");
+///
+/// let matcher = FlatErrorMatcher::new()
+///     .type_name("std::io::Error")
+///     .message_contains("denied")
+///     .in_chain();
+/// assert!(matcher.matches(&err));
+/// assert!(!FlatErrorMatcher::new().depth(1).matches(&err));
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlatErrorMatcher {
+    message: Option<MessageCriterion>,
+    type_name: Option<String>,
+    code: Option<String>,
+    depth: Option<usize>,
+    in_chain: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum MessageCriterion {
+    Equals(String),
+    Contains(String),
+    StartsWith(String),
+    EndsWith(String),
+    #[cfg(feature = "regex")]
+    Matches(crate::regex::Pattern),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatErrorMatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut separator = "";
+        let mut next = |f: &mut Formatter<'_>| {
+            let result = f.write_str(separator);
+            separator = ", ";
+            result
+        };
+        if let Some(type_name) = &self.type_name {
+            next(f)?;
+            write!(f, "type `{type_name}`")?;
+        }
+        if let Some(message) = &self.message {
+            next(f)?;
+            match message {
+                MessageCriterion::Equals(s) => write!(f, "message `{s}`")?,
+                MessageCriterion::Contains(s) => write!(f, "message contains `{s}`")?,
+                MessageCriterion::StartsWith(s) => write!(f, "message starts with `{s}`")?,
+                MessageCriterion::EndsWith(s) => write!(f, "message ends with `{s}`")?,
+                #[cfg(feature = "regex")]
+                MessageCriterion::Matches(p) => write!(f, "message matches `{}`", p.0)?,
+            }
+        }
+        if let Some(code) = &self.code {
+            next(f)?;
+            write!(f, "code `{code}`")?;
+        }
+        if let Some(depth) = self.depth {
+            next(f)?;
+            write!(f, "depth {depth}")?;
+        }
+        if self.in_chain {
+            next(f)?;
+            write!(f, "in chain")?;
+        }
+        if separator.is_empty() {
+            write!(f, "any error")?;
+        }
+        Ok(())
+    }
+}
+
+impl FlatErrorMatcher {
+    ///
+    /// Construct a new matcher that matches any error.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Require the message to be equal to `message`.
+    ///
+    pub fn message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = Some(MessageCriterion::Equals(message.into()));
+        self
+    }
+
+    ///
+    /// Require the message to contain `substring`.
+    ///
+    pub fn message_contains<S: Into<String>>(mut self, substring: S) -> Self {
+        self.message = Some(MessageCriterion::Contains(substring.into()));
+        self
+    }
+
+    ///
+    /// Require the message to start with `prefix`.
+    ///
+    pub fn message_starts_with<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.message = Some(MessageCriterion::StartsWith(prefix.into()));
+        self
+    }
+
+    ///
+    /// Require the message to end with `suffix`.
+    ///
+    pub fn message_ends_with<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.message = Some(MessageCriterion::EndsWith(suffix.into()));
+        self
+    }
+
+    ///
+    /// Require the message to match the regular expression `pattern`.
+    ///
+    #[cfg(feature = "regex")]
+    pub fn message_matches(mut self, pattern: ::regex::Regex) -> Self {
+        self.message = Some(MessageCriterion::Matches(crate::regex::Pattern(pattern)));
+        self
+    }
+
+    ///
+    /// Require the original type name to match `type_name`, see the type documentation for how
+    /// type names are compared.
+    ///
+    pub fn type_name<S: Into<String>>(mut self, type_name: S) -> Self {
+        self.type_name = Some(type_name.into());
+        self
+    }
+
+    ///
+    /// Require the error code to be equal to `code`.
+    ///
+    pub fn code<S: Into<String>>(mut self, code: S) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    ///
+    /// Require the number of errors in the source chain, including the error itself, to be
+    /// `depth`. This always applies to the error being matched, even if
+    /// [`FlatErrorMatcher::in_chain`] is set.
+    ///
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    ///
+    /// Apply the message, type name, and code criteria to each error in the source chain,
+    /// including the error itself, matching if any one error meets them all.
+    ///
+    pub fn in_chain(mut self) -> Self {
+        self.in_chain = true;
+        self
+    }
+
+    ///
+    /// Returns `true` if `error` meets all of the criteria of this matcher.
+    ///
+    pub fn matches(&self, error: &FlatError) -> bool {
+        if let Some(depth) = self.depth {
+            if error.chain_frames().count() != depth {
+                return false;
+            }
+        }
+        if self.in_chain {
+            error.chain_frames().any(|frame| self.matches_frame(frame))
+        } else {
+            self.matches_frame(&error.inner.frame)
+        }
+    }
+
+    fn matches_frame(&self, frame: &Frame) -> bool {
+        self.message
+            .as_ref()
+            .map_or(true, |message| message.matches(&frame.message))
+            && self.type_name.as_deref().map_or(true, |type_name| {
+                type_name_matches(type_name, frame.original_type_name)
+            })
+            && self
+                .code
+                .as_deref()
+                .map_or(true, |code| frame.code() == Some(code))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl MessageCriterion {
+    fn matches(&self, message: &str) -> bool {
+        match self {
+            Self::Equals(s) => message == s,
+            Self::Contains(s) => message.contains(s.as_str()),
+            Self::StartsWith(s) => message.starts_with(s.as_str()),
+            Self::EndsWith(s) => message.ends_with(s.as_str()),
+            #[cfg(feature = "regex")]
+            Self::Matches(p) => p.0.is_match(message),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns `true` if the segments of `expected` appear in order within `actual`, with the same
+/// first and last segments; or if `expected` is a single segment equal to the last segment of
+/// `actual`.
+///
+fn type_name_matches(expected: &str, actual: &str) -> bool {
+    if expected == actual {
+        return true;
+    }
+    if !expected.contains("::") {
+        return short_type_name(actual) == expected;
+    }
+    let actual = actual.split_once('<').map_or(actual, |(base, _)| base);
+    let mut expected_segments = expected.split("::");
+    let mut actual_segments = actual.split("::");
+    expected_segments.next() == actual_segments.next()
+        && expected.rsplit("::").next() == actual.rsplit("::").next()
+        && expected_segments.all(|segment| actual_segments.any(|actual| actual == segment))
+}
@@ -0,0 +1,55 @@
+/*!
+Provides [`FlatError::record_metric`], which increments a [`metrics`](https://docs.rs/metrics)
+counter labelled with the class, code, and root cause of an error, so that error rates may be
+broken down by the same taxonomy used elsewhere without extracting the labels by hand.
+*/
+
+use crate::FlatError;
+use ::metrics::{counter, KeyName};
+use alloc::string::{String, ToString};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Increment the counter `name` by one, with the following labels:
+    ///
+    /// * `class`; the name of the class of the error, see [`FlatError::class`].
+    /// * `code`; the code of this error, see [`FlatError::code`], or an empty string if it has
+    ///   none.
+    /// * `root_type`; the original type name of the root cause, the last error in the source
+    ///   chain, or of this error if it has no source.
+    ///
+    /// As with any use of the `metrics` crate, this has no effect unless a recorder has been
+    /// installed.
+    ///
+    /// ```rust
+    /// use flat_error::{ErrorClass, FlatError};
+    ///
+    /// FlatError::new("no such user")
+    ///     .with_class(ErrorClass::NotFound)
+    ///     .with_code("E0404")
+    ///     .context("loading profile")
+    ///     .record_metric("app_errors");
+    /// ```
+    ///
+    pub fn record_metric<N>(&self, name: N)
+    where
+        N: Into<KeyName>,
+    {
+        let root_type = self
+            .chain_frames()
+            .last()
+            .map(|frame| frame.original_type_name.to_string())
+            .unwrap_or_default();
+        counter!(
+            name,
+            "class" => self.class().name(),
+            "code" => self.code().map(String::from).unwrap_or_default(),
+            "root_type" => root_type,
+        )
+        .increment(1);
+    }
+}
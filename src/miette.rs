@@ -0,0 +1,65 @@
+/*!
+Provides an implementation of [`miette::Diagnostic`](https://docs.rs/miette) for [`FlatError`].
+
+As `miette::Report` may be created from any `Diagnostic` this also provides `From<FlatError>` for
+`miette::Report`, and the report will include the complete source chain.
+
+```rust
+use flat_error::{FlatError, Severity};
+use std::io::{Error as IoError, ErrorKind};
+
+let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+    .context("reading config file")
+    .with_code("E1042")
+    .with_severity(Severity::Warning);
+
+let report = miette::Report::from(err);
+assert_eq!(report.code().unwrap().to_string(), "E1042");
+assert_eq!(report.severity(), Some(miette::Severity::Warning));
+assert_eq!(report.chain().count(), 2);
+```
+
+*/
+
+use crate::{FlatError, Severity};
+use ::miette::{Diagnostic, Severity as MietteSeverity};
+use alloc::boxed::Box;
+use core::fmt::Display;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Diagnostic for FlatError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let code: Box<dyn Display + 'a> = Box::new(self.code()?);
+        Some(code)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let help: Box<dyn Display + 'a> = Box::new(self.help()?);
+        Some(help)
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let url: Box<dyn Display + 'a> = Box::new(self.docs_url()?);
+        Some(url)
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.related().len() == 0 {
+            return None;
+        }
+        Some(Box::new(
+            self.related().map(|related| -> &dyn Diagnostic { related }),
+        ))
+    }
+
+    fn severity(&self) -> Option<MietteSeverity> {
+        Some(match self.severity() {
+            Severity::Info => MietteSeverity::Advice,
+            Severity::Warning => MietteSeverity::Warning,
+            Severity::Error | Severity::Critical => MietteSeverity::Error,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+/*!
+Provides conversions between [`FlatError`] and [`napi::Error`](https://docs.rs/napi), so that a
+Node.js native module may return flattened errors to JavaScript, and capture the failures of
+JavaScript calls back into `FlatError`.
+
+A `napi::Error` is flattened with its reason as the error message, and its status captured as
+the field `napi_status`, holding the numeric value of the status. A `FlatError` is converted into
+a `napi::Error` with its message and source chain, in the [`DisplayStyle::Compact`] style, as the
+reason, and a status chosen by [`FlatError::napi_status`].
+
+```rust
+use flat_error::FlatError;
+use napi::{Error, Status};
+
+let err = FlatError::from(Error::new(Status::InvalidArg, "expected a string"));
+assert_eq!(err.to_string(), "expected a string");
+assert_eq!(err.napi_status(), Status::InvalidArg);
+
+let err = Error::from(FlatError::new("no such user").context("loading profile"));
+assert_eq!(err.status, Status::GenericFailure);
+assert_eq!(err.reason, "loading profile: no such user");
+```
+
+*/
+
+use crate::{DisplayStyle, ErrorClass, FlatError};
+use ::napi::{Error, Status};
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const NAPI_STATUS_FIELD: &str = "napi_status";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(error: Error) -> Self {
+        let flat = if error.reason.is_empty() {
+            FlatError::from_display_of::<Error, _>(&error.status)
+        } else {
+            FlatError::from_display_of::<Error, _>(&error.reason)
+        };
+        flat.with_field(NAPI_STATUS_FIELD, error.status as i32)
+    }
+}
+
+impl From<FlatError> for Error {
+    fn from(error: FlatError) -> Self {
+        Error::new(
+            error.napi_status(),
+            error.display_with(DisplayStyle::Compact),
+        )
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return the N-API status for this error, as used by `From<FlatError>` for `napi::Error`.
+    /// This is the status captured in the field `napi_status`, if present, or one corresponding
+    /// to the [class](FlatError::class) of the error, otherwise it is `Status::GenericFailure`.
+    ///
+    pub fn napi_status(&self) -> Status {
+        if let Some(status) = self
+            .field(NAPI_STATUS_FIELD)
+            .and_then(|status| status.parse::<i32>().ok())
+        {
+            return Status::from(status);
+        }
+        match self.class() {
+            ErrorClass::InvalidInput => Status::InvalidArg,
+            ErrorClass::Cancelled => Status::Cancelled,
+            _ => Status::GenericFailure,
+        }
+    }
+}
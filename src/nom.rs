@@ -0,0 +1,102 @@
+/*!
+Provides an implementation of `From<nom::Err<E>>` for [`FlatError`], for any error type `E` that
+implements `Debug`, capturing whether parsing failed with an error, a failure, or on incomplete
+input as the field `nom.kind`.
+
+Errors from parsers over `&str` using the default `nom::error::Error` type may instead be
+flattened with [`FlatError::from_nom_error`], which takes the complete input so that the
+position at which parsing failed is captured as the error's [`SourceSpan`], and the code of the
+failing parser as the field `nom.code`.
+
+```rust
+use flat_error::FlatError;
+use nom::{bytes::complete::tag, IResult, Parser};
+
+fn greeting(input: &str) -> IResult<&str, &str> {
+    tag("hello").parse(input)
+}
+
+let input = "hello\nhallo";
+let (rest, _) = greeting(input).unwrap();
+let err = FlatError::from_nom_error(input, greeting(&rest[1..]).unwrap_err());
+
+assert_eq!(err.to_string(), "parse error: Tag");
+assert_eq!(err.field("nom.code"), Some("Tag"));
+assert_eq!(err.span().unwrap().to_string(), "2:1");
+```
+*/
+
+use crate::{FlatError, SourceSpan};
+use ::nom::{error::Error, Err, Offset};
+use core::fmt::Debug;
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const NOM_KIND_FIELD: &str = "nom.kind";
+
+const NOM_CODE_FIELD: &str = "nom.code";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<E> From<Err<E>> for FlatError
+where
+    E: Debug,
+{
+    #[track_caller]
+    fn from(e: Err<E>) -> Self {
+        FlatError::from_any(&e).with_field(NOM_KIND_FIELD, kind_name(&e))
+    }
+}
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` from the error returned by a parser over `input`. The message
+    /// of the new error names the failing parser, rather than including the remaining input as
+    /// the `Display` form of `nom::Err` does; and the position of the remaining input within
+    /// `input` is captured as the error's span. If parsing failed on incomplete input this is the
+    /// same as `FlatError::from(error)`.
+    ///
+    pub fn from_nom_error(input: &str, error: Err<Error<&str>>) -> Self {
+        let kind = kind_name(&error);
+        match &error {
+            Err::Error(inner) | Err::Failure(inner) => {
+                let flat = FlatError::from_display_of::<Err<Error<&str>>, _>(&format_args!(
+                    "parse error: {}",
+                    inner.code.description()
+                ))
+                .with_field(NOM_KIND_FIELD, kind)
+                .with_field(NOM_CODE_FIELD, format_args!("{:?}", inner.code));
+                match input_offset(input, inner.input) {
+                    Some(offset) => flat.with_span(SourceSpan::from_range(input, offset..offset)),
+                    None => flat,
+                }
+            }
+            Err::Incomplete(_) => FlatError::from(error),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn kind_name<E>(error: &Err<E>) -> &'static str {
+    match error {
+        Err::Incomplete(_) => "incomplete",
+        Err::Error(_) => "error",
+        Err::Failure(_) => "failure",
+    }
+}
+
+///
+/// Return the byte offset of `remaining` within `input`, if it is a part of `input`.
+///
+fn input_offset(input: &str, remaining: &str) -> Option<usize> {
+    let range = input.as_bytes().as_ptr_range();
+    let start = remaining.as_ptr();
+    (range.start <= start && start <= range.end).then(|| input.offset(remaining))
+}
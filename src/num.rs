@@ -0,0 +1,93 @@
+/*!
+Provides [`FlatError::int_error_kind`], returning the kind of a flattened `ParseIntError` or
+`ParseFloatError`, so that parsing layers may distinguish, for example, empty input from
+overflow without inspecting the message.
+
+```rust
+use flat_error::FlatError;
+use std::num::IntErrorKind;
+
+let err = FlatError::from("".parse::<u8>().unwrap_err()).context("reading port");
+assert_eq!(err.int_error_kind(), Some(IntErrorKind::Empty));
+
+let err = FlatError::from("300".parse::<u8>().unwrap_err());
+assert_eq!(err.int_error_kind(), Some(IntErrorKind::PosOverflow));
+```
+*/
+
+use crate::{compat::Error, FlatError, Frame};
+use core::num::{IntErrorKind, ParseFloatError, ParseIntError};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The kind of a `ParseIntError` or `ParseFloatError`, captured in the details of a frame. This
+/// stands in for `IntErrorKind`, which cannot be ordered, and the unstable `FloatErrorKind`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum NumErrorKind {
+    Empty,
+    InvalidDigit,
+    PosOverflow,
+    NegOverflow,
+    Zero,
+    InvalidFloat,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return the kind of this error, or of the first error in its source chain, flattened from
+    /// a `ParseIntError` or `ParseFloatError`. The kind of a `ParseFloatError` is not public, and
+    /// so is reported as `IntErrorKind::Empty` for an empty string and
+    /// `IntErrorKind::InvalidDigit` otherwise.
+    ///
+    /// The kind is captured under the same conditions as [`FlatError::io_kind`], that is when
+    /// the original error is known to be `'static`.
+    ///
+    pub fn int_error_kind(&self) -> Option<IntErrorKind> {
+        self.chain_frames()
+            .find_map(|frame| frame.details().num_kind)
+            .map(|kind| match kind {
+                NumErrorKind::Empty => IntErrorKind::Empty,
+                NumErrorKind::InvalidDigit | NumErrorKind::InvalidFloat => {
+                    IntErrorKind::InvalidDigit
+                }
+                NumErrorKind::PosOverflow => IntErrorKind::PosOverflow,
+                NumErrorKind::NegOverflow => IntErrorKind::NegOverflow,
+                NumErrorKind::Zero => IntErrorKind::Zero,
+            })
+    }
+}
+
+impl Frame {
+    ///
+    /// Capture the kind of `error` if it is a `ParseIntError` or `ParseFloatError`.
+    ///
+    pub(crate) fn capture_num_kind(&mut self, error: &(dyn Error + 'static)) {
+        let kind = if let Some(error) = error.downcast_ref::<ParseIntError>() {
+            match error.kind() {
+                IntErrorKind::Empty => NumErrorKind::Empty,
+                IntErrorKind::InvalidDigit => NumErrorKind::InvalidDigit,
+                IntErrorKind::PosOverflow => NumErrorKind::PosOverflow,
+                IntErrorKind::NegOverflow => NumErrorKind::NegOverflow,
+                IntErrorKind::Zero => NumErrorKind::Zero,
+                _ => return,
+            }
+        } else if let Some(error) = error.downcast_ref::<ParseFloatError>() {
+            if *error == "".parse::<f64>().unwrap_err() {
+                NumErrorKind::Empty
+            } else {
+                NumErrorKind::InvalidFloat
+            }
+        } else {
+            return;
+        };
+        self.details_mut().num_kind = Some(kind);
+    }
+}
@@ -0,0 +1,285 @@
+/*!
+Provides the [`FlattenOptions`] type, used to control how an error is flattened by
+[`FlatError::from_any_with`](crate::FlatError::from_any_with).
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Options controlling how an error, and its source chain, are flattened.
+///
+/// ```rust
+/// use flat_error::{FlatError, FlattenOptions};
+///
+/// let nested = FlatError::new_static("disk full")
+///     .context("writing block")
+///     .context("saving file");
+///
+/// let options = FlattenOptions::new().with_max_depth(1);
+/// let err = FlatError::from_any_with(&nested, options);
+///
+/// assert_eq!(err.flat_source().unwrap().to_string(), "writing block");
+/// assert_eq!(
+///     err.flat_source().unwrap().flat_source().unwrap().to_string(),
+///     "… 1 more"
+/// );
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlattenOptions {
+    max_depth: Option<usize>,
+    normalize_type_names: bool,
+    max_message_len: Option<usize>,
+    dedup_frames: bool,
+    capture_debug: bool,
+    sanitize_messages: Option<bool>,
+    #[cfg(feature = "std")]
+    intern_messages: Option<bool>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The default maximum number of errors in the source chain that will be flattened.
+///
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for FlattenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlattenOptions {
+    ///
+    /// Construct a new set of options with default values.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            max_depth: None,
+            normalize_type_names: false,
+            max_message_len: None,
+            dedup_frames: false,
+            capture_debug: false,
+            sanitize_messages: None,
+            #[cfg(feature = "std")]
+            intern_messages: None,
+        }
+    }
+
+    ///
+    /// Return a copy of these options with the maximum number of errors in the source chain that
+    /// will be flattened set to `max_depth`. If the chain is longer it is truncated and a final
+    /// source with a message of the form `"… N more"` is added.
+    ///
+    pub const fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the maximum number of errors in the source chain that will be flattened. If not
+    /// set on these options this is the maximum depth of the installed
+    /// [`FlatErrorConfig`](crate::FlatErrorConfig), or [`DEFAULT_MAX_DEPTH`].
+    ///
+    pub fn max_depth(&self) -> usize {
+        #[cfg(feature = "std")]
+        if self.max_depth.is_none() {
+            return crate::FlatErrorConfig::global().max_depth();
+        }
+        self.max_depth.unwrap_or(DEFAULT_MAX_DEPTH)
+    }
+
+    ///
+    /// Return a copy of these options with type name normalization set to `normalize`. When
+    /// enabled, each path within a captured type name is reduced to its final segment, so that
+    /// `core::result::Result<u8, alloc::string::String>` becomes `Result<u8, String>`. This
+    /// removes module paths which may differ between compiler versions, as the output of
+    /// `type_name` is not guaranteed to be stable.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlattenOptions};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let options = FlattenOptions::new().with_normalized_type_names(true);
+    /// let err = FlatError::from_any_with(&IoError::from(ErrorKind::NotFound), options);
+    ///
+    /// assert_eq!(err.original_type_name(), "Error");
+    /// ```
+    ///
+    pub const fn with_normalized_type_names(self, normalize: bool) -> Self {
+        Self {
+            normalize_type_names: normalize,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if captured type names are normalized.
+    ///
+    pub const fn normalize_type_names(&self) -> bool {
+        self.normalize_type_names
+    }
+
+    ///
+    /// Return a copy of these options with the maximum length, in bytes, of each captured
+    /// message set to `max_len`. Longer messages are truncated, on a character boundary, to at
+    /// most `max_len` bytes and an ellipsis appended, which is not counted in `max_len`, so that
+    /// a truncated message is at most `max_len + 3` bytes long. The length of the original
+    /// message is available from
+    /// [`FlatError::original_message_len`](crate::FlatError::original_message_len).
+    ///
+    /// Some errors include entire request or response bodies in their message, limiting the
+    /// length keeps both clones and logs small.
+    ///
+    pub const fn with_max_message_len(self, max_len: usize) -> Self {
+        Self {
+            max_message_len: Some(max_len),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the maximum length, in bytes, of each captured message. If not set on these
+    /// options this is the maximum length of the installed
+    /// [`FlatErrorConfig`](crate::FlatErrorConfig), if any.
+    ///
+    pub fn max_message_len(&self) -> Option<usize> {
+        #[cfg(feature = "std")]
+        if self.max_message_len.is_none() {
+            return crate::FlatErrorConfig::global().max_message_len();
+        }
+        self.max_message_len
+    }
+
+    ///
+    /// Return a copy of these options with frame deduplication set to `dedup`. When enabled,
+    /// consecutive errors in the source chain with the same original type name and message are
+    /// collapsed into a single error, whose [`FlatError::repeat_count`](crate::FlatError::repeat_count)
+    /// is the number of errors collapsed. The error retained is the innermost of those
+    /// collapsed, as it is the most likely to carry additional details. Note that errors in the
+    /// source chain are generally only known by the type name `dyn Error`.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlattenOptions};
+    ///
+    /// let nested = FlatError::new_static("connection reset")
+    ///     .context("connection reset")
+    ///     .context("connection reset")
+    ///     .context("fetching profile");
+    ///
+    /// let options = FlattenOptions::new().with_dedup_frames(true);
+    /// let err = FlatError::from_any_with(&nested, options);
+    ///
+    /// assert_eq!(err.frames().len(), 2);
+    /// let source = err.flat_source().unwrap();
+    /// assert_eq!(source.to_string(), "connection reset");
+    /// assert_eq!(source.repeat_count(), 3);
+    /// ```
+    ///
+    pub const fn with_dedup_frames(self, dedup: bool) -> Self {
+        Self {
+            dedup_frames: dedup,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if consecutive identical errors in the source chain are collapsed.
+    ///
+    pub const fn dedup_frames(&self) -> bool {
+        self.dedup_frames
+    }
+
+    ///
+    /// Return a copy of these options with `Debug` capture set to `capture`. When enabled, the
+    /// `Debug` form of the error, and of each error in its source chain, is captured in addition
+    /// to its message, see [`FlatError::debug_repr`](crate::FlatError::debug_repr). Many errors
+    /// expose details, such as kinds and codes, only in their `Debug` form. The captured form is
+    /// redacted, sanitized, and limited by [`FlattenOptions::with_max_message_len`], as the
+    /// message is, as it may well contain the same values.
+    ///
+    pub const fn with_debug_capture(self, capture: bool) -> Self {
+        Self {
+            capture_debug: capture,
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if the `Debug` form of each error is captured.
+    ///
+    pub const fn capture_debug(&self) -> bool {
+        self.capture_debug
+    }
+
+    ///
+    /// Return a copy of these options with message sanitization set to `sanitize`. When enabled,
+    /// ANSI escape sequences are removed from the message of the error, and of each error in its
+    /// source chain, and newlines and other control characters are escaped; so that untrusted
+    /// data embedded in a message cannot forge log lines or mangle a terminal. See
+    /// [`FlatError::sanitized`](crate::FlatError::sanitized) for the rules applied.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, FlattenOptions};
+    /// use std::io::Error as IoError;
+    ///
+    /// let options = FlattenOptions::new().with_sanitized_messages(true);
+    /// let err = FlatError::from_any_with(&IoError::other("no user 'x'\n[WARN] forged"), options);
+    ///
+    /// assert_eq!(err.to_string(), r"no user 'x'\n[WARN] forged");
+    /// ```
+    ///
+    pub const fn with_sanitized_messages(self, sanitize: bool) -> Self {
+        Self {
+            sanitize_messages: Some(sanitize),
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if messages are sanitized. If not set on these options this is the setting
+    /// of the installed [`FlatErrorConfig`](crate::FlatErrorConfig).
+    ///
+    pub fn sanitize_messages(&self) -> bool {
+        #[cfg(feature = "std")]
+        if self.sanitize_messages.is_none() {
+            return crate::FlatErrorConfig::global().sanitize_messages();
+        }
+        self.sanitize_messages.unwrap_or_default()
+    }
+
+    ///
+    /// Return a copy of these options with message interning set to `intern`. When enabled, the
+    /// message of the error, and of each error in its source chain, is replaced by an identical
+    /// message from a global pool, so that errors flattened repeatedly with the same message
+    /// share a single allocation; see [`FlatError::from_any_interned`](crate::FlatError::from_any_interned).
+    ///
+    #[cfg(feature = "std")]
+    pub const fn with_interned_messages(self, intern: bool) -> Self {
+        Self {
+            intern_messages: Some(intern),
+            ..self
+        }
+    }
+
+    ///
+    /// Returns `true` if messages are interned. If not set on these options this is the setting
+    /// of the installed [`FlatErrorConfig`](crate::FlatErrorConfig).
+    ///
+    #[cfg(feature = "std")]
+    pub fn intern_messages(&self) -> bool {
+        self.intern_messages
+            .unwrap_or_else(|| crate::FlatErrorConfig::global().intern_messages())
+    }
+}
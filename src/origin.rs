@@ -0,0 +1,119 @@
+/*!
+Captures the identity of the process in which an error was flattened, its id and host name, as
+metadata fields; so that errors serialized and passed between services retain their origin.
+
+As features are unified across a build, and these fields are included when comparing and
+rendering errors, capture must also be enabled at runtime, by the application, with
+[`set_capture_origin`].
+
+```rust
+use flat_error::{set_capture_origin, FlatError, PROCESS_ID_FIELD};
+use std::io::{Error as IoError, ErrorKind};
+
+set_capture_origin(true);
+let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+
+assert_eq!(err.process_id(), Some(std::process::id()));
+assert_eq!(err.field(PROCESS_ID_FIELD), Some(std::process::id().to_string().as_str()));
+```
+*/
+
+use crate::{FlatError, Frame};
+use alloc::string::{String, ToString};
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of the metadata field holding the id of the process in which an error was
+/// flattened.
+///
+pub const PROCESS_ID_FIELD: &str = "process.id";
+
+///
+/// The name of the metadata field holding the name of the host on which an error was
+/// flattened.
+///
+pub const HOST_NAME_FIELD: &str = "host.name";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Enable, or disable, capturing the process id and host name of errors as they are flattened
+/// by [`FlatError::from_any`] and the other constructors and `From` implementations that
+/// flatten an error. Capture is disabled by default.
+///
+pub fn set_capture_origin(enabled: bool) {
+    CAPTURE_ORIGIN.store(enabled, Ordering::Relaxed);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+static CAPTURE_ORIGIN: AtomicBool = AtomicBool::new(false);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return the id of the process in which this error was flattened, if it was captured. This
+    /// is the value of the field [`PROCESS_ID_FIELD`].
+    ///
+    pub fn process_id(&self) -> Option<u32> {
+        self.field(PROCESS_ID_FIELD).and_then(|id| id.parse().ok())
+    }
+
+    ///
+    /// Return the name of the host on which this error was flattened, if it was captured. This
+    /// is the value of the field [`HOST_NAME_FIELD`].
+    ///
+    pub fn host_name(&self) -> Option<&str> {
+        self.field(HOST_NAME_FIELD)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Frame {
+    pub(crate) fn capture_origin(&mut self) {
+        if !CAPTURE_ORIGIN.load(Ordering::Relaxed) {
+            return;
+        }
+        self.set_field(PROCESS_ID_FIELD.into(), std::process::id().to_string());
+        if let Some(host_name) = host_name() {
+            self.set_field(HOST_NAME_FIELD.into(), host_name.into());
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the name of this host, determined once. The standard library does not provide this,
+/// so it is read from the kernel on Linux, or otherwise from the environment.
+///
+fn host_name() -> Option<&'static str> {
+    static HOST_NAME: OnceLock<Option<String>> = OnceLock::new();
+    HOST_NAME
+        .get_or_init(|| {
+            #[cfg(target_os = "linux")]
+            if let Ok(name) = std::fs::read_to_string("/proc/sys/kernel/hostname") {
+                return Some(name.trim().to_string()).filter(|name| !name.is_empty());
+            }
+            ["HOSTNAME", "COMPUTERNAME"]
+                .into_iter()
+                .find_map(|name| std::env::var(name).ok())
+                .filter(|name| !name.is_empty())
+        })
+        .as_deref()
+}
@@ -0,0 +1,185 @@
+/*!
+Provides [`catch_flat`] and [`join_flat`], for converting a panic into a [`FlatError`], and
+[`install_flat_panic_hook`] for reporting panics as a `FlatError`.
+*/
+
+use crate::{compat::PanicHookInfo, FlatError, FlatErrorConfig, Frame, SharedStr};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::Any;
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    panic::{catch_unwind, set_hook, UnwindSafe},
+    thread::{self, JoinHandle},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The original type name of a [`FlatError`] created from a panic.
+///
+pub const PANIC_TYPE_NAME: &str = "panic";
+
+///
+/// The name of the metadata field holding the source code location of a panic, as
+/// `file:line:column`, see [`FlatError::from_panic_info`].
+///
+pub const PANIC_LOCATION_FIELD: &str = "panic.location";
+
+///
+/// The name of the metadata field holding the name of the thread that panicked, see
+/// [`FlatError::from_panic_info`].
+///
+pub const THREAD_NAME_FIELD: &str = "thread.name";
+
+///
+/// The name of the metadata field holding the backtrace of a panic, see
+/// [`FlatError::from_panic_info`].
+///
+pub const BACKTRACE_FIELD: &str = "backtrace";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Invoke the closure `f`, returning its result or, if it panics, a [`FlatError`] created from
+/// the panic. If the panic payload is a `&str` or `String`, as it is for the `panic!` macro,
+/// this becomes the error message, and the original type name of the error is
+/// [`PANIC_TYPE_NAME`].
+///
+/// As with `std::panic::catch_unwind`, this only catches unwinding panics and the panic hook is
+/// still invoked.
+///
+/// ```rust
+/// use flat_error::{catch_flat, PANIC_TYPE_NAME};
+///
+/// let err = catch_flat(|| -> u32 { panic!("worker {} failed", 3) }).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "worker 3 failed");
+/// assert_eq!(err.original_type_name(), PANIC_TYPE_NAME);
+/// assert_eq!(catch_flat(|| 42), Ok(42));
+/// ```
+///
+pub fn catch_flat<F, T>(f: F) -> Result<T, FlatError>
+where
+    F: FnOnce() -> T + UnwindSafe,
+{
+    catch_unwind(f).map_err(FlatError::from_join_panic)
+}
+
+///
+/// Wait for the thread `handle` to finish, returning its result or, if it panicked, a
+/// [`FlatError`] created from the panic, see [`FlatError::from_join_panic`].
+///
+/// ```rust
+/// use flat_error::join_flat;
+/// use std::thread;
+///
+/// let err = join_flat(thread::spawn(|| -> u32 { panic!("worker failed") })).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "worker failed");
+/// assert_eq!(join_flat(thread::spawn(|| 42)), Ok(42));
+/// ```
+///
+pub fn join_flat<T>(handle: JoinHandle<T>) -> Result<T, FlatError> {
+    handle.join().map_err(FlatError::from_join_panic)
+}
+
+///
+/// Install a panic hook, replacing any existing hook, that creates a [`FlatError`] for each
+/// panic, see [`FlatError::from_panic_info`], and passes it to `report`. This allows panics to
+/// be reported with the same shape as other errors, for example by logging them or sending them
+/// to an error tracking service. The existing hook, which writes the panic to standard error,
+/// may be called by `report` if it is first retrieved with `std::panic::take_hook`.
+///
+/// ```rust,no_run
+/// use flat_error::install_flat_panic_hook;
+///
+/// install_flat_panic_hook(|err| {
+///     eprintln!("{}", err.report().pretty(true));
+/// });
+/// ```
+///
+pub fn install_flat_panic_hook<F>(report: F)
+where
+    F: Fn(FlatError) + Send + Sync + 'static,
+{
+    set_hook(Box::new(move |info| {
+        report(FlatError::from_panic_info(info))
+    }));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` from a panic `payload`, such as the error returned by
+    /// `JoinHandle::join` or `std::panic::catch_unwind`. If the payload is a `&str` or `String`,
+    /// as it is for the `panic!` macro, this becomes the error message, otherwise the message is
+    /// `"Box<dyn Any>"`. The original type name of the error is [`PANIC_TYPE_NAME`].
+    ///
+    pub fn from_join_panic(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => SharedStr::from(*message),
+            Err(payload) => payload_message(&*payload),
+        };
+        Self::from_frames(Frame::created(PANIC_TYPE_NAME, message), Vec::new())
+    }
+
+    ///
+    /// Construct a new `FlatError` from the `info` passed to a panic hook. The message is taken
+    /// from the panic payload, as for [`FlatError::from_join_panic`], and the location of the
+    /// panic and the name of the current thread are captured as the fields
+    /// [`PANIC_LOCATION_FIELD`] and [`THREAD_NAME_FIELD`]. If backtraces are enabled, with the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables or by the installed
+    /// [`FlatErrorConfig`], the backtrace is captured as the field [`BACKTRACE_FIELD`].
+    ///
+    // PanicHookInfo is an alias for PanicInfo before Rust 1.81, see the compat module.
+    #[allow(clippy::incompatible_msrv)]
+    pub fn from_panic_info(info: &PanicHookInfo<'_>) -> Self {
+        let message = payload_message(info.payload());
+        let mut frame = Frame::created(PANIC_TYPE_NAME, message);
+        if let Some(location) = info.location() {
+            frame.set_field(PANIC_LOCATION_FIELD.into(), location.to_string());
+        }
+        let thread = thread::current();
+        frame.set_field(
+            THREAD_NAME_FIELD.into(),
+            thread.name().unwrap_or("<unnamed>").into(),
+        );
+        let backtrace = if FlatErrorConfig::global().capture_backtraces() {
+            Backtrace::force_capture()
+        } else {
+            Backtrace::capture()
+        };
+        if backtrace.status() == BacktraceStatus::Captured {
+            frame.set_field(BACKTRACE_FIELD.into(), backtrace.to_string());
+        }
+        Self::from_frames(frame, Vec::new())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the message of a panic `payload`, if it is a `&str` or `String`.
+///
+fn payload_message(payload: &(dyn Any + Send)) -> SharedStr {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        SharedStr::Static(message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        SharedStr::from(message.clone())
+    } else {
+        SharedStr::Static("Box<dyn Any>")
+    }
+}
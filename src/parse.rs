@@ -0,0 +1,119 @@
+/*!
+Provides the implementation of `FromStr` for [`FlatError`], which parses the alternate `Display`
+format.
+*/
+
+use crate::{compat::Error, intern_type_name, FlatError, Frame, SharedStr, FLAT_ERROR_TYPE_NAME};
+use alloc::{string::ToString, vec::Vec};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The error returned when a string cannot be parsed as a [`FlatError`].
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FlatErrorParseError {
+    kind: ParseErrorKind,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ParseErrorKind {
+    MissingTypeName,
+    InvalidAttributes,
+}
+
+const CODE_PREFIX: &str = "code: `";
+const SOURCE_PREFIX: &str = "source: ";
+const TYPE_NAME_PREFIX: &str = "original type: `";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatErrorParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ParseErrorKind::MissingTypeName => {
+                write!(f, "flat error does not end with an original type name")
+            }
+            ParseErrorKind::InvalidAttributes => {
+                write!(f, "flat error does not contain valid attributes")
+            }
+        }
+    }
+}
+
+impl Error for FlatErrorParseError {}
+
+impl FromStr for FlatError {
+    type Err = FlatErrorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rest, type_name) = s
+            .strip_suffix("`)")
+            .and_then(|rest| rest.rsplit_once(TYPE_NAME_PREFIX))
+            .filter(|(_, type_name)| !type_name.contains('`'))
+            .ok_or(FlatErrorParseError::new(ParseErrorKind::MissingTypeName))?;
+
+        // Messages are not escaped, so the shortest message that leaves valid attributes is
+        // chosen.
+        let (message, code, source) = rest
+            .match_indices(" (")
+            .find_map(|(index, _)| {
+                parse_attributes(&rest[index + 2..])
+                    .map(|(code, source)| (&rest[..index], code, source))
+            })
+            .ok_or(FlatErrorParseError::new(ParseErrorKind::InvalidAttributes))?;
+
+        let mut frame = Frame::new(FLAT_ERROR_TYPE_NAME, SharedStr::from(message.to_string()));
+        frame.original_type_name = intern_type_name(type_name);
+        if let Some(code) = code {
+            frame.details_mut().code = Some(code.to_string());
+        }
+        let error = FlatError::from_frames(frame, Vec::new());
+        Ok(match source {
+            Some(source) => error.caused_by(FlatError::new(source)),
+            None => error,
+        })
+    }
+}
+
+impl FlatErrorParseError {
+    const fn new(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Parse the optional code and source attributes that precede the original type name, each of
+/// which is followed by `", "`.
+///
+fn parse_attributes(attributes: &str) -> Option<(Option<&str>, Option<&str>)> {
+    let (code, attributes) = match attributes.strip_prefix(CODE_PREFIX) {
+        Some(rest) => {
+            let (code, rest) = rest.split_once("`, ")?;
+            (Some(code), rest)
+        }
+        None => (None, attributes),
+    };
+    let source = if attributes.is_empty() {
+        None
+    } else {
+        Some(attributes.strip_prefix(SOURCE_PREFIX)?.strip_suffix(", ")?)
+    };
+    Some((code, source))
+}
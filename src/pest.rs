@@ -0,0 +1,108 @@
+/*!
+Provides an implementation of `From<pest::error::Error<R>>` for [`FlatError`]. The message of the
+new error is the short form of the error, such as `expected value`, rather than the multi-line
+`Display` form that quotes the input; the position of the error is captured as the error's
+[`SourceSpan`], the rules expected and not expected at that position as the fields
+`pest.positives` and `pest.negatives`, and any path set on the error as the field
+[`FILE_PATH_FIELD`](crate::FILE_PATH_FIELD).
+
+```rust
+use flat_error::FlatError;
+use pest::{
+    error::{Error, ErrorVariant},
+    Position,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Rule {
+    Key,
+    Value,
+}
+
+let input = "name =\n";
+let err = Error::new_from_pos(
+    ErrorVariant::ParsingError {
+        positives: vec![Rule::Value],
+        negatives: vec![],
+    },
+    Position::new(input, 6).unwrap(),
+);
+let err = FlatError::from(err);
+
+assert_eq!(err.to_string(), "expected Value");
+assert_eq!(err.field("pest.positives"), Some("Value"));
+assert_eq!(err.span().unwrap().to_string(), "1:7");
+```
+*/
+
+use crate::{FlatError, SourceSpan, FILE_PATH_FIELD};
+use ::pest::{
+    error::{Error, ErrorVariant, InputLocation, LineColLocation},
+    RuleType,
+};
+use alloc::{format, string::String, vec::Vec};
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const PEST_POSITIVES_FIELD: &str = "pest.positives";
+
+const PEST_NEGATIVES_FIELD: &str = "pest.negatives";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<R> From<Error<R>> for FlatError
+where
+    R: RuleType,
+{
+    #[track_caller]
+    fn from(e: Error<R>) -> Self {
+        let mut flat = FlatError::from_display_of::<Error<R>, _>(&e.variant.message())
+            .with_span(span(&e.location, &e.line_col));
+        if let ErrorVariant::ParsingError {
+            positives,
+            negatives,
+        } = &e.variant
+        {
+            if !positives.is_empty() {
+                flat = flat.with_field(PEST_POSITIVES_FIELD, rule_names(positives));
+            }
+            if !negatives.is_empty() {
+                flat = flat.with_field(PEST_NEGATIVES_FIELD, rule_names(negatives));
+            }
+        }
+        match e.path() {
+            Some(path) => flat.with_field(FILE_PATH_FIELD, path),
+            None => flat,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn span(location: &InputLocation, line_col: &LineColLocation) -> SourceSpan {
+    let (offset, len) = match *location {
+        InputLocation::Pos(offset) => (offset, 0),
+        InputLocation::Span((start, end)) => (start, end.saturating_sub(start)),
+    };
+    let (line, column) = match *line_col {
+        LineColLocation::Pos(line_col) | LineColLocation::Span(line_col, _) => line_col,
+    };
+    SourceSpan::new(offset, len, line, column)
+}
+
+///
+/// Return the names of `rules`, as their `Debug` forms, separated by `, `.
+///
+fn rule_names<R: RuleType>(rules: &[R]) -> String {
+    rules
+        .iter()
+        .map(|rule| format!("{rule:?}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
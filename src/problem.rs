@@ -0,0 +1,114 @@
+/*!
+Provides the [`ProblemDetails`] type, an RFC 7807 `application/problem+json` document describing
+a [`FlatError`], see [`FlatError::problem_details`].
+
+```rust
+use flat_error::{ErrorClass, FlatError};
+
+let problem = FlatError::new_static("no such user")
+    .with_code("E0404")
+    .with_class(ErrorClass::NotFound)
+    .with_docs_url("https://errors.example.com/E0404")
+    .with_field("user_id", 42)
+    .problem_details();
+
+assert_eq!(
+    serde_json::to_string(&problem).unwrap(),
+    concat!(
+        r#"{"type":"https://errors.example.com/E0404","title":"Not Found","status":404,"#,
+        r#""detail":"no such user","class":"not_found","code":"E0404","user_id":"42"}"#
+    )
+);
+```
+*/
+
+use crate::FlatError;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An RFC 7807 problem details document, serialized as a JSON object with the members below and
+/// each extension as an additional member. Extension members are always strings.
+///
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct ProblemDetails {
+    /// A URI identifying the type of the problem, the member `type`, defaults to `about:blank`.
+    #[serde(rename = "type", default = "about_blank")]
+    pub problem_type: String,
+    /// A short summary of the type of the problem.
+    pub title: String,
+    /// The HTTP status code.
+    pub status: u16,
+    /// An explanation specific to this occurrence of the problem, optional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// A URI identifying this occurrence of the problem, optional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Additional members describing the problem.
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ProblemDetails {
+    ///
+    /// The media type of a problem details document.
+    ///
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+}
+
+impl From<&FlatError> for ProblemDetails {
+    fn from(error: &FlatError) -> Self {
+        error.problem_details()
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return an RFC 7807 problem details document describing this error. The type is the
+    /// documentation URL of this error, or `about:blank`, the status is that returned by
+    /// [`FlatError::http_status`] and the title its reason phrase, and the detail is the
+    /// message of this error, not including its source chain. The extensions are the class of
+    /// this error, its code if it has one, and its metadata fields other than `http_status`.
+    ///
+    /// As the detail is the message of this error it should be one that clients may see.
+    ///
+    pub fn problem_details(&self) -> ProblemDetails {
+        let status = self.http_status();
+        let mut extensions = BTreeMap::new();
+        for (key, value) in self.fields().filter(|(key, _)| *key != "http_status") {
+            extensions.insert(key.into(), value.into());
+        }
+        extensions.insert("class".into(), self.class().name().into());
+        if let Some(code) = self.code() {
+            extensions.insert("code".into(), code.into());
+        }
+        ProblemDetails {
+            problem_type: self.docs_url().map_or_else(about_blank, Into::into),
+            title: status.canonical_reason().unwrap_or_default().into(),
+            status: status.as_u16(),
+            detail: Some(self.to_string()),
+            instance: None,
+            extensions,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn about_blank() -> String {
+    "about:blank".into()
+}
@@ -0,0 +1,91 @@
+/*!
+Provides constructors for a [`FlatError`] describing a failed subprocess, see
+[`FlatError::from_command_output`] and [`FlatError::from_exit_status`].
+*/
+
+use crate::{captured_type_name, FlatError, Frame, SharedStr};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::process::{ExitStatus, Output};
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The maximum number of bytes of a subprocess's standard error retained as the message of an
+/// error created by [`FlatError::from_command_output`].
+///
+pub const MAX_COMMAND_STDERR_LEN: usize = 1024;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` describing a subprocess that exited with `status`. The message
+    /// is the `Display` form of `status` and the fields `exit_code` and, on Unix platforms,
+    /// `signal` are set if available.
+    ///
+    /// Note that this does not check whether `status` indicates success.
+    ///
+    pub fn from_exit_status(status: ExitStatus) -> Self {
+        let message = SharedStr::from(status.to_string());
+        Self::from_frames(
+            exit_status_frame(captured_type_name::<ExitStatus>(), message, status),
+            Vec::new(),
+        )
+    }
+
+    ///
+    /// Construct a new `FlatError` describing a subprocess from its captured `output`. The
+    /// message is the standard error of the subprocess, with surrounding whitespace removed and
+    /// truncated to [`MAX_COMMAND_STDERR_LEN`] bytes, or if it wrote nothing to standard error
+    /// the `Display` form of its exit status. The fields `exit_code` and `signal` are set as
+    /// for [`FlatError::from_exit_status`].
+    ///
+    /// Note that this does not check whether `output` indicates success.
+    ///
+    /// ```rust,no_run
+    /// use flat_error::FlatError;
+    /// use std::process::Command;
+    ///
+    /// let output = Command::new("ls").arg("/no/such/dir").output().unwrap();
+    /// if !output.status.success() {
+    ///     let err = FlatError::from_command_output(&output).context("listing directory");
+    ///     assert!(err.flat_source().unwrap().field("exit_code").is_some());
+    /// }
+    /// ```
+    ///
+    pub fn from_command_output(output: &Output) -> Self {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr = stderr.trim();
+        let message = if stderr.is_empty() {
+            SharedStr::from(output.status.to_string())
+        } else {
+            SharedStr::from(String::from(stderr))
+        };
+        let mut frame = exit_status_frame(captured_type_name::<Output>(), message, output.status);
+        frame.truncate_message(MAX_COMMAND_STDERR_LEN);
+        Self::from_frames(frame, Vec::new())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn exit_status_frame(type_name: &'static str, message: SharedStr, status: ExitStatus) -> Frame {
+    let mut frame = Frame::created(type_name, message);
+    if let Some(code) = status.code() {
+        frame.set_field(String::from("exit_code"), code.to_string());
+    }
+    #[cfg(unix)]
+    if let Some(signal) = ::std::os::unix::process::ExitStatusExt::signal(&status) {
+        frame.set_field(String::from("signal"), signal.to_string());
+    }
+    frame
+}
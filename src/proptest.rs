@@ -0,0 +1,94 @@
+/*!
+Implements [`proptest::arbitrary::Arbitrary`](https://docs.rs/proptest) for [`FlatError`] and
+[`Severity`], so that `any::<FlatError>()` may be used in property tests.
+
+Each generated error has a random message, severity, and optional code; the original type name
+is either one of a set of common error types or a random path; and the error has a source chain
+of up to four errors, each generated in the same way.
+
+```rust
+use flat_error::FlatError;
+use proptest::{prelude::*, test_runner::TestRunner};
+
+let mut runner = TestRunner::default();
+runner
+    .run(&any::<FlatError>(), |err| {
+        prop_assert_eq!(err.clone(), err);
+        Ok(())
+    })
+    .unwrap();
+```
+*/
+
+use crate::{
+    intern_type_name, FlatError, Frame, Severity, SharedStr, ARBITRARY_MAX_SOURCES,
+    ARBITRARY_TYPE_NAMES, FLAT_ERROR_TYPE_NAME,
+};
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::vec,
+    option, prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+use alloc::string::{String, ToString};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Arbitrary for FlatError {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            frame_strategy(),
+            vec(frame_strategy(), 0..=ARBITRARY_MAX_SOURCES),
+        )
+            .prop_map(|(frame, sources)| FlatError::from_frames(frame, sources))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Severity {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(&[
+            Severity::Info,
+            Severity::Warning,
+            Severity::Error,
+            Severity::Critical,
+        ])
+        .boxed()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn frame_strategy() -> impl Strategy<Value = Frame> {
+    let type_name = prop_oneof![
+        select(ARBITRARY_TYPE_NAMES),
+        "[a-z_]{1,8}(::[a-z_]{1,8}){0,3}::[A-Z][A-Za-z]{0,15}"
+            .prop_map(|type_name| intern_type_name(&type_name)),
+    ];
+    (
+        type_name,
+        any::<String>(),
+        any::<Severity>(),
+        option::of("[A-Z]{1,3}[0-9]{1,4}"),
+    )
+        .prop_map(|(type_name, message, severity, code)| {
+            let mut frame = Frame::new(FLAT_ERROR_TYPE_NAME, SharedStr::from(message));
+            frame.original_type_name = type_name;
+            frame.severity = severity;
+            if let Some(code) = code {
+                frame.details_mut().code = Some(code.to_string());
+            }
+            frame
+        })
+}
@@ -0,0 +1,101 @@
+/*!
+Provides the [`Redactor`] trait, used to scrub sensitive values from messages as errors are
+flattened, see [`FlatError::from_any_redacted`], and with the **std** feature
+`FlatErrorConfig::with_redactor` for a redactor applied to every error flattened.
+*/
+
+use crate::{compat::Error, FlatError, FlattenOptions, SharedStr};
+use alloc::{borrow::Cow, string::String};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `Redactor` rewrites the message of each error captured when flattening, for example to
+/// remove file paths, email addresses, or access tokens before the message is stored and later
+/// logged.
+///
+/// This trait is implemented for any function, or closure, taking a `&str` and returning a
+/// `String`.
+///
+/// ```rust
+/// use flat_error::FlatError;
+/// use std::io::Error as IoError;
+///
+/// let redactor = |message: &str| message.replace("hunter2", "****");
+///
+/// let err = FlatError::from_any_redacted(
+///     &IoError::other("bad password: hunter2"),
+///     &redactor,
+/// );
+/// assert_eq!(err.to_string(), "bad password: ****");
+/// ```
+///
+pub trait Redactor {
+    ///
+    /// Return `message` with any sensitive values removed, or `message` itself if it does not
+    /// need to change.
+    ///
+    fn redact<'a>(&self, message: &'a str) -> Cow<'a, str>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<F> Redactor for F
+where
+    F: Fn(&str) -> String,
+{
+    fn redact<'a>(&self, message: &'a str) -> Cow<'a, str> {
+        Cow::Owned(self(message))
+    }
+}
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, as
+    /// [`FlatError::from_any`] does, and apply `redactor` to the message of the error and of each
+    /// error in its source chain.
+    ///
+    #[track_caller]
+    pub fn from_any_redacted<E, R>(error: &E, redactor: &R) -> Self
+    where
+        E: Error + ?Sized,
+        R: Redactor + ?Sized,
+    {
+        let options = FlattenOptions::new();
+        Self::finish_flatten(
+            Self::flatten(error, options),
+            error,
+            options,
+            Some(redactor),
+        )
+    }
+
+    ///
+    /// Apply `redactor` to the message of this error and of each error in its source chain, and
+    /// to any captured `Debug` form.
+    ///
+    pub(crate) fn redacted<R>(self, redactor: &R) -> Self
+    where
+        R: Redactor + ?Sized,
+    {
+        let (mut frame, mut sources) = self.into_frames();
+        for frame in ::core::iter::once(&mut frame).chain(&mut sources) {
+            if let Cow::Owned(message) = redactor.redact(&frame.message) {
+                frame.message = SharedStr::from(message);
+            }
+            if let Some(Cow::Owned(repr)) = frame
+                .details()
+                .debug_repr
+                .as_deref()
+                .map(|repr| redactor.redact(repr))
+            {
+                frame.details_mut().debug_repr = Some(repr);
+            }
+        }
+        Self::from_frames(frame, sources)
+    }
+}
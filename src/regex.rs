@@ -0,0 +1,70 @@
+/*!
+Provides [`FlatError::matches_pattern`] and [`FlatError::chain_matches_pattern`], matching the
+messages of an error and its source chain against a `regex::Regex`, for messages that embed
+variable data such as paths, addresses, or counts. See also
+[`FlatErrorMatcher::message_matches`](crate::FlatErrorMatcher::message_matches).
+
+```rust
+use flat_error::FlatError;
+use regex::Regex;
+
+let err = FlatError::new("connection to 10.0.0.7:5432 refused").context("loading user 42");
+
+let refused = Regex::new(r"^connection to [\d.]+:\d+ refused$").unwrap();
+assert!(!err.matches_pattern(&refused));
+assert!(err.chain_matches_pattern(&refused));
+```
+*/
+
+use crate::FlatError;
+use ::regex::Regex;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `Regex` that is compared by its pattern, so that it may be held by types that implement
+/// `PartialEq` and `Eq`, such as [`FlatErrorMatcher`](crate::FlatErrorMatcher).
+///
+#[derive(Clone)]
+pub(crate) struct Pattern(pub(crate) Regex);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Returns `true` if the message of this error matches `pattern`.
+    ///
+    pub fn matches_pattern(&self, pattern: &Regex) -> bool {
+        pattern.is_match(&self.inner.frame.message)
+    }
+
+    ///
+    /// Returns `true` if the message of this error, or of any error in its source chain, matches
+    /// `pattern`.
+    ///
+    pub fn chain_matches_pattern(&self, pattern: &Regex) -> bool {
+        self.chain_frames()
+            .any(|frame| pattern.is_match(&frame.message))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for Pattern {}
@@ -0,0 +1,166 @@
+/*!
+Provides the [`FlatReport`] type, for reporting an error and its complete source chain.
+*/
+
+use crate::FlatError;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `FlatReport` renders a [`FlatError`] and its complete source chain in the same manner as the
+/// (currently unstable) `std::error::Report` type. The `Debug` implementation is the same as the
+/// `Display` implementation so that it may be returned from `main`.
+///
+/// ```rust,no_run
+/// use flat_error::{FlatError, FlatReport};
+///
+/// fn main() -> Result<(), FlatReport> {
+///     std::fs::read_to_string("config.toml")
+///         .map_err(|e| FlatError::from(e).context("reading config file").report().pretty(true))?;
+///     Ok(())
+/// }
+/// ```
+///
+/// By default the report is written on a single line, the pretty form writes the source span,
+/// see [`FlatError::with_span`], if any, below the message, then each source on its own line,
+/// followed by any related errors, see [`FlatError::with_related`], any help text, see
+/// [`FlatError::with_help`], any documentation URL, see [`FlatError::with_docs_url`], and any
+/// span trace captured with the **tracing-error** feature.
+///
+/// ```rust
+/// use flat_error::FlatError;
+/// use std::io::{Error as IoError, ErrorKind};
+///
+/// let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("reading config file");
+///
+/// assert_eq!(
+///     err.clone().report().to_string(),
+///     "reading config file: entity not found"
+/// );
+/// assert_eq!(
+///     err.report().pretty(true).to_string(),
+///     "reading config file\n\nCaused by:\n      entity not found"
+/// );
+/// ```
+///
+#[derive(Clone, PartialEq)]
+pub struct FlatReport {
+    error: FlatError,
+    pretty: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FlatReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.error)?;
+        let sources = self.error.sources();
+        if self.pretty {
+            if let Some(span) = self.error.span() {
+                write!(f, "\n  --> {span}")?;
+            }
+            if sources.len() > 0 {
+                write!(f, "\n\nCaused by:")?;
+                let multiple = sources.len() > 1;
+                for (index, source) in sources.enumerate() {
+                    if multiple {
+                        write!(f, "\n{index: >4}: ")?;
+                    } else {
+                        write!(f, "\n      ")?;
+                    }
+                    write_indented(f, &source.message)?;
+                }
+            }
+            let mut related = self.error.related().peekable();
+            if related.peek().is_some() {
+                write!(f, "\n\nRelated:")?;
+                for related in related {
+                    write!(f, "\n      ")?;
+                    write_indented(f, &related.inner.frame.message)?;
+                }
+            }
+            if let Some(help) = self.error.help() {
+                write!(f, "\n\nHelp: ")?;
+                write_indented(f, help)?;
+            }
+            if let Some(url) = self.error.docs_url() {
+                write!(f, "\n\nSee: {url}")?;
+            }
+            #[cfg(feature = "tracing-error")]
+            if let Some(span_trace) = self.error.span_trace_str() {
+                write!(f, "\n\nSpan trace:\n{span_trace}")?;
+            }
+        } else {
+            for source in sources {
+                write!(f, ": {}", source.message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for FlatReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+impl From<FlatError> for FlatReport {
+    fn from(error: FlatError) -> Self {
+        Self {
+            error,
+            pretty: false,
+        }
+    }
+}
+
+impl FlatReport {
+    ///
+    /// Set whether the report is written in its multi-line, pretty, form.
+    ///
+    pub fn pretty(self, pretty: bool) -> Self {
+        Self { pretty, ..self }
+    }
+
+    ///
+    /// Return the error being reported.
+    ///
+    pub fn error(&self) -> &FlatError {
+        &self.error
+    }
+
+    ///
+    /// Consume this report and return the error being reported.
+    ///
+    pub fn into_error(self) -> FlatError {
+        self.error
+    }
+}
+
+impl FlatError {
+    ///
+    /// Consume this error and return a [`FlatReport`] that renders it and its source chain.
+    ///
+    pub fn report(self) -> FlatReport {
+        FlatReport::from(self)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn write_indented(f: &mut Formatter<'_>, message: &str) -> FmtResult {
+    for (i, line) in message.split('\n').enumerate() {
+        if i > 0 {
+            write!(f, "\n      ")?;
+        }
+        f.write_str(line)?;
+    }
+    Ok(())
+}
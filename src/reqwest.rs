@@ -0,0 +1,43 @@
+/*!
+Provides an implementation of `From<reqwest::Error>` for [`FlatError`]. The HTTP status code of
+the response, if any, is captured as the field `status`, and the fields `timeout` and `connect`
+are set to `true` if the request timed out or failed to connect, respectively.
+
+```rust
+use flat_error::FlatError;
+
+let response = reqwest::Response::from(
+    http::Response::builder().status(503).body("").unwrap(),
+);
+let err = FlatError::from(response.error_for_status().unwrap_err());
+
+assert_eq!(err.field("status"), Some("503"));
+assert_eq!(err.field("timeout"), None);
+```
+
+*/
+
+use crate::FlatError;
+use ::reqwest::Error;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let mut flat = FlatError::from_error(&e);
+        if let Some(status) = e.status() {
+            flat = flat.with_field("status", status.as_u16());
+        }
+        if e.is_timeout() {
+            flat = flat.with_field("timeout", true);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if e.is_connect() {
+            flat = flat.with_field("connect", true);
+        }
+        flat
+    }
+}
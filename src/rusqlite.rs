@@ -0,0 +1,56 @@
+/*!
+Provides an implementation of `From<rusqlite::Error>` for [`FlatError`]. If the error was
+returned by SQLite its primary error code, such as `ConstraintViolation`, and extended result
+code are captured as the fields `sqlite_code` and `extended_code`. For a constraint violation
+the constraint reported by SQLite, such as `users.email` for a `UNIQUE` constraint or the name of
+a `CHECK` constraint, is captured as the field `constraint`.
+
+```rust
+use flat_error::FlatError;
+use rusqlite::Connection;
+
+let connection = Connection::open_in_memory().unwrap();
+connection
+    .execute_batch("CREATE TABLE users (email TEXT UNIQUE);
+                    INSERT INTO users VALUES ('a@example.com');")
+    .unwrap();
+let err = FlatError::from(
+    connection
+        .execute("INSERT INTO users VALUES ('a@example.com')", [])
+        .unwrap_err(),
+);
+
+assert_eq!(err.field("sqlite_code"), Some("ConstraintViolation"));
+assert_eq!(err.field("extended_code"), Some("2067"));
+assert_eq!(err.field("constraint"), Some("users.email"));
+```
+
+*/
+
+use crate::FlatError;
+use ::rusqlite::{ffi::ErrorCode, Error};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let mut flat = FlatError::from_error(&e);
+        if let Error::SqliteFailure(sqlite_error, message) = &e {
+            flat = flat
+                .with_field("sqlite_code", format_args!("{:?}", sqlite_error.code))
+                .with_field("extended_code", sqlite_error.extended_code);
+            if sqlite_error.code == ErrorCode::ConstraintViolation {
+                if let Some((_, constraint)) = message
+                    .as_deref()
+                    .and_then(|message| message.split_once("constraint failed: "))
+                {
+                    flat = flat.with_field("constraint", constraint);
+                }
+            }
+        }
+        flat
+    }
+}
@@ -0,0 +1,155 @@
+/*!
+Provides the sanitization of captured messages, so that untrusted data embedded in the message of
+a third-party error can neither forge log lines nor mangle a terminal when the error is printed.
+Sanitization is enabled for an error by [`FlatError::from_any_sanitized`], by
+[`FlattenOptions::with_sanitized_messages`], or for all errors by
+`FlatErrorConfig::with_sanitized_messages` with the **std** feature.
+
+```rust
+use flat_error::FlatError;
+use std::io::Error as IoError;
+
+let untrusted = "admin\n[INFO] login succeeded \x1b[2Jfor root";
+let err = FlatError::from_any_sanitized(&IoError::other(format!("unknown user {untrusted}")));
+
+assert_eq!(
+    err.to_string(),
+    r"unknown user admin\n[INFO] login succeeded for root"
+);
+```
+
+The rules applied are described by [`FlatError::sanitized`].
+*/
+
+use crate::{compat::Error, FlatError, FlattenOptions, SharedStr};
+use alloc::{borrow::Cow, string::String};
+use core::{fmt::Write, iter, str::Chars};
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const ESCAPE: char = '\x1b';
+
+const BELL: char = '\x07';
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` by flattening the provided `error`, as [`FlatError::from_any`]
+    /// does, with the message of the error and of each error in its source chain sanitized.
+    ///
+    #[track_caller]
+    pub fn from_any_sanitized<E>(error: &E) -> Self
+    where
+        E: Error + ?Sized,
+    {
+        Self::from_any_with(error, FlattenOptions::new().with_sanitized_messages(true))
+    }
+
+    ///
+    /// Return this error with the message of each error in its source chain, and the `Debug`
+    /// form of each if captured, see [`FlattenOptions::with_debug_capture`], sanitized. When a
+    /// message is sanitized:
+    ///
+    /// * ANSI escape sequences, including control sequences such as `ESC [ 2 J` and operating
+    ///   system commands such as `ESC ] 0 ; title BEL`, are removed.
+    /// * Newlines, carriage returns, and tabs are escaped as `\n`, `\r`, and `\t`.
+    /// * Any other control character, and the Unicode line and paragraph separators and
+    ///   bidirectional formatting characters, are escaped in the form `\u{1b}`.
+    ///
+    /// Messages that are already safe to print are not copied.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let err = FlatError::new("bad name \"\x1b[31mred\x1b[0m\"\r\n")
+    ///     .context("reading users")
+    ///     .sanitized();
+    /// assert_eq!(err.report().to_string(), r#"reading users: bad name "red"\r\n"#);
+    /// ```
+    ///
+    pub fn sanitized(self) -> Self {
+        let (mut frame, mut sources) = self.into_frames();
+        for frame in iter::once(&mut frame).chain(&mut sources) {
+            if let Cow::Owned(message) = sanitize(&frame.message) {
+                frame.message = SharedStr::from(message);
+            }
+            if let Some(Cow::Owned(repr)) = frame.details().debug_repr.as_deref().map(sanitize) {
+                frame.details_mut().debug_repr = Some(repr);
+            }
+        }
+        Self::from_frames(frame, sources)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return `message` with ANSI escape sequences removed and control characters escaped, or
+/// `message` itself if it contains neither.
+///
+fn sanitize(message: &str) -> Cow<'_, str> {
+    if !message.chars().any(is_unsafe) {
+        return Cow::Borrowed(message);
+    }
+    let mut sanitized = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ESCAPE => skip_escape_sequence(&mut chars),
+            '\n' => sanitized.push_str("\\n"),
+            '\r' => sanitized.push_str("\\r"),
+            '\t' => sanitized.push_str("\\t"),
+            c if is_unsafe(c) => {
+                let _ = write!(sanitized, "\\u{{{:x}}}", c as u32);
+            }
+            c => sanitized.push(c),
+        }
+    }
+    Cow::Owned(sanitized)
+}
+
+///
+/// Returns `true` if `c` may not be printed as-is.
+///
+fn is_unsafe(c: char) -> bool {
+    c.is_control()
+        || matches!(c, '\u{2028}' | '\u{2029}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+///
+/// Skip the remainder of an ANSI escape sequence, following its initial `ESC`. Control
+/// sequences end with a character in the range `@` to `~`, operating system commands and other
+/// strings end with `BEL` or `ESC \`, and any other sequence is a single character.
+///
+fn skip_escape_sequence(chars: &mut Chars<'_>) {
+    match chars.next() {
+        Some('[') => {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+        Some(']' | 'P' | 'X' | '^' | '_') => {
+            while let Some(c) = chars.next() {
+                if c == BELL {
+                    break;
+                }
+                if c == ESCAPE {
+                    if chars.as_str().starts_with('\\') {
+                        let _ = chars.next();
+                    }
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,33 @@
+/*!
+Implements [`schemars::JsonSchema`](https://docs.rs/schemars) for [`FlatError`], describing the
+form produced by its `Serialize` implementation, see the [`serde`](crate::serde) module.
+
+```rust
+use flat_error::FlatError;
+
+let schema = schemars::schema_for!(FlatError);
+assert_eq!(schema.get("title").unwrap(), "FlatError");
+```
+*/
+
+use crate::{serde::FrameRepr, FlatError};
+use ::schemars::{JsonSchema, Schema, SchemaGenerator};
+use alloc::borrow::Cow;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl JsonSchema for FlatError {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("FlatError")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("flat_error::FlatError")
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        FrameRepr::json_schema(generator)
+    }
+}
@@ -0,0 +1,54 @@
+/*!
+Provides [`FlatError::with_secret_field`], recording that a `secrecy::SecretBox` value, such as a
+`SecretString`, was involved in an error as a metadata field with the value
+[`REDACTED_FIELD_VALUE`], so that the secret itself is never copied into the error.
+
+```rust
+use flat_error::{FlatError, REDACTED_FIELD_VALUE};
+use secrecy::SecretString;
+
+let token = SecretString::from("hunter2");
+let err = FlatError::new("token rejected").with_secret_field("token", &token);
+
+assert_eq!(err.field("token"), Some(REDACTED_FIELD_VALUE));
+assert!(!format!("{err:?}").contains("hunter2"));
+```
+*/
+
+use crate::FlatError;
+use ::secrecy::SecretBox;
+use ::zeroize::Zeroize;
+use alloc::string::{String, ToString};
+
+// ------------------------------------------------------------------------------------------------
+// Public Constants
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The value of a metadata field added by [`FlatError::with_secret_field`] in place of the
+/// secret value.
+///
+pub const REDACTED_FIELD_VALUE: &str = "[redacted]";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return this error with the metadata field `key` set to [`REDACTED_FIELD_VALUE`], noting
+    /// that `secret` was involved in the error without exposing it, see
+    /// [`FlatError::with_field`].
+    ///
+    pub fn with_secret_field<K, S>(mut self, key: K, secret: &SecretBox<S>) -> Self
+    where
+        K: Into<String>,
+        S: Zeroize + ?Sized,
+    {
+        let _ = secret;
+        self.inner
+            .frame
+            .set_field(key.into(), REDACTED_FIELD_VALUE.to_string());
+        self
+    }
+}
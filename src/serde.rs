@@ -0,0 +1,338 @@
+/*!
+Implements `Serialize` and `Deserialize` for [`FlatError`], [`FlatErrorGroup`](crate::FlatErrorGroup),
+and [`Severity`](crate::Severity).
+
+A `FlatError` is serialized as a map with the keys below, the optional keys are omitted when
+empty. The source chain is serialized as a sequence, starting with the immediate source, of maps
+with the same keys; when deserializing, any sources of a source are appended in order after it.
+
+| Key            | Value                                          |
+|----------------|------------------------------------------------|
+| `message`      | The error message.                             |
+| `type`         | The original type name of the error.           |
+| `severity`     | The severity, one of `info`, `warning`, `error`, or `critical`, defaults to `error`. |
+| `code`         | The error code, optional.                      |
+| `class`        | The [`ErrorClass`](crate::ErrorClass), optional. |
+| `fields`       | A map of metadata fields, optional.            |
+| `message_key`  | The message key, optional.                     |
+| `message_args` | A map of message arguments, optional.          |
+| `message_template` | The message template, optional.          |
+| `debug_repr`   | The `Debug` form of the original error, optional. |
+| `repeat_count` | The number of identical errors collapsed into this one, optional. |
+| `help`         | The help text, optional.                       |
+| `docs_url`     | The documentation URL, optional.               |
+| `span`         | A struct, the source span, with the fields `offset`, `len`, `line`, and `column`, optional. |
+| `branches`     | A sequence of errors, the branches of the error, optional. |
+| `related`      | A sequence of errors, the related errors, optional. |
+| `timestamp`    | The time the error was created, in the RFC 3339 form, optional; only with the **timestamp** feature. |
+| `sources`      | The source chain, optional.                    |
+
+The location, original error, and any `std::io` details are not serialized.
+
+```rust
+use flat_error::FlatError;
+# flat_error::FlatErrorConfig::new().with_timestamp_capture(false).install().unwrap();
+
+let err = FlatError::new_static("disk full")
+    .with_code("E1042")
+    .context("saving file");
+
+let json = serde_json::to_string(&err).unwrap();
+assert_eq!(
+    json,
+    concat!(
+        r#"{"message":"saving file","type":"flat_error::FlatError","severity":"error","#,
+        r#""sources":[{"message":"disk full","type":"flat_error::FlatError","#,
+        r#""severity":"error","code":"E1042"}]}"#
+    )
+);
+assert_eq!(serde_json::from_str::<FlatError>(&json).unwrap(), err);
+```
+*/
+
+use crate::{
+    intern_type_name, ErrorClass, Fields, FlatError, Frame, Severity, SharedStr, SourceSpan,
+    FLAT_ERROR_TYPE_NAME,
+};
+use ::serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::fmt::{Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The serialized form of a [`FlatError`], and of each error in its source chain.
+///
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+#[serde(rename = "FlatError")]
+pub(crate) struct FrameRepr<'a> {
+    message: Cow<'a, str>,
+    #[serde(rename = "type")]
+    type_name: Cow<'a, str>,
+    #[serde(default)]
+    severity: Severity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    class: Option<ErrorClass>,
+    #[serde(default, skip_serializing_if = "Pairs::is_empty")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "alloc::collections::BTreeMap<String, String>")
+    )]
+    fields: Pairs<'a>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message_key: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Pairs::is_empty")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "alloc::collections::BTreeMap<String, String>")
+    )]
+    message_args: Pairs<'a>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message_template: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    debug_repr: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    repeat_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    docs_url: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    span: Option<SourceSpan>,
+    #[serde(default, skip_serializing_if = "<[FlatError]>::is_empty")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<FlatError>"))]
+    branches: Cow<'a, [FlatError]>,
+    #[serde(default, skip_serializing_if = "<[FlatError]>::is_empty")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<FlatError>"))]
+    related: Cow<'a, [FlatError]>,
+    #[cfg(feature = "timestamp")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "timestamp_repr"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    timestamp: Option<::std::time::SystemTime>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<FlatError>"))]
+    sources: Vec<FrameRepr<'a>>,
+}
+
+///
+/// Key-value pairs, serialized as a map that retains the order of the pairs.
+///
+#[derive(Default)]
+struct Pairs<'a>(Vec<(Cow<'a, str>, Cow<'a, str>)>);
+
+struct PairsVisitor;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Serialize for FlatError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut repr = FrameRepr::from(&self.inner.frame);
+        repr.sources = self.sources().map(FrameRepr::from).collect();
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FlatError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut repr = FrameRepr::deserialize(deserializer)?;
+        let mut sources = Vec::new();
+        flatten_sources(core::mem::take(&mut repr.sources), &mut sources);
+        sources.reverse();
+        Ok(FlatError::from_frames(repr.into(), sources))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> From<&'a Frame> for FrameRepr<'a> {
+    fn from(frame: &'a Frame) -> Self {
+        let details = frame.details();
+        Self {
+            message: Cow::Borrowed(&frame.message),
+            type_name: Cow::Borrowed(frame.original_type_name),
+            severity: frame.severity,
+            code: details.code.as_deref().map(Cow::Borrowed),
+            class: details.class,
+            fields: Pairs::borrowed(&details.fields),
+            message_key: details.message_key.as_deref().map(Cow::Borrowed),
+            message_args: Pairs::borrowed(&details.message_args),
+            message_template: details.message_template.as_deref().map(Cow::Borrowed),
+            debug_repr: details.debug_repr.as_deref().map(Cow::Borrowed),
+            repeat_count: details.repeat_count,
+            help: details.help.as_deref().map(Cow::Borrowed),
+            docs_url: details.docs_url.as_deref().map(Cow::Borrowed),
+            span: details.span,
+            branches: Cow::Borrowed(&details.branches),
+            related: Cow::Borrowed(&details.related),
+            #[cfg(feature = "timestamp")]
+            timestamp: details.timestamp.0,
+            sources: Vec::new(),
+        }
+    }
+}
+
+impl From<FrameRepr<'_>> for Frame {
+    fn from(repr: FrameRepr<'_>) -> Self {
+        let mut frame = Frame::new(
+            FLAT_ERROR_TYPE_NAME,
+            SharedStr::from(repr.message.into_owned()),
+        );
+        frame.original_type_name = intern_type_name(&repr.type_name);
+        frame.severity = repr.severity;
+        if repr.code.is_some()
+            || repr.class.is_some()
+            || !repr.fields.is_empty()
+            || repr.message_key.is_some()
+            || !repr.message_args.is_empty()
+            || repr.message_template.is_some()
+            || repr.debug_repr.is_some()
+            || repr.repeat_count.is_some()
+            || repr.help.is_some()
+            || repr.docs_url.is_some()
+            || repr.span.is_some()
+            || !repr.branches.is_empty()
+            || !repr.related.is_empty()
+        {
+            let details = frame.details_mut();
+            details.code = repr.code.map(Cow::into_owned);
+            details.class = repr.class;
+            details.fields = Fields(repr.fields.into_owned());
+            details.message_key = repr.message_key.map(Cow::into_owned);
+            details.message_args = repr.message_args.into_owned();
+            details.message_template = repr.message_template.map(Cow::into_owned);
+            details.debug_repr = repr.debug_repr.map(Cow::into_owned);
+            details.repeat_count = repr.repeat_count;
+            details.help = repr.help.map(Cow::into_owned);
+            details.docs_url = repr.docs_url.map(Cow::into_owned);
+            details.span = repr.span;
+            details.branches = repr.branches.into_owned();
+            details.related = repr.related.into_owned();
+        }
+        #[cfg(feature = "timestamp")]
+        if repr.timestamp.is_some() {
+            frame.details_mut().timestamp = crate::timestamp::Timestamp(repr.timestamp);
+        }
+        frame
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Pairs<'a> {
+    fn borrowed(pairs: &'a [(String, String)]) -> Self {
+        Self(
+            pairs
+                .iter()
+                .map(|(k, v)| (Cow::Borrowed(k.as_str()), Cow::Borrowed(v.as_str())))
+                .collect(),
+        )
+    }
+
+    fn into_owned(self) -> Vec<(String, String)> {
+        self.0
+            .into_iter()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Serialize for Pairs<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Pairs<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(PairsVisitor)
+    }
+}
+
+impl<'de> Visitor<'de> for PairsVisitor {
+    type Value = Pairs<'static>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "a map of strings")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut pairs = Vec::with_capacity(access.size_hint().unwrap_or_default());
+        while let Some((key, value)) = access.next_entry::<String, String>()? {
+            pairs.push((Cow::Owned(key), Cow::Owned(value)));
+        }
+        Ok(Pairs(pairs))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Append each source, followed by its own sources, to `frames`.
+///
+fn flatten_sources(sources: Vec<FrameRepr<'_>>, frames: &mut Vec<Frame>) {
+    for mut source in sources {
+        let nested = core::mem::take(&mut source.sources);
+        frames.push(source.into());
+        flatten_sources(nested, frames);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Modules
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serializes an optional timestamp in the RFC 3339 form.
+///
+#[cfg(feature = "timestamp")]
+mod timestamp_repr {
+    use crate::timestamp::{parse_rfc3339, Rfc3339};
+    use ::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+    use alloc::borrow::Cow;
+    use std::time::SystemTime;
+
+    pub(super) fn serialize<S: Serializer>(
+        timestamp: &Option<SystemTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match timestamp {
+            Some(timestamp) => serializer.collect_str(&Rfc3339(*timestamp)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<SystemTime>, D::Error> {
+        Option::<Cow<'de, str>>::deserialize(deserializer)?
+            .map(|s| {
+                parse_rfc3339(&s).ok_or_else(|| D::Error::custom("expected an RFC 3339 timestamp"))
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,42 @@
+/*!
+Provides an implementation of `From<serde_json::Error>` for [`FlatError`]. In addition to the
+message, the line and column at which the error was detected, and the category of the error, are
+captured as the fields `line`, `column`, and `category`.
+
+```rust
+use flat_error::FlatError;
+
+let err = FlatError::from(serde_json::from_str::<u32>("\n  true").unwrap_err());
+
+assert_eq!(err.field("line"), Some("2"));
+assert_eq!(err.field("column"), Some("6"));
+assert_eq!(err.field("category"), Some("data"));
+```
+
+*/
+
+use crate::FlatError;
+use ::serde_json::{error::Category, Error};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let category = match e.classify() {
+            Category::Io => "io",
+            Category::Syntax => "syntax",
+            Category::Data => "data",
+            Category::Eof => "eof",
+        };
+        let flat = FlatError::from_error(&e).with_field("category", category);
+        if e.line() == 0 {
+            flat
+        } else {
+            flat.with_field("line", e.line())
+                .with_field("column", e.column())
+        }
+    }
+}
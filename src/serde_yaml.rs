@@ -0,0 +1,46 @@
+/*!
+Provides an implementation of `From<serde_yaml::Error>` for [`FlatError`]. If the error has a
+location within the input, its line, column, and byte index are captured as the fields `line`,
+`column`, and `index`, and as the error's [`SourceSpan`](crate::SourceSpan).
+
+```rust
+use flat_error::FlatError;
+use std::collections::BTreeMap;
+
+let yaml = "port: 80\nhost: [1, 2]\n";
+let err = FlatError::from(serde_yaml::from_str::<BTreeMap<String, u32>>(yaml).unwrap_err());
+
+assert_eq!(err.field("line"), Some("2"));
+assert_eq!(err.field("column"), Some("7"));
+assert_eq!(err.field("index"), Some("15"));
+assert_eq!(err.span().map(|span| span.offset), Some(15));
+```
+
+*/
+
+use crate::{FlatError, SourceSpan};
+use ::serde_yaml::Error;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let flat = FlatError::from_error(&e);
+        match e.location() {
+            Some(location) => flat
+                .with_field("line", location.line())
+                .with_field("column", location.column())
+                .with_field("index", location.index())
+                .with_span(SourceSpan::new(
+                    location.index(),
+                    0,
+                    location.line(),
+                    location.column(),
+                )),
+            None => flat,
+        }
+    }
+}
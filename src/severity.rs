@@ -0,0 +1,51 @@
+/*!
+Provides the [`Severity`] type that may be attached to a [`FlatError`](crate::FlatError).
+*/
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The severity of an error, used by reporters to decide how an error is to be rendered or
+/// routed. Values are ordered from least to most severe, and the default is `Error`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub enum Severity {
+    /// Informational only, the operation was not affected.
+    Info,
+    /// The operation completed, but possibly not as expected.
+    Warning,
+    /// The operation failed.
+    #[default]
+    Error,
+    /// The operation failed and the process, or a significant part of it, cannot continue.
+    Critical,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Info => "info",
+                Self::Warning => "warning",
+                Self::Error => "error",
+                Self::Critical => "critical",
+            }
+        )
+    }
+}
@@ -0,0 +1,109 @@
+/*!
+Implements [`slog::Value`](https://docs.rs/slog/latest/slog/trait.Value.html) for [`FlatError`],
+so that an error may be attached to a record as a structured value that includes its source
+chain.
+
+The value is emitted as a `SerdeValue`, a sequence with one map per error in the chain, starting
+with the error itself; each map has the keys `message`, `type`, `severity`, and `code` if the
+error has a code. Serializers that do not support nested values receive the error and its source
+chain in the [`DisplayStyle::Compact`](crate::DisplayStyle::Compact) style instead.
+
+```rust
+use flat_error::FlatError;
+use slog::{error, o, Discard, Logger};
+
+let logger = Logger::root(Discard, o!());
+let err = FlatError::new("disk full").context("saving file");
+
+error!(logger, "request failed"; "error" => &err);
+```
+*/
+
+use crate::{DisplayStyle, FlatError, Frame};
+use ::serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer as SerdeSerializer};
+use ::slog::{Key, Record, Result as SlogResult, SerdeValue, Serializer, Value};
+use alloc::{boxed::Box, string::ToString};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The `SerdeValue` emitted for a [`FlatError`]; this owns a clone of the error so that it may
+/// be sent to asynchronous drains.
+///
+#[derive(Clone)]
+struct SlogChain(FlatError);
+
+struct SlogFrame<'a>(&'a Frame);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Value for FlatError {
+    fn serialize(
+        &self,
+        _record: &Record<'_>,
+        key: Key,
+        serializer: &mut dyn Serializer,
+    ) -> SlogResult {
+        serializer.emit_serde(key, &SlogChain(self.clone()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Value for SlogChain {
+    fn serialize(
+        &self,
+        record: &Record<'_>,
+        key: Key,
+        serializer: &mut dyn Serializer,
+    ) -> SlogResult {
+        Value::serialize(&self.0, record, key, serializer)
+    }
+}
+
+impl SerdeValue for SlogChain {
+    fn serialize_fallback(&self, key: Key, serializer: &mut dyn Serializer) -> SlogResult {
+        serializer.emit_arguments(
+            key,
+            &format_args!("{}", self.0.display_with(DisplayStyle::Compact)),
+        )
+    }
+
+    fn as_serde(&self) -> &dyn ::erased_serde::Serialize {
+        self
+    }
+
+    fn to_sendable(&self) -> Box<dyn SerdeValue + Send + 'static> {
+        Box::new(self.clone())
+    }
+}
+
+impl Serialize for SlogChain {
+    fn serialize<S: SerdeSerializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.chain_frames().count()))?;
+        for frame in self.0.chain_frames() {
+            seq.serialize_element(&SlogFrame(frame))?;
+        }
+        seq.end()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Serialize for SlogFrame<'_> {
+    fn serialize<S: SerdeSerializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let code = self.0.code();
+        let mut map = serializer.serialize_map(Some(3 + usize::from(code.is_some())))?;
+        map.serialize_entry("message", &*self.0.message)?;
+        map.serialize_entry("type", self.0.original_type_name)?;
+        map.serialize_entry("severity", &self.0.severity.to_string())?;
+        if let Some(code) = code {
+            map.serialize_entry("code", code)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,126 @@
+/*!
+Provides the [`SourceSpan`] type, the position of an error within some source text, so that
+parsers flattening their errors retain where in the input the error was detected.
+
+```rust
+use flat_error::{FlatError, SourceSpan};
+
+let err = FlatError::new_static("expected `]`")
+    .with_span(SourceSpan::new(15, 1, 2, 7))
+    .context("parsing config.yaml");
+
+assert_eq!(err.span().map(|span| span.line), Some(2));
+assert_eq!(err.span().unwrap().to_string(), "2:7");
+```
+*/
+
+#[cfg(feature = "alloc")]
+use crate::FlatError;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Range,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A span within some source text, as both a byte range, from `offset` of `len` bytes, and the
+/// line and column at which it starts. Lines and columns count from 1, and a span that only
+/// marks a position has a `len` of zero.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(::schemars::JsonSchema))]
+pub struct SourceSpan {
+    /// The byte offset of the start of the span.
+    pub offset: usize,
+    /// The length of the span in bytes.
+    pub len: usize,
+    /// The line on which the span starts.
+    pub line: usize,
+    /// The column, within `line`, at which the span starts.
+    pub column: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for SourceSpan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl SourceSpan {
+    ///
+    /// Construct a new span of `len` bytes from `offset`, starting at `line` and `column`.
+    ///
+    pub const fn new(offset: usize, len: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            len,
+            line,
+            column,
+        }
+    }
+
+    ///
+    /// Construct a new span for the byte `range` of `source`, calculating the line and column at
+    /// which it starts; columns are counted in characters. If the range starts beyond the end of
+    /// `source` the line and column are those of the end of `source`.
+    ///
+    /// ```rust
+    /// use flat_error::SourceSpan;
+    ///
+    /// let span = SourceSpan::from_range("port: 80\nhost: [1, 2]\n", 15..16);
+    /// assert_eq!(span, SourceSpan::new(15, 1, 2, 7));
+    /// ```
+    ///
+    pub fn from_range(source: &str, range: Range<usize>) -> Self {
+        let mut start = range.start.min(source.len());
+        while !source.is_char_boundary(start) {
+            start -= 1;
+        }
+        let before = &source[..start];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            offset: range.start,
+            len: range.end.saturating_sub(range.start),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    ///
+    /// Return the byte range of this span.
+    ///
+    pub const fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.len
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl<C> FlatError<C> {
+    ///
+    /// Return a copy of this error with `span` attached, the position within some source text at
+    /// which the error was detected. This is included in the pretty form of a
+    /// [`FlatReport`](crate::FlatReport) and when serialized.
+    ///
+    pub fn with_span(mut self, span: SourceSpan) -> Self {
+        self.inner.frame.details_mut().span = Some(span);
+        self
+    }
+
+    ///
+    /// Return the span attached to this error or, if none, to the first error in its source
+    /// chain with a span; so that context added to a parse error retains its position.
+    ///
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.chain_frames().find_map(|frame| frame.details().span)
+    }
+}
@@ -0,0 +1,46 @@
+/*!
+Provides an implementation of `From<sqlx::Error>` for [`FlatError`]. If the error was returned
+by the database its SQLSTATE, or other driver specific code, is captured as the field
+`sqlstate`, and the name of any violated constraint and the affected table as the fields
+`constraint` and `table`. These allow conflicts and retryable failures to be identified after
+the error has been flattened.
+
+```rust
+use flat_error::FlatError;
+
+let err = FlatError::from(sqlx::Error::RowNotFound);
+
+assert_eq!(
+    err.to_string(),
+    "no rows returned by a query that expected to return at least one row"
+);
+assert_eq!(err.field("sqlstate"), None);
+```
+
+*/
+
+use crate::FlatError;
+use ::sqlx::Error;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let mut flat = FlatError::from_error(&e);
+        if let Some(database_error) = e.as_database_error() {
+            if let Some(code) = database_error.code() {
+                flat = flat.with_field("sqlstate", code);
+            }
+            if let Some(constraint) = database_error.constraint() {
+                flat = flat.with_field("constraint", constraint);
+            }
+            if let Some(table) = database_error.table() {
+                flat = flat.with_field("table", table);
+            }
+        }
+        flat
+    }
+}
@@ -0,0 +1,99 @@
+/*!
+Provides [`FlatError::templated`], constructing an error from a message template and named
+arguments while retaining both, so that errors may be grouped, localized, or logged by their
+template without parsing the rendered message.
+
+A template refers to an argument by name, in braces, and a literal brace is written twice, as
+`{{` or `}}`. A reference to an argument that is not provided is left as written.
+
+```rust
+use flat_error::FlatError;
+
+let err = FlatError::templated("failed to open {path}", [("path", "/etc/app.toml")]);
+
+assert_eq!(err.to_string(), "failed to open /etc/app.toml");
+assert_eq!(err.message_template(), Some("failed to open {path}"));
+assert_eq!(
+    err.message_args().collect::<Vec<_>>(),
+    vec![("path", "/etc/app.toml")]
+);
+```
+*/
+
+use crate::FlatError;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` with the message rendered from `template` and `args`. The
+    /// template is retained, see [`FlatError::message_template`], and the arguments are
+    /// attached as the message arguments, see [`FlatError::message_args`]. The original type
+    /// name of the new error is that of `FlatError` itself.
+    ///
+    pub fn templated<T, A, N, V>(template: T, args: A) -> Self
+    where
+        T: Into<String>,
+        A: IntoIterator<Item = (N, V)>,
+        N: Into<String>,
+        V: Display,
+    {
+        let template = template.into();
+        let args: Vec<(String, String)> = args
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.to_string()))
+            .collect();
+        let mut error = FlatError::new(render_template(&template, &args));
+        let details = error.inner.frame.details_mut();
+        details.message_template = Some(template);
+        details.message_args = args;
+        error
+    }
+
+    ///
+    /// Return the template from which the message of this error was rendered, if any, see
+    /// [`FlatError::templated`].
+    ///
+    pub fn message_template(&self) -> Option<&str> {
+        self.inner.frame.details().message_template.as_deref()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Render `template`, replacing each reference to an argument with its value.
+///
+fn render_template(template: &str, args: &[(String, String)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find(['{', '}']) {
+        rendered.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            rendered.push_str(&rest[..1]);
+            rest = &rest[2..];
+        } else if let Some((name, value)) = rest[1..]
+            .split_once('}')
+            .filter(|_| rest.starts_with('{'))
+            .and_then(|(name, _)| args.iter().find(|(arg, _)| arg == name))
+        {
+            rendered.push_str(value);
+            rest = &rest[name.len() + 2..];
+        } else {
+            rendered.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
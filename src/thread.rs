@@ -0,0 +1,52 @@
+/*!
+Provides [`FlatError::from_spawn_error`], flattening the error returned when a thread could not
+be spawned, by `std::thread::Builder::spawn` or `spawn_scoped`, with the name of the thread
+recorded as the field [`THREAD_NAME_FIELD`].
+
+```rust
+use flat_error::{FlatError, THREAD_NAME_FIELD};
+use std::thread::Builder;
+
+fn start_worker() -> Result<(), FlatError> {
+    let name = "worker-1";
+    let handle = Builder::new()
+        .name(name.to_string())
+        .spawn(|| 42)
+        .map_err(|e| FlatError::from_spawn_error(&e, Some(name)))?;
+    assert_eq!(handle.join().map_err(FlatError::from_join_panic)?, 42);
+    Ok(())
+}
+
+assert!(start_worker().is_ok());
+```
+*/
+
+use crate::{FlatError, THREAD_NAME_FIELD};
+use std::io::Error as IoError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` from the `error` returned when a thread could not be spawned,
+    /// recording `thread_name`, or `"<unnamed>"` if the thread was not named, as the field
+    /// [`THREAD_NAME_FIELD`]. As for any `std::io::Error` the kind and operating system error
+    /// code of `error` are captured, see [`FlatError::io_kind`].
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, THREAD_NAME_FIELD};
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// let err = FlatError::from_spawn_error(&IoError::from(ErrorKind::OutOfMemory), None);
+    ///
+    /// assert_eq!(err.field(THREAD_NAME_FIELD), Some("<unnamed>"));
+    /// assert_eq!(err.io_kind(), Some(ErrorKind::OutOfMemory));
+    /// ```
+    ///
+    pub fn from_spawn_error(error: &IoError, thread_name: Option<&str>) -> Self {
+        FlatError::from_error(error)
+            .with_field(THREAD_NAME_FIELD, thread_name.unwrap_or("<unnamed>"))
+    }
+}
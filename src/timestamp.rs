@@ -0,0 +1,281 @@
+/*!
+Provides [`FlatError::timestamp`], the time at which an error was flattened, and the RFC 3339
+form used for timestamps in structured renderings.
+*/
+
+use crate::{FlatError, FlatErrorConfig, Frame};
+#[cfg(any(feature = "log", feature = "tracing"))]
+use alloc::string::{String, ToString};
+#[cfg(any(feature = "serde", feature = "log", feature = "tracing"))]
+use core::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "serde")]
+use core::time::Duration;
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+use std::time::SystemTime;
+#[cfg(any(feature = "serde", feature = "log", feature = "tracing"))]
+use std::time::UNIX_EPOCH;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Displays a `SystemTime` in the RFC 3339 form, in UTC, with fractional seconds only when
+/// non-zero; for example `2025-01-31T09:15:00.125Z`.
+///
+#[cfg(any(feature = "serde", feature = "log", feature = "tracing"))]
+pub(crate) struct Rfc3339(pub(crate) SystemTime);
+
+///
+/// The time a frame was captured, held in its details. This is not considered when comparing,
+/// ordering, or hashing errors, see [`FlatError::timestamp`]; so that `Details` may derive these
+/// traits, all values compare as equal and hash to nothing.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Timestamp(pub(crate) Option<SystemTime>);
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(any(feature = "serde", feature = "log", feature = "tracing"))]
+const SECONDS_PER_DAY: i64 = 86_400;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return the time at which the underlying error occurred; that is, the time captured for
+    /// the last error in the source chain of this error, or this error itself, for which a time
+    /// was captured. A time is captured whenever an error is created, either by flattening
+    /// another error or by a constructor such as [`FlatError::new`] or [`FlatError::context`],
+    /// unless disabled by [`FlatErrorConfig::with_timestamp_capture`]. No time is captured for
+    /// an error constructed by [`FlatError::new_static`], as it may be constructed in a `const`
+    /// context, nor for one that is deserialized or decoded without a time.
+    ///
+    /// The timestamp is not considered when comparing, ordering, or hashing errors, so that
+    /// errors created at different times are otherwise equal.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use std::time::SystemTime;
+    ///
+    /// let before = SystemTime::now();
+    /// let err = FlatError::new("disk full").context("saving file");
+    ///
+    /// assert!(err.timestamp().unwrap() >= before);
+    /// ```
+    ///
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.chain_frames()
+            .filter_map(|frame| frame.details().timestamp.0)
+            .last()
+    }
+
+    ///
+    /// Return a copy of this error with its own timestamp set to `timestamp`.
+    ///
+    pub fn with_timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.inner.frame.details_mut().timestamp = Timestamp(Some(timestamp));
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Frame {
+    pub(crate) fn capture_timestamp(&mut self) {
+        if FlatErrorConfig::global().capture_timestamps() {
+            self.details_mut().timestamp = Timestamp(Some(SystemTime::now()));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl PartialEq for Timestamp {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(any(feature = "serde", feature = "log", feature = "tracing"))]
+impl Display for Rfc3339 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (seconds, nanos) = match self.0.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+            Err(e) => {
+                let duration = e.duration();
+                let seconds = -(duration.as_secs() as i64);
+                match duration.subsec_nanos() {
+                    0 => (seconds, 0),
+                    nanos => (seconds - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+        let days = seconds.div_euclid(SECONDS_PER_DAY);
+        let time = seconds.rem_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+            time / 3600,
+            time % 3600 / 60,
+            time % 60
+        )?;
+        if nanos % 1_000_000 == 0 && nanos != 0 {
+            write!(f, ".{:03}", nanos / 1_000_000)?;
+        } else if nanos % 1_000 == 0 && nanos != 0 {
+            write!(f, ".{:06}", nanos / 1_000)?;
+        } else if nanos != 0 {
+            write!(f, ".{nanos:09}")?;
+        }
+        f.write_str("Z")
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return `timestamp` in the RFC 3339 form, see [`Rfc3339`].
+///
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub(crate) fn format_rfc3339(timestamp: SystemTime) -> String {
+    Rfc3339(timestamp).to_string()
+}
+
+///
+/// Parse a timestamp in the RFC 3339 form, with an offset of `Z` or `±HH:MM`, and at most
+/// nanosecond precision.
+///
+#[cfg(feature = "serde")]
+pub(crate) fn parse_rfc3339(s: &str) -> Option<SystemTime> {
+    fn number(s: &str) -> Option<i64> {
+        if s.bytes().all(|b| b.is_ascii_digit()) {
+            s.parse().ok()
+        } else {
+            None
+        }
+    }
+    let (date, rest) = s.split_once(['T', 't', ' '])?;
+    let mut date_parts = date.splitn(3, '-');
+    let year = number(date_parts.next().filter(|s| s.len() == 4)?)?;
+    let month = number(date_parts.next().filter(|s| s.len() == 2)?)?;
+    let day = number(date_parts.next().filter(|s| s.len() == 2)?)?;
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+
+    let (time, offset) = if let Some(time) = rest.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let index = rest.rfind(['+', '-'])?;
+        let (time, offset) = rest.split_at(index);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        if hours.len() != 2 || minutes.len() != 2 {
+            return None;
+        }
+        (time, sign * (number(hours)? * 3600 + number(minutes)? * 60))
+    };
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut time_parts = time.splitn(3, ':');
+    let hour = number(time_parts.next().filter(|s| s.len() == 2)?)?;
+    let minute = number(time_parts.next().filter(|s| s.len() == 2)?)?;
+    let second = number(time_parts.next().filter(|s| s.len() == 2)?)?;
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let nanos = match fraction.len() {
+        0 => 0,
+        len @ 1..=9 => number(fraction)? * 10_i64.pow(9 - len as u32),
+        _ => return None,
+    };
+
+    let seconds =
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second
+            - offset;
+    let nanos = Duration::from_nanos(nanos as u64);
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64) + nanos)
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(seconds.unsigned_abs()))?
+            .checked_add(nanos)
+    }
+}
+
+///
+/// Return the year, month, and day of the date `days` after 1970-01-01, in the proleptic
+/// Gregorian calendar.
+///
+#[cfg(any(feature = "serde", feature = "log", feature = "tracing"))]
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+///
+/// Return the number of days from 1970-01-01 to the given date, the inverse of
+/// [`civil_from_days`].
+///
+#[cfg(feature = "serde")]
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(feature = "serde")]
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
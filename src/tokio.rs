@@ -0,0 +1,66 @@
+/*!
+Provides implementations of `From` for the [`tokio`](https://docs.rs/tokio) task and
+synchronization errors. For a `JoinError` the field `panic` or `cancelled` is set to `true`
+depending on why the task failed; the values of the generic channel errors are dropped, as for
+the standard library's `SendError`.
+
+```rust
+use flat_error::FlatError;
+
+let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+let err = runtime.block_on(async {
+    let task = tokio::spawn(std::future::pending::<()>());
+    task.abort();
+    FlatError::from(task.await.unwrap_err())
+});
+
+assert_eq!(err.field("cancelled"), Some("true"));
+```
+
+*/
+
+use crate::FlatError;
+use ::tokio::{
+    sync::{mpsc, oneshot, AcquireError, TryAcquireError},
+    task::JoinError,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<JoinError> for FlatError {
+    #[track_caller]
+    fn from(e: JoinError) -> Self {
+        let flat = FlatError::from_error(&e);
+        if e.is_panic() {
+            flat.with_field("panic", true)
+        } else if e.is_cancelled() {
+            flat.with_field("cancelled", true)
+        } else {
+            flat
+        }
+    }
+}
+
+flat_from_impls!(
+    AcquireError,
+    TryAcquireError,
+    mpsc::error::TryRecvError,
+    oneshot::error::RecvError,
+    oneshot::error::TryRecvError,
+);
+
+impl<T> From<mpsc::error::SendError<T>> for FlatError {
+    #[track_caller]
+    fn from(e: mpsc::error::SendError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
+
+impl<T> From<mpsc::error::TrySendError<T>> for FlatError {
+    #[track_caller]
+    fn from(e: mpsc::error::TrySendError<T>) -> Self {
+        FlatError::from_any(&e)
+    }
+}
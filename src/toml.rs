@@ -0,0 +1,41 @@
+/*!
+Provides implementations of `From` for the [`toml`](https://docs.rs/toml) deserialization and
+serialization errors. If a deserialization error has a span within the input, its start and end
+byte offsets are captured as the fields `span_start` and `span_end`.
+
+```rust
+use flat_error::FlatError;
+
+let err = FlatError::from(toml::from_str::<toml::Table>("name = ").unwrap_err());
+
+assert_eq!(err.field("span_start"), Some("7"));
+assert_eq!(err.field("span_end"), Some("7"));
+```
+
+*/
+
+use crate::FlatError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<::toml::de::Error> for FlatError {
+    #[track_caller]
+    fn from(e: ::toml::de::Error) -> Self {
+        let flat = FlatError::from_error(&e);
+        match e.span() {
+            Some(span) => flat
+                .with_field("span_start", span.start)
+                .with_field("span_end", span.end),
+            None => flat,
+        }
+    }
+}
+
+impl From<::toml::ser::Error> for FlatError {
+    #[track_caller]
+    fn from(e: ::toml::ser::Error) -> Self {
+        FlatError::from_error(&e)
+    }
+}
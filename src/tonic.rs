@@ -0,0 +1,140 @@
+/*!
+Provides conversions between [`FlatError`] and [`tonic::Status`](https://docs.rs/tonic).
+
+A `Status` is flattened with its message as the error message, and its code captured as the
+field `grpc_code`, holding the numeric value of the code. A `FlatError` is converted into a
+`Status` with its own message, not including its source chain, and a code chosen by
+[`FlatError::grpc_code`], or by the mapping provided to [`FlatError::into_status_with`].
+
+```rust
+use flat_error::FlatError;
+use tonic::{Code, Status};
+
+let err = FlatError::from(Status::not_found("no such user"));
+assert_eq!(err.to_string(), "no such user");
+assert_eq!(err.field("grpc_code"), Some("5"));
+
+let status = Status::from(err.context("loading profile"));
+assert_eq!(status.code(), Code::Unknown);
+assert_eq!(status.message(), "loading profile");
+```
+
+*/
+
+use crate::{captured_type_name, compat::Error, ErrorClass, FlatError, FlattenOptions};
+use ::tonic::{Code, Status};
+use alloc::string::ToString;
+use core::{
+    any::TypeId,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Flattened in place of a `Status`, whose `Display` implementation includes its code and
+/// details as well as its message.
+///
+struct StatusMessage<'a>(&'a Status);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Status> for FlatError {
+    #[track_caller]
+    fn from(status: Status) -> Self {
+        let mut flat = FlatError::from_any_with(&StatusMessage(&status), FlattenOptions::new());
+        flat.inner.frame.original_type_name = captured_type_name::<Status>();
+        flat.inner.frame.type_id = Some(TypeId::of::<Status>());
+        flat.with_field("grpc_code", i32::from(status.code()))
+    }
+}
+
+impl Display for StatusMessage<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.0.message())
+    }
+}
+
+impl Debug for StatusMessage<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl Error for StatusMessage<'_> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl From<FlatError> for Status {
+    fn from(error: FlatError) -> Self {
+        let code = error.grpc_code();
+        error.into_status_with(|_| code)
+    }
+}
+
+impl From<ErrorClass> for Code {
+    fn from(class: ErrorClass) -> Self {
+        match class {
+            ErrorClass::NotFound => Code::NotFound,
+            ErrorClass::PermissionDenied => Code::PermissionDenied,
+            ErrorClass::Unauthenticated => Code::Unauthenticated,
+            ErrorClass::InvalidInput => Code::InvalidArgument,
+            ErrorClass::Conflict => Code::AlreadyExists,
+            ErrorClass::Timeout => Code::DeadlineExceeded,
+            ErrorClass::Unavailable => Code::Unavailable,
+            ErrorClass::ResourceExhausted => Code::ResourceExhausted,
+            ErrorClass::Cancelled => Code::Cancelled,
+            ErrorClass::Unsupported => Code::Unimplemented,
+            ErrorClass::Internal => Code::Internal,
+        }
+    }
+}
+
+impl FlatError {
+    ///
+    /// Return the gRPC status code for this error, as used by `From<FlatError>` for
+    /// `tonic::Status`. This is the code captured in the field `grpc_code`, if present, or
+    /// one corresponding to the class of the first error in the source chain that can be
+    /// classified, see [`FlatError::class`], otherwise it is `Code::Unknown`.
+    ///
+    pub fn grpc_code(&self) -> Code {
+        if let Some(code) = self
+            .field("grpc_code")
+            .and_then(|code| code.parse::<i32>().ok())
+        {
+            return Code::from_i32(code);
+        }
+        self.chain_class().map_or(Code::Unknown, Code::from)
+    }
+
+    ///
+    /// Convert this error into a `tonic::Status` with the code returned by `map`, and this
+    /// error's own message.
+    ///
+    /// ```rust
+    /// use flat_error::{FlatError, Severity};
+    /// use tonic::{Code, Status};
+    ///
+    /// let status = FlatError::new("database unavailable")
+    ///     .with_severity(Severity::Critical)
+    ///     .into_status_with(|e| match e.severity() {
+    ///         Severity::Critical => Code::Unavailable,
+    ///         _ => e.grpc_code(),
+    ///     });
+    ///
+    /// assert_eq!(status.code(), Code::Unavailable);
+    /// ```
+    ///
+    pub fn into_status_with<F>(self, map: F) -> Status
+    where
+        F: FnOnce(&FlatError) -> Code,
+    {
+        Status::new(map(&self), self.to_string())
+    }
+}
@@ -0,0 +1,214 @@
+/*!
+Provides a [`tower`](https://docs.rs/tower) middleware, [`FlattenErrorLayer`], which converts
+the error of any service into a [`FlatError`], so that a stack of heterogeneous services may
+share a single `Clone` and `PartialEq` error type.
+
+The error of the inner service is converted using `Into<FlatError>`, which is implemented for the
+`BoxError` used by most `tower` middleware as well as for the errors supported by this crate.
+Context describing the request may be added to each error with
+[`FlattenErrorLayer::with_context`]; as the request is moved into the inner service this context
+is produced for each request, before the inner service is called.
+
+```rust
+use flat_error::{tower::FlattenErrorLayer, FlatError};
+use std::io::{Error as IoError, ErrorKind};
+use tower::{service_fn, Layer, ServiceExt};
+
+let service = FlattenErrorLayer::new()
+    .with_context(|path: &&str| format!("loading {path}"))
+    .layer(service_fn(|_: &str| async {
+        Err::<(), _>(IoError::from(ErrorKind::NotFound))
+    }));
+
+let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+let err: FlatError = runtime.block_on(service.oneshot("profile.toml")).unwrap_err();
+
+assert_eq!(err.to_string(), "loading profile.toml");
+assert_eq!(err.flat_source().unwrap().io_kind(), Some(ErrorKind::NotFound));
+```
+
+*/
+
+use crate::FlatError;
+use ::tower::{Layer, Service};
+use alloc::string::{String, ToString};
+use core::{
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use pin_project_lite::pin_project;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A [`Layer`] which wraps a service in [`FlattenError`], converting its errors into
+/// [`FlatError`].
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FlattenErrorLayer<F = ()> {
+    context: F,
+}
+
+///
+/// A [`Service`] which converts the errors of the inner service into [`FlatError`], optionally
+/// adding context describing the request, see [`FlattenErrorLayer`].
+///
+#[derive(Clone, Copy, Debug)]
+pub struct FlattenError<S, F = ()> {
+    inner: S,
+    context: F,
+}
+
+///
+/// Produces the context added to an error returned for a request, see
+/// [`FlattenErrorLayer::with_context`].
+///
+/// This trait is implemented for `()`, which adds no context, and for any function, or closure,
+/// taking a reference to the request and returning a value implementing `Display`.
+///
+pub trait RequestContext<Req> {
+    ///
+    /// Return the context to add to an error returned for `request`, if any.
+    ///
+    fn context(&self, request: &Req) -> Option<String>;
+}
+
+pin_project! {
+    ///
+    /// The response future of [`FlattenError`].
+    ///
+    #[derive(Debug)]
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+        context: Option<String>,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlattenErrorLayer {
+    ///
+    /// Construct a new layer which adds no context to errors.
+    ///
+    pub const fn new() -> Self {
+        Self { context: () }
+    }
+}
+
+impl<F> FlattenErrorLayer<F> {
+    ///
+    /// Return a copy of this layer which adds the context produced by `context` for each request
+    /// to any error returned for it, see [`FlatError::context`].
+    ///
+    pub fn with_context<G>(self, context: G) -> FlattenErrorLayer<G> {
+        FlattenErrorLayer { context }
+    }
+}
+
+impl<S, F: Clone> Layer<S> for FlattenErrorLayer<F> {
+    type Service = FlattenError<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FlattenError {
+            inner,
+            context: self.context.clone(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<S> FlattenError<S> {
+    ///
+    /// Construct a new service wrapping `inner`, which adds no context to errors.
+    ///
+    pub const fn new(inner: S) -> Self {
+        Self { inner, context: () }
+    }
+}
+
+impl<S, F> FlattenError<S, F> {
+    ///
+    /// Return a reference to the inner service.
+    ///
+    pub const fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    ///
+    /// Consume this service, returning the inner service.
+    ///
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, F, Req> Service<Req> for FlattenError<S, F>
+where
+    S: Service<Req>,
+    S::Error: Into<FlatError>,
+    F: RequestContext<Req>,
+{
+    type Response = S::Response;
+    type Error = FlatError;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Req) -> Self::Future {
+        let context = self.context.context(&request);
+        ResponseFuture {
+            inner: self.inner.call(request),
+            context,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<Req> RequestContext<Req> for () {
+    fn context(&self, _: &Req) -> Option<String> {
+        None
+    }
+}
+
+impl<Req, F, C> RequestContext<Req> for F
+where
+    F: Fn(&Req) -> C,
+    C: Display,
+{
+    fn context(&self, request: &Req) -> Option<String> {
+        Some(self(request).to_string())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F, T, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<T, E>>,
+    E: Into<FlatError>,
+{
+    type Output = Result<T, FlatError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let result = ready!(this.inner.poll(cx));
+        Poll::Ready(result.map_err(|e| {
+            let flat = e.into();
+            match this.context.take() {
+                Some(context) => flat.context(context),
+                None => flat,
+            }
+        }))
+    }
+}
@@ -0,0 +1,64 @@
+/*!
+Provides [`FlatError::trace`], which emits a [`tracing`](https://docs.rs/tracing) event for an
+error with its details recorded as structured fields, rather than only its `Display` form.
+*/
+
+use crate::{DisplayStyle, FlatError};
+use ::tracing::{event, Level};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Emit a `tracing` event at `level` for this error. The message of the event is the error
+    /// message, and the following fields are recorded:
+    ///
+    /// * `error.type`; the original type name of the error.
+    /// * `error.code`; the error code, if present.
+    /// * `error.severity`; the severity of the error.
+    /// * `error.depth`; the number of errors in the source chain, including this error.
+    /// * `error.chain`; the error and its source chain, in the [`DisplayStyle::Compact`] style.
+    /// * `error.timestamp`; the time the error was created, in the RFC 3339 form, only with
+    ///   the **timestamp** feature.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    /// use tracing::Level;
+    ///
+    /// FlatError::new("disk full")
+    ///     .with_code("E1042")
+    ///     .context("saving file")
+    ///     .trace(Level::WARN);
+    /// ```
+    ///
+    pub fn trace(&self, level: Level) {
+        #[cfg(feature = "timestamp")]
+        let timestamp = self.timestamp().map(crate::timestamp::format_rfc3339);
+        #[cfg(not(feature = "timestamp"))]
+        let timestamp: Option<alloc::string::String> = None;
+        macro_rules! trace_at {
+            ($level:expr) => {
+                event!(
+                    $level,
+                    error.type = self.original_type_name(),
+                    error.code = self.code(),
+                    error.severity = %self.severity(),
+                    error.depth = self.chain_frames().count(),
+                    error.chain = %self.display_with(DisplayStyle::Compact),
+                    error.timestamp = timestamp.as_deref(),
+                    "{}",
+                    self
+                )
+            };
+        }
+        match level {
+            Level::TRACE => trace_at!(Level::TRACE),
+            Level::DEBUG => trace_at!(Level::DEBUG),
+            Level::INFO => trace_at!(Level::INFO),
+            Level::WARN => trace_at!(Level::WARN),
+            _ => trace_at!(Level::ERROR),
+        }
+    }
+}
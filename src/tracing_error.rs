@@ -0,0 +1,93 @@
+/*!
+Provides [`FlatError::span_trace_str`], the `tracing` span trace captured, using the
+`tracing-error` crate, when an error was flattened. This requires that the `ErrorLayer` is
+installed in the current subscriber, otherwise no span trace is captured.
+
+```rust
+use flat_error::FlatError;
+use tracing_subscriber::layer::SubscriberExt;
+
+let subscriber = tracing_subscriber::registry().with(tracing_error::ErrorLayer::default());
+tracing::subscriber::with_default(subscriber, || {
+    let span = tracing::info_span!("load_config", path = "app.toml");
+    let _entered = span.enter();
+
+    let err = FlatError::from_any(&"x".parse::<u8>().unwrap_err());
+    assert!(err.span_trace_str().unwrap().contains("load_config"));
+});
+```
+*/
+
+use crate::{FlatError, Frame};
+use alloc::{string::ToString, sync::Arc};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+use tracing_error::{SpanTrace as TracingSpanTrace, SpanTraceStatus};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The rendered span trace captured for a frame, held in its details. As with the timestamp,
+/// this is not considered when comparing, ordering, or hashing errors, and so all values are
+/// equal.
+///
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SpanTrace(pub(crate) Option<Arc<str>>);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Return the span trace captured when this error, or the first error in its source chain
+    /// for which one was captured, was flattened. The span trace is included in the pretty form
+    /// of a [`FlatReport`](crate::FlatReport), and is not considered when comparing, ordering, or
+    /// hashing errors.
+    ///
+    pub fn span_trace_str(&self) -> Option<&str> {
+        self.chain_frames()
+            .find_map(|frame| frame.details().span_trace.0.as_deref())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Frame {
+    pub(crate) fn capture_span_trace(&mut self) {
+        let span_trace = TracingSpanTrace::capture();
+        if span_trace.status() == SpanTraceStatus::CAPTURED {
+            self.details_mut().span_trace = SpanTrace(Some(Arc::from(span_trace.to_string())));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl PartialEq for SpanTrace {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SpanTrace {}
+
+impl PartialOrd for SpanTrace {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SpanTrace {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for SpanTrace {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
@@ -0,0 +1,114 @@
+/*!
+Provides implementations of [`ufmt::uDisplay`](https://docs.rs/ufmt) and `ufmt::uDebug` for
+[`FlatErrorFixed`] and, with the `alloc` feature, [`FlatError`](crate::FlatError), so that they
+may be written by `ufmt`-based logging on embedded targets without the cost of `core::fmt`.
+
+The `uDisplay` form is the error message, as for `Display`. The `uDebug` form is a subset of the
+`Debug` form, including the message, original type name, and code of an error and, for a
+`FlatError`, its source chain.
+
+```rust
+use flat_error::{FlatError, FlatErrorFixed};
+use ufmt::uwrite;
+
+let fixed: FlatErrorFixed<16> = FlatErrorFixed::new("disk full");
+let mut out = String::new();
+uwrite!(out, "{}", fixed).unwrap();
+assert_eq!(out, "disk full");
+
+let err = FlatError::new("disk full").with_code("E1042").context("saving file");
+let mut out = String::new();
+uwrite!(out, "{:?}", err).unwrap();
+assert_eq!(
+    out,
+    r#"FlatError { message: "saving file", type: "flat_error::FlatError", source: FlatError { message: "disk full", type: "flat_error::FlatError", code: "E1042" } }"#
+);
+```
+*/
+
+use crate::FlatErrorFixed;
+use ::ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A string written in quotes, with `"` and `\` escaped, by `uDebug`; as `ufmt` does not
+/// implement `uDebug` for `str`.
+///
+struct Quoted<'a>(&'a str);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl uDisplay for crate::FlatError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(&self.inner.frame.message)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl uDebug for crate::FlatError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut debug = f.debug_struct("FlatError")?;
+        debug
+            .field("message", &Quoted(&self.inner.frame.message))?
+            .field("type", &Quoted(self.original_type_name()))?;
+        if let Some(code) = self.code() {
+            debug.field("code", &Quoted(code))?;
+        }
+        if let Some(source) = self.flat_source() {
+            debug.field("source", source)?;
+        }
+        debug.finish()
+    }
+}
+
+impl<const N: usize> uDisplay for FlatErrorFixed<N> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(self.message())
+    }
+}
+
+impl<const N: usize> uDebug for FlatErrorFixed<N> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("FlatErrorFixed")?
+            .field("original_type_name", &Quoted(self.original_type_name()))?
+            .field("message", &Quoted(self.message()))?
+            .field("truncated", &self.is_truncated())?
+            .finish()
+    }
+}
+
+impl uDebug for Quoted<'_> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_char('"')?;
+        let mut rest = self.0;
+        while let Some(index) = rest.find(['"', '\\']) {
+            f.write_str(&rest[..index])?;
+            f.write_char('\\')?;
+            f.write_str(&rest[index..=index])?;
+            rest = &rest[index + 1..];
+        }
+        f.write_str(rest)?;
+        f.write_char('"')
+    }
+}
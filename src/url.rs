@@ -0,0 +1,30 @@
+/*!
+Provides an implementation of `From<url::ParseError>` for [`FlatError`]. The parse error does not
+record a position within the input, so the kind of the error is captured instead as the field
+`kind`.
+
+```rust
+use flat_error::FlatError;
+use url::Url;
+
+let err = FlatError::from(Url::parse("/no/scheme").unwrap_err());
+
+assert_eq!(err.to_string(), "relative URL without a base");
+assert_eq!(err.field("kind"), Some("RelativeUrlWithoutBase"));
+```
+
+*/
+
+use crate::FlatError;
+use ::url::ParseError;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<ParseError> for FlatError {
+    #[track_caller]
+    fn from(e: ParseError) -> Self {
+        FlatError::from_error(&e).with_field("kind", format_args!("{e:?}"))
+    }
+}
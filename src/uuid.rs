@@ -0,0 +1,39 @@
+/*!
+Provides an implementation of `From<uuid::Error>` for [`FlatError`]. If the error is caused by
+an invalid character the byte offset of that character in the input, as reported in the
+message of the error, is captured as the field `position`.
+
+```rust
+use flat_error::FlatError;
+use uuid::Uuid;
+
+let err = FlatError::from(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0cx").unwrap_err());
+
+assert_eq!(err.field("position"), Some("35"));
+```
+
+*/
+
+use crate::FlatError;
+use ::uuid::Error;
+use alloc::string::ToString;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Error> for FlatError {
+    #[track_caller]
+    fn from(e: Error) -> Self {
+        let flat = FlatError::from_error(&e);
+        let position = e
+            .to_string()
+            .strip_prefix("invalid character")
+            .and_then(|message| message.rsplit_once(" at "))
+            .and_then(|(_, position)| position.parse::<usize>().ok());
+        match position {
+            Some(position) => flat.with_field("position", position),
+            None => flat,
+        }
+    }
+}
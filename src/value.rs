@@ -0,0 +1,130 @@
+/*!
+Provides [`FlatError::from_display`] and [`FlatError::from_debug`], for flattening values that
+describe a failure but do not implement `Error`, such as exit statuses, the status structs of
+foreign libraries, and protocol codes.
+
+```rust
+use flat_error::FlatError;
+
+#[derive(Debug)]
+struct Status {
+    code: u16,
+}
+
+let err = FlatError::from_debug(&Status { code: 503 });
+
+assert_eq!(err.to_string(), "Status { code: 503 }");
+assert!(err.original_type_name().ends_with("::Status"));
+```
+*/
+
+use crate::{captured_type_name, compat::Error, FlatError, FlattenOptions};
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Flattened in place of a value that implements `Display`, using it for the message.
+///
+struct DisplayValue<'a, T: ?Sized>(&'a T);
+
+///
+/// Flattened in place of a value that implements `Debug`, using it for the message.
+///
+struct DebugValue<'a, T: ?Sized>(&'a T);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FlatError {
+    ///
+    /// Construct a new `FlatError` from a value that is not an error, with the `Display` form of
+    /// `value` as its message. The original type name of the new error is that of `value`, and
+    /// it has no source.
+    ///
+    /// ```rust
+    /// use flat_error::FlatError;
+    ///
+    /// let status: u16 = 503;
+    /// let err = FlatError::from_display(&status);
+    ///
+    /// assert_eq!(err.to_string(), "503");
+    /// assert_eq!(err.original_type_name(), "u16");
+    /// ```
+    ///
+    #[track_caller]
+    pub fn from_display<T>(value: &T) -> Self
+    where
+        T: Display + ?Sized,
+    {
+        Self::from_value(&DisplayValue(value), captured_type_name::<T>())
+    }
+
+    ///
+    /// Construct a new `FlatError` from a value that is not an error, with the `Debug` form of
+    /// `value` as its message. The original type name of the new error is that of `value`, and
+    /// it has no source.
+    ///
+    #[track_caller]
+    pub fn from_debug<T>(value: &T) -> Self
+    where
+        T: Debug + ?Sized,
+    {
+        Self::from_value(&DebugValue(value), captured_type_name::<T>())
+    }
+
+    ///
+    /// Construct a new `FlatError` with the `Display` form of `message` as its message, and the
+    /// original type name of `T`; for integrations that replace the verbose message of an error
+    /// of type `T` with a shorter one.
+    ///
+    #[cfg(any(feature = "napi", feature = "nom", feature = "pest"))]
+    #[track_caller]
+    pub(crate) fn from_display_of<T, M>(message: &M) -> Self
+    where
+        T: ?Sized,
+        M: Display + ?Sized,
+    {
+        Self::from_value(&DisplayValue(message), captured_type_name::<T>())
+    }
+
+    #[track_caller]
+    fn from_value<E: Error>(value: &E, type_name: &'static str) -> Self {
+        let mut flat = FlatError::from_any_with(value, FlattenOptions::new());
+        flat.inner.frame.original_type_name = type_name;
+        flat
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T: Display + ?Sized> Display for DisplayValue<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.0, f)
+    }
+}
+
+impl<T: ?Sized> Debug for DisplayValue<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(captured_type_name::<T>())
+    }
+}
+
+impl<T: Display + ?Sized> Error for DisplayValue<'_, T> {}
+
+impl<T: Debug + ?Sized> Display for DebugValue<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl<T: Debug + ?Sized> Debug for DebugValue<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl<T: Debug + ?Sized> Error for DebugValue<'_, T> {}
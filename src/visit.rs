@@ -0,0 +1,168 @@
+/*!
+Provides the [`FlatErrorVisitor`] trait and [`FlatError::walk`], a structured traversal of an
+error, its source chain, and the branches of the errors in that chain, for exporters that
+produce their own representation of an error.
+
+```rust
+use flat_error::{FlatError, FlatErrorVisitor, FlatFrame};
+
+#[derive(Default)]
+struct Outline(Vec<String>);
+
+impl FlatErrorVisitor for Outline {
+    fn visit_frame(&mut self, depth: usize, frame: FlatFrame<'_>) {
+        self.0.push(format!("{}{}", "  ".repeat(depth), frame.message()));
+    }
+
+    fn enter_branch(&mut self, depth: usize, index: usize) {
+        self.0.push(format!("{}branch {index}:", "  ".repeat(depth + 1)));
+    }
+}
+
+let err = FlatError::join(FlatError::new_static("a"), FlatError::new_static("b"))
+    .context("both failed");
+
+let mut outline = Outline::default();
+err.walk(&mut outline);
+
+assert_eq!(
+    outline.0,
+    ["both failed", "  a; b", "    branch 0:", "    a", "    branch 1:", "    b"]
+);
+```
+*/
+
+use crate::{FlatError, FlatFrame, Frame};
+use alloc::{vec, vec::Vec};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A visitor called by [`FlatError::walk`] for each error in the tree formed by an error, its
+/// source chain, and the branches of the errors in that chain, see [`FlatError::join`].
+///
+/// Errors are visited depth first, in the same order as [`FlatError::tree_frames`], where the
+/// depth of the error itself is `0`; the children of each error are its source followed by its
+/// branches. The branches of an error are visited between calls to
+/// [`FlatErrorVisitor::enter_branch`] and [`FlatErrorVisitor::leave_branch`].
+///
+/// This trait is implemented for any function, or closure, taking a depth and a [`FlatFrame`].
+///
+pub trait FlatErrorVisitor {
+    ///
+    /// Visit the error `frame`, at `depth` in the tree.
+    ///
+    fn visit_frame(&mut self, depth: usize, frame: FlatFrame<'_>);
+
+    ///
+    /// Called before the branch at `index` of the error at `depth` is visited; the errors of
+    /// the branch are visited at greater depths. By default this does nothing.
+    ///
+    fn enter_branch(&mut self, depth: usize, index: usize) {
+        let _ = (depth, index);
+    }
+
+    ///
+    /// Called after the branch at `index` of the error at `depth` is visited. By default this
+    /// does nothing.
+    ///
+    fn leave_branch(&mut self, depth: usize, index: usize) {
+        let _ = (depth, index);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A pending step of [`FlatError::walk`].
+///
+enum Step<'a> {
+    Visit {
+        depth: usize,
+        frame: &'a Frame,
+        sources: &'a [Frame],
+    },
+    EnterBranch(usize, usize),
+    LeaveBranch(usize, usize),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> FlatError<C> {
+    ///
+    /// Call `visitor` for this error, each error in its source chain, and each error in the
+    /// branches of those errors, see [`FlatErrorVisitor`].
+    ///
+    pub fn walk<V>(&self, visitor: &mut V)
+    where
+        V: FlatErrorVisitor + ?Sized,
+    {
+        let mut steps = vec![Step::Visit {
+            depth: 0,
+            frame: &self.inner.frame,
+            sources: self.source_frames(),
+        }];
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Visit {
+                    depth,
+                    frame,
+                    sources,
+                } => {
+                    visitor.visit_frame(depth, FlatFrame::new(frame));
+                    push_children(&mut steps, depth, frame, sources);
+                }
+                Step::EnterBranch(depth, index) => visitor.enter_branch(depth, index),
+                Step::LeaveBranch(depth, index) => visitor.leave_branch(depth, index),
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> FlatErrorVisitor for F
+where
+    F: FnMut(usize, FlatFrame<'_>),
+{
+    fn visit_frame(&mut self, depth: usize, frame: FlatFrame<'_>) {
+        self(depth, frame)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Push the steps for the children of `frame`, in reverse so that its source is visited first.
+///
+fn push_children<'a>(
+    steps: &mut Vec<Step<'a>>,
+    depth: usize,
+    frame: &'a Frame,
+    sources: &'a [Frame],
+) {
+    for (index, branch) in frame.details().branches.iter().enumerate().rev() {
+        steps.push(Step::LeaveBranch(depth, index));
+        steps.push(Step::Visit {
+            depth: depth + 1,
+            frame: &branch.inner.frame,
+            sources: branch.source_frames(),
+        });
+        steps.push(Step::EnterBranch(depth, index));
+    }
+    if let Some((source, sources)) = sources.split_last() {
+        steps.push(Step::Visit {
+            depth: depth + 1,
+            frame: source,
+            sources,
+        });
+    }
+}
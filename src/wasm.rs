@@ -0,0 +1,80 @@
+/*!
+Provides conversions between [`FlatError`] and the JavaScript values of
+[`wasm-bindgen`](https://docs.rs/wasm-bindgen), available when compiling for `wasm32`.
+
+A `JsValue` that is a JavaScript `Error` is flattened with its `name` as the original type name,
+its `message` as the message, its `stack`, if present, as the field `stack`, and its `cause`
+chain as the source chain, up to [`DEFAULT_MAX_DEPTH`] causes. Any other value is flattened with its string value, or its `Debug`
+form, as the message.
+
+A `FlatError` is converted into a `JsValue` holding a JavaScript `Error` with the same message
+and name, with its source chain as the `cause` chain. As `FlatError` implements `Error` it may
+also be converted into a `JsError`, using the implementation provided by `wasm-bindgen`.
+*/
+
+use crate::{
+    captured_type_name, intern_type_name, FlatError, FlatErrorBuilder, FlattenOptions, Redactor,
+    DEFAULT_MAX_DEPTH,
+};
+use ::js_sys::{Error as JsErrorObject, Reflect};
+use ::wasm_bindgen::{JsCast, JsValue};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<JsValue> for FlatError {
+    fn from(value: JsValue) -> Self {
+        let flat = from_js_value(&value, DEFAULT_MAX_DEPTH);
+        let error = flat.clone();
+        FlatError::finish_flatten(flat, &error, FlattenOptions::new(), None::<&dyn Redactor>)
+    }
+}
+
+impl From<FlatError> for JsValue {
+    fn from(error: FlatError) -> Self {
+        to_js_error(&error).into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn from_js_value(value: &JsValue, depth: usize) -> FlatError {
+    match value.dyn_ref::<JsErrorObject>() {
+        Some(js_error) => {
+            let mut builder = FlatErrorBuilder::new()
+                .message(String::from(js_error.message()))
+                .type_name(intern_type_name(&String::from(js_error.name())));
+            if let Some(stack) = Reflect::get(value, &JsValue::from_str("stack"))
+                .ok()
+                .and_then(|stack| stack.as_string())
+            {
+                builder = builder.field("stack", stack);
+            }
+            let cause = js_error.cause();
+            if depth > 0 && !(cause.is_undefined() || cause.is_null()) {
+                builder = builder.source(from_js_value(&cause, depth - 1));
+            }
+            builder.build()
+        }
+        None => FlatErrorBuilder::new()
+            .message(value.as_string().unwrap_or_else(|| format!("{value:?}")))
+            .type_name(captured_type_name::<JsValue>())
+            .build(),
+    }
+}
+
+fn to_js_error(error: &FlatError) -> JsErrorObject {
+    let js_error = JsErrorObject::new(&error.to_string());
+    js_error.set_name(error.original_type_name());
+    if let Some(source) = error.flat_source() {
+        js_error.set_cause(&to_js_error(source));
+    }
+    js_error
+}
@@ -0,0 +1,524 @@
+/*!
+Provides a compact, versioned, binary encoding of a [`FlatError`] and its source chain, for
+embedding in message queue payloads, gRPC trailers, and other transports where the JSON form
+provided by the **serde** feature is too large.
+
+An encoded error starts with a single byte, the version of the format, [`WIRE_VERSION`], followed
+by the error in the [`postcard`](https://docs.rs/postcard) format. A new version of the format is
+only introduced by a new release of this crate, and [`decode`] continues to accept the versions
+supported by earlier releases.
+
+The message, original type name, severity, code, fields, message key, arguments, and template,
+`Debug` form, repeat count, help text, documentation URL, span, branches, related errors, and
+class of each error in the chain are encoded. The location, original error, timestamp, and any
+`std::io` details are not. Version 1 of the format did not encode the class, which was instead
+held in the field `class`; such a field is decoded as the class of the error.
+
+Branches and related errors may be nested at most [`WIRE_MAX_NESTING`] deep, so that decoding a
+hostile payload cannot exhaust the stack; [`decode`] rejects anything nested more deeply, or
+followed by unexpected bytes, as malformed.
+
+```rust
+use flat_error::{wire, FlatError, Severity};
+
+let err = FlatError::new_static("disk full")
+    .with_code("E1042")
+    .with_severity(Severity::Critical)
+    .context("saving file");
+
+let bytes = wire::encode(&err);
+assert_eq!(bytes[0], wire::WIRE_VERSION);
+assert_eq!(wire::decode(&bytes), Ok(err));
+```
+*/
+
+use crate::{
+    compat::Error, intern_type_name, ErrorClass, Fields, FlatError, Frame, Severity, SharedStr,
+    SourceSpan, FLAT_ERROR_TYPE_NAME,
+};
+use ::serde::{
+    de::{DeserializeSeed, Deserializer, Error as DeError, SeqAccess, Visitor},
+    Deserialize, Serialize,
+};
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The version of the format written by [`encode`].
+///
+pub const WIRE_VERSION: u8 = 2;
+
+///
+/// The maximum depth to which branches and related errors may be nested in an error decoded by
+/// [`decode`].
+///
+pub const WIRE_MAX_NESTING: usize = 32;
+
+///
+/// The error returned when bytes cannot be decoded as a [`FlatError`] by [`decode`].
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WireError {
+    kind: WireErrorKind,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum WireErrorKind {
+    Empty,
+    UnsupportedVersion(u8),
+    Malformed,
+}
+
+///
+/// The encoded form of a [`FlatError`], its own frame followed by the frames of its source chain
+/// starting with the immediate source.
+///
+type WireChain<'a> = Vec<WireFrame<'a>>;
+
+///
+/// The encoded form of a single [`Frame`]. Fields are never skipped, as the format is not
+/// self-describing, and new fields may only be added in a new version of the format. This is
+/// deserialized by [`FrameSeed`], which limits the nesting of branches and related errors.
+///
+#[derive(Serialize)]
+struct WireFrame<'a> {
+    message: Cow<'a, str>,
+    type_name: Cow<'a, str>,
+    severity: u8,
+    code: Option<Cow<'a, str>>,
+    fields: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    message_key: Option<Cow<'a, str>>,
+    message_args: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    message_template: Option<Cow<'a, str>>,
+    debug_repr: Option<Cow<'a, str>>,
+    repeat_count: Option<u64>,
+    help: Option<Cow<'a, str>>,
+    docs_url: Option<Cow<'a, str>>,
+    span: Option<(u64, u64, u64, u64)>,
+    branches: Vec<WireChain<'a>>,
+    related: Vec<WireChain<'a>>,
+    class: Option<Cow<'a, str>>,
+}
+
+///
+/// Deserializes a [`WireChain`], in the format `version`, whose frames are nested `depth` deep,
+/// failing if this is deeper than [`WIRE_MAX_NESTING`].
+///
+#[derive(Clone, Copy)]
+struct ChainSeed {
+    version: u8,
+    depth: usize,
+}
+
+///
+/// Deserializes the branches or related errors of a frame, each a [`WireChain`], in the format
+/// `version`, nested `depth` deep.
+///
+#[derive(Clone, Copy)]
+struct ChainsSeed {
+    version: u8,
+    depth: usize,
+}
+
+///
+/// Deserializes a [`WireFrame`], in the format `version`, nested `depth` deep.
+///
+#[derive(Clone, Copy)]
+struct FrameSeed {
+    version: u8,
+    depth: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Constants
+// ------------------------------------------------------------------------------------------------
+
+const FRAME_FIELDS: &[&str] = &[
+    "message",
+    "type_name",
+    "severity",
+    "code",
+    "fields",
+    "message_key",
+    "message_args",
+    "message_template",
+    "debug_repr",
+    "repeat_count",
+    "help",
+    "docs_url",
+    "span",
+    "branches",
+    "related",
+    "class",
+];
+
+///
+/// The field in which version 1 of the format held the class of an error.
+///
+const V1_CLASS_FIELD: &str = "class";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Encode `error`, and its source chain, in the current version of the format.
+///
+pub fn encode(error: &FlatError) -> Vec<u8> {
+    ::postcard::to_extend(&chain_to_wire(error), vec![WIRE_VERSION])
+        .expect("encoding to a Vec cannot fail")
+}
+
+///
+/// Decode an error, and its source chain, previously encoded by [`encode`].
+///
+pub fn decode(bytes: &[u8]) -> Result<FlatError, WireError> {
+    match bytes.split_first() {
+        None => Err(WireError::new(WireErrorKind::Empty)),
+        Some((&version @ (1 | WIRE_VERSION), rest)) => {
+            let mut deserializer = ::postcard::Deserializer::from_bytes(rest);
+            let chain = ChainSeed { version, depth: 0 }.deserialize(&mut deserializer);
+            chain
+                .ok()
+                .filter(|_| deserializer.finalize().is_ok_and(<[u8]>::is_empty))
+                .and_then(|chain| chain_from_wire(chain, 0))
+                .ok_or(WireError::new(WireErrorKind::Malformed))
+        }
+        Some((&version, _)) => Err(WireError::new(WireErrorKind::UnsupportedVersion(version))),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for WireError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            WireErrorKind::Empty => write!(f, "encoded flat error is empty"),
+            WireErrorKind::UnsupportedVersion(version) => {
+                write!(f, "encoded flat error has unsupported version {version}")
+            }
+            WireErrorKind::Malformed => write!(f, "encoded flat error is malformed"),
+        }
+    }
+}
+
+impl Error for WireError {}
+
+impl WireError {
+    const fn new(kind: WireErrorKind) -> Self {
+        Self { kind }
+    }
+
+    ///
+    /// Return the version of the format found, if it is not one supported by [`decode`].
+    ///
+    pub fn unsupported_version(&self) -> Option<u8> {
+        match self.kind {
+            WireErrorKind::UnsupportedVersion(version) => Some(version),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> From<&'a Frame> for WireFrame<'a> {
+    fn from(frame: &'a Frame) -> Self {
+        let details = frame.details();
+        Self {
+            message: Cow::Borrowed(&frame.message),
+            type_name: Cow::Borrowed(frame.original_type_name),
+            severity: match frame.severity {
+                Severity::Info => 0,
+                Severity::Warning => 1,
+                Severity::Error => 2,
+                Severity::Critical => 3,
+            },
+            code: details.code.as_deref().map(Cow::Borrowed),
+            fields: pairs_to_wire(&details.fields),
+            message_key: details.message_key.as_deref().map(Cow::Borrowed),
+            message_args: pairs_to_wire(&details.message_args),
+            message_template: details.message_template.as_deref().map(Cow::Borrowed),
+            debug_repr: details.debug_repr.as_deref().map(Cow::Borrowed),
+            repeat_count: details.repeat_count.map(|count| count as u64),
+            help: details.help.as_deref().map(Cow::Borrowed),
+            docs_url: details.docs_url.as_deref().map(Cow::Borrowed),
+            span: details.span.map(|span| {
+                (
+                    span.offset as u64,
+                    span.len as u64,
+                    span.line as u64,
+                    span.column as u64,
+                )
+            }),
+            branches: details.branches.iter().map(chain_to_wire).collect(),
+            related: details.related.iter().map(chain_to_wire).collect(),
+            class: details.class.map(|class| Cow::Borrowed(class.name())),
+        }
+    }
+}
+
+impl WireFrame<'_> {
+    fn into_frame(self, depth: usize) -> Option<Frame> {
+        let mut frame = Frame::new(
+            FLAT_ERROR_TYPE_NAME,
+            SharedStr::from(self.message.into_owned()),
+        );
+        frame.original_type_name = intern_type_name(&self.type_name);
+        frame.severity = match self.severity {
+            0 => Severity::Info,
+            1 => Severity::Warning,
+            2 => Severity::Error,
+            3 => Severity::Critical,
+            _ => return None,
+        };
+        let span = match self.span {
+            Some((offset, len, line, column)) => Some(SourceSpan::new(
+                usize::try_from(offset).ok()?,
+                usize::try_from(len).ok()?,
+                usize::try_from(line).ok()?,
+                usize::try_from(column).ok()?,
+            )),
+            None => None,
+        };
+        let repeat_count = match self.repeat_count {
+            Some(count) => Some(usize::try_from(count).ok()?),
+            None => None,
+        };
+        let class = match self.class {
+            Some(name) => Some(ErrorClass::from_name(&name)?),
+            None => None,
+        };
+        if self.code.is_some()
+            || !self.fields.is_empty()
+            || self.message_key.is_some()
+            || !self.message_args.is_empty()
+            || self.message_template.is_some()
+            || self.debug_repr.is_some()
+            || repeat_count.is_some()
+            || self.help.is_some()
+            || self.docs_url.is_some()
+            || span.is_some()
+            || !self.branches.is_empty()
+            || !self.related.is_empty()
+            || class.is_some()
+        {
+            let details = frame.details_mut();
+            details.code = self.code.map(Cow::into_owned);
+            details.class = class;
+            details.fields = Fields(pairs_from_wire(self.fields));
+            details.message_key = self.message_key.map(Cow::into_owned);
+            details.message_args = pairs_from_wire(self.message_args);
+            details.message_template = self.message_template.map(Cow::into_owned);
+            details.debug_repr = self.debug_repr.map(Cow::into_owned);
+            details.repeat_count = repeat_count;
+            details.help = self.help.map(Cow::into_owned);
+            details.docs_url = self.docs_url.map(Cow::into_owned);
+            details.span = span;
+            details.branches = self
+                .branches
+                .into_iter()
+                .map(|chain| chain_from_wire(chain, depth + 1))
+                .collect::<Option<_>>()?;
+            details.related = self
+                .related
+                .into_iter()
+                .map(|chain| chain_from_wire(chain, depth + 1))
+                .collect::<Option<_>>()?;
+        }
+        Some(frame)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'de> DeserializeSeed<'de> for ChainSeed {
+    type Value = WireChain<'de>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.depth > WIRE_MAX_NESTING {
+            return Err(D::Error::custom("encoded errors are nested too deeply"));
+        }
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ChainSeed {
+    type Value = WireChain<'de>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a sequence of encoded frames")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut chain = Vec::new();
+        let frame_seed = FrameSeed {
+            version: self.version,
+            depth: self.depth,
+        };
+        while let Some(frame) = seq.next_element_seed(frame_seed)? {
+            chain.push(frame);
+        }
+        Ok(chain)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ChainsSeed {
+    type Value = Vec<WireChain<'de>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ChainsSeed {
+    type Value = Vec<WireChain<'de>>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a sequence of encoded chains")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut chains = Vec::new();
+        let chain_seed = ChainSeed {
+            version: self.version,
+            depth: self.depth,
+        };
+        while let Some(chain) = seq.next_element_seed(chain_seed)? {
+            chains.push(chain);
+        }
+        Ok(chains)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for FrameSeed {
+    type Value = WireFrame<'de>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("WireFrame", FRAME_FIELDS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for FrameSeed {
+    type Value = WireFrame<'de>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an encoded frame")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let nested = ChainsSeed {
+            version: self.version,
+            depth: self.depth + 1,
+        };
+        let mut frame = WireFrame {
+            message: next_field(&mut seq, 0)?,
+            type_name: next_field(&mut seq, 1)?,
+            severity: next_field(&mut seq, 2)?,
+            code: next_field(&mut seq, 3)?,
+            fields: next_field(&mut seq, 4)?,
+            message_key: next_field(&mut seq, 5)?,
+            message_args: next_field(&mut seq, 6)?,
+            message_template: next_field(&mut seq, 7)?,
+            debug_repr: next_field(&mut seq, 8)?,
+            repeat_count: next_field(&mut seq, 9)?,
+            help: next_field(&mut seq, 10)?,
+            docs_url: next_field(&mut seq, 11)?,
+            span: next_field(&mut seq, 12)?,
+            branches: seq
+                .next_element_seed(nested)?
+                .ok_or_else(|| A::Error::invalid_length(13, &"an encoded frame"))?,
+            related: seq
+                .next_element_seed(nested)?
+                .ok_or_else(|| A::Error::invalid_length(14, &"an encoded frame"))?,
+            class: None,
+        };
+        if self.version == 1 {
+            if let Some(index) = frame
+                .fields
+                .iter()
+                .position(|(name, _)| name == V1_CLASS_FIELD)
+            {
+                frame.class = Some(frame.fields.remove(index).1);
+            }
+        } else {
+            frame.class = next_field(&mut seq, 15)?;
+        }
+        Ok(frame)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the next field of a [`WireFrame`], the field at `index`.
+///
+fn next_field<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    seq.next_element()?
+        .ok_or_else(|| A::Error::invalid_length(index, &"an encoded frame"))
+}
+
+fn chain_to_wire(error: &FlatError) -> WireChain<'_> {
+    error.chain_frames().map(WireFrame::from).collect()
+}
+
+fn chain_from_wire(chain: WireChain<'_>, depth: usize) -> Option<FlatError> {
+    if chain.is_empty() || depth > WIRE_MAX_NESTING {
+        return None;
+    }
+    let mut frames = chain
+        .into_iter()
+        .map(|frame| frame.into_frame(depth))
+        .collect::<Option<Vec<_>>>()?;
+    let frame = frames.remove(0);
+    frames.reverse();
+    Some(FlatError::from_frames(frame, frames))
+}
+
+fn pairs_to_wire(pairs: &[(String, String)]) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (Cow::Borrowed(k.as_str()), Cow::Borrowed(v.as_str())))
+        .collect()
+}
+
+fn pairs_from_wire(pairs: Vec<(Cow<'_, str>, Cow<'_, str>)>) -> Vec<(String, String)> {
+    pairs
+        .into_iter()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect()
+}
@@ -0,0 +1,87 @@
+/*!
+Provides implementations of the `Zeroize` and `ZeroizeOnDrop` traits from the
+[`zeroize`](https://docs.rs/zeroize) crate for [`FlatError`] and [`FlatErrorFixed`], so that
+errors from authentication or cryptographic code do not leave sensitive messages or metadata in
+memory once they are no longer used.
+
+When this feature is enabled the messages and metadata of a [`FlatError`] are wiped when the
+last error sharing them is dropped, or when the error is zeroized, see [`FlatError::zeroize`].
+Any original error retained by [`FlatError::from_any_retaining`] is released, but it cannot be
+wiped, and copies made before the error was flattened are not affected.
+
+```rust
+use flat_error::FlatError;
+use zeroize::Zeroize;
+
+let mut err = FlatError::new("invalid key: 0a7f93c2")
+    .with_field("key_id", "k-42")
+    .context("decrypting payload");
+err.zeroize();
+
+assert_eq!(err.to_string(), "");
+assert_eq!(err.fields().count(), 0);
+assert!(err.flat_source().is_none());
+```
+*/
+
+use crate::{Details, FlatError, FlatErrorFixed, Frame, SharedStr, FLAT_ERROR_TYPE_NAME};
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+use alloc::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<C> Zeroize for FlatError<C> {
+    ///
+    /// Replace this error with an empty error of the same category, with no metadata or source
+    /// chain. The messages and metadata that were replaced are wiped unless they are still shared
+    /// with a clone of this error, in which case they are wiped when the last clone is dropped.
+    ///
+    fn zeroize(&mut self) {
+        self.inner.frame = Frame::new(FLAT_ERROR_TYPE_NAME, SharedStr::Static(""));
+        self.inner.sources = None;
+    }
+}
+
+impl<C> ZeroizeOnDrop for FlatError<C> {}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<const N: usize> Zeroize for FlatErrorFixed<N> {
+    fn zeroize(&mut self) {
+        self.message.zeroize();
+        self.length = 0;
+        self.truncated = false;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Drop for SharedStr {
+    fn drop(&mut self) {
+        match self {
+            SharedStr::Shared(value) => {
+                if let Some(value) = Arc::get_mut(value) {
+                    value.zeroize();
+                }
+            }
+            #[cfg(feature = "inline-messages")]
+            SharedStr::Inline(value) => value.bytes.zeroize(),
+            SharedStr::Static(_) => {}
+        }
+    }
+}
+
+impl Drop for Details {
+    fn drop(&mut self) {
+        self.code.zeroize();
+        self.fields.zeroize();
+        self.message_key.zeroize();
+        self.message_args.zeroize();
+        self.message_template.zeroize();
+        self.debug_repr.zeroize();
+        self.help.zeroize();
+        self.docs_url.zeroize();
+    }
+}
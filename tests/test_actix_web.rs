@@ -0,0 +1,37 @@
+#![cfg(feature = "actix-web")]
+
+use actix_web::{body::MessageBody, http::StatusCode, ResponseError};
+use flat_error::{ErrorClass, FlatError};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_status_code_from_class() {
+    let err = FlatError::new("slow down").with_class(ErrorClass::ResourceExhausted);
+    assert_eq!(err.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(
+        FlatError::from(IoError::from(ErrorKind::PermissionDenied)).status_code(),
+        StatusCode::FORBIDDEN
+    );
+}
+
+#[test]
+fn test_error_response_hides_details() {
+    let err = FlatError::from(IoError::other("connecting to db-7.internal:5432"))
+        .context("loading profile");
+    let response = err.error_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+    let body = response.into_body().try_into_bytes().unwrap();
+    assert_eq!(
+        String::from_utf8(body.to_vec()).unwrap(),
+        r#"{"class":"internal","error":"Internal Server Error","status":500}"#
+    );
+}
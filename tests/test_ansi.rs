@@ -0,0 +1,92 @@
+#![cfg(feature = "ansi")]
+
+use flat_error::{FlatError, Severity};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_render_plain() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .context("starting server");
+    assert_eq!(
+        err.render_pretty_with(false),
+        "error: starting server\n  caused by: reading config file\n  caused by: entity not found"
+    );
+}
+
+#[test]
+fn test_render_colored() {
+    let err = FlatError::new_static("disk full")
+        .context("saving file")
+        .with_code("E1042");
+    assert_eq!(
+        err.render_pretty_with(true),
+        "\x1b[1;31merror[E1042]:\x1b[0m saving file\n  \x1b[1mcaused by:\x1b[0m disk full"
+    );
+}
+
+#[test]
+fn test_render_severity() {
+    let err = FlatError::new_static("low disk space").with_severity(Severity::Warning);
+    assert_eq!(
+        err.render_pretty_with(true),
+        "\x1b[1;33mwarning:\x1b[0m low disk space"
+    );
+}
+
+#[test]
+fn test_render_multi_line_message() {
+    let err = FlatError::new_static("line one\nline two").context("parsing");
+    assert_eq!(
+        err.render_pretty_with(false),
+        "error: parsing\n  caused by: line one\n  line two"
+    );
+}
+
+#[test]
+fn test_render_respects_no_color() {
+    let err = FlatError::new_static("disk full");
+    std::env::set_var("NO_COLOR", "1");
+    assert_eq!(err.render_pretty(), "error: disk full");
+    std::env::set_var("NO_COLOR", "");
+    assert_eq!(err.render_pretty(), "\x1b[1;31merror:\x1b[0m disk full");
+    std::env::remove_var("NO_COLOR");
+}
+
+#[test]
+fn test_render_help() {
+    let err = FlatError::new_static("destination exists").with_help("try --force");
+    assert_eq!(
+        err.render_pretty_with(false),
+        "error: destination exists\n  help: try --force"
+    );
+    assert_eq!(
+        err.render_pretty_with(true),
+        "\x1b[1;31merror:\x1b[0m destination exists\n  \x1b[1;36mhelp:\x1b[0m try --force"
+    );
+}
+
+#[test]
+fn test_render_docs_url() {
+    let err =
+        FlatError::new_static("replica lag exceeded").with_docs_url("https://example.com/lag");
+    assert_eq!(
+        err.render_pretty_with(false),
+        "error: replica lag exceeded\n  see: https://example.com/lag"
+    );
+}
+
+#[test]
+fn test_render_related() {
+    let err = FlatError::new_static("duplicate key `name`")
+        .with_related(FlatError::new_static("first defined at line 3"));
+    assert_eq!(
+        err.render_pretty_with(false),
+        "error: duplicate key `name`\n  related: first defined at line 3"
+    );
+}
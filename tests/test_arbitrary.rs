@@ -0,0 +1,31 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use flat_error::{FlatError, Severity};
+use std::iter::successors;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_arbitrary_chain_depth() {
+    let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&data);
+    let mut depths = Vec::new();
+    while !u.is_empty() {
+        let err = FlatError::arbitrary(&mut u).unwrap();
+        let depth = successors(Some(&err), |err| err.flat_source()).count();
+        assert!((1..=5).contains(&depth));
+        depths.push(depth);
+    }
+    assert!(depths.iter().any(|depth| *depth > 1));
+}
+
+#[test]
+fn test_arbitrary_empty_input() {
+    let mut u = Unstructured::new(&[]);
+    let err = FlatError::arbitrary(&mut u).unwrap();
+    assert_eq!(err.severity(), Severity::Info);
+    assert!(err.flat_source().is_none());
+}
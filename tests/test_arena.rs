@@ -0,0 +1,66 @@
+#![cfg(feature = "arena")]
+
+use flat_error::{FlatArena, FlatError, DEFAULT_MAX_DEPTH};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+fn nested(depth: usize) -> FlatError {
+    (1..depth).fold(FlatError::new("root cause"), |err, n| {
+        err.context(format!("level {n}"))
+    })
+}
+
+#[test]
+fn test_flatten_chain() {
+    let arena = FlatArena::new();
+    let err = arena.flatten(&IoError::new(
+        ErrorKind::InvalidData,
+        FlatError::new("bad checksum").context("reading block"),
+    ));
+    assert_eq!(err.to_string(), "reading block");
+    assert_eq!(err.original_type_name(), "std::io::error::Error");
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        vec!["reading block", "bad checksum"]
+    );
+    let source = err.flat_source().unwrap();
+    assert_eq!(source.message(), "bad checksum");
+    assert!(source.flat_source().is_none());
+}
+
+#[test]
+fn test_into_flat_error_matches_flattened() {
+    let error = IoError::new(
+        ErrorKind::InvalidData,
+        FlatError::new("bad checksum").context("reading block"),
+    );
+    let arena = FlatArena::new();
+    let from_arena = FlatError::from(arena.flatten(&error));
+    let flat = FlatError::from_any(&error);
+    assert_eq!(
+        from_arena.chain_messages().collect::<Vec<_>>(),
+        flat.chain_messages().collect::<Vec<_>>()
+    );
+    assert_eq!(from_arena.original_type_name(), flat.original_type_name());
+}
+
+#[test]
+fn test_max_depth() {
+    let arena = FlatArena::new();
+    let err = arena.flatten(&nested(DEFAULT_MAX_DEPTH + 5));
+    assert_eq!(err.chain_messages().len(), DEFAULT_MAX_DEPTH + 2);
+    assert_eq!(err.chain_messages().last(), Some("… 4 more"));
+}
+
+#[test]
+fn test_reset_reuses_allocation() {
+    let mut arena = FlatArena::with_capacity(4096);
+    for _ in 0..10 {
+        for n in 0..20 {
+            let err = arena.flatten(&IoError::other(format!("record {n} is invalid")));
+            assert_eq!(err.message(), format!("record {n} is invalid"));
+        }
+        assert!(arena.allocated_bytes() >= 4096);
+        arena.reset();
+    }
+}
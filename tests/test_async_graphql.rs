@@ -0,0 +1,35 @@
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{ErrorExtensions, Value};
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_extend_without_code() {
+    let err = FlatError::from(IoError::from(ErrorKind::PermissionDenied))
+        .context("loading profile")
+        .extend();
+    assert_eq!(err.message, "loading profile");
+    let extensions = err.extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("code"), None);
+    assert_eq!(
+        extensions.get("class"),
+        Some(&Value::from("permission_denied"))
+    );
+}
+
+#[test]
+fn test_extend_with_additional_extensions() {
+    let err = FlatError::new_static("rate limited")
+        .with_code("E0429")
+        .extend_with(|_, extensions| extensions.set("retry_after", 30));
+    let extensions = err.extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("code"), Some(&Value::from("E0429")));
+    assert_eq!(extensions.get("class"), Some(&Value::from("internal")));
+    assert_eq!(extensions.get("retry_after"), Some(&Value::from(30)));
+}
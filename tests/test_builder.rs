@@ -0,0 +1,110 @@
+use flat_error::{FlatError, FlatErrorBuilder, Severity};
+use pretty_assertions::assert_eq;
+use std::panic::Location;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_builder_defaults() {
+    let err = FlatErrorBuilder::new().build();
+    assert_eq!(err.to_string(), "");
+    assert_eq!(err.original_type_name(), "flat_error::FlatError");
+    assert_eq!(err.severity(), Severity::Error);
+    assert_eq!(err.code(), None);
+    assert_eq!(err.location(), None);
+    assert!(err.flat_source().is_none());
+}
+
+#[test]
+fn test_builder_matches_methods() {
+    let built = FlatError::builder()
+        .message("not ready")
+        .code("E1")
+        .severity(Severity::Warning)
+        .field("attempt", 2)
+        .source(FlatError::new("starting"))
+        .build();
+    let expected = FlatError::new("not ready")
+        .with_code("E1")
+        .with_severity(Severity::Warning)
+        .with_field("attempt", 2)
+        .caused_by(FlatError::new("starting"));
+    assert_eq!(built, expected);
+}
+
+#[test]
+fn test_builder_location() {
+    let location = Location::caller();
+    let err: FlatError = FlatError::builder()
+        .message("failed")
+        .location(location)
+        .into();
+    assert_eq!(err.location(), Some(location));
+    assert_eq!(err.location().map(|l| l.file()), Some(file!()));
+}
+
+#[test]
+fn test_builder_source_chain() {
+    let err = FlatError::builder()
+        .message("a")
+        .source(FlatError::new("b").caused_by(FlatError::new("c")))
+        .build();
+    assert_eq!(
+        err.flat_source().and_then(|e| e.flat_source()),
+        Some(&FlatError::new("c"))
+    );
+}
+
+#[test]
+fn test_builder_as_frame() {
+    let builder = FlatError::builder().message("not ready").code("E1");
+    assert_eq!(builder.as_frame().message(), "not ready");
+    assert_eq!(builder.as_frame().code(), Some("E1"));
+}
+
+#[test]
+fn test_map_frames_identity() {
+    let err = FlatError::new("root").context("middle").context("top");
+    assert_eq!(err.clone().map_frames(Some), Some(err));
+}
+
+#[test]
+fn test_map_frames_rewrites() {
+    let err = FlatError::new("root").with_code("E1").context("top");
+    let mut index = 0;
+    let mapped = err
+        .map_frames(|builder| {
+            index += 1;
+            let message = format!("{index}: {}", builder.as_frame().message());
+            Some(builder.message(message).field("index", index))
+        })
+        .unwrap();
+    assert_eq!(mapped.format_chain(", "), "1: top, 2: root");
+    assert_eq!(mapped.field("index"), Some("1"));
+    let source = mapped.flat_source().unwrap();
+    assert_eq!(source.field("index"), Some("2"));
+    assert_eq!(source.code(), Some("E1"));
+}
+
+#[test]
+fn test_map_frames_removes() {
+    let err = FlatError::new("root").context("middle").context("top");
+    let mapped = err
+        .clone()
+        .map_frames(|builder| (builder.as_frame().message() != "top").then_some(builder))
+        .unwrap();
+    assert_eq!(mapped, FlatError::new("root").context("middle"));
+    assert_eq!(err.map_frames(|_| None), None);
+}
+
+#[test]
+fn test_map_frames_ignores_source_and_keeps_category() {
+    let err = FlatError::new("root").context("top").with_category(7);
+    let mapped = err
+        .map_frames(|builder| Some(builder.source(FlatError::new("ignored"))))
+        .unwrap();
+    assert_eq!(mapped.chain_messages().collect::<Vec<_>>(), ["top", "root"]);
+    assert_eq!(mapped.category(), &7);
+}
@@ -0,0 +1,68 @@
+use flat_error::{CatalogEntry, ErrorCatalog, ErrorClass, Severity};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn catalog() -> ErrorCatalog {
+    ErrorCatalog::new()
+        .with_entry(
+            CatalogEntry::new("E0404", "no user with id {id} in {{users}}")
+                .with_class(ErrorClass::NotFound)
+                .with_docs_url("https://errors.example.com/E0404"),
+        )
+        .with_entry(CatalogEntry::new("E0001", "failed: {reason}").with_severity(Severity::Warning))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_register_and_get() {
+    let mut catalog = catalog();
+    assert_eq!(catalog.len(), 2);
+    assert!(catalog.contains("E0404"));
+    assert_eq!(
+        catalog.get("E0404").unwrap().class(),
+        Some(ErrorClass::NotFound)
+    );
+    assert_eq!(
+        catalog
+            .entries()
+            .map(CatalogEntry::code)
+            .collect::<Vec<_>>(),
+        vec!["E0001", "E0404"]
+    );
+
+    let replaced = catalog.register(CatalogEntry::new("E0001", "failed"));
+    assert_eq!(replaced.unwrap().template(), "failed: {reason}");
+    assert_eq!(catalog.len(), 2);
+}
+
+#[test]
+fn test_new_error_from_entry() {
+    let err = catalog().new_error("E0404", [("id", 42)]);
+    assert_eq!(err.to_string(), "no user with id 42 in {users}");
+    assert_eq!(err.code(), Some("E0404"));
+    assert_eq!(err.class(), ErrorClass::NotFound);
+    assert_eq!(err.severity(), Severity::Error);
+    assert_eq!(err.docs_url(), Some("https://errors.example.com/E0404"));
+    assert_eq!(err.message_args().collect::<Vec<_>>(), vec![("id", "42")]);
+}
+
+#[test]
+fn test_new_error_missing_argument() {
+    let err = catalog().new_error("E0001", [("other", "x")]);
+    assert_eq!(err.to_string(), "failed: {reason}");
+    assert_eq!(err.severity(), Severity::Warning);
+}
+
+#[test]
+fn test_new_error_unknown_code() {
+    let err = catalog().new_error("E9999", [("id", 1)]);
+    assert_eq!(err.to_string(), "unknown error code `E9999`");
+    assert_eq!(err.code(), None);
+    assert_eq!(err.class(), ErrorClass::Internal);
+}
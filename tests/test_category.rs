@@ -0,0 +1,86 @@
+use flat_error::{ExtendedError, FlatError};
+use pretty_assertions::assert_eq;
+use std::{
+    collections::HashSet,
+    error::Error,
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Kind {
+    Config,
+    Network,
+}
+
+fn categorized(kind: Kind) -> FlatError<Kind> {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .with_category(kind)
+}
+
+fn assert_extended<E: ExtendedError>(_: &E) {}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_category_default_is_unit() {
+    let err: FlatError = FlatError::new("not ready");
+    assert_eq!(err.category(), &());
+}
+
+#[test]
+fn test_with_category() {
+    let err = categorized(Kind::Config);
+    assert_eq!(err.category(), &Kind::Config);
+    assert_eq!(err.to_string(), "reading config file");
+    assert_eq!(err.frames().len(), 2);
+    assert_eq!(
+        err.source().map(|e| e.to_string()),
+        Some("entity not found".to_string())
+    );
+    assert_extended(&err);
+}
+
+#[test]
+fn test_map_category() {
+    let err = categorized(Kind::Config).map_category(|kind| kind == Kind::Network);
+    assert_eq!(err.category(), &false);
+}
+
+#[test]
+fn test_into_category() {
+    let (err, kind) = categorized(Kind::Network).into_category();
+    assert_eq!(kind, Kind::Network);
+    assert_eq!(
+        err,
+        FlatError::from(IoError::from(ErrorKind::NotFound)).context("reading config file")
+    );
+}
+
+#[test]
+fn test_category_eq_and_hash() {
+    assert_eq!(categorized(Kind::Config), categorized(Kind::Config).clone());
+    assert_ne!(categorized(Kind::Config), categorized(Kind::Network));
+    assert!(categorized(Kind::Config) < categorized(Kind::Network));
+    let set: HashSet<_> = [categorized(Kind::Config), categorized(Kind::Config)].into();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn test_category_debug() {
+    let err = FlatError::new("not ready").with_category(Kind::Config);
+    assert_eq!(
+        format!("{err:?}"),
+        r#"FlatError { message: "not ready", type: "flat_error::FlatError", category: Config }"#
+    );
+    assert_eq!(
+        format!("{:?}", FlatError::new("not ready")),
+        r#"FlatError { message: "not ready", type: "flat_error::FlatError" }"#
+    );
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, NaiveDate};
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_parse_error_kind() {
+    fn parse(s: &str) -> Result<NaiveDate, FlatError> {
+        Ok(NaiveDate::parse_from_str(s, "%Y-%m-%d")?)
+    }
+    let err = parse("2024-01").unwrap_err();
+    assert_eq!(err.original_type_name(), "chrono::format::ParseError");
+    assert_eq!(err.to_string(), "premature end of input");
+    assert_eq!(err.fields().collect::<Vec<_>>(), vec![("kind", "TooShort")]);
+}
+
+#[test]
+fn test_rfc3339_out_of_range() {
+    let err = FlatError::from(DateTime::parse_from_rfc3339("2024-13-01T00:00:00Z").unwrap_err());
+    assert_eq!(err.field("kind"), Some("OutOfRange"));
+}
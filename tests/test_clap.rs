@@ -0,0 +1,68 @@
+#![cfg(feature = "clap")]
+
+use clap::{value_parser, Arg, Command};
+use flat_error::{ErrorClass, FlatError, FlatExit};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn parse(args: &[&str]) -> FlatError {
+    Command::new("app")
+        .version("1.0.0")
+        .arg(
+            Arg::new("count")
+                .long("count")
+                .value_parser(value_parser!(u8)),
+        )
+        .try_get_matches_from(args)
+        .unwrap_err()
+        .into()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_usage_error() {
+    let err = parse(&["app", "--count", "many"]);
+    assert_eq!(err.clap_kind(), Some("ValueValidation"));
+    assert!(!err.is_clap_display());
+    assert_eq!(err.class(), ErrorClass::InvalidInput);
+    assert_eq!(err.exit_code(), 2);
+    assert!(err.to_string().contains("invalid value 'many'"));
+}
+
+#[test]
+fn test_display_help() {
+    let err = parse(&["app", "--help"]);
+    assert_eq!(err.clap_kind(), Some("DisplayHelp"));
+    assert!(err.is_clap_display());
+    assert_eq!(err.exit_code(), 0);
+    assert!(err.to_string().contains("--count <count>"));
+}
+
+#[test]
+fn test_display_version() {
+    let err = parse(&["app", "--version"]);
+    assert_eq!(err.clap_kind(), Some("DisplayVersion"));
+    assert!(err.is_clap_display());
+    assert_eq!(err.to_string(), "app 1.0.0\n");
+}
+
+#[test]
+fn test_clap_error_in_chain() {
+    let err = FlatError::new("parsing arguments").caused_by(parse(&["app", "--verbose"]));
+    assert_eq!(err.clap_kind(), Some("UnknownArgument"));
+    assert_eq!(FlatExit::from(err).exit_code(), 2);
+}
+
+#[test]
+fn test_not_clap_error() {
+    let err = FlatError::new("failed");
+    assert_eq!(err.clap_kind(), None);
+    assert!(!err.is_clap_display());
+    assert_eq!(err.exit_code(), 1);
+}
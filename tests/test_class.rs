@@ -0,0 +1,141 @@
+use flat_error::{ErrorClass, FlatError, FlatIoError};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_default_class() {
+    assert_eq!(ErrorClass::default(), ErrorClass::Internal);
+    assert_eq!(FlatError::new("oops").class(), ErrorClass::Internal);
+}
+
+#[test]
+fn test_class_names() {
+    assert_eq!(
+        ErrorClass::PermissionDenied.to_string(),
+        "permission_denied"
+    );
+    assert_eq!(
+        ErrorClass::from_name("resource_exhausted"),
+        Some(ErrorClass::ResourceExhausted)
+    );
+    assert_eq!(ErrorClass::from_name("unknown"), None);
+}
+
+#[test]
+fn test_class_from_io_kind() {
+    for (kind, class) in [
+        (ErrorKind::NotFound, ErrorClass::NotFound),
+        (ErrorKind::PermissionDenied, ErrorClass::PermissionDenied),
+        (ErrorKind::AlreadyExists, ErrorClass::Conflict),
+        (ErrorKind::TimedOut, ErrorClass::Timeout),
+        (ErrorKind::ConnectionRefused, ErrorClass::Unavailable),
+        (ErrorKind::Other, ErrorClass::Internal),
+    ] {
+        assert_eq!(FlatError::from(IoError::from(kind)).class(), class);
+    }
+}
+
+#[test]
+fn test_class_from_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("loading profile");
+    assert_eq!(err.class(), ErrorClass::NotFound);
+    assert_eq!(err.explicit_class(), None);
+}
+
+#[test]
+fn test_class_from_parse_error() {
+    let err = FlatError::from_error(&"1.x".parse::<f64>().unwrap_err()).context("reading");
+    assert_eq!(err.class(), ErrorClass::InvalidInput);
+}
+
+#[test]
+fn test_with_class_overrides_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("loading profile")
+        .with_class(ErrorClass::Unavailable);
+    assert_eq!(err.class(), ErrorClass::Unavailable);
+    assert_eq!(err.explicit_class(), Some(ErrorClass::Unavailable));
+    assert_eq!(err.field("class"), None);
+}
+
+#[test]
+fn test_from_io_kind() {
+    let err = FlatError::from_io_kind(ErrorKind::AlreadyExists, "user exists");
+    assert_eq!(err.to_string(), "user exists");
+    assert_eq!(err.io_kind(), Some(ErrorKind::AlreadyExists));
+    assert_eq!(err.class(), ErrorClass::Conflict);
+    assert_eq!(err.explicit_class(), None);
+    assert_eq!(
+        IoError::from(FlatIoError::from(err)).kind(),
+        ErrorKind::AlreadyExists
+    );
+}
+
+#[test]
+fn test_classified_shorthands() {
+    for (err, class, kind) in [
+        (
+            FlatError::not_found("a"),
+            ErrorClass::NotFound,
+            Some(ErrorKind::NotFound),
+        ),
+        (
+            FlatError::permission_denied("a"),
+            ErrorClass::PermissionDenied,
+            Some(ErrorKind::PermissionDenied),
+        ),
+        (
+            FlatError::unauthenticated("a"),
+            ErrorClass::Unauthenticated,
+            None,
+        ),
+        (
+            FlatError::invalid_input("a"),
+            ErrorClass::InvalidInput,
+            Some(ErrorKind::InvalidInput),
+        ),
+        (
+            FlatError::conflict("a"),
+            ErrorClass::Conflict,
+            Some(ErrorKind::AlreadyExists),
+        ),
+        (
+            FlatError::timeout("a"),
+            ErrorClass::Timeout,
+            Some(ErrorKind::TimedOut),
+        ),
+        (FlatError::unavailable("a"), ErrorClass::Unavailable, None),
+        (
+            FlatError::resource_exhausted("a"),
+            ErrorClass::ResourceExhausted,
+            None,
+        ),
+        (
+            FlatError::cancelled("a"),
+            ErrorClass::Cancelled,
+            Some(ErrorKind::Interrupted),
+        ),
+        (
+            FlatError::unsupported("a"),
+            ErrorClass::Unsupported,
+            Some(ErrorKind::Unsupported),
+        ),
+        (FlatError::internal("a"), ErrorClass::Internal, None),
+    ] {
+        assert_eq!(err.to_string(), "a");
+        assert_eq!(err.class(), class);
+        assert_eq!(err.explicit_class(), Some(class));
+        assert_eq!(err.io_kind(), kind);
+    }
+}
+
+#[test]
+fn test_classified_class_survives_context() {
+    let err = FlatError::timeout("no response").context("fetching profile");
+    assert_eq!(err.class(), ErrorClass::Timeout);
+    assert_eq!(err.explicit_class(), None);
+}
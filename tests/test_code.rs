@@ -0,0 +1,37 @@
+use flat_error::FlatError;
+use pretty_assertions::{assert_eq, assert_ne};
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn not_found() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_no_code_by_default() {
+    assert_eq!(not_found().code(), None);
+}
+
+#[test]
+fn test_code_participates_in_eq() {
+    assert_eq!(not_found().with_code("E1"), not_found().with_code("E1"));
+    assert_ne!(not_found().with_code("E1"), not_found().with_code("E2"));
+    assert_ne!(not_found().with_code("E1"), not_found());
+}
+
+#[test]
+fn test_code_in_alternate_display() {
+    let err = not_found().with_code("E1042").context("loading");
+    assert_eq!(err.to_string(), "loading".to_string());
+    assert_eq!(
+        format!("{:#}", err.flat_source().unwrap()),
+        "entity not found (code: `E1042`, original type: `std::io::error::Error`)".to_string()
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "codespan-reporting")]
+
+use codespan_reporting::diagnostic::{LabelStyle, Severity as CodespanSeverity};
+use flat_error::{FlatError, Severity, SourceSpan};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const SOURCE: &str = "name: a\nport: 80\nname: b\n";
+
+#[test]
+fn test_diagnostic_labels_and_notes() {
+    let err = FlatError::new_static("duplicate key `name`")
+        .with_span(SourceSpan::from_range(SOURCE, 17..21))
+        .with_related(
+            FlatError::new_static("first defined here")
+                .with_span(SourceSpan::from_range(SOURCE, 0..4)),
+        )
+        .with_help("remove one of the definitions")
+        .context("invalid config")
+        .with_severity(Severity::Warning);
+    let diagnostic = err.to_codespan_diagnostic(7_u32);
+    assert_eq!(diagnostic.severity, CodespanSeverity::Warning);
+    assert_eq!(diagnostic.message, "invalid config");
+    assert_eq!(diagnostic.code, None);
+    let labels: Vec<_> = diagnostic
+        .labels
+        .iter()
+        .map(|label| {
+            (
+                label.style,
+                label.file_id,
+                label.range.clone(),
+                label.message.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        labels,
+        vec![(LabelStyle::Primary, 7, 17..21, "duplicate key `name`")]
+    );
+    assert_eq!(
+        diagnostic.notes,
+        vec!["help: remove one of the definitions".to_string()]
+    );
+}
+
+#[test]
+fn test_diagnostic_related_and_sources() {
+    let err = FlatError::new_static("duplicate key `name`")
+        .context("reading config")
+        .with_span(SourceSpan::from_range(SOURCE, 17..21))
+        .with_related(
+            FlatError::new_static("first defined here")
+                .with_span(SourceSpan::from_range(SOURCE, 0..4)),
+        )
+        .with_code("E0001");
+    let diagnostic = err.to_codespan_diagnostic(());
+    assert_eq!(diagnostic.code.as_deref(), Some("E0001"));
+    assert_eq!(diagnostic.labels.len(), 2);
+    assert_eq!(diagnostic.labels[0].style, LabelStyle::Primary);
+    assert_eq!(diagnostic.labels[0].message, "");
+    assert_eq!(diagnostic.labels[1].style, LabelStyle::Secondary);
+    assert_eq!(diagnostic.labels[1].range, 0..4);
+    assert_eq!(diagnostic.labels[1].message, "first defined here");
+    assert_eq!(
+        diagnostic.notes,
+        vec!["caused by: duplicate key `name`".to_string()]
+    );
+}
+
+#[test]
+fn test_diagnostic_without_span() {
+    let diagnostic = FlatError::new_static("disk full").to_codespan_diagnostic(());
+    assert_eq!(diagnostic.severity, CodespanSeverity::Error);
+    assert!(diagnostic.labels.is_empty());
+    assert!(diagnostic.notes.is_empty());
+}
@@ -0,0 +1,132 @@
+use flat_error::{
+    DisplayStyle, FlatError, FlatErrorConfig, FlattenOptions, BACKTRACE_FIELD, DEFAULT_MAX_DEPTH,
+};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    hash::{BuildHasher, RandomState},
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn read_config() -> Result<(), FlatError> {
+    Err(IoError::new(
+        ErrorKind::NotFound,
+        "no such file: /etc/secret.toml",
+    ))?;
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_default_config() {
+    let config = FlatErrorConfig::new();
+    assert_eq!(config.max_depth(), DEFAULT_MAX_DEPTH);
+    assert_eq!(config.max_message_len(), None);
+    assert!(!config.capture_backtraces());
+    assert!(!config.capture_locations());
+    assert!(config.capture_timestamps());
+    assert!(!config.sanitize_messages());
+    assert!(!config.intern_messages());
+    assert!(config.redactor().is_none());
+    assert_eq!(config.display_style(), DisplayStyle::Compact);
+}
+
+// The configuration may only be installed once in each process, and so is tested by a single
+// test.
+#[test]
+fn test_installed_config() {
+    FlatErrorConfig::new()
+        .with_max_depth(2)
+        .with_max_message_len(24)
+        .with_backtrace_capture(true)
+        .with_location_capture(true)
+        .with_timestamp_capture(false)
+        .with_sanitized_messages(true)
+        .with_interned_messages(true)
+        .with_redactor(|message: &str| message.replace("secret", "******"))
+        .with_display_style(DisplayStyle::CausedBy)
+        .install()
+        .unwrap();
+    assert!(FlatErrorConfig::new().install().is_err());
+    assert_eq!(FlatErrorConfig::global().max_depth(), 2);
+
+    let err = read_config().unwrap_err();
+    assert_eq!(err.to_string(), "no such file: /etc/*****…");
+    assert_eq!(err.original_message_len(), Some(30));
+    assert_eq!(err.location().unwrap().file(), file!());
+    assert!(err.field(BACKTRACE_FIELD).is_some());
+
+    let first = FlatError::from(IoError::other("captured"));
+    let second = FlatError::from(IoError::other("captured"));
+    assert_ne!(first.location(), second.location());
+    assert_ne!(first.field(BACKTRACE_FIELD), second.field(BACKTRACE_FIELD));
+    assert_eq!(first, second);
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(&first), state.hash_one(&second));
+    assert!(first.eq_error(&IoError::other("captured")));
+    #[cfg(feature = "timestamp")]
+    assert_eq!(err.timestamp(), None);
+
+    let explicit = FlatError::from_any_with(
+        &IoError::other("kept\nline, as it is"),
+        FlattenOptions::new()
+            .with_sanitized_messages(false)
+            .with_max_message_len(32),
+    );
+    assert_eq!(explicit.to_string(), "kept\nline, as it is");
+    let captured = FlatError::from_any_with(
+        &IoError::other("no such file: /etc/secret.toml"),
+        FlattenOptions::new()
+            .with_debug_capture(true)
+            .with_max_message_len(80),
+    );
+    assert_eq!(
+        captured.debug_repr(),
+        Some(r#"Custom { kind: Other, error: "no such file: /etc/******.toml" }"#)
+    );
+    let redacted = FlatError::from_any_redacted(&IoError::other("secret"), &|message: &str| {
+        message.to_uppercase()
+    });
+    assert_eq!(redacted.to_string(), "SECRET");
+
+    let boxed: Box<dyn Error + Send + Sync> = "boxed\nerror".into();
+    let err = FlatError::from(boxed);
+    assert_eq!(err.to_string(), r"boxed\nerror");
+    assert_eq!(err.location().unwrap().file(), file!());
+
+    let first = FlatError::from_any(&IoError::other("interned"));
+    let second = FlatError::from_any(&IoError::other("interned"));
+    assert!(FlattenOptions::new().intern_messages());
+    assert_eq!(
+        first.chain_messages().next().unwrap().as_ptr(),
+        second.chain_messages().next().unwrap().as_ptr()
+    );
+    let first = read_config().unwrap_err();
+    let second = read_config().unwrap_err();
+    assert_eq!(
+        first.chain_messages().next().unwrap().as_ptr(),
+        second.chain_messages().next().unwrap().as_ptr()
+    );
+
+    let nested = FlatError::new("a").context("b").context("c").context("d");
+    let err = FlatError::from_any(&nested);
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        ["d", "c", "b", "… 1 more"]
+    );
+    let err = FlatError::from_any_with(&nested, FlattenOptions::new().with_max_depth(3));
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        ["d", "c", "b", "a"]
+    );
+
+    assert_eq!(DisplayStyle::configured(), DisplayStyle::CausedBy);
+    assert_eq!(DisplayStyle::default(), DisplayStyle::Compact);
+}
@@ -0,0 +1,104 @@
+use flat_error::{DisplayStyle, FlatError, OptionExt, ResultExt};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_context_layers_source() {
+    let inner = FlatError::from(IoError::from(ErrorKind::NotFound));
+    let err = inner.clone().context("reading config file");
+    assert_eq!(err.to_string(), "reading config file".to_string());
+    assert_eq!(err.original_type_name(), "flat_error::FlatError");
+    assert_eq!(err.flat_source(), Some(&inner));
+}
+
+#[test]
+fn test_context_stacks() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .context("starting server");
+    assert_eq!(
+        format!("{err:#}"),
+        "starting server (source: reading config file, original type: `flat_error::FlatError`)"
+            .to_string()
+    );
+}
+
+#[test]
+fn test_result_context() {
+    let result: Result<(), IoError> = Err(IoError::from(ErrorKind::NotFound));
+    let err = result.context("reading config file").unwrap_err();
+    assert_eq!(err.to_string(), "reading config file".to_string());
+    assert_eq!(
+        err.flat_source().map(|e| e.original_type_name()),
+        Some("std::io::error::Error")
+    );
+}
+
+#[test]
+fn test_result_with_context_is_lazy() {
+    let result: Result<u32, FlatError> = Ok(1);
+    let value = result
+        .with_context(|| -> String { panic!("context should not be evaluated") })
+        .unwrap();
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_caused_by() {
+    let err = FlatError::new("request failed")
+        .caused_by(FlatError::new("connection reset").context("sending request"));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "request failed: sending request: connection reset"
+    );
+}
+
+#[test]
+fn test_caused_by_appends_to_root() {
+    let err = FlatError::new("a")
+        .caused_by(FlatError::new("b"))
+        .caused_by(FlatError::new("c").caused_by(FlatError::new("d")));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "a: b: c: d"
+    );
+    assert_eq!(
+        err.flat_source(),
+        Some(&FlatError::new("b").caused_by(FlatError::new("c").caused_by(FlatError::new("d"))))
+    );
+}
+
+#[test]
+fn test_with_source() {
+    let err = FlatError::new("loading config").with_source(&IoError::from(ErrorKind::NotFound));
+    assert_eq!(
+        err.flat_source().and_then(|e| e.io_kind()),
+        Some(ErrorKind::NotFound)
+    );
+    assert_eq!(
+        err.flat_source().map(|e| e.to_string()),
+        Some("entity not found".to_string())
+    );
+}
+
+#[test]
+fn test_option_ok_or_flat() {
+    assert_eq!(Some(1).ok_or_flat("missing"), Ok(1));
+    let err = None::<u8>.ok_or_flat(format_args!("missing field {}", "port"));
+    assert_eq!(err, Err(FlatError::new("missing field port")));
+}
+
+#[test]
+fn test_option_ok_or_flat_with_is_lazy() {
+    let value = Some(1).ok_or_flat_with(|| -> &str { panic!("should not be called") });
+    assert_eq!(value, Ok(1));
+    let err = None::<u8>
+        .ok_or_flat_with(|| "missing field port")
+        .unwrap_err();
+    assert_eq!(err.to_string(), "missing field port");
+    assert!(err.flat_source().is_none());
+}
@@ -0,0 +1,61 @@
+use flat_error::{FlatError, Severity};
+use std::{
+    env, fs,
+    io::{Error as IoError, ErrorKind},
+    path::PathBuf,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn report_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("flat-error-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_write_crash_report() {
+    let dir = report_dir("crash");
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .with_field("path", "/etc/app.toml")
+        .context("reading config file")
+        .with_code("E1")
+        .with_severity(Severity::Critical);
+
+    let path = err.write_crash_report(&dir).unwrap();
+    let report = fs::read_to_string(&path).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(path.parent(), Some(dir.as_path()));
+    let name = path.file_name().unwrap().to_str().unwrap();
+    assert!(name.starts_with("crash-report-"));
+    assert!(name.ends_with(&format!("-{}.txt", std::process::id())));
+
+    assert!(report.starts_with("Crash Report\n"));
+    assert!(report.contains(&format!("Process id:   {}\n", std::process::id())));
+    assert!(report.contains(&format!("OS:           {} ", env::consts::OS)));
+    assert!(report.contains(
+        "- reading config file\n  type: flat_error::FlatError\n  severity: critical\n  code: E1\n"
+    ));
+    assert!(report.contains(
+        "  - entity not found\n    type: std::io::error::Error\n    severity: error\n    path: /etc/app.toml\n"
+    ));
+}
+
+#[test]
+fn test_write_crash_report_missing_dir() {
+    let dir = env::temp_dir()
+        .join("flat-error-crash-missing")
+        .join("nested");
+    let err = FlatError::new("failed");
+    assert_eq!(
+        err.write_crash_report(&dir).unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+}
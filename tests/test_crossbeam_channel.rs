@@ -0,0 +1,50 @@
+#![cfg(feature = "crossbeam-channel")]
+
+use crossbeam_channel::{bounded, Select};
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_send_errors() {
+    fn send(tx: &crossbeam_channel::Sender<String>) -> Result<(), FlatError> {
+        tx.try_send(String::from("payload"))?;
+        Ok(())
+    }
+    let (tx, rx) = bounded(0);
+    assert_eq!(
+        send(&tx).unwrap_err().to_string(),
+        "sending on a full channel"
+    );
+
+    drop(rx);
+    let err = FlatError::from(tx.send(String::from("payload")).unwrap_err());
+    assert_eq!(err.to_string(), "sending on a disconnected channel");
+}
+
+#[test]
+fn test_recv_errors() {
+    let (tx, rx) = bounded::<u8>(1);
+    let err = FlatError::from(rx.try_recv().unwrap_err());
+    assert_eq!(err.to_string(), "receiving on an empty channel");
+
+    drop(tx);
+    let err = FlatError::from(rx.recv().unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "receiving on an empty and disconnected channel"
+    );
+}
+
+#[test]
+fn test_select_timeout_error() {
+    let (_tx, rx) = bounded::<u8>(1);
+    let mut select = Select::new();
+    let _ = select.recv(&rx);
+    let err = FlatError::from(select.select_timeout(Duration::from_millis(1)).unwrap_err());
+    assert_eq!(err.to_string(), "timed out waiting on select");
+}
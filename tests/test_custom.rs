@@ -0,0 +1,74 @@
+use flat_error::{ErrorClass, FlatError, FlatErrorBuilder, FlattenCustom, Severity};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct ReplicaLag {
+    seconds: u32,
+    source: IoError,
+}
+
+impl Display for ReplicaLag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "replica is {} seconds behind", self.seconds)
+    }
+}
+
+impl Error for ReplicaLag {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl FlattenCustom for ReplicaLag {
+    fn flatten_custom(&self, builder: FlatErrorBuilder) -> FlatErrorBuilder {
+        let builder = builder
+            .class(ErrorClass::Unavailable)
+            .field("lag_seconds", self.seconds);
+        if self.seconds > 60 {
+            builder.severity(Severity::Critical)
+        } else {
+            builder
+        }
+    }
+}
+
+fn lag(seconds: u32) -> ReplicaLag {
+    ReplicaLag {
+        seconds,
+        source: IoError::from(ErrorKind::TimedOut),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_custom_keeps_default_flattening() {
+    let err = FlatError::from_custom(&lag(5));
+    assert_eq!(err.to_string(), "replica is 5 seconds behind");
+    assert_eq!(err.class(), ErrorClass::Unavailable);
+    assert_eq!(err.field("lag_seconds"), Some("5"));
+    assert_eq!(err.severity(), Severity::Error);
+    assert_eq!(
+        err.flat_source().unwrap().io_kind(),
+        Some(ErrorKind::TimedOut)
+    );
+    assert!(err.is_exact::<ReplicaLag>());
+}
+
+#[test]
+fn test_from_custom_differs_from_from_error() {
+    let err = FlatError::from_custom(&lag(90));
+    assert_eq!(err.severity(), Severity::Critical);
+    assert_eq!(FlatError::from_error(&lag(90)).field("lag_seconds"), None);
+}
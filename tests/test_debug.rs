@@ -0,0 +1,54 @@
+use flat_error::{FlatError, Severity};
+use pretty_assertions::assert_eq;
+use std::panic::Location;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_debug_compact() {
+    let err = FlatError::new("entity not found").context("reading config file");
+    assert_eq!(
+        format!("{err:?}"),
+        "FlatError { message: \"reading config file\", type: \"flat_error::FlatError\", \
+         source: FlatError { message: \"entity not found\", type: \"flat_error::FlatError\" } }"
+    );
+}
+
+#[test]
+fn test_debug_pretty_all_fields() {
+    let err = FlatError::new("disk full")
+        .with_field("device", "sda1")
+        .with_severity(Severity::Critical)
+        .with_message_key("disk.full", [("device", "sda1")])
+        .with_code("E1042");
+    assert_eq!(
+        format!("{err:#?}"),
+        r#"FlatError {
+    message: "disk full",
+    type: "flat_error::FlatError",
+    code: "E1042",
+    severity: Critical,
+    fields: {
+        "device": "sda1",
+    },
+    message_key: "disk.full",
+}"#
+    );
+}
+
+#[test]
+fn test_debug_location() {
+    let location = Location::caller();
+    let err = FlatError::builder()
+        .message("failed")
+        .location(location)
+        .build();
+    assert_eq!(
+        format!("{err:?}"),
+        format!(
+            "FlatError {{ message: \"failed\", type: \"flat_error::FlatError\", location: {location} }}"
+        )
+    );
+}
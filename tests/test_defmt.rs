@@ -0,0 +1,24 @@
+#![cfg(feature = "defmt")]
+
+use flat_error::{FlatError, FlatErrorFixed};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+// There is no global logger on the host, so these tests only ensure the implementations exist.
+fn assert_format<T: defmt::Format>() {}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flat_error_is_format() {
+    assert_format::<FlatError>();
+}
+
+#[test]
+fn test_flat_error_fixed_is_format() {
+    assert_format::<FlatErrorFixed<32>>();
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "derive")]
+
+use flat_error::{assert_extended_error, ExtendedError, FlatError};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    io::{Error as IoError, ErrorKind},
+    num::ParseIntError,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug, ExtendedError)]
+#[error("record {id} is invalid: {reason}")]
+struct RecordError {
+    id: u32,
+    reason: String,
+}
+
+#[derive(Debug, ExtendedError)]
+#[error("{0} of {1} {{items}}")]
+struct CountError(usize, usize);
+
+#[derive(Debug, ExtendedError)]
+#[error("nothing to do")]
+struct NothingError;
+
+#[derive(Debug, ExtendedError)]
+#[error("loading {name}")]
+struct LoadError {
+    name: String,
+    source: FlatError,
+}
+
+#[derive(Debug, ExtendedError)]
+#[error("limit is {}", limit * 2)]
+struct LimitError {
+    limit: u32,
+}
+
+#[derive(Debug, ExtendedError)]
+#[error("wrapped {0:?}")]
+struct Wrapped<T: std::fmt::Debug>(T);
+
+#[derive(Debug, ExtendedError)]
+enum AppError {
+    #[error("bad number")]
+    Number(#[from] ParseIntError),
+    #[error("i/o failed")]
+    Io(#[from(IoError, std::fmt::Error)] FlatError),
+    #[error(transparent)]
+    Record(#[from] RecordError),
+    #[error("stopped")]
+    Stopped,
+}
+
+#[derive(Debug, ExtendedError)]
+enum Never {}
+
+assert_extended_error!(
+    RecordError,
+    CountError,
+    NothingError,
+    LoadError,
+    LimitError,
+    Wrapped<u8>,
+    AppError,
+    Never
+);
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_named_fields_display() {
+    let err = RecordError {
+        id: 7,
+        reason: "missing name".to_string(),
+    };
+    assert_eq!(err.to_string(), "record 7 is invalid: missing name");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_positional_fields_display() {
+    assert_eq!(CountError(3, 5).to_string(), "3 of 5 {items}");
+    assert_eq!(NothingError.to_string(), "nothing to do");
+    assert_eq!(LimitError { limit: 4 }.to_string(), "limit is 8");
+    assert_eq!(Wrapped("x").to_string(), r#"wrapped "x""#);
+}
+
+#[test]
+fn test_source_field() {
+    let err = LoadError {
+        name: "profile".to_string(),
+        source: FlatError::new("entity not found"),
+    };
+    assert_eq!(err.to_string(), "loading profile");
+    assert_eq!(err.source().unwrap().to_string(), "entity not found");
+}
+
+#[test]
+fn test_from_direct() {
+    let err = AppError::from("x".parse::<u8>().unwrap_err());
+    assert_eq!(err.to_string(), "bad number");
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "invalid digit found in string"
+    );
+}
+
+#[test]
+fn test_from_flattened() {
+    let err = AppError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.to_string(), "i/o failed");
+    let source = err.source().unwrap().downcast_ref::<FlatError>().unwrap();
+    assert_eq!(source.io_kind(), Some(ErrorKind::NotFound));
+
+    let err = AppError::from(std::fmt::Error);
+    assert!(matches!(&err, AppError::Io(flat) if flat.original_type_short_name() == "Error"));
+}
+
+#[test]
+fn test_transparent() {
+    let err = AppError::from(RecordError {
+        id: 1,
+        reason: "empty".to_string(),
+    });
+    assert_eq!(err.to_string(), "record 1 is invalid: empty");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_clone_and_eq() {
+    let err = AppError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.clone(), err);
+    assert_ne!(err, AppError::Stopped);
+    assert_eq!(AppError::Stopped, AppError::Stopped);
+    assert_ne!(CountError(1, 2), CountError(1, 3));
+    assert_eq!(NothingError.clone(), NothingError);
+}
+
+#[test]
+fn test_flatten_derived_error() {
+    let err = FlatError::from_error(&LoadError {
+        name: "profile".to_string(),
+        source: FlatError::new("entity not found"),
+    });
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        vec!["loading profile", "entity not found"]
+    );
+}
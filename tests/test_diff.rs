@@ -0,0 +1,89 @@
+use flat_error::{FlatError, Severity};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn denied() -> FlatError {
+    FlatError::from(IoError::new(ErrorKind::PermissionDenied, "access denied"))
+        .context("reading settings")
+        .context("opening config")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_no_differences() {
+    let diff = denied().diff(&denied());
+    assert!(diff.is_empty());
+    assert_eq!(diff.len(), 0);
+    assert_eq!(diff.to_string(), "no differences");
+}
+
+#[test]
+fn test_differing_values() {
+    let actual = FlatError::new("disk full")
+        .with_severity(Severity::Critical)
+        .context("saving file");
+    let expected = FlatError::new("disk empty").context("saving file");
+    let diff = actual.diff(&expected);
+    assert_eq!(
+        diff.iter()
+            .map(|d| (d.frame_index(), d.field(), d.actual(), d.expected()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                Some(1),
+                Some("message"),
+                Some(r#""disk full""#),
+                Some(r#""disk empty""#)
+            ),
+            (Some(1), Some("severity"), Some("Critical"), Some("Error")),
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "frame 1, message: expected \"disk empty\", found \"disk full\"\n\
+         frame 1, severity: expected Error, found Critical"
+    );
+}
+
+#[test]
+fn test_missing_frame() {
+    let actual = denied();
+    let expected = denied().caused_by(FlatError::new("file locked"));
+    let diff = actual.diff(&expected);
+    assert_eq!(diff.len(), 1);
+    assert_eq!(
+        diff.to_string(),
+        r#"frame 3: expected "file locked" (flat_error::FlatError), found none"#
+    );
+}
+
+#[test]
+fn test_unexpected_frame() {
+    let actual = FlatError::new("timed out").context("connecting");
+    let expected = FlatError::new_static("connecting");
+    let diff = actual.diff(&expected);
+    let difference = diff.iter().last().unwrap();
+    assert_eq!(difference.frame_index(), Some(1));
+    assert_eq!(difference.field(), None);
+    assert_eq!(
+        difference.actual(),
+        Some(r#""timed out" (flat_error::FlatError)"#)
+    );
+    assert_eq!(difference.expected(), None);
+}
+
+#[test]
+fn test_category() {
+    let actual = FlatError::new("failed").with_category(1);
+    let expected = FlatError::new("failed").with_category(2);
+    let diff = actual.diff(&expected);
+    assert_eq!(diff.to_string(), "category: expected 2, found 1");
+    assert_eq!((&diff).into_iter().next().unwrap().frame_index(), None);
+}
@@ -0,0 +1,177 @@
+use flat_error::{DisplayStyle, FlatError};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn chain() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .context("starting server")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_display_compact() {
+    assert_eq!(
+        chain().display_with(DisplayStyle::Compact).to_string(),
+        "starting server: reading config file: entity not found".to_string()
+    );
+}
+
+#[test]
+fn test_display_caused_by() {
+    assert_eq!(
+        chain().display_with(DisplayStyle::CausedBy).to_string(),
+        "starting server\ncaused by: reading config file\ncaused by: entity not found".to_string()
+    );
+}
+
+#[test]
+fn test_display_tree() {
+    assert_eq!(
+        chain().display_with(DisplayStyle::Tree).to_string(),
+        "starting server\n└─ reading config file\n   └─ entity not found".to_string()
+    );
+}
+
+#[test]
+fn test_display_no_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    for style in [
+        DisplayStyle::Compact,
+        DisplayStyle::CausedBy,
+        DisplayStyle::Tree,
+    ] {
+        assert_eq!(
+            err.display_with(style).to_string(),
+            "entity not found".to_string()
+        );
+    }
+}
+
+#[test]
+fn test_logfmt_single() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound)).with_code("E1");
+    assert_eq!(
+        err.to_logfmt(),
+        "msg=\"entity not found\" type=std::io::error::Error code=E1 severity=error".to_string()
+    );
+}
+
+#[test]
+fn test_logfmt_chain() {
+    let err = chain();
+    assert_eq!(
+        err.to_logfmt(),
+        "msg=\"starting server\" type=flat_error::FlatError severity=error \
+         source.msg=\"reading config file\" source.type=flat_error::FlatError \
+         source.severity=error source.source.msg=\"entity not found\" \
+         source.source.type=std::io::error::Error source.source.severity=error"
+            .to_string()
+    );
+}
+
+#[test]
+fn test_logfmt_escapes() {
+    let err = FlatError::from(IoError::other("bad \"value\"\nnext=line")).with_field("empty", "");
+    assert_eq!(
+        err.to_logfmt(),
+        "msg=\"bad \\\"value\\\"\\nnext=line\" type=std::io::error::Error severity=error \
+         field.empty=\"\""
+            .to_string()
+    );
+}
+
+#[test]
+fn test_snapshot_chain() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .with_field("path", "/etc/app.toml")
+        .with_field("mode", "read")
+        .with_code("E1042")
+        .context("reading config file")
+        .with_message_key("config.read", [("path", "/etc/app.toml")]);
+    assert_eq!(
+        err.display_with(DisplayStyle::Snapshot).to_string(),
+        "reading config file\n  type: FlatError\n  severity: error\n  message_key: config.read\n  \
+         message_arg.path: /etc/app.toml\ncaused by: entity not found\n  type: Error\n  \
+         code: E1042\n  severity: error\n  field.mode: read\n  field.path: /etc/app.toml"
+    );
+}
+
+#[test]
+fn test_snapshot_type_names() {
+    let err = FlatError::builder()
+        .message("failed")
+        .type_name("app::worker::Error<alloc::string::String>::h0123456789abcdef")
+        .source(
+            FlatError::builder()
+                .type_name("app::hash::hasher::Error")
+                .build(),
+        )
+        .build();
+    assert_eq!(
+        err.display_with(DisplayStyle::Snapshot).to_string(),
+        "failed\n  type: Error<String>\n  severity: error\ncaused by: \n  type: Error\n  \
+         severity: error"
+    );
+}
+
+#[test]
+fn test_display_width_and_alignment() {
+    let err = FlatError::new("not found");
+    assert_eq!(format!("[{err:12}]"), "[not found   ]");
+    assert_eq!(format!("[{err:>12}]"), "[   not found]");
+    assert_eq!(format!("[{err:*^13}]"), "[**not found**]");
+    assert_eq!(format!("[{err:4}]"), "[not found]");
+}
+
+#[test]
+fn test_display_precision_truncates() {
+    let err = FlatError::new("entité introuvable");
+    assert_eq!(format!("{err:.6}"), "entité");
+    assert_eq!(format!("[{err:>8.3}]"), "[     ent]");
+}
+
+#[test]
+fn test_display_alternate_with_flags() {
+    let err = FlatError::new("not found").with_code("E1");
+    assert_eq!(
+        format!("{err:#}"),
+        "not found (code: `E1`, original type: `flat_error::FlatError`)"
+    );
+    assert_eq!(format!("{err:#.15}"), "not found (code");
+    assert_eq!(
+        format!("{err:.<#69}|"),
+        "not found (code: `E1`, original type: `flat_error::FlatError`).......|"
+    );
+}
+
+#[test]
+fn test_format_chain() {
+    let err = chain();
+    assert_eq!(
+        err.format_chain(" | "),
+        "starting server | reading config file | entity not found"
+    );
+    assert_eq!(
+        err.format_chain(": "),
+        err.display_with(DisplayStyle::Compact).to_string()
+    );
+    assert_eq!(FlatError::new("alone").format_chain(" | "), "alone");
+}
+
+#[test]
+fn test_write_chain() {
+    let mut buffer = String::from("error=");
+    chain().write_chain(&mut buffer, "/").unwrap();
+    assert_eq!(
+        buffer,
+        "error=starting server/reading config file/entity not found"
+    );
+}
@@ -0,0 +1,69 @@
+use flat_error::{DynExtendedError, FlatError};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodedError(u32);
+
+impl Display for CodedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "error code {}", self.0)
+    }
+}
+
+impl Error for CodedError {}
+
+fn errors() -> Vec<Box<dyn DynExtendedError>> {
+    vec![
+        Box::new(CodedError(1)),
+        Box::new(FlatError::new("error code 1")),
+        Box::new(CodedError(2)),
+    ]
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_dyn_clone() {
+    let errors = errors();
+    let copies = errors.clone();
+    assert_eq!(
+        copies.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        ["error code 1", "error code 1", "error code 2"]
+    );
+    assert_eq!(copies[2].downcast_ref::<CodedError>(), Some(&CodedError(2)));
+}
+
+#[test]
+fn test_dyn_eq_requires_same_type() {
+    let errors = errors();
+    assert_eq!(errors.clone(), errors);
+    assert!(*errors[0] == *errors.clone()[0]);
+    assert!(*errors[0] != *errors[1]);
+    assert!(*errors[0] != *errors[2]);
+    assert!(errors[0].dyn_eq(&CodedError(1)));
+}
+
+#[test]
+fn test_dyn_downcast() {
+    let errors = errors();
+    assert!(errors[1].is::<FlatError>());
+    assert!(!errors[1].is::<CodedError>());
+    assert!(errors[0].downcast_ref::<FlatError>().is_none());
+}
+
+#[test]
+fn test_dyn_as_error() {
+    let errors = errors();
+    let source: &dyn Error = errors[0].as_ref();
+    assert_eq!(FlatError::from_any(source).to_string(), "error code 1");
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "serde")]
+
+use flat_error::{EcsDocument, FlatError};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_ecs_document_minimal() {
+    let document = FlatError::from(IoError::from(ErrorKind::NotFound)).ecs_document();
+    assert_eq!(document.error.error_type, "std::io::error::Error");
+    assert_eq!(document.error.message, "entity not found");
+    assert_eq!(document.error.code, None);
+    assert_eq!(
+        document.error.stack_trace.as_deref(),
+        Some("entity not found")
+    );
+    assert!(document.labels.is_empty());
+    assert_eq!(
+        serde_json::to_string(&document).unwrap(),
+        r#"{"error":{"type":"std::io::error::Error","message":"entity not found","stack_trace":"entity not found"}}"#
+    );
+}
+
+#[test]
+fn test_ecs_document_stack_trace_includes_chain() {
+    let document = FlatError::new("disk full")
+        .context("writing block")
+        .context("saving file")
+        .ecs_document();
+    assert_eq!(document.error.message, "saving file");
+    assert_eq!(
+        document.error.stack_trace.as_deref(),
+        Some("saving file\ncaused by: writing block\ncaused by: disk full")
+    );
+}
+
+#[test]
+fn test_ecs_document_backtrace_in_stack_trace() {
+    let document = FlatError::new("panicked")
+        .with_field("backtrace", "0: main\n1: start")
+        .with_field("thread.name", "worker-1")
+        .ecs_document();
+    assert_eq!(
+        document.error.stack_trace.as_deref(),
+        Some("panicked\n0: main\n1: start")
+    );
+    assert_eq!(document.labels.len(), 1);
+    assert_eq!(document.labels["thread_name"], "worker-1");
+}
+
+#[test]
+fn test_ecs_document_round_trip() {
+    let document = EcsDocument::from(
+        &FlatError::new("no such user")
+            .with_code("E0404")
+            .with_field("user_id", 42),
+    );
+    let json = serde_json::to_string(&document).unwrap();
+    assert_eq!(
+        serde_json::from_str::<EcsDocument>(&json).unwrap(),
+        document
+    );
+}
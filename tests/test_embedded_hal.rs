@@ -0,0 +1,102 @@
+#![cfg(feature = "embedded-hal")]
+
+use embedded_hal::{
+    digital,
+    i2c::{self, ErrorKind as I2cErrorKind, NoAcknowledgeSource},
+    spi::{self, ErrorKind as SpiErrorKind},
+};
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+
+#[derive(Debug)]
+enum DriverError {
+    Nack,
+}
+
+impl i2c::Error for DriverError {
+    fn kind(&self) -> I2cErrorKind {
+        I2cErrorKind::NoAcknowledge(NoAcknowledgeSource::Data)
+    }
+}
+
+struct Sensor;
+
+impl i2c::ErrorType for Sensor {
+    type Error = FlatError;
+}
+
+#[test]
+fn test_i2c_kinds_round_trip() {
+    for kind in [
+        I2cErrorKind::Bus,
+        I2cErrorKind::ArbitrationLoss,
+        I2cErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+        I2cErrorKind::NoAcknowledge(NoAcknowledgeSource::Data),
+        I2cErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown),
+        I2cErrorKind::Overrun,
+        I2cErrorKind::Other,
+    ] {
+        let err = FlatError::from(kind);
+        assert_eq!(err.to_string(), kind.to_string());
+        assert_eq!(err.i2c_error_kind(), Some(kind));
+        assert_eq!(i2c::Error::kind(&err), kind);
+    }
+}
+
+#[test]
+fn test_spi_kinds_round_trip() {
+    for kind in [
+        SpiErrorKind::Overrun,
+        SpiErrorKind::ModeFault,
+        SpiErrorKind::FrameFormat,
+        SpiErrorKind::ChipSelectFault,
+        SpiErrorKind::Other,
+    ] {
+        let err = FlatError::from(kind);
+        assert_eq!(err.spi_error_kind(), Some(kind));
+        assert_eq!(spi::Error::kind(&err), kind);
+        assert_eq!(err.i2c_error_kind(), None);
+    }
+}
+
+#[test]
+fn test_digital_kind() {
+    let err = FlatError::from(digital::ErrorKind::Other);
+    assert_eq!(err.digital_error_kind(), Some(digital::ErrorKind::Other));
+    assert_eq!(err.spi_error_kind(), None);
+}
+
+#[test]
+fn test_kind_found_in_source_chain() {
+    let err = FlatError::from(SpiErrorKind::ModeFault).context("writing display buffer");
+    assert_eq!(err.spi_error_kind(), Some(SpiErrorKind::ModeFault));
+}
+
+#[test]
+fn test_kind_defaults_to_other() {
+    let err = FlatError::new("calibration failed");
+    assert_eq!(i2c::Error::kind(&err), I2cErrorKind::Other);
+    assert_eq!(spi::Error::kind(&err), SpiErrorKind::Other);
+}
+
+#[test]
+fn test_from_driver_error() {
+    let err = FlatError::from_i2c_error(&DriverError::Nack);
+    assert_eq!(err.to_string(), "Nack");
+    assert!(err.original_type_name().ends_with("::DriverError"));
+    assert_eq!(
+        err.i2c_error_kind(),
+        Some(I2cErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))
+    );
+}
+
+#[test]
+fn test_driver_error_type() {
+    fn kind_of<T: i2c::ErrorType>(error: T::Error) -> I2cErrorKind {
+        i2c::Error::kind(&error)
+    }
+    assert_eq!(
+        kind_of::<Sensor>(FlatError::from(I2cErrorKind::Bus)),
+        I2cErrorKind::Bus
+    );
+}
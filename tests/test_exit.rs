@@ -0,0 +1,62 @@
+use flat_error::{ErrorClass, FlatError, FlatExit};
+use pretty_assertions::assert_eq;
+use std::{
+    io::{Error as IoError, ErrorKind},
+    process::{ExitCode, Termination},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn exit() -> FlatExit {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .into()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_renders_pretty_report() {
+    assert_eq!(
+        format!("{:?}", exit()),
+        "reading config file\n\nCaused by:\n      entity not found"
+    );
+}
+
+#[test]
+fn test_exit_code() {
+    assert_eq!(exit().exit_code(), 66);
+    assert_eq!(exit().with_exit_code(74).exit_code(), 74);
+    assert_eq!(exit().with_exit_code(74).report(), ExitCode::from(74));
+}
+
+#[test]
+fn test_from_report() {
+    let err = FlatError::new("failed");
+    assert_eq!(FlatExit::from(err.clone().report()).into_error(), err);
+}
+
+#[test]
+fn test_exit_code_from_class() {
+    let err = FlatError::new("busy").with_class(ErrorClass::Unavailable);
+    assert_eq!(err.exit_code(), 69);
+    assert_eq!(FlatExit::from(err).report(), ExitCode::from(69));
+    assert_eq!(
+        FlatError::from(IoError::from(ErrorKind::PermissionDenied)).exit_code(),
+        77
+    );
+}
+
+#[test]
+fn test_exit_code_unclassified() {
+    assert_eq!(FlatError::new("failed").exit_code(), 1);
+    assert_eq!(FlatExit::from(FlatError::new("failed")).exit_code(), 1);
+    assert_eq!(
+        FlatError::from(IoError::other("disk on fire")).exit_code(),
+        74
+    );
+}
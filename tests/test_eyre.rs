@@ -0,0 +1,30 @@
+#![cfg(feature = "eyre")]
+
+use flat_error::{eyre::IntoEyre, FlatError};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_report_into_flat() {
+    let report = eyre::eyre!("disk full").wrap_err("could not save file");
+    let err = FlatError::from(report);
+    assert_eq!(err.to_string(), "could not save file".to_string());
+    assert_eq!(
+        err.flat_source().map(|e| e.to_string()),
+        Some("disk full".to_string())
+    );
+}
+
+#[test]
+fn test_flat_into_report() {
+    let err = FlatError::from(eyre::eyre!("disk full").wrap_err("could not save file"));
+    let report = err.clone().into_eyre();
+    assert_eq!(
+        report.chain().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec!["could not save file".to_string(), "disk full".to_string()]
+    );
+    assert_eq!(report.downcast_ref::<FlatError>(), Some(&err));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "ffi")]
+
+use flat_error::{
+    ffi::{
+        flat_error_chain_frame, flat_error_chain_free, flat_error_chain_len,
+        FLAT_ERROR_SEVERITY_CRITICAL, FLAT_ERROR_SEVERITY_ERROR,
+    },
+    FlatError, Severity,
+};
+use pretty_assertions::assert_eq;
+use std::{
+    ffi::CStr,
+    io::{Error as IoError, ErrorKind},
+    ptr::null_mut,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn text(s: *const std::ffi::c_char) -> Option<&'static str> {
+    if s.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(s) }.to_str().unwrap())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_chain_frames() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .with_code("E1042")
+        .context("reading\0config")
+        .with_severity(Severity::Critical);
+    let chain = err.to_ffi();
+    unsafe {
+        assert_eq!(flat_error_chain_len(chain), 2);
+
+        let frame = &*flat_error_chain_frame(chain, 0);
+        assert_eq!(text(frame.message), Some("reading\u{FFFD}config"));
+        assert_eq!(text(frame.type_name), Some("flat_error::FlatError"));
+        assert_eq!(text(frame.code), None);
+        assert_eq!(frame.severity, FLAT_ERROR_SEVERITY_CRITICAL);
+
+        let frame = &*flat_error_chain_frame(chain, 1);
+        assert_eq!(text(frame.message), Some("entity not found"));
+        assert_eq!(text(frame.type_name), Some("std::io::error::Error"));
+        assert_eq!(text(frame.code), Some("E1042"));
+        assert_eq!(frame.severity, FLAT_ERROR_SEVERITY_ERROR);
+
+        assert!(flat_error_chain_frame(chain, 2).is_null());
+        flat_error_chain_free(chain);
+    }
+}
+
+#[test]
+fn test_null_chain() {
+    unsafe {
+        assert_eq!(flat_error_chain_len(null_mut()), 0);
+        assert!(flat_error_chain_frame(null_mut(), 0).is_null());
+        flat_error_chain_free(null_mut());
+    }
+}
@@ -0,0 +1,61 @@
+use flat_error::FlatError;
+use pretty_assertions::{assert_eq, assert_ne};
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn not_found() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_no_fields_by_default() {
+    assert_eq!(not_found().fields().count(), 0);
+    assert_eq!(not_found().field("path"), None);
+}
+
+#[test]
+fn test_with_field_replaces_value() {
+    let err = not_found()
+        .with_field("path", "/tmp/a")
+        .with_field("attempt", 1)
+        .with_field("path", "/tmp/b");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("path", "/tmp/b"), ("attempt", "1")]
+    );
+}
+
+#[test]
+fn test_fields_participate_in_eq() {
+    let err = not_found().with_field("path", "/tmp/a");
+    assert_eq!(err.clone(), err);
+    assert_ne!(not_found(), err);
+    assert_ne!(not_found().with_field("path", "/tmp/b"), err);
+}
+
+#[test]
+fn test_fields_survive_context() {
+    let err = not_found().with_field("path", "/tmp/a").context("loading");
+    assert_eq!(err.field("path"), None);
+    assert_eq!(
+        err.flat_source().and_then(|e| e.field("path")),
+        Some("/tmp/a")
+    );
+}
+
+#[test]
+fn test_fields_of_clone_are_independent() {
+    let err = not_found().with_field("path", "/tmp/a");
+    let copy = err.clone().with_field("path", "/tmp/b").with_code("E1");
+    assert_eq!(err.field("path"), Some("/tmp/a"));
+    assert_eq!(err.code(), None);
+    assert_eq!(copy.field("path"), Some("/tmp/b"));
+    assert_eq!(copy.code(), Some("E1"));
+}
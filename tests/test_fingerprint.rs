@@ -0,0 +1,118 @@
+use flat_error::FlatError;
+use pretty_assertions::{assert_eq, assert_ne};
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_fingerprint_is_stable() {
+    assert_eq!(
+        FlatError::new_static("disk full").fingerprint(),
+        0xd8cd_4284_ea04_b362
+    );
+}
+
+#[test]
+fn test_fingerprint_masks_numbers() {
+    assert_eq!(
+        FlatError::new("retry 1 of 3").fingerprint(),
+        FlatError::new("retry 10 of 30").fingerprint()
+    );
+    assert_ne!(
+        FlatError::new("retry 1 of 3").fingerprint(),
+        FlatError::new("retry of 3").fingerprint()
+    );
+}
+
+#[test]
+fn test_fingerprint_includes_chain() {
+    let err = FlatError::new_static("disk full");
+    assert_ne!(
+        err.fingerprint(),
+        err.clone().context("saving file").fingerprint()
+    );
+    assert_ne!(
+        FlatError::new_static("a").context("b").fingerprint(),
+        FlatError::new_static("ab").fingerprint()
+    );
+}
+
+#[test]
+fn test_fingerprint_includes_type_name() {
+    let io = FlatError::from(IoError::other("disk full"));
+    assert_ne!(
+        io.fingerprint(),
+        FlatError::new_static("disk full").fingerprint()
+    );
+    assert_eq!(
+        io.fingerprint(),
+        FlatError::from_parts("Error", "disk full", None).fingerprint()
+    );
+}
+
+#[test]
+fn test_fingerprint_ignores_metadata() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(
+        err.fingerprint(),
+        err.clone().with_field("path", "/tmp/a").fingerprint()
+    );
+}
+
+#[test]
+fn test_normalized_message_numbers() {
+    let err = FlatError::new("retry 3 of 5 after 1.5s, exit code -2 (utf8, v2)");
+    assert_eq!(
+        err.normalized_message(),
+        "retry <n> of <n> after <n>s, exit code -<n> (utf8, v2)"
+    );
+}
+
+#[test]
+fn test_normalized_message_paths() {
+    for (message, expected) in [
+        ("failed to read /tmp/a", "failed to read <path>"),
+        ("failed to read \"/tmp/b c\"", "failed to read \"<path> c\""),
+        ("opening ./config.toml: denied", "opening <path>: denied"),
+        ("opening C:\\Users\\app.log", "opening <path>"),
+        ("dividing a / b", "dividing a / b"),
+    ] {
+        assert_eq!(FlatError::new(message).normalized_message(), expected);
+    }
+}
+
+#[test]
+fn test_normalized_message_addresses() {
+    for (message, expected) in [
+        ("connecting to 127.0.0.1", "connecting to <addr>"),
+        ("connecting to 10.0.0.1:8080.", "connecting to <addr>."),
+        ("connecting to [::1]:443", "connecting to <addr>"),
+        ("connecting to fe80::1", "connecting to <addr>"),
+        ("segfault at 0x7ffd3a2b", "segfault at <addr>"),
+        ("version 1.2.3", "version <n>"),
+    ] {
+        assert_eq!(FlatError::new(message).normalized_message(), expected);
+    }
+}
+
+#[test]
+fn test_normalized_message_uuids() {
+    let err = FlatError::new("order 7d444840-9dc0-11d1-b245-5ffdce74fad2 not found");
+    assert_eq!(err.normalized_message(), "order <uuid> not found");
+}
+
+#[test]
+fn test_normalized_message_whitespace() {
+    let err = FlatError::new("  line 12\n\tcolumn 4 ");
+    assert_eq!(err.normalized_message(), "  line <n>\n\tcolumn <n> ");
+}
+
+#[test]
+fn test_fingerprint_masks_volatile_values() {
+    assert_eq!(
+        FlatError::new("failed to read /tmp/a").fingerprint(),
+        FlatError::new("failed to read /var/b").fingerprint()
+    );
+}
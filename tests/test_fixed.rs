@@ -0,0 +1,40 @@
+use flat_error::FlatErrorFixed;
+use pretty_assertions::{assert_eq, assert_ne};
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_fixed_from_any() {
+    let err: FlatErrorFixed<64> = FlatErrorFixed::from_any(&IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.message(), "entity not found");
+    assert_eq!(err.original_type_name(), "std::io::error::Error");
+    assert!(!err.is_truncated());
+    assert_eq!(
+        format!("{err:#}"),
+        "entity not found (original type: `std::io::error::Error`)".to_string()
+    );
+}
+
+#[test]
+fn test_fixed_truncates_on_char_boundary() {
+    let err: FlatErrorFixed<5> = FlatErrorFixed::new("naïve");
+    assert_eq!(err.message(), "naïv");
+    assert!(err.is_truncated());
+    assert_eq!(err.capacity(), 5);
+    assert_eq!(
+        format!("{err:#}"),
+        "naïv… (original type: `flat_error::fixed::FlatErrorFixed<5>`)".to_string()
+    );
+}
+
+#[test]
+fn test_fixed_eq_ignores_unused_buffer() {
+    let mut a: FlatErrorFixed<8> = FlatErrorFixed::new("abcdef");
+    let b: FlatErrorFixed<8> = FlatErrorFixed::new("abc");
+    assert_ne!(a, b);
+    a = FlatErrorFixed::new("abc");
+    assert_eq!(a, b);
+}
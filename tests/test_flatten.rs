@@ -0,0 +1,87 @@
+use flat_error::{FlatError, Flatten};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flatten() {
+    let err = IoError::from(ErrorKind::NotFound);
+    let flat = err.flatten();
+    assert_eq!(flat, FlatError::from_any(&err));
+    assert_eq!(flat.to_string(), "entity not found");
+    assert!(!flat.has_original());
+}
+
+#[test]
+fn test_flatten_chain_messages() {
+    let messages = [
+        "a",
+        "a message long enough that it cannot be stored inline",
+        "short",
+        "",
+        "another message that is longer than the ones before it",
+    ];
+    let nested = messages
+        .iter()
+        .rev()
+        .fold(None, |source: Option<FlatError>, message| {
+            Some(match source {
+                Some(source) => source.context(*message),
+                None => FlatError::new(*message),
+            })
+        })
+        .unwrap();
+    let flat = FlatError::from_any(&IoError::other(nested));
+    assert_eq!(flat.chain_messages().collect::<Vec<_>>(), messages);
+}
+
+#[test]
+fn test_flatten_dyn() {
+    let err: Box<dyn Error + Send + Sync> = Box::new(IoError::from(ErrorKind::NotFound));
+    let flat = err.as_ref().flatten();
+    assert_eq!(flat.to_string(), "entity not found");
+}
+
+#[test]
+fn test_flatten_in_map_err() {
+    let result: Result<(), FlatError> =
+        Err(IoError::from(ErrorKind::TimedOut)).map_err(|e| e.flatten());
+    assert_eq!(result.unwrap_err().to_string(), "timed out");
+}
+
+#[test]
+fn test_flatten_retaining() {
+    let flat = IoError::from(ErrorKind::NotFound).flatten_retaining();
+    assert!(flat.has_original());
+    assert_eq!(
+        flat.downcast_ref::<IoError>().map(IoError::kind),
+        Some(ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn test_source_of_type_in_chain() {
+    let err = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .context("starting server");
+    assert!(err.downcast_ref::<IoError>().is_none());
+    assert_eq!(
+        err.source_of_type::<IoError>().map(IoError::kind),
+        Some(ErrorKind::NotFound)
+    );
+    assert!(err.source_of_type::<std::fmt::Error>().is_none());
+}
+
+#[test]
+fn test_source_of_type_without_original() {
+    let err = IoError::from(ErrorKind::NotFound)
+        .flatten()
+        .context("starting server");
+    assert!(err.source_of_type::<IoError>().is_none());
+}
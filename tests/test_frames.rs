@@ -0,0 +1,131 @@
+use flat_error::{FlatError, Severity};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn chain() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+        .with_field("path", "/etc/app.toml")
+        .context("reading config file")
+        .with_severity(Severity::Critical)
+        .context("starting server")
+        .with_code("E1")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_frames_in_order() {
+    let err = chain();
+    let frames = err.frames();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(
+        frames
+            .map(|frame| (frame.message(), frame.original_type_short_name()))
+            .collect::<Vec<_>>(),
+        [
+            ("starting server", "FlatError"),
+            ("reading config file", "FlatError"),
+            ("entity not found", "Error"),
+        ]
+    );
+}
+
+#[test]
+fn test_frames_reversed_and_len() {
+    let err = chain();
+    let mut frames = err.frames();
+    assert_eq!(frames.next_back().unwrap().message(), "entity not found");
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames.next().unwrap().message(), "starting server");
+    assert_eq!(frames.next_back().unwrap().message(), "reading config file");
+    assert_eq!(frames.len(), 0);
+    assert!(frames.next().is_none());
+    assert!(frames.next_back().is_none());
+}
+
+#[test]
+fn test_frame_by_index() {
+    let err = chain();
+    assert_eq!(err.frame(0).unwrap().code(), Some("E1"));
+    assert_eq!(err.frame(1).unwrap().severity(), Severity::Critical);
+    let root = err.frame(2).unwrap();
+    assert_eq!(root.field("path"), Some("/etc/app.toml"));
+    assert_eq!(
+        root.fields().collect::<Vec<_>>(),
+        [("path", "/etc/app.toml")]
+    );
+    assert!(err.frame(3).is_none());
+    assert_eq!(err.frames().nth(2), Some(root));
+}
+
+#[test]
+fn test_frame_debug() {
+    let err = FlatError::new("entity not found").context("reading config file");
+    assert_eq!(
+        format!("{:?}", err.frame(0).unwrap()),
+        "FlatError { message: \"reading config file\", type: \"flat_error::FlatError\" }"
+    );
+}
+
+#[test]
+fn test_chain_messages() {
+    let err = chain();
+    assert_eq!(err.chain_messages().len(), 3);
+    assert_eq!(
+        err.chain_messages().map(String::from).collect::<Vec<_>>(),
+        vec!["starting server", "reading config file", "entity not found"]
+    );
+    assert_eq!(err.chain_messages().next_back(), Some("entity not found"));
+    assert_eq!(
+        FlatError::new_static("alone")
+            .chain_messages()
+            .collect::<Vec<_>>(),
+        vec!["alone"]
+    );
+}
+
+#[test]
+fn test_chain_type_names() {
+    let err = chain();
+    assert_eq!(
+        err.chain_type_names().collect::<Vec<_>>(),
+        vec![
+            "flat_error::FlatError",
+            "flat_error::FlatError",
+            "std::io::error::Error"
+        ]
+    );
+}
+
+#[test]
+fn test_find() {
+    let err = chain();
+    let found = err.find(|frame| frame.severity() == Severity::Critical);
+    assert_eq!(
+        found.map(|frame| frame.message()),
+        Some("reading config file")
+    );
+    assert!(err.find(|frame| frame.field("missing").is_some()).is_none());
+}
+
+#[test]
+fn test_any_message_contains() {
+    let err = chain();
+    assert!(err.any_message_contains("starting"));
+    assert!(err.any_message_contains("not found"));
+    assert!(!err.any_message_contains("connection refused"));
+}
+
+#[test]
+fn test_position_of_type() {
+    let err = chain();
+    assert_eq!(err.position_of_type::<FlatError>(), Some(0));
+    assert_eq!(err.position_of_type::<IoError>(), Some(2));
+    assert_eq!(err.position_of_type::<std::fmt::Error>(), None);
+}
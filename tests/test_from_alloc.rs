@@ -0,0 +1,47 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::{alloc::Layout, ffi::CString};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_try_reserve_error() {
+    let err = FlatError::from(Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "memory allocation failed because the computed capacity exceeded the collection's maximum"
+    );
+}
+
+#[test]
+fn test_from_nul_error() {
+    let err = FlatError::from(CString::new("a\0b").unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "nul byte found in provided data at position: 1"
+    );
+}
+
+#[test]
+fn test_from_utf8_error() {
+    fn decode(bytes: Vec<u8>) -> Result<String, FlatError> {
+        Ok(String::from_utf8(bytes)?)
+    }
+    let err = decode(vec![0xff]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid utf-8 sequence of 1 bytes from index 0"
+    );
+    assert_eq!(err.original_type_name(), "alloc::string::FromUtf8Error");
+}
+
+#[test]
+fn test_from_layout_error() {
+    let err = FlatError::from(Layout::from_size_align(8, 3).unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "invalid parameters to Layout::from_size_align"
+    );
+}
@@ -0,0 +1,152 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::{
+    num::{IntErrorKind, NonZeroU8},
+    str::FromStr,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_parse_int_error() {
+    fn parse(s: &str) -> Result<u8, FlatError> {
+        Ok(s.parse::<u8>()?)
+    }
+    let err = parse("256").unwrap_err();
+    assert_eq!(err.to_string(), "number too large to fit in target type");
+    assert_eq!(err.original_type_name(), "core::num::error::ParseIntError");
+    assert_eq!(err.int_error_kind(), Some(IntErrorKind::PosOverflow));
+}
+
+#[test]
+fn test_parse_int_error_kinds() {
+    for (input, kind) in [
+        ("", IntErrorKind::Empty),
+        ("4x", IntErrorKind::InvalidDigit),
+        ("-129", IntErrorKind::NegOverflow),
+        ("128", IntErrorKind::PosOverflow),
+    ] {
+        let err = FlatError::from(input.parse::<i8>().unwrap_err());
+        assert_eq!(err.int_error_kind(), Some(kind));
+    }
+    let err = FlatError::from(NonZeroU8::from_str("0").unwrap_err());
+    assert_eq!(err.int_error_kind(), Some(IntErrorKind::Zero));
+}
+
+#[test]
+fn test_parse_int_error_kind_in_source_chain() {
+    let err = FlatError::new_static("invalid port")
+        .caused_by(FlatError::from("".parse::<u16>().unwrap_err()))
+        .context("reading config");
+    assert_eq!(err.int_error_kind(), Some(IntErrorKind::Empty));
+    assert_eq!(FlatError::new("no kind").int_error_kind(), None);
+}
+
+#[test]
+fn test_from_parse_float_error() {
+    let err = FlatError::from("x".parse::<f64>().unwrap_err());
+    assert_eq!(err.to_string(), "invalid float literal");
+    assert_eq!(err.int_error_kind(), Some(IntErrorKind::InvalidDigit));
+
+    let err = FlatError::from("".parse::<f32>().unwrap_err());
+    assert_eq!(err.int_error_kind(), Some(IntErrorKind::Empty));
+}
+
+#[test]
+fn test_from_try_from_int_error() {
+    let err = FlatError::from(u8::try_from(-1_i32).unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "out of range integral type conversion attempted"
+    );
+    assert!(err.is_exact::<std::num::TryFromIntError>());
+}
+
+#[test]
+fn test_from_utf8_error() {
+    fn decode(bytes: &[u8]) -> Result<&str, FlatError> {
+        Ok(std::str::from_utf8(bytes)?)
+    }
+    let err = decode(&[0x66, 0xff]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid utf-8 sequence of 1 bytes from index 1"
+    );
+}
+
+#[test]
+fn test_from_parse_bool_error() {
+    let err = FlatError::from("yes".parse::<bool>().unwrap_err());
+    assert_eq!(err.to_string(), "provided string was not `true` or `false`");
+}
+
+#[test]
+fn test_from_char_errors() {
+    let err = FlatError::from(char::try_from(0xd800_u32).unwrap_err());
+    assert_eq!(err.to_string(), "converted integer out of range for `char`");
+
+    let err = FlatError::from("ab".parse::<char>().unwrap_err());
+    assert_eq!(err.to_string(), "too many characters in string");
+
+    let err = FlatError::from(char::decode_utf16([0xd800]).next().unwrap().unwrap_err());
+    assert_eq!(err.to_string(), "unpaired surrogate found: d800");
+}
+
+#[test]
+fn test_from_try_from_float_secs_error() {
+    fn timeout(secs: f64) -> Result<std::time::Duration, FlatError> {
+        Ok(std::time::Duration::try_from_secs_f64(secs)?)
+    }
+    let err = timeout(-1.0).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot convert float seconds to Duration: value is negative"
+    );
+    let err = timeout(f64::INFINITY).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot convert float seconds to Duration: value is either too big or NaN"
+    );
+}
+
+#[test]
+fn test_from_fmt_error() {
+    use std::fmt::Write;
+
+    struct Full;
+
+    impl Write for Full {
+        fn write_str(&mut self, _: &str) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+    }
+
+    fn render() -> Result<(), FlatError> {
+        write!(Full, "{}", 1)?;
+        Ok(())
+    }
+    let err = render().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "an error occurred when formatting an argument"
+    );
+    assert!(err.is_exact::<std::fmt::Error>());
+}
+
+#[test]
+fn test_from_infallible() {
+    fn convert<T, U>(value: T) -> Result<U, FlatError>
+    where
+        U: TryFrom<T>,
+        U::Error: Into<FlatError>,
+    {
+        U::try_from(value).map_err(Into::into)
+    }
+    assert_eq!(convert::<u8, u16>(7), Ok(7));
+    assert_eq!(
+        convert::<u16, u8>(300).unwrap_err().to_string(),
+        "out of range integral type conversion attempted"
+    );
+}
@@ -0,0 +1,54 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn boxed() -> Box<dyn Error + Send + Sync> {
+    Box::new(IoError::other(
+        FlatError::new("disk full").context("writing block"),
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_boxed_send_sync() {
+    let err = FlatError::from(boxed());
+    assert_eq!(err.to_string(), "writing block");
+    assert_eq!(err.original_type_name(), "std::io::error::Error");
+    assert_eq!(err.io_kind(), Some(ErrorKind::Other));
+    assert_eq!(err.flat_source().unwrap().to_string(), "disk full");
+}
+
+#[test]
+fn test_from_boxed() {
+    let boxed: Box<dyn Error> = boxed();
+    let err = FlatError::from(boxed);
+    assert_eq!(err.to_string(), "writing block");
+    assert_eq!(err.io_kind(), Some(ErrorKind::Other));
+}
+
+#[test]
+fn test_from_borrowed() {
+    let boxed = boxed();
+    let borrowed: &dyn Error = &*boxed;
+    let err = FlatError::from(borrowed);
+    assert_eq!(err.to_string(), "writing block");
+    assert_eq!(err.flat_source().unwrap().to_string(), "disk full");
+}
+
+#[test]
+fn test_question_mark() {
+    fn run() -> Result<(), FlatError> {
+        Err(boxed())?
+    }
+    assert_eq!(run().unwrap_err().to_string(), "writing block");
+}
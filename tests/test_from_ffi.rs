@@ -0,0 +1,37 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::ffi::{CStr, CString};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_nul_error() {
+    fn to_c(s: &str) -> Result<CString, FlatError> {
+        Ok(CString::new(s)?)
+    }
+    let err = to_c("a\0b").unwrap_err();
+    assert_eq!(err.original_type_name(), "alloc::ffi::c_str::NulError");
+}
+
+#[test]
+fn test_from_bytes_with_nul_error() {
+    let err = FlatError::from(CStr::from_bytes_with_nul(b"abc").unwrap_err());
+    assert_eq!(err.to_string(), "data provided is not nul terminated");
+
+    let err = FlatError::from(CStr::from_bytes_until_nul(b"abc").unwrap_err());
+    assert_eq!(err.to_string(), "data provided does not contain a nul");
+}
+
+#[test]
+fn test_from_vec_with_nul_error() {
+    let err = FlatError::from(CString::from_vec_with_nul(b"abc".to_vec()).unwrap_err());
+    assert_eq!(err.to_string(), "data provided is not nul terminated");
+}
+
+#[test]
+fn test_from_into_string_error() {
+    let err = FlatError::from(CString::new(vec![0xff]).unwrap().into_string().unwrap_err());
+    assert_eq!(err.to_string(), "C string contained non-utf8 bytes");
+}
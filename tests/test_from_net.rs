@@ -0,0 +1,20 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::net::{AddrParseError, IpAddr, SocketAddr};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_addr_parse_error() {
+    fn parse(s: &str) -> Result<SocketAddr, FlatError> {
+        Ok(s.parse()?)
+    }
+    let err = parse("localhost:80").unwrap_err();
+    assert_eq!(err.to_string(), "invalid socket address syntax");
+    assert!(err.is_exact::<AddrParseError>());
+
+    let err = FlatError::from("10.0.0".parse::<IpAddr>().unwrap_err());
+    assert_eq!(err.to_string(), "invalid IP address syntax");
+}
@@ -0,0 +1,91 @@
+use flat_error::{FlatError, THREAD_NAME_FIELD};
+use pretty_assertions::assert_eq;
+use std::{
+    cell::RefCell,
+    io::{Error as IoError, ErrorKind},
+    path::Path,
+    sync::mpsc::{channel, sync_channel, Sender},
+    thread::{self, AccessError},
+    time::Duration,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_channel_send_errors() {
+    fn send(value: String) -> Result<(), FlatError> {
+        let (tx, rx) = channel();
+        drop(rx);
+        tx.send(value)?;
+        Ok(())
+    }
+    let err = send(String::from("payload")).unwrap_err();
+    assert_eq!(err.to_string(), "sending on a closed channel");
+
+    let (tx, _rx) = sync_channel(0);
+    let err = FlatError::from(tx.try_send(1).unwrap_err());
+    assert_eq!(err.to_string(), "sending on a full channel");
+}
+
+#[test]
+fn test_from_channel_recv_errors() {
+    let (tx, rx) = channel::<u8>();
+    let err = FlatError::from(rx.try_recv().unwrap_err());
+    assert_eq!(err.to_string(), "receiving on an empty channel");
+
+    let err = FlatError::from(rx.recv_timeout(Duration::from_millis(1)).unwrap_err());
+    assert_eq!(err.to_string(), "timed out waiting on channel");
+
+    drop(tx);
+    let err = FlatError::from(rx.recv().unwrap_err());
+    assert_eq!(err.to_string(), "receiving on a closed channel");
+}
+
+#[test]
+fn test_from_var_error() {
+    fn var(name: &str) -> Result<String, FlatError> {
+        Ok(std::env::var(name)?)
+    }
+    let err = var("FLAT_ERROR_NO_SUCH_VARIABLE").unwrap_err();
+    assert_eq!(err.to_string(), "environment variable not found");
+    assert!(err.is_exact::<std::env::VarError>());
+}
+
+#[test]
+fn test_from_strip_prefix_error() {
+    let err = FlatError::from(Path::new("/etc/app.toml").strip_prefix("/var").unwrap_err());
+    assert_eq!(err.to_string(), "prefix not found");
+}
+
+#[test]
+fn test_from_access_error() {
+    struct Probe(Sender<FlatError>);
+
+    impl Drop for Probe {
+        fn drop(&mut self) {
+            // The thread-local holding this value is being destroyed, so may not be accessed.
+            if let Err(e) = PROBE.try_with(|_| ()) {
+                let _ = self.0.send(FlatError::from(e));
+            }
+        }
+    }
+
+    thread_local!(static PROBE: RefCell<Option<Probe>> = const { RefCell::new(None) });
+
+    let (tx, rx) = channel();
+    thread::spawn(move || PROBE.with(|probe| *probe.borrow_mut() = Some(Probe(tx))))
+        .join()
+        .unwrap();
+    let err = rx.recv().unwrap();
+    assert!(err.is_exact::<AccessError>());
+}
+
+#[test]
+fn test_from_spawn_error() {
+    let err = FlatError::from_spawn_error(&IoError::from(ErrorKind::WouldBlock), Some("worker"));
+    assert_eq!(err.field(THREAD_NAME_FIELD), Some("worker"));
+    assert_eq!(err.io_kind(), Some(ErrorKind::WouldBlock));
+    assert!(err.is_exact::<IoError>());
+}
@@ -0,0 +1,49 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct ExitStatus(i32);
+
+impl Display for ExitStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "exit status: {}", self.0)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_display() {
+    let err = FlatError::from_display(&ExitStatus(2));
+    assert_eq!(err.to_string(), "exit status: 2");
+    assert_eq!(err.original_type_name(), "test_from_value::ExitStatus");
+    assert_eq!(err.flat_source(), None);
+}
+
+#[test]
+fn test_from_debug() {
+    let err = FlatError::from_debug(&ExitStatus(2));
+    assert_eq!(err.to_string(), "ExitStatus(2)");
+    assert_eq!(err.original_type_short_name(), "ExitStatus");
+}
+
+#[test]
+fn test_from_display_unsized() {
+    let err = FlatError::from_display("connection closed by peer");
+    assert_eq!(err.to_string(), "connection closed by peer");
+    assert_eq!(err.original_type_name(), "str");
+}
+
+#[test]
+fn test_from_debug_array() {
+    let err = FlatError::from_debug(&[0u8; 4]);
+    assert_eq!(err.to_string(), "[0, 0, 0, 0]");
+    assert_eq!(err.original_type_name(), "[u8; 4]");
+}
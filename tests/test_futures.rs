@@ -0,0 +1,75 @@
+#![cfg(feature = "futures")]
+
+use flat_error::{
+    futures::{TryFutureFlatExt, TryStreamFlatExt},
+    FlatError,
+};
+use futures_util::{future, stream, Stream, StreamExt};
+use pretty_assertions::assert_eq;
+use std::{
+    future::Future,
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+fn not_found() -> IoError {
+    IoError::from(ErrorKind::NotFound)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_future_flat_err() {
+    let result = block_on(future::ready(Err::<(), _>(not_found())).flat_err());
+    let err = result.unwrap_err();
+    assert_eq!(err.to_string(), "entity not found");
+    assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+}
+
+#[test]
+fn test_future_flat_err_ok() {
+    let result = block_on(future::ready(Ok::<_, IoError>(42)).flat_err());
+    assert_eq!(result, Ok(42));
+}
+
+#[test]
+fn test_future_flat_context() {
+    let result = block_on(future::ready(Err::<(), _>(not_found())).flat_context("loading"));
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        ["loading", "entity not found"]
+    );
+}
+
+#[test]
+fn test_stream_flat_err() {
+    let items = block_on(
+        stream::iter(vec![Ok(1), Err(not_found()), Ok(2)])
+            .flat_err()
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(items, vec![Ok(1), Err(FlatError::from(not_found())), Ok(2)]);
+}
+
+#[test]
+fn test_stream_flat_context() {
+    let stream = stream::iter(vec![Err(not_found()), Ok(1), Err(not_found())])
+        .flat_context("reading record");
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+    let items = block_on(stream.collect::<Vec<_>>());
+    let expected = Err(FlatError::from(not_found()).context("reading record"));
+    assert_eq!(items, vec![expected.clone(), Ok(1), expected]);
+}
@@ -0,0 +1,143 @@
+use flat_error::{ExtendedError, FlatError, FlatErrorGroup, IteratorExt};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn not_found() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+}
+
+fn denied() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::PermissionDenied))
+}
+
+fn assert_extended<E: ExtendedError>(_: &E) {}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_group_is_extended_error() {
+    assert_extended(&FlatErrorGroup::new());
+}
+
+#[test]
+fn test_group_push_and_iter() {
+    let mut group = FlatErrorGroup::new();
+    assert!(group.is_empty());
+    group.push(not_found());
+    group.push(denied());
+    assert_eq!(group.len(), 2);
+    assert_eq!(
+        group.iter().cloned().collect::<Vec<_>>(),
+        vec![not_found(), denied()]
+    );
+}
+
+#[test]
+fn test_group_from_iterator() {
+    let group: FlatErrorGroup = vec![not_found(), denied()].into_iter().collect();
+    assert_eq!(group, FlatErrorGroup::from(vec![not_found(), denied()]));
+}
+
+#[test]
+fn test_group_push_dedup() {
+    let mut group = FlatErrorGroup::new();
+    group.push_dedup(not_found());
+    group.push_dedup(denied());
+    group.push_dedup(not_found());
+    group.push_dedup(not_found().context("reading"));
+    assert_eq!(group.len(), 3);
+    assert_eq!(
+        group
+            .iter()
+            .map(FlatError::repeat_count)
+            .collect::<Vec<_>>(),
+        vec![2, 1, 1]
+    );
+    assert_eq!(group.total_count(), 4);
+}
+
+#[test]
+fn test_group_dedup_merges_counts() {
+    let mut first = FlatErrorGroup::new();
+    first.push_dedup(not_found());
+    first.push_dedup(not_found());
+    let mut group: FlatErrorGroup = first.into_iter().chain([denied(), not_found()]).collect();
+    assert_eq!(group.total_count(), 4);
+    group.dedup();
+    assert_eq!(group.len(), 2);
+    assert_eq!(group.to_string(), "entity not found; permission denied");
+    assert_eq!(group.as_slice()[0].repeat_count(), 3);
+    assert_eq!(group.total_count(), 4);
+}
+
+#[test]
+fn test_group_dedup_by_fingerprint() {
+    let mut group: FlatErrorGroup = ["timed out after 10s", "timed out after 30s", "refused"]
+        .into_iter()
+        .map(FlatError::new)
+        .collect();
+    group.dedup();
+    assert_eq!(group.len(), 3);
+    group.dedup_by_fingerprint();
+    assert_eq!(group.to_string(), "timed out after 10s; refused");
+    assert_eq!(
+        group
+            .iter()
+            .map(FlatError::repeat_count)
+            .collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+}
+
+#[test]
+fn test_group_display() {
+    let group: FlatErrorGroup = vec![not_found(), denied()].into_iter().collect();
+    assert_eq!(
+        group.to_string(),
+        "entity not found; permission denied".to_string()
+    );
+    assert_eq!(
+        format!("{group:#}"),
+        "entity not found (original type: `std::io::error::Error`)\n\
+         permission denied (original type: `std::io::error::Error`)"
+            .to_string()
+    );
+}
+
+#[test]
+fn test_collect_flat_values() {
+    let values: Result<Vec<u8>, FlatErrorGroup> =
+        ["1", "2"].iter().map(|s| s.parse::<u8>()).collect_flat();
+    assert_eq!(values, Ok(vec![1, 2]));
+
+    let empty = std::iter::empty::<Result<u8, FlatError>>().collect_flat();
+    assert_eq!(empty, Ok(vec![]));
+}
+
+#[test]
+fn test_collect_flat_errors() {
+    let results = vec![
+        Ok(1),
+        Err(not_found()),
+        Ok(2),
+        Err(FlatError::new_static("bad")),
+    ];
+    let errors = results.into_iter().collect_flat().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.to_string(), "entity not found; bad");
+}
+
+#[test]
+fn test_collect_flat_converts_errors() {
+    let errors = [Err(IoError::from(ErrorKind::NotFound)), Ok(())]
+        .into_iter()
+        .collect_flat()
+        .unwrap_err();
+    assert_eq!(errors.as_slice(), [not_found()]);
+}
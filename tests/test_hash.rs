@@ -0,0 +1,48 @@
+use flat_error::{FlatError, LazyFlat};
+use pretty_assertions::assert_eq;
+use std::{
+    collections::HashSet,
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_dedup_in_hash_set() {
+    let errors: HashSet<FlatError> = [
+        FlatError::from(IoError::from(ErrorKind::NotFound)),
+        FlatError::from(IoError::from(ErrorKind::NotFound)),
+        FlatError::from(IoError::from(ErrorKind::NotFound)).context("loading"),
+        FlatError::new("entity not found"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn test_hash_ignores_original() {
+    let mut errors = HashSet::new();
+    errors.insert(FlatError::from(IoError::from(ErrorKind::NotFound)));
+    assert!(!errors.insert(FlatError::from_any_retaining(IoError::from(
+        ErrorKind::NotFound
+    ))));
+}
+
+#[test]
+fn test_hash_includes_attributes() {
+    let mut errors = HashSet::new();
+    errors.insert(FlatError::new("failed"));
+    assert!(errors.insert(FlatError::new("failed").with_code("E1")));
+    assert!(errors.insert(FlatError::new("failed").with_field("k", "v")));
+    assert!(!errors.insert(FlatError::new("failed").with_field("k", "v")));
+}
+
+#[test]
+fn test_lazy_hash() {
+    let mut errors = HashSet::new();
+    errors.insert(LazyFlat::new(IoError::from(ErrorKind::NotFound)));
+    assert!(!errors.insert(LazyFlat::new(IoError::from(ErrorKind::NotFound))));
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "std")]
+
+use flat_error::{FlatError, FlatErrorConfig};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind},
+    mem,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct LoadError(IoError);
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "could not load")
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+fn tag_io_sources(error: &dyn Error, builder: &mut flat_error::FlatErrorBuilder) {
+    if let Some(io_error) = error.source().and_then(|e| e.downcast_ref::<IoError>()) {
+        *builder = mem::take(builder).field("io", io_error.kind());
+    }
+    *builder = mem::take(builder).field("hooked", true);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+// The configuration may only be installed once in each process, and so is tested by a single
+// test.
+#[test]
+fn test_flatten_hook() {
+    FlatErrorConfig::new()
+        .with_flatten_hook(tag_io_sources)
+        .install()
+        .unwrap();
+
+    let err = FlatError::from_any(&LoadError(IoError::from(ErrorKind::NotFound)));
+    assert_eq!(err.field("hooked"), Some("true"));
+    assert_eq!(err.field("io"), Some("entity not found"));
+    assert_eq!(
+        err.flat_source().map(|e| e.to_string()),
+        Some("entity not found".to_string())
+    );
+    assert_eq!(err.flat_source().and_then(|e| e.field("hooked")), None);
+
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.field("hooked"), Some("true"));
+    assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+
+    let err = FlatError::new("not flattened");
+    assert_eq!(err.field("hooked"), None);
+}
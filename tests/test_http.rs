@@ -0,0 +1,69 @@
+#![cfg(feature = "axum")]
+
+use axum::response::{IntoResponse, Response};
+use flat_error::FlatError;
+use http::StatusCode;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn body(response: Response) -> String {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let bytes = runtime
+        .block_on(axum::body::to_bytes(response.into_body(), usize::MAX))
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_http_status_from_io_kind() {
+    let err = FlatError::from(IoError::from(ErrorKind::PermissionDenied));
+    assert_eq!(err.http_status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        FlatError::new("failed").http_status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[test]
+fn test_with_http_status() {
+    let err = FlatError::new("no such user").with_http_status(StatusCode::NOT_FOUND);
+    assert_eq!(err.field("http_status"), Some("404"));
+    assert_eq!(err.into_response().status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn test_into_response_hides_details() {
+    let err = FlatError::new("password=hunter2").context("connecting");
+    assert_eq!(body(err.clone().into_response()), "Internal Server Error");
+    assert_eq!(
+        body(err.into_detailed_response()),
+        "connecting: password=hunter2"
+    );
+}
+
+#[test]
+fn test_http_status_from_class() {
+    let err = FlatError::new("busy").with_class(flat_error::ErrorClass::Unavailable);
+    assert_eq!(err.http_status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[test]
+fn test_http_status_from_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("loading profile")
+        .context("handling request");
+    assert_eq!(err.http_status(), StatusCode::NOT_FOUND);
+
+    let err = FlatError::from("x".parse::<u8>().unwrap_err()).context("parsing port");
+    assert_eq!(err.http_status(), StatusCode::BAD_REQUEST);
+}
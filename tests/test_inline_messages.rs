@@ -0,0 +1,62 @@
+#![cfg(feature = "inline-messages")]
+
+use flat_error::{FlatError, FlattenOptions};
+use pretty_assertions::assert_eq;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+};
+
+#[derive(Debug)]
+struct Pieces(&'static [&'static str]);
+
+impl Display for Pieces {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.0.iter().try_for_each(|piece| f.write_str(piece))
+    }
+}
+
+impl std::error::Error for Pieces {}
+
+#[test]
+fn test_messages_around_capacity() {
+    for message in [
+        "",
+        "short",
+        "exactly twenty-two b..",
+        "twenty-three bytes long",
+        "a much longer message that is stored on the heap",
+    ] {
+        let err = FlatError::from(IoError::other(message));
+        assert_eq!(err.to_string(), message);
+    }
+}
+
+#[test]
+fn test_message_written_in_pieces() {
+    let err = FlatError::from_any(&Pieces(&["connection ", "reset ", "by peer"]));
+    assert_eq!(err.to_string(), "connection reset by peer");
+    let err = FlatError::from_any(&Pieces(&["disk ", "full"]));
+    assert_eq!(err.to_string(), "disk full");
+}
+
+#[test]
+fn test_multibyte_message() {
+    let err = FlatError::from(IoError::other("fichier « ïntrouvable »"));
+    assert_eq!(err.to_string(), "fichier « ïntrouvable »");
+}
+
+#[test]
+fn test_inline_messages_compare_and_clone() {
+    let err = FlatError::from(IoError::other("not found"));
+    assert_eq!(err.clone(), err);
+    assert_eq!(err, "not found");
+}
+
+#[test]
+fn test_truncated_message() {
+    let options = FlattenOptions::new().with_max_message_len(10);
+    let err = FlatError::from_any_with(&IoError::other("a message longer than ten bytes"), options);
+    assert_eq!(err.to_string(), "a message …");
+    assert_eq!(err.original_message_len(), Some(31));
+}
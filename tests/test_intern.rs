@@ -0,0 +1,48 @@
+use flat_error::{FlatError, FlattenOptions};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+fn message_ptrs(err: &FlatError) -> Vec<*const u8> {
+    err.chain_messages().map(str::as_ptr).collect()
+}
+
+#[test]
+fn test_interned_messages_are_shared() {
+    let flatten = || {
+        FlatError::from_any_interned(&IoError::new(
+            ErrorKind::InvalidData,
+            FlatError::new("bad checksum in block header")
+                .context("reading block from segment file"),
+        ))
+    };
+    let first = flatten();
+    let second = flatten();
+    assert_eq!(first, second);
+    assert_eq!(message_ptrs(&first), message_ptrs(&second));
+}
+
+#[test]
+fn test_messages_not_interned_by_default() {
+    let first = FlatError::from_any(&IoError::other("not interned"));
+    let second = FlatError::from_any(&IoError::other("not interned"));
+    assert_eq!(first, second);
+    assert_ne!(message_ptrs(&first), message_ptrs(&second));
+}
+
+#[test]
+fn test_interned_with_options() {
+    let options = FlattenOptions::new()
+        .with_interned_messages(true)
+        .with_max_message_len(24);
+    assert!(options.intern_messages());
+    let first = FlatError::from_any_with(
+        &IoError::other("truncated and then interned in the pool"),
+        options,
+    );
+    let second = FlatError::from_any_with(
+        &IoError::other("truncated and then interned in the pool"),
+        options,
+    );
+    assert_eq!(first.to_string(), "truncated and then inter…");
+    assert_eq!(message_ptrs(&first), message_ptrs(&second));
+}
@@ -0,0 +1,83 @@
+use flat_error::{FlatError, FlatIoError, IntoFlat, FILE_PATH_FIELD};
+use pretty_assertions::assert_eq;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct DefaultError;
+
+impl Display for DefaultError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "default conversion")
+    }
+}
+
+impl std::error::Error for DefaultError {}
+
+impl IntoFlat for DefaultError {}
+
+#[derive(Debug)]
+struct OwningError {
+    message: String,
+    attempts: u32,
+}
+
+impl Display for OwningError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} after {} attempts", self.message, self.attempts)
+    }
+}
+
+impl std::error::Error for OwningError {}
+
+impl IntoFlat for OwningError {
+    fn into_flat(self) -> FlatError {
+        FlatError::new(self.message).with_field("attempts", self.attempts)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_default_into_flat() {
+    let err = DefaultError.into_flat();
+    assert_eq!(err.to_string(), "default conversion");
+    assert!(err.is_exact::<DefaultError>());
+}
+
+#[test]
+fn test_custom_into_flat() {
+    let result: Result<(), OwningError> = Err(OwningError {
+        message: "connection reset".to_string(),
+        attempts: 3,
+    });
+    let err = result.map_err(IntoFlat::into_flat).unwrap_err();
+    assert_eq!(err.to_string(), "connection reset");
+    assert_eq!(err.field("attempts"), Some("3"));
+}
+
+#[test]
+fn test_flat_error_into_flat_is_identity() {
+    let err = FlatError::new("unchanged").with_code("E1");
+    assert_eq!(err.clone().into_flat(), err);
+}
+
+#[test]
+fn test_io_errors_into_flat() {
+    let err = IoError::from(ErrorKind::NotFound).into_flat();
+    assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+
+    let err = FlatIoError::new(ErrorKind::NotFound, "no such file")
+        .with_path("/no/such/file")
+        .into_flat();
+    assert_eq!(err.to_string(), "no such file");
+    assert_eq!(err.field(FILE_PATH_FIELD), Some("/no/such/file"));
+}
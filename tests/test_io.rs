@@ -0,0 +1,88 @@
+use flat_error::{FlatError, ResultExt};
+use pretty_assertions::{assert_eq, assert_ne};
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_io_kind_from() {
+    let err = FlatError::from(IoError::from(ErrorKind::PermissionDenied));
+    assert_eq!(err.io_kind(), Some(ErrorKind::PermissionDenied));
+}
+
+#[test]
+fn test_io_kind_retaining() {
+    let err = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+}
+
+#[test]
+fn test_io_kind_in_source() {
+    let result: Result<(), IoError> = Err(IoError::from(ErrorKind::TimedOut));
+    let err = result.context("connecting").unwrap_err();
+    assert_eq!(err.io_kind(), None);
+    assert_eq!(
+        err.flat_source().and_then(|e| e.io_kind()),
+        Some(ErrorKind::TimedOut)
+    );
+}
+
+#[test]
+fn test_io_kind_participates_in_eq() {
+    let err = IoError::new(ErrorKind::NotFound, "missing");
+    assert_ne!(FlatError::from_any(&err), FlatError::from(err));
+}
+
+#[test]
+fn test_os_error_code() {
+    let err = FlatError::from(IoError::from_raw_os_error(13));
+    assert_eq!(err.os_error_code(), Some(13));
+    assert_eq!(err.io_kind(), Some(IoError::from_raw_os_error(13).kind()));
+}
+
+#[test]
+fn test_no_os_error_code() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.os_error_code(), None);
+}
+
+#[test]
+fn test_to_io_error_kind() {
+    let err = FlatError::from(IoError::from(ErrorKind::PermissionDenied));
+    let io_error = err.to_io_error();
+    assert_eq!(io_error.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(io_error.to_string(), "permission denied");
+    assert_eq!(
+        FlatError::from(io_error).io_kind(),
+        Some(ErrorKind::PermissionDenied)
+    );
+}
+
+#[test]
+fn test_to_io_error_kind_from_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::TimedOut)).context("connecting");
+    let io_error = IoError::from(err.clone());
+    assert_eq!(io_error.kind(), ErrorKind::TimedOut);
+    assert_eq!(io_error.to_string(), "connecting");
+    assert_eq!(
+        io_error
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<FlatError>()),
+        Some(&err)
+    );
+}
+
+#[test]
+fn test_to_io_error_os_error_code() {
+    let io_error = FlatError::from(IoError::from_raw_os_error(13)).to_io_error();
+    assert_eq!(io_error.raw_os_error(), Some(13));
+}
+
+#[test]
+fn test_to_io_error_other() {
+    let io_error = FlatError::new("disk full").to_io_error();
+    assert_eq!(io_error.kind(), ErrorKind::Other);
+    assert_eq!(io_error.to_string(), "disk full");
+}
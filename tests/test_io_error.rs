@@ -0,0 +1,72 @@
+use flat_error::{ExtendedError, FlatError, FlatIoError, FILE_PATH_FIELD};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    io::{Error as IoError, ErrorKind},
+    path::Path,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_io_error() {
+    let err = FlatIoError::from(IoError::from_raw_os_error(2));
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert_eq!(err.raw_os_error(), Some(2));
+    assert_eq!(err.path(), None);
+    assert_eq!(err.to_string(), IoError::from_raw_os_error(2).to_string());
+}
+
+#[test]
+fn test_with_path() {
+    let err =
+        FlatIoError::new(ErrorKind::PermissionDenied, "permission denied").with_path("/etc/shadow");
+    assert_eq!(err.path(), Some(Path::new("/etc/shadow")));
+    assert_eq!(err.raw_os_error(), None);
+    assert_eq!(err.to_string(), "permission denied: /etc/shadow");
+    assert_eq!(err.as_flat().to_string(), "permission denied");
+}
+
+#[test]
+fn test_source_chain() {
+    let err = FlatIoError::from(IoError::new(
+        ErrorKind::InvalidData,
+        FlatError::new("bad header").caused_by(FlatError::new("unexpected eof")),
+    ));
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.chain_to_strings(), ["bad header", "unexpected eof"]);
+    assert_eq!(err.source().unwrap().to_string(), "unexpected eof");
+}
+
+#[test]
+fn test_io_error_round_trip() {
+    let err = FlatIoError::from(IoError::from_raw_os_error(13)).with_path("/root");
+    let io_error = IoError::from(err.clone());
+    assert_eq!(io_error.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(FlatIoError::from(io_error), err);
+}
+
+#[test]
+fn test_flat_error_round_trip() {
+    let err = FlatIoError::from(IoError::from(ErrorKind::NotFound)).with_path("/etc/app.toml");
+    let flat = FlatError::from(err.clone());
+    assert_eq!(flat.io_kind(), Some(ErrorKind::NotFound));
+    assert_eq!(flat.field(FILE_PATH_FIELD), Some("/etc/app.toml"));
+    assert_eq!(FlatIoError::from(flat), err);
+}
+
+#[test]
+fn test_from_flat_error() {
+    let err = FlatIoError::from(
+        FlatError::from(IoError::from_raw_os_error(2)).context("reading config file"),
+    );
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert_eq!(err.raw_os_error(), Some(2));
+    assert_eq!(err.to_string(), "reading config file");
+
+    let err = FlatIoError::from(FlatError::new("no kind"));
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(err.raw_os_error(), None);
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "journald")]
+
+use flat_error::{FlatError, Severity};
+use pretty_assertions::assert_eq;
+use std::io::Error as IoError;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn chain() -> FlatError {
+    FlatError::from(IoError::from_raw_os_error(2))
+        .context("reading config file")
+        .with_field("config.path", "/etc/app.toml")
+        .with_code("E1042")
+        .with_severity(Severity::Critical)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_journald_fields() {
+    let not_found = IoError::from_raw_os_error(2).to_string();
+    let fields = chain().journald_fields();
+    assert_eq!(
+        fields,
+        [
+            ("MESSAGE", format!("reading config file: {not_found}")),
+            ("PRIORITY", "2".to_string()),
+            ("ERRNO", "2".to_string()),
+            ("FLAT_MESSAGE", "reading config file".to_string()),
+            ("FLAT_TYPE", "flat_error::FlatError".to_string()),
+            ("FLAT_CODE", "E1042".to_string()),
+            ("FLAT_SEVERITY", "critical".to_string()),
+            ("FLAT_DEPTH", "2".to_string()),
+            ("FLAT_SOURCE_1_MESSAGE", not_found),
+            ("FLAT_SOURCE_1_TYPE", "std::io::error::Error".to_string()),
+            ("FLAT_FIELD_CONFIG_PATH", "/etc/app.toml".to_string()),
+        ]
+        .map(|(name, value)| (name.to_string(), value))
+    );
+}
+
+#[test]
+fn test_journald_fields_minimal() {
+    let fields = FlatError::new("failed")
+        .with_severity(Severity::Warning)
+        .journald_fields();
+    let names = fields
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "MESSAGE",
+            "PRIORITY",
+            "FLAT_MESSAGE",
+            "FLAT_TYPE",
+            "FLAT_SEVERITY",
+            "FLAT_DEPTH"
+        ]
+    );
+    assert_eq!(fields[1].1, "4");
+}
+
+#[test]
+fn test_syslog_structured_data() {
+    let not_found = IoError::from_raw_os_error(2).to_string();
+    assert_eq!(
+        chain().syslog_structured_data("flat@32473"),
+        format!(
+            r#"[flat@32473 message="reading config file" type="flat_error::FlatError" code="E1042" severity="critical" errno="2" source.1="{not_found}" source.1.type="std::io::error::Error" field.config.path="/etc/app.toml"]"#
+        )
+    );
+}
+
+#[test]
+fn test_syslog_structured_data_escaping() {
+    let err = FlatError::new(r#"bad "value" [x\y]"#).with_field("a key=1", "v");
+    assert_eq!(
+        err.syslog_structured_data("e@1"),
+        r#"[e@1 message="bad \"value\" [x\\y\]" type="flat_error::FlatError" severity="error" field.a_key_1="v"]"#
+    );
+}
@@ -0,0 +1,51 @@
+use flat_error::{FlatError, LazyFlat};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_lazy_not_flattened() {
+    let err = LazyFlat::new(IoError::from(ErrorKind::NotFound));
+    assert!(!err.is_flattened());
+    assert_eq!(
+        err.inner().downcast_ref::<IoError>().map(|e| e.kind()),
+        Some(ErrorKind::NotFound)
+    );
+    assert!(!err.is_flattened());
+}
+
+#[test]
+fn test_lazy_flattened_on_debug() {
+    let err = LazyFlat::new(IoError::from(ErrorKind::NotFound));
+    let _ = format!("{err:?}");
+    assert!(err.is_flattened());
+}
+
+#[test]
+fn test_lazy_matches_eager() {
+    let lazy = LazyFlat::new(IoError::from(ErrorKind::NotFound));
+    let eager = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(lazy.get(), &eager);
+    assert_eq!(lazy.get().original_type_name(), "std::io::error::Error");
+    assert_eq!(format!("{lazy:#}"), format!("{eager:#}"));
+}
+
+#[test]
+fn test_lazy_retains_original() {
+    let flat: FlatError = LazyFlat::new(IoError::from(ErrorKind::NotFound)).into();
+    assert_eq!(
+        flat.downcast_ref::<IoError>().map(|e| e.kind()),
+        Some(ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn test_lazy_eq() {
+    let lhs = LazyFlat::new(IoError::from(ErrorKind::NotFound));
+    let rhs = LazyFlat::new(IoError::from(ErrorKind::NotFound));
+    assert_eq!(lhs, rhs);
+    assert_eq!(lhs.clone(), lhs);
+}
@@ -1,4 +1,4 @@
-use flat_error::FlatError;
+use flat_error::{ExtendedError, ExtendedErrorSync, FlatError};
 use pretty_assertions::assert_eq;
 use std::{
     error::Error,
@@ -49,3 +49,215 @@ fn test_my_error_type_name() {
     let err = FlatError::from_any(&MyError);
     assert_eq!(err.original_type_name(), "test_lib::MyError".to_string());
 }
+
+#[test]
+fn test_new_static() {
+    let err = FlatError::new_static("not ready");
+    assert_eq!(err.to_string(), "not ready".to_string());
+    assert_eq!(err.original_type_name(), "flat_error::FlatError");
+    assert!(err.flat_source().is_none());
+}
+
+#[test]
+fn test_new_static_in_const() {
+    static NOT_READY: FlatError = FlatError::new_static("not ready");
+    assert_eq!(NOT_READY.to_string(), "not ready".to_string());
+    assert_eq!(NOT_READY.original_type_name(), "flat_error::FlatError");
+    assert!(NOT_READY.flat_source().is_none());
+}
+
+#[test]
+fn test_new_static_copied_when_modified() {
+    let err = FlatError::new_static("not ready");
+    let modified = err.clone().with_code("E503").context("starting");
+    assert_eq!(err, FlatError::new_static("not ready"));
+    assert_eq!(err.code(), None);
+    assert_eq!(modified.flat_source().unwrap().code(), Some("E503"));
+    assert_eq!(modified.flat_source().unwrap().to_string(), "not ready");
+}
+
+#[test]
+fn test_size_is_independent_of_category() {
+    assert_eq!(size_of::<FlatError>(), 4 * size_of::<usize>());
+    assert_eq!(size_of::<FlatError<[u64; 4]>>(), size_of::<FlatError>());
+    assert!(size_of::<Result<(), FlatError>>() <= 5 * size_of::<usize>());
+}
+
+#[test]
+fn test_new_static_eq_context() {
+    let err = FlatError::from_any(&MyError).context("not ready");
+    assert_eq!(
+        FlatError::new_static("not ready"),
+        FlatError::new_static("not ready")
+    );
+    assert_eq!(
+        err.original_type_name(),
+        FlatError::new_static("not ready").original_type_name()
+    );
+}
+
+#[test]
+fn test_deep_chain() {
+    let mut err = FlatError::new_static("root cause");
+    for depth in 0..100_000 {
+        err = err.context(depth);
+    }
+    let copy = err.clone();
+    assert_eq!(err, copy);
+    assert_eq!(err.to_string(), "99999");
+    assert_eq!(err.flat_source().unwrap().to_string(), "99998");
+}
+
+#[test]
+fn test_deep_chain_through_source() {
+    let err = (0..100_000).fold(FlatError::new_static("root cause"), |err, depth| {
+        err.context(depth)
+    });
+    let mut depth = 0;
+    let mut current: Option<&dyn Error> = Some(&err);
+    while let Some(error) = current.and_then(Error::source) {
+        depth += 1;
+        current = Some(error);
+    }
+    assert_eq!(depth, 100_000);
+    assert_eq!(current.unwrap().to_string(), "root cause");
+}
+
+#[test]
+fn test_source_chain() {
+    let err = FlatError::new_static("root cause")
+        .context("middle")
+        .context("top");
+    let mut messages = Vec::new();
+    let mut current: Option<&dyn Error> = Some(&err);
+    while let Some(error) = current {
+        messages.push(error.to_string());
+        current = error.source();
+    }
+    assert_eq!(messages, vec!["top", "middle", "root cause"]);
+    assert_eq!(
+        err.flat_source().and_then(|e| e.flat_source()),
+        Some(&FlatError::new_static("root cause"))
+    );
+}
+
+#[test]
+fn test_new() {
+    let err = FlatError::new("parse failed");
+    assert_eq!(err.to_string(), "parse failed");
+    assert_eq!(err.original_type_name(), "flat_error::FlatError");
+    assert!(err.flat_source().is_none());
+    assert_eq!(err, FlatError::new_static("parse failed"));
+}
+
+#[test]
+fn test_from_strings() {
+    let err: FlatError = "parse failed".into();
+    assert_eq!(err, FlatError::new("parse failed"));
+    let err: FlatError = String::from("parse failed").into();
+    assert_eq!(err, FlatError::new("parse failed"));
+}
+
+#[test]
+fn test_into_parts() {
+    let err = FlatError::from_any(&MyError).context("top");
+    let (type_name, message, source) = err.into_parts();
+    assert_eq!(type_name, "flat_error::FlatError");
+    assert_eq!(message, "top");
+    assert_eq!(source, Some(FlatError::from_any(&MyError)));
+}
+
+#[test]
+fn test_from_parts_round_trip() {
+    let err = FlatError::from_any(&MyError)
+        .context("middle")
+        .context("top");
+    let (type_name, message, source) = err.clone().into_parts();
+    assert_eq!(FlatError::from_parts(type_name, message, source), err);
+
+    let (type_name, message, source) = FlatError::from_any(&MyError).into_parts();
+    assert_eq!(type_name, "test_lib::MyError");
+    assert_eq!(source, None);
+    assert_eq!(
+        FlatError::from_parts(type_name, message, source),
+        FlatError::from_any(&MyError)
+    );
+}
+
+#[test]
+fn test_eq_str() {
+    let err = FlatError::from_any(&MyError).context("loading");
+    assert_eq!(err, "loading");
+    assert_eq!("loading", err);
+    assert!(err == *"loading");
+    assert!(*"loading" == err);
+    assert!(err != "MyError!");
+}
+
+#[test]
+fn test_eq_error() {
+    let err = FlatError::from_any(&MyError);
+    assert!(err.eq_error(&MyError));
+    assert!(!err.context("loading").eq_error(&MyError));
+    assert!(!FlatError::new("MyError!").eq_error(&MyError));
+}
+
+#[test]
+fn test_extended_type_name() {
+    assert_eq!(MyError.type_name(), "test_lib::MyError");
+    assert_eq!(
+        FlatError::from_any(&MyError).type_name(),
+        "flat_error::FlatError"
+    );
+}
+
+#[test]
+fn test_extended_boxed() {
+    let boxed = MyError.boxed();
+    assert_eq!(boxed.to_string(), "MyError!");
+    assert!(boxed.downcast_ref::<MyError>().is_some());
+}
+
+#[test]
+fn test_extended_chain_to_strings() {
+    let err = FlatError::from_any(&MyError)
+        .context("loading")
+        .context("starting");
+    assert_eq!(err.chain_to_strings(), ["starting", "loading", "MyError!"]);
+    assert_eq!(MyError.chain_to_strings(), ["MyError!"]);
+}
+
+#[test]
+fn test_extended_sync_across_threads() {
+    fn on_thread<E: ExtendedErrorSync>(err: E) -> Vec<String> {
+        std::thread::spawn(move || err.chain_to_strings())
+            .join()
+            .unwrap()
+    }
+    assert_eq!(on_thread(MyError), ["MyError!"]);
+    assert_eq!(
+        on_thread(FlatError::from_any(&MyError).context("loading")),
+        ["loading", "MyError!"]
+    );
+}
+
+#[test]
+fn test_clone_shares_chain() {
+    let err = (1..100).fold(FlatError::new_static("0"), |err, depth| err.context(depth));
+    let clone = err.clone();
+    assert_eq!(clone, err);
+    assert_eq!(clone.flat_source(), err.flat_source());
+    assert_eq!(clone.chain_to_strings().len(), 100);
+}
+
+#[test]
+fn test_clone_modified_independently() {
+    let err = FlatError::new("root").context("middle").context("top");
+    let clone = err.clone().caused_by(FlatError::new("cause"));
+    assert_eq!(err.chain_to_strings(), vec!["top", "middle", "root"]);
+    assert_eq!(
+        clone.chain_to_strings(),
+        vec!["top", "middle", "root", "cause"]
+    );
+    assert_ne!(clone, err);
+}
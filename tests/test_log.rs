@@ -0,0 +1,150 @@
+#![cfg(feature = "log")]
+
+use flat_error::{log_error, FlatError};
+use log::{
+    kv::{Error as KvError, Key, Source, ToValue, Value, VisitSource},
+    Level, LevelFilter, Log, Metadata, Record,
+};
+use pretty_assertions::assert_eq;
+use std::sync::{Mutex, Once};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+type Captured = Vec<(Level, String, String, Vec<(String, String)>)>;
+
+struct Capture(Mutex<Captured>);
+
+struct Pairs(Vec<(String, String)>);
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+static INIT: Once = Once::new();
+
+impl<'kvs> VisitSource<'kvs> for Pairs {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), KvError> {
+        self.0.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+}
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let mut pairs = Pairs(Vec::new());
+        record.key_values().visit(&mut pairs).unwrap();
+        self.0.lock().unwrap().push((
+            record.level(),
+            record.target().to_string(),
+            record.args().to_string(),
+            pairs.0,
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+fn captured_for(target: &str) -> Captured {
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+    });
+    LOGGER
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, t, _, _)| t == target)
+        .cloned()
+        .collect()
+}
+
+fn pairs(source: &dyn Source) -> Vec<(String, String)> {
+    let mut pairs = Pairs(Vec::new());
+    source.visit(&mut pairs).unwrap();
+    pairs.0
+}
+
+fn owned(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_to_value() {
+    let err = FlatError::new("disk full").context("saving file");
+    assert_eq!(err.to_value().to_string(), "saving file");
+}
+
+#[test]
+fn test_source_pairs() {
+    let err = FlatError::new("no space left")
+        .context("disk full")
+        .context("saving file")
+        .with_code("E1042");
+    assert_eq!(err.count(), 7);
+    assert_eq!(
+        pairs(&err),
+        owned(&[
+            ("error.message", "saving file"),
+            ("error.type", "flat_error::FlatError"),
+            ("error.code", "E1042"),
+            ("error.severity", "error"),
+            ("error.depth", "3"),
+            ("error.source", "disk full"),
+            ("error.root", "no space left"),
+        ])
+    );
+}
+
+#[test]
+fn test_source_pairs_no_sources() {
+    let err = FlatError::new("disk full");
+    assert_eq!(err.count(), 4);
+    assert_eq!(
+        pairs(&err),
+        owned(&[
+            ("error.message", "disk full"),
+            ("error.type", "flat_error::FlatError"),
+            ("error.severity", "error"),
+            ("error.depth", "1"),
+        ])
+    );
+}
+
+#[test]
+fn test_log_error_macro() {
+    captured_for("test_log_error_macro");
+    let err = FlatError::new("disk full").context("saving file");
+    log_error!(target: "test_log_error_macro", err);
+    let records = captured_for("test_log_error_macro");
+    assert_eq!(records.len(), 1);
+    let (level, _, message, pairs) = &records[0];
+    assert_eq!(*level, Level::Error);
+    assert_eq!(message, "saving file");
+    assert_eq!(
+        pairs.last(),
+        Some(&(
+            "error.chain".to_string(),
+            "saving file: disk full".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_log_error_default_target() {
+    captured_for("test_log");
+    log_error!(FlatError::new("disk full"));
+    let records = captured_for("test_log");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].2, "disk full");
+}
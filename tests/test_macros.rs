@@ -0,0 +1,108 @@
+use flat_error::{assert_extended_error, flat_enum, flat_from_impls, FlatError};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "could not parse")
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug)]
+pub struct ValidationError(ParseError);
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "not valid")
+    }
+}
+
+impl Error for ValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+flat_from_impls!(ParseError, ValidationError,);
+
+assert_extended_error!(FlatError, std::fmt::Error);
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_generated_from_impl() {
+    let err: FlatError = ParseError.into();
+    assert_eq!(err, FlatError::from_any(&ParseError));
+}
+
+#[test]
+fn test_generated_from_impl_keeps_source() {
+    let err: FlatError = ValidationError(ParseError).into();
+    assert_eq!(err.to_string(), "not valid".to_string());
+    assert_eq!(
+        err.flat_source().map(|e| e.to_string()),
+        Some("could not parse".to_string())
+    );
+}
+
+#[test]
+fn test_assert_extended_error_in_fn() {
+    assert_extended_error!(std::num::ParseIntError, std::str::Utf8Error,);
+}
+
+flat_enum! {
+    #[allow(dead_code)]
+    enum AppError {
+        Parse(from ParseError),
+        Validation(from ValidationError),
+        /// A message that is not the result of another error.
+        Custom(String),
+        Code(u16)
+    }
+}
+
+#[test]
+fn test_flat_enum_from() {
+    let err: AppError = ValidationError(ParseError).into();
+    assert_eq!(
+        err,
+        AppError::Validation(FlatError::from_error(&ValidationError(ParseError)))
+    );
+    assert_eq!(err.to_string(), "not valid");
+    assert_eq!(
+        err.source().map(|e| e.to_string()),
+        Some("could not parse".to_string())
+    );
+}
+
+#[test]
+fn test_flat_enum_question_mark() {
+    fn parse() -> Result<(), AppError> {
+        Err(ParseError)?
+    }
+    assert!(matches!(parse(), Err(AppError::Parse(_))));
+}
+
+#[test]
+fn test_flat_enum_value() {
+    let err = AppError::Custom("not ready".to_string());
+    assert_eq!(err.to_string(), "not ready");
+    assert!(err.source().is_none());
+    assert_eq!(AppError::Code(42).clone().to_string(), "42");
+}
+
+assert_extended_error!(AppError);
@@ -0,0 +1,119 @@
+use flat_error::{assert_flat_matches, FlatError, FlatErrorMatcher};
+use pretty_assertions::assert_eq;
+use std::{
+    io::{Error as IoError, ErrorKind},
+    panic::catch_unwind,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn denied() -> FlatError {
+    FlatError::from(IoError::new(ErrorKind::PermissionDenied, "access denied"))
+        .context("reading settings")
+        .with_code("E403")
+        .context("opening config")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_matcher_message() {
+    let err = denied();
+    assert!(FlatErrorMatcher::new()
+        .message("opening config")
+        .matches(&err));
+    assert!(FlatErrorMatcher::new()
+        .message_contains("config")
+        .matches(&err));
+    assert!(FlatErrorMatcher::new()
+        .message_starts_with("opening")
+        .matches(&err));
+    assert!(FlatErrorMatcher::new()
+        .message_ends_with("config")
+        .matches(&err));
+    assert!(!FlatErrorMatcher::new()
+        .message_contains("denied")
+        .matches(&err));
+    assert!(FlatErrorMatcher::new()
+        .message_contains("denied")
+        .in_chain()
+        .matches(&err));
+}
+
+#[test]
+fn test_matcher_type_name() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    for type_name in ["std::io::error::Error", "std::io::Error", "Error"] {
+        assert!(FlatErrorMatcher::new().type_name(type_name).matches(&err));
+    }
+    for type_name in [
+        "io::Error",
+        "std::io::ErrorKind",
+        "core::io::Error",
+        "error",
+    ] {
+        assert!(!FlatErrorMatcher::new().type_name(type_name).matches(&err));
+    }
+}
+
+#[test]
+fn test_matcher_criteria_apply_to_one_frame() {
+    let err = denied();
+    assert!(FlatErrorMatcher::new()
+        .code("E403")
+        .message("reading settings")
+        .in_chain()
+        .matches(&err));
+    assert!(!FlatErrorMatcher::new()
+        .code("E403")
+        .message("opening config")
+        .in_chain()
+        .matches(&err));
+}
+
+#[test]
+fn test_matcher_depth() {
+    let err = denied();
+    assert!(FlatErrorMatcher::new().depth(3).matches(&err));
+    assert!(!FlatErrorMatcher::new()
+        .depth(3)
+        .matches(err.flat_source().unwrap()));
+}
+
+#[test]
+fn test_matcher_display() {
+    assert_eq!(FlatErrorMatcher::new().to_string(), "any error");
+    assert_eq!(
+        FlatErrorMatcher::new()
+            .message_contains("denied")
+            .type_name("std::io::Error")
+            .code("E403")
+            .depth(2)
+            .in_chain()
+            .to_string(),
+        "type `std::io::Error`, message contains `denied`, code `E403`, depth 2, in chain"
+    );
+}
+
+#[test]
+fn test_assert_flat_matches() {
+    let err = denied();
+    assert_flat_matches!(err, message starts_with "opening", depth: 3);
+    assert_flat_matches!(&err, type: "std::io::Error", message contains "denied", in chain);
+    assert_flat_matches!(err, code: "E403", message ends_with "settings", in chain,);
+}
+
+#[test]
+fn test_assert_flat_matches_failure() {
+    let result = catch_unwind(|| assert_flat_matches!(denied(), code: "E500"));
+    let panic = result.unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(
+        message.starts_with("assertion failed: error does not match\n  error: opening config (")
+    );
+    assert!(message.ends_with("\nmatcher: code `E500`"));
+}
@@ -0,0 +1,53 @@
+use flat_error::{DisplayStyle, FlatError};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_message_top_only() {
+    let err = FlatError::new("disk full")
+        .context("saving file")
+        .map_message(|m| m.to_uppercase());
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "SAVING FILE: disk full"
+    );
+}
+
+#[test]
+fn test_map_messages_in_order() {
+    let mut seen = Vec::new();
+    let err = FlatError::new("c")
+        .context("b")
+        .context("a")
+        .map_messages(|m| {
+            seen.push(m.to_string());
+            m.repeat(2)
+        });
+    assert_eq!(seen, vec!["a", "b", "c"]);
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "aa: bb: cc"
+    );
+}
+
+#[test]
+fn test_prefix_and_suffix() {
+    let err = FlatError::new("disk full")
+        .prefix_message(3)
+        .prefix_message("step ")
+        .suffix_message(" (retrying)");
+    assert_eq!(err.to_string(), "step 3disk full (retrying)");
+}
+
+#[test]
+fn test_transform_keeps_attributes() {
+    let err = FlatError::new("disk full")
+        .with_code("E1")
+        .with_field("path", "/tmp")
+        .prefix_message("x: ");
+    assert_eq!(err.code(), Some("E1"));
+    assert_eq!(err.field("path"), Some("/tmp"));
+}
@@ -0,0 +1,49 @@
+use flat_error::FlatError;
+use pretty_assertions::{assert_eq, assert_ne};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_no_message_key_by_default() {
+    let err = FlatError::new("open failed");
+    assert_eq!(err.message_key(), None);
+    assert_eq!(err.message_args().count(), 0);
+}
+
+#[test]
+fn test_message_key_replaces_args() {
+    let err = FlatError::new("open failed")
+        .with_message_key("fs.open_failed", [("path", "/tmp/a")])
+        .with_message_key("fs.read_failed", [("attempt", 2)]);
+    assert_eq!(err.message_key(), Some("fs.read_failed"));
+    assert_eq!(
+        err.message_args().collect::<Vec<_>>(),
+        vec![("attempt", "2")]
+    );
+}
+
+#[test]
+fn test_message_key_participates_in_eq() {
+    let err = FlatError::new("open failed").with_message_key("fs.open_failed", [("path", "/a")]);
+    assert_eq!(err.clone(), err);
+    assert_ne!(FlatError::new("open failed"), err);
+    assert_ne!(
+        FlatError::new("open failed").with_message_key("fs.open_failed", [("path", "/b")]),
+        err
+    );
+}
+
+#[test]
+fn test_builder_message_key() {
+    let err = FlatError::builder()
+        .message("open failed")
+        .message_key("fs.open_failed", [("path", "/tmp/a")])
+        .build();
+    assert_eq!(err.message_key(), Some("fs.open_failed"));
+    assert_eq!(
+        err.message_args().collect::<Vec<_>>(),
+        vec![("path", "/tmp/a")]
+    );
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "metrics")]
+
+use flat_error::{ErrorClass, FlatError};
+use metrics::{
+    with_local_recorder, Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder,
+    SharedString, Unit,
+};
+use pretty_assertions::assert_eq;
+use std::{
+    io::{Error as IoError, ErrorKind},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+type Recorded = Vec<(String, Vec<(String, String)>, u64)>;
+
+#[derive(Default)]
+struct TestRecorder {
+    counters: Mutex<Vec<(Key, Arc<TestCounter>)>>,
+}
+
+#[derive(Default)]
+struct TestCounter(AtomicU64);
+
+impl Recorder for TestRecorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        let mut counters = self.counters.lock().unwrap();
+        let counter = match counters.iter().find(|(existing, _)| existing == key) {
+            Some((_, counter)) => counter.clone(),
+            None => {
+                let counter = Arc::new(TestCounter::default());
+                counters.push((key.clone(), counter.clone()));
+                counter
+            }
+        };
+        Counter::from_arc(counter)
+    }
+
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+        Histogram::noop()
+    }
+}
+
+impl CounterFn for TestCounter {
+    fn increment(&self, value: u64) {
+        self.0.fetch_add(value, Ordering::SeqCst);
+    }
+
+    fn absolute(&self, value: u64) {
+        self.0.store(value, Ordering::SeqCst);
+    }
+}
+
+impl TestRecorder {
+    fn recorded(&self) -> Recorded {
+        self.counters
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, counter)| {
+                (
+                    key.name().to_string(),
+                    key.labels()
+                        .map(|label| (label.key().to_string(), label.value().to_string()))
+                        .collect(),
+                    counter.0.load(Ordering::SeqCst),
+                )
+            })
+            .collect()
+    }
+}
+
+fn labels(class: &str, code: &str, root_type: &str) -> Vec<(String, String)> {
+    vec![
+        ("class".to_string(), class.to_string()),
+        ("code".to_string(), code.to_string()),
+        ("root_type".to_string(), root_type.to_string()),
+    ]
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_record_metric_labels() {
+    let recorder = TestRecorder::default();
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("loading profile")
+        .with_code("E0404");
+    with_local_recorder(&recorder, || err.record_metric("app_errors"));
+    assert_eq!(
+        recorder.recorded(),
+        vec![(
+            "app_errors".to_string(),
+            labels("not_found", "E0404", "std::io::error::Error"),
+            1
+        )]
+    );
+}
+
+#[test]
+fn test_record_metric_increments() {
+    let recorder = TestRecorder::default();
+    let err = FlatError::new("database busy").with_class(ErrorClass::Unavailable);
+    with_local_recorder(&recorder, || {
+        err.record_metric("app_errors");
+        err.record_metric("app_errors");
+        FlatError::new("disk full").record_metric(String::from("app_errors"));
+    });
+    assert_eq!(
+        recorder.recorded(),
+        vec![
+            (
+                "app_errors".to_string(),
+                labels("unavailable", "", "flat_error::FlatError"),
+                2
+            ),
+            (
+                "app_errors".to_string(),
+                labels("internal", "", "flat_error::FlatError"),
+                1
+            ),
+        ]
+    );
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "miette")]
+
+use flat_error::{FlatError, Severity};
+use miette::Diagnostic;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_diagnostic_defaults() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert!(Diagnostic::code(&err).is_none());
+    assert_eq!(Diagnostic::severity(&err), Some(miette::Severity::Error));
+}
+
+#[test]
+fn test_diagnostic_code_and_severity() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .with_code("E1")
+        .with_severity(Severity::Info);
+    assert_eq!(
+        Diagnostic::code(&err).map(|c| c.to_string()),
+        Some("E1".to_string())
+    );
+    assert_eq!(Diagnostic::severity(&err), Some(miette::Severity::Advice));
+}
+
+#[test]
+fn test_diagnostic_help() {
+    let err = FlatError::new_static("destination exists").with_help("try --force");
+    assert_eq!(
+        Diagnostic::help(&err).map(|h| h.to_string()),
+        Some("try --force".to_string())
+    );
+    assert!(Diagnostic::help(&FlatError::new_static("disk full")).is_none());
+}
+
+#[test]
+fn test_diagnostic_url() {
+    let err = FlatError::new_static("disk full").with_docs_url("https://example.com/disk-full");
+    assert_eq!(
+        Diagnostic::url(&err).map(|u| u.to_string()),
+        Some("https://example.com/disk-full".to_string())
+    );
+}
+
+#[test]
+fn test_report_keeps_chain() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("reading config");
+    let report = miette::Report::from(err);
+    assert_eq!(
+        report.chain().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec!["reading config".to_string(), "entity not found".to_string()]
+    );
+}
+
+#[test]
+fn test_diagnostic_related() {
+    let err = FlatError::new_static("duplicate key `name`")
+        .with_related(FlatError::new_static("first defined at line 3"));
+    let related: Vec<String> = Diagnostic::related(&err)
+        .unwrap()
+        .map(|related| related.to_string())
+        .collect();
+    assert_eq!(related, vec!["first defined at line 3".to_string()]);
+    assert!(Diagnostic::related(&FlatError::new_static("disk full")).is_none());
+}
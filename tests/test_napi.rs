@@ -0,0 +1,42 @@
+#![cfg(feature = "napi")]
+
+use flat_error::{ErrorClass, FlatError};
+use napi::{Error, Status};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_napi_error() {
+    let err = FlatError::from(Error::from_reason("callback threw"));
+    assert_eq!(err.to_string(), "callback threw");
+    assert!(err.original_type_name().starts_with("napi::error::Error"));
+    assert_eq!(err.fields().collect::<Vec<_>>(), vec![("napi_status", "9")]);
+    assert_eq!(err.napi_status(), Status::GenericFailure);
+}
+
+#[test]
+fn test_from_napi_error_without_reason() {
+    let err = FlatError::from(Error::from_status(Status::StringExpected));
+    assert_eq!(err.to_string(), "StringExpected");
+    assert_eq!(err.napi_status(), Status::StringExpected);
+}
+
+#[test]
+fn test_into_napi_error_from_class() {
+    let err = Error::from(FlatError::invalid_input("negative count").context("resizing"));
+    assert_eq!(err.status, Status::InvalidArg);
+    assert_eq!(err.reason, "resizing: negative count");
+
+    let err = Error::from(FlatError::new("stopped").with_class(ErrorClass::Cancelled));
+    assert_eq!(err.status, Status::Cancelled);
+}
+
+#[test]
+fn test_napi_status_round_trip() {
+    let err = Error::from(FlatError::from(Error::new(Status::QueueFull, "queue full")));
+    assert_eq!(err.status, Status::QueueFull);
+    assert_eq!(err.reason, "queue full");
+}
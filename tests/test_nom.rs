@@ -0,0 +1,68 @@
+#![cfg(feature = "nom")]
+
+use flat_error::{FlatError, SourceSpan};
+use nom::{
+    bytes::{complete::tag, streaming},
+    character::complete::digit1,
+    combinator::cut,
+    error::Error,
+    IResult, Parser,
+};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+fn assignment(input: &str) -> IResult<&str, &str> {
+    let (input, _) = tag("x = ")(input)?;
+    cut(digit1).parse(input)
+}
+
+#[test]
+fn test_from_nom_err() {
+    fn parse(s: &str) -> Result<&str, FlatError> {
+        Ok(assignment(s)?.1)
+    }
+    let err = parse("y = 1").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"Parsing Error: Error { input: "y = 1", code: Tag }"#
+    );
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("nom.kind", "error")]
+    );
+    assert_eq!(err.span(), None);
+}
+
+#[test]
+fn test_from_nom_error_failure_span() {
+    let input = "x = 1\nx = y";
+    let err = FlatError::from_nom_error(input, assignment(&input[6..]).unwrap_err());
+    assert_eq!(err.to_string(), "parse error: Digit");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("nom.kind", "failure"), ("nom.code", "Digit")]
+    );
+    assert_eq!(err.span(), Some(SourceSpan::new(10, 0, 2, 5)));
+}
+
+#[test]
+fn test_from_nom_error_foreign_input() {
+    let other = String::from("z");
+    let err = FlatError::from_nom_error("x = 1", assignment(&other).unwrap_err());
+    assert_eq!(err.field("nom.code"), Some("Tag"));
+    assert_eq!(err.span(), None);
+}
+
+#[test]
+fn test_from_nom_error_incomplete() {
+    let err = FlatError::from_nom_error(
+        "hel",
+        streaming::tag::<_, _, Error<&str>>("hello")("hel").unwrap_err(),
+    );
+    assert_eq!(err.to_string(), "Parsing requires 2 bytes/chars");
+    assert_eq!(err.field("nom.kind"), Some("incomplete"));
+    assert_eq!(err.field("nom.code"), None);
+}
@@ -0,0 +1,245 @@
+use flat_error::{DisplayStyle, FlatError, FlattenOptions, DEFAULT_MAX_DEPTH};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct Ping(&'static str);
+
+#[derive(Debug)]
+pub struct Pong(&'static str);
+
+static PING: Ping = Ping("ping");
+
+static PONG: Pong = Pong("pong");
+
+impl Display for Ping {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for Ping {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&PONG)
+    }
+}
+
+impl Display for Pong {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for Pong {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&PING)
+    }
+}
+
+fn chain(length: usize) -> FlatError {
+    (1..length).fold(FlatError::new_static("0"), |err, depth| err.context(depth))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_default_options() {
+    assert_eq!(FlattenOptions::default().max_depth(), DEFAULT_MAX_DEPTH);
+    assert_eq!(FlattenOptions::new(), FlattenOptions::default());
+}
+
+#[test]
+fn test_within_max_depth() {
+    let nested = chain(4);
+    let err = FlatError::from_any_with(&nested, FlattenOptions::new().with_max_depth(3));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "3: 2: 1: 0"
+    );
+}
+
+#[test]
+fn test_exceeds_max_depth() {
+    let nested = chain(6);
+    let err = FlatError::from_any_with(&nested, FlattenOptions::new().with_max_depth(2));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "5: 4: 3: … 3 more"
+    );
+}
+
+#[test]
+fn test_default_max_depth() {
+    let nested = chain(DEFAULT_MAX_DEPTH + 11);
+    let err = FlatError::from_any(&nested);
+    assert!(err
+        .display_with(DisplayStyle::Compact)
+        .to_string()
+        .ends_with(": 10: … 10 more"));
+}
+
+#[test]
+fn test_cyclic_sources() {
+    let err = FlatError::from_any(&PING);
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "ping: pong: ping: … cyclic source chain"
+    );
+}
+
+#[test]
+fn test_cyclic_sources_beyond_max_depth() {
+    let err = FlatError::from_any_with(&PING, FlattenOptions::new().with_max_depth(0));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "ping: … cyclic source chain"
+    );
+}
+
+#[test]
+fn test_normalized_type_names() {
+    let lock = std::sync::Mutex::new(0_u8);
+    let err = std::sync::PoisonError::new(lock.lock().unwrap());
+    let options = FlattenOptions::new().with_normalized_type_names(true);
+    assert!(options.normalize_type_names());
+
+    let flat = FlatError::from_any(&err);
+    assert!(flat.original_type_name().starts_with("std::sync::"));
+    assert_eq!(flat.original_type_short_name(), "PoisonError");
+
+    let flat = FlatError::from_any_with(&err, options);
+    assert_eq!(flat.original_type_name(), "PoisonError<MutexGuard<'_, u8>>");
+    assert_eq!(flat.original_type_short_name(), "PoisonError");
+}
+
+#[test]
+fn test_normalized_source_type_names() {
+    let nested = chain(2);
+    let options = FlattenOptions::new().with_normalized_type_names(true);
+    let flat = FlatError::from_any_with(&nested, options);
+    assert_eq!(flat.original_type_name(), "FlatError");
+    assert_eq!(
+        flat.flat_source().map(|e| e.original_type_name()),
+        Some("dyn Error")
+    );
+}
+
+#[derive(Debug)]
+pub struct Wrapper(std::io::Error);
+
+impl Display for Wrapper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "wrapper")
+    }
+}
+
+impl Error for Wrapper {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn test_source_at_same_address_not_cyclic() {
+    let err = FlatError::from_any(&Wrapper(std::io::Error::other("inner")));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "wrapper: inner"
+    );
+}
+
+#[derive(Debug)]
+pub struct Retry(usize);
+
+impl Display for Retry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "retry failed")
+    }
+}
+
+impl Error for Retry {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.0 {
+            0 => None,
+            1 => Some(&PONG),
+            _ => Some(Box::leak(Box::new(Retry(self.0 - 1)))),
+        }
+    }
+}
+
+#[test]
+fn test_no_dedup_frames_by_default() {
+    assert!(!FlattenOptions::new().dedup_frames());
+    let err = FlatError::from_any(&Wrapper(std::io::Error::other("inner")));
+    assert_eq!(err.repeat_count(), 1);
+
+    let err = FlatError::from_any(&Retry(3));
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "retry failed: retry failed: retry failed: pong: ping: … cyclic source chain"
+    );
+}
+
+#[test]
+fn test_dedup_frames() {
+    let options = FlattenOptions::new().with_dedup_frames(true);
+    let err = FlatError::from_any_with(&Retry(4), options);
+    assert_eq!(err.repeat_count(), 1);
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "retry failed: retry failed: pong: ping: … cyclic source chain"
+    );
+    assert_eq!(
+        err.frames().map(|f| f.repeat_count()).collect::<Vec<_>>(),
+        vec![1, 3, 1, 1, 1]
+    );
+}
+
+#[test]
+fn test_dedup_frames_requires_same_type() {
+    let options = FlattenOptions::new().with_dedup_frames(true);
+    let err = FlatError::from_any_with(&Wrapper(std::io::Error::other(Retry(3))), options);
+    assert_eq!(
+        err.display_with(DisplayStyle::Compact).to_string(),
+        "wrapper: retry failed: retry failed: pong: ping: … cyclic source chain"
+    );
+    assert_eq!(
+        err.frames().map(|f| f.repeat_count()).collect::<Vec<_>>(),
+        vec![1, 1, 2, 1, 1, 1]
+    );
+}
+
+#[test]
+fn test_no_debug_capture_by_default() {
+    assert!(!FlattenOptions::new().capture_debug());
+    let err = FlatError::from_any(&Wrapper(std::io::Error::other("inner")));
+    assert_eq!(err.debug_repr(), None);
+    assert!(err.frames().all(|f| f.debug_repr().is_none()));
+}
+
+#[test]
+fn test_debug_capture() {
+    let options = FlattenOptions::new().with_debug_capture(true);
+    let err = FlatError::from_any_with(&Wrapper(std::io::Error::other("inner")), options);
+    assert_eq!(err.to_string(), "wrapper");
+    assert_eq!(
+        err.debug_repr(),
+        Some(r#"Wrapper(Custom { kind: Other, error: "inner" })"#)
+    );
+    assert_eq!(
+        err.frames().map(|f| f.debug_repr()).collect::<Vec<_>>(),
+        vec![
+            Some(r#"Wrapper(Custom { kind: Other, error: "inner" })"#),
+            Some(r#"Custom { kind: Other, error: "inner" }"#),
+        ]
+    );
+}
@@ -0,0 +1,52 @@
+use flat_error::{FlatError, Severity};
+use pretty_assertions::{assert_eq, assert_ne};
+use std::cmp::Ordering;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_sort_by_message() {
+    let mut errors = vec![
+        FlatError::new("c"),
+        FlatError::new("a"),
+        FlatError::new("b"),
+    ];
+    errors.sort();
+    assert_eq!(
+        errors,
+        vec![
+            FlatError::new("a"),
+            FlatError::new("b"),
+            FlatError::new("c")
+        ]
+    );
+}
+
+#[test]
+fn test_type_name_before_message() {
+    let flat = FlatError::new("a");
+    let other = FlatError::from_any(&"1.5".parse::<u8>().unwrap_err());
+    assert_eq!(flat.original_type_name(), "flat_error::FlatError");
+    assert_eq!(flat.cmp(&other), Ordering::Greater);
+}
+
+#[test]
+fn test_source_before_attributes() {
+    let lhs = FlatError::new("a")
+        .with_severity(Severity::Critical)
+        .caused_by(FlatError::new("x"));
+    let rhs = FlatError::new("a").caused_by(FlatError::new("y"));
+    assert_eq!(lhs.cmp(&rhs), Ordering::Less);
+    assert_eq!(FlatError::new("a").cmp(&rhs), Ordering::Less);
+}
+
+#[test]
+fn test_ord_consistent_with_eq() {
+    let lhs = FlatError::new("a").with_code("E1");
+    let rhs = FlatError::new("a").with_code("E2");
+    assert_ne!(lhs, rhs);
+    assert_ne!(lhs.cmp(&rhs), Ordering::Equal);
+    assert_eq!(lhs.cmp(&lhs.clone()), Ordering::Equal);
+}
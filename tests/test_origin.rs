@@ -0,0 +1,31 @@
+#![cfg(feature = "origin")]
+
+use flat_error::{set_capture_origin, FlatError, HOST_NAME_FIELD, PROCESS_ID_FIELD};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_origin_capture() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.process_id(), None);
+    assert_eq!(err.host_name(), None);
+
+    set_capture_origin(true);
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound)).context("loading profile");
+    set_capture_origin(false);
+
+    assert_eq!(err.process_id(), None);
+    let source = err.flat_source().unwrap();
+    assert_eq!(source.process_id(), Some(std::process::id()));
+    assert_eq!(
+        source.field(PROCESS_ID_FIELD),
+        Some(std::process::id().to_string().as_str())
+    );
+    assert_eq!(source.host_name(), source.field(HOST_NAME_FIELD));
+    #[cfg(target_os = "linux")]
+    assert!(source.host_name().is_some_and(|name| !name.is_empty()));
+}
@@ -0,0 +1,42 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_not_retained_by_default() {
+    let err = FlatError::from_any(&IoError::from(ErrorKind::NotFound));
+    assert!(!err.has_original());
+    assert!(err.downcast_ref::<IoError>().is_none());
+}
+
+#[test]
+fn test_retained_downcast() {
+    let err = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound));
+    assert!(err.has_original());
+    assert_eq!(
+        err.downcast_ref::<IoError>().map(|e| e.kind()),
+        Some(ErrorKind::NotFound)
+    );
+    assert!(err.downcast_ref::<std::fmt::Error>().is_none());
+}
+
+#[test]
+fn test_retained_shared_by_clones() {
+    let err = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound));
+    let cloned = err.clone();
+    assert_eq!(
+        cloned.into_original().map(|e| e.to_string()),
+        Some(IoError::from(ErrorKind::NotFound).to_string())
+    );
+}
+
+#[test]
+fn test_retained_ignored_by_eq() {
+    let retained = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound));
+    let flat = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(retained, flat);
+}
@@ -0,0 +1,68 @@
+use flat_error::{catch_flat, join_flat, FlatError, PANIC_TYPE_NAME};
+use pretty_assertions::assert_eq;
+use std::{
+    panic::{catch_unwind, panic_any},
+    thread,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_catch_flat_ok() {
+    assert_eq!(catch_flat(|| "done"), Ok("done"));
+}
+
+#[test]
+fn test_catch_flat_static_message() {
+    let err = catch_flat(|| panic!("static message")).unwrap_err();
+    assert_eq!(err.to_string(), "static message");
+    assert_eq!(err.original_type_name(), PANIC_TYPE_NAME);
+    assert!(err.flat_source().is_none());
+}
+
+#[test]
+fn test_catch_flat_formatted_message() {
+    let name = "worker";
+    let err = catch_flat(|| panic!("{name} failed")).unwrap_err();
+    assert_eq!(err.to_string(), "worker failed");
+}
+
+#[test]
+fn test_catch_flat_other_payload() {
+    let err = catch_flat(|| panic_any(42_u32)).unwrap_err();
+    assert_eq!(err.to_string(), "Box<dyn Any>");
+    assert_eq!(err.original_type_name(), PANIC_TYPE_NAME);
+}
+
+#[test]
+fn test_from_join_panic() {
+    let payload = catch_unwind(|| panic!("from payload")).unwrap_err();
+    let err = FlatError::from_join_panic(payload);
+    assert_eq!(err.to_string(), "from payload");
+    assert_eq!(err.original_type_name(), PANIC_TYPE_NAME);
+}
+
+#[test]
+fn test_join_flat() {
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            thread::spawn(move || {
+                if i == 1 {
+                    panic!("worker {i} failed");
+                }
+                i
+            })
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(join_flat).collect();
+    assert_eq!(
+        results,
+        vec![
+            Ok(0),
+            Err(FlatError::from_join_panic(Box::new("worker 1 failed"))),
+            Ok(2)
+        ]
+    );
+}
@@ -0,0 +1,49 @@
+use flat_error::{
+    install_flat_panic_hook, FlatError, PANIC_LOCATION_FIELD, PANIC_TYPE_NAME, THREAD_NAME_FIELD,
+};
+use pretty_assertions::assert_eq;
+use std::{
+    panic,
+    sync::{Mutex, PoisonError},
+    thread,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+static REPORTED: Mutex<Vec<FlatError>> = Mutex::new(Vec::new());
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_install_flat_panic_hook() {
+    install_flat_panic_hook(|err| {
+        REPORTED
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(err)
+    });
+    let line = line!() + 3;
+    let result = thread::Builder::new()
+        .name("flat-worker".into())
+        .spawn(|| panic!("worker {} failed", 3))
+        .unwrap()
+        .join();
+    let _ = panic::take_hook();
+    assert!(result.is_err());
+
+    let reported = REPORTED.lock().unwrap();
+    let err = reported
+        .iter()
+        .find(|err| err.field(THREAD_NAME_FIELD) == Some("flat-worker"))
+        .unwrap();
+    assert_eq!(err.to_string(), "worker 3 failed");
+    assert_eq!(err.original_type_name(), PANIC_TYPE_NAME);
+    assert!(err
+        .field(PANIC_LOCATION_FIELD)
+        .unwrap()
+        .starts_with(&format!("{}:{line}:", file!())));
+}
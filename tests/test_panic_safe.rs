@@ -0,0 +1,92 @@
+use flat_error::{ExtendedError, FlatError};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct BadDisplay(IoError);
+
+impl Display for BadDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a message that is never finished")?;
+        panic!("formatting is broken")
+    }
+}
+
+impl Error for BadDisplay {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[derive(Debug)]
+struct BadSource;
+
+impl Display for BadSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("bad source")
+    }
+}
+
+impl Error for BadSource {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        panic!("source is broken")
+    }
+}
+
+#[derive(Debug)]
+struct Outer(BadDisplay);
+
+impl Display for Outer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("outer")
+    }
+}
+
+impl Error for Outer {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_display_panics() {
+    let err = FlatError::from_error(&BadDisplay(IoError::from(ErrorKind::NotFound)));
+    assert_eq!(err.to_string(), "… panicked while formatting message");
+    assert_eq!(err.original_type_short_name(), "BadDisplay");
+    assert_eq!(
+        err.flat_source().unwrap().io_kind(),
+        Some(ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn test_display_panics_in_source_chain() {
+    let err = FlatError::from_error(&Outer(BadDisplay(IoError::from(ErrorKind::NotFound))));
+    assert_eq!(
+        err.chain_to_strings(),
+        [
+            "outer",
+            "… panicked while formatting message",
+            "entity not found",
+        ]
+    );
+}
+
+#[test]
+fn test_source_panics() {
+    let err = FlatError::from_error(&BadSource);
+    assert_eq!(err.to_string(), "bad source");
+    assert_eq!(err.flat_source(), None);
+}
@@ -0,0 +1,70 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_parse_minimal() {
+    let err: FlatError = "entity not found (original type: `std::io::error::Error`)"
+        .parse()
+        .unwrap();
+    assert_eq!(err.to_string(), "entity not found");
+    assert_eq!(err.original_type_name(), "std::io::error::Error");
+    assert_eq!(err.code(), None);
+    assert!(err.flat_source().is_none());
+}
+
+#[test]
+fn test_round_trip_io_error() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound)).with_code("E2");
+    let text = format!("{err:#}");
+    let parsed: FlatError = text.parse().unwrap();
+    assert_eq!(parsed.to_string(), err.to_string());
+    assert_eq!(parsed.original_type_name(), err.original_type_name());
+    assert_eq!(parsed.code(), Some("E2"));
+    assert_eq!(format!("{parsed:#}"), text);
+}
+
+#[test]
+fn test_parse_message_with_parentheses() {
+    let err = FlatError::new("failed (attempt 2)")
+        .caused_by(FlatError::new("timed out (after 5s), giving up"));
+    let text = format!("{err:#}");
+    let parsed: FlatError = text.parse().unwrap();
+    assert_eq!(parsed, err);
+}
+
+#[test]
+fn test_parse_empty_message() {
+    let parsed: FlatError = " (original type: `a::B`)".parse().unwrap();
+    assert_eq!(parsed.to_string(), "");
+    assert_eq!(parsed.original_type_name(), "a::B");
+}
+
+#[test]
+fn test_parsed_type_names_are_interned() {
+    let first: FlatError = "one (original type: `parse::Interned`)".parse().unwrap();
+    let second: FlatError = "two (original type: `parse::Interned`)".parse().unwrap();
+    let name: &'static str = first.original_type_name();
+    assert_eq!(name, "parse::Interned");
+    assert!(std::ptr::eq(name, second.original_type_name()));
+}
+
+#[test]
+fn test_parse_invalid() {
+    let err = "entity not found".parse::<FlatError>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "flat error does not end with an original type name"
+    );
+    let err = "entity not found (cause: x, original type: `a::B`)"
+        .parse::<FlatError>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "flat error does not contain valid attributes"
+    );
+}
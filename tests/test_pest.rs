@@ -0,0 +1,64 @@
+#![cfg(feature = "pest")]
+
+use flat_error::{FlatError, SourceSpan, FILE_PATH_FIELD};
+use pest::{
+    error::{Error, ErrorVariant},
+    Position, Span,
+};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Rule {
+    Number,
+    String,
+    Keyword,
+}
+
+const INPUT: &str = "a = 1\nb = if\n";
+
+#[test]
+fn test_parsing_error() {
+    let err = FlatError::from(Error::new_from_pos(
+        ErrorVariant::ParsingError {
+            positives: vec![Rule::Number, Rule::String],
+            negatives: vec![Rule::Keyword],
+        },
+        Position::new(INPUT, 10).unwrap(),
+    ));
+    assert_eq!(
+        err.to_string(),
+        "unexpected Keyword; expected Number or String"
+    );
+    assert!(err.original_type_name().starts_with("pest::error::Error<"));
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![
+            ("pest.positives", "Number, String"),
+            ("pest.negatives", "Keyword")
+        ]
+    );
+    assert_eq!(err.span(), Some(SourceSpan::new(10, 0, 2, 5)));
+}
+
+#[test]
+fn test_custom_error_span_and_path() {
+    let err = FlatError::from(
+        Error::<Rule>::new_from_span(
+            ErrorVariant::CustomError {
+                message: "reserved word".to_string(),
+            },
+            Span::new(INPUT, 10, 12).unwrap(),
+        )
+        .with_path("config.txt"),
+    );
+    assert_eq!(err.to_string(), "reserved word");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![(FILE_PATH_FIELD, "config.txt")]
+    );
+    assert_eq!(err.span(), Some(SourceSpan::new(10, 2, 2, 5)));
+}
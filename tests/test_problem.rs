@@ -0,0 +1,49 @@
+#![cfg(all(feature = "http", feature = "serde"))]
+
+use flat_error::{FlatError, ProblemDetails};
+use http::StatusCode;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_problem_details_defaults() {
+    let problem = FlatError::from(IoError::from(ErrorKind::PermissionDenied)).problem_details();
+    assert_eq!(problem.problem_type, "about:blank");
+    assert_eq!(problem.title, "Forbidden");
+    assert_eq!(problem.status, 403);
+    assert_eq!(problem.detail.as_deref(), Some("permission denied"));
+    assert_eq!(problem.extensions.len(), 1);
+    assert_eq!(problem.extensions["class"], "permission_denied");
+}
+
+#[test]
+fn test_problem_details_excludes_http_status_field() {
+    let problem = FlatError::new("slow down")
+        .with_http_status(StatusCode::TOO_MANY_REQUESTS)
+        .problem_details();
+    assert_eq!(problem.status, 429);
+    assert_eq!(problem.extensions.get("http_status"), None);
+}
+
+#[test]
+fn test_problem_details_round_trip() {
+    let problem = ProblemDetails::from(&FlatError::new("no such user").with_code("E0404"));
+    let json = serde_json::to_string(&problem).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ProblemDetails>(&json).unwrap(),
+        problem
+    );
+}
+
+#[test]
+fn test_problem_details_deserialize_minimal() {
+    let problem: ProblemDetails =
+        serde_json::from_str(r#"{"title":"Not Found","status":404}"#).unwrap();
+    assert_eq!(problem.problem_type, "about:blank");
+    assert_eq!(problem.detail, None);
+    assert!(problem.extensions.is_empty());
+}
@@ -0,0 +1,63 @@
+#![cfg(unix)]
+
+use flat_error::{FlatError, MAX_COMMAND_STDERR_LEN};
+use pretty_assertions::assert_eq;
+use std::process::Command;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_exit_status() {
+    let status = Command::new("sh").args(["-c", "exit 3"]).status().unwrap();
+    let err = FlatError::from_exit_status(status);
+    assert_eq!(err.to_string(), "exit status: 3");
+    assert_eq!(err.original_type_name(), "std::process::ExitStatus");
+    assert_eq!(err.field("exit_code"), Some("3"));
+    assert_eq!(err.field("signal"), None);
+}
+
+#[test]
+fn test_from_exit_status_signal() {
+    let status = Command::new("sh")
+        .args(["-c", "kill -9 $$"])
+        .status()
+        .unwrap();
+    let err = FlatError::from_exit_status(status);
+    assert_eq!(err.field("exit_code"), None);
+    assert_eq!(err.field("signal"), Some("9"));
+}
+
+#[test]
+fn test_from_command_output_stderr() {
+    let output = Command::new("sh")
+        .args(["-c", "echo '  disk full  ' >&2; exit 1"])
+        .output()
+        .unwrap();
+    let err = FlatError::from_command_output(&output);
+    assert_eq!(err.to_string(), "disk full");
+    assert_eq!(err.original_type_name(), "std::process::Output");
+    assert_eq!(err.field("exit_code"), Some("1"));
+}
+
+#[test]
+fn test_from_command_output_no_stderr() {
+    let output = Command::new("sh").args(["-c", "exit 2"]).output().unwrap();
+    let err = FlatError::from_command_output(&output);
+    assert_eq!(err.to_string(), "exit status: 2");
+}
+
+#[test]
+fn test_from_command_output_bounded() {
+    let output = Command::new("sh")
+        .args(["-c", "head -c 5000 /dev/zero | tr '\\0' x >&2; exit 1"])
+        .output()
+        .unwrap();
+    let err = FlatError::from_command_output(&output);
+    assert_eq!(
+        err.to_string().len(),
+        MAX_COMMAND_STDERR_LEN + '…'.len_utf8()
+    );
+    assert_eq!(err.original_message_len(), Some(5000));
+}
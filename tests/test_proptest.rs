@@ -0,0 +1,22 @@
+#![cfg(feature = "proptest")]
+
+use flat_error::FlatError;
+use proptest::prelude::*;
+use std::iter::successors;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+proptest! {
+    #[test]
+    fn test_proptest_chain_depth(err in any::<FlatError>()) {
+        let depth = successors(Some(&err), |err| err.flat_source()).count();
+        prop_assert!((1..=5).contains(&depth));
+    }
+
+    #[test]
+    fn test_proptest_clone_eq(err in any::<FlatError>()) {
+        prop_assert_eq!(err.clone(), err);
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(flat_error_provider)]
+#![feature(error_generic_member_access)]
+
+use flat_error::{FlatError, Severity};
+use pretty_assertions::assert_eq;
+use std::{
+    backtrace::Backtrace,
+    error::{request_ref, request_value, Error},
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct TracedError(Backtrace);
+
+impl Display for TracedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "traced")
+    }
+}
+
+impl Error for TracedError {
+    fn provide<'a>(&'a self, request: &mut std::error::Request<'a>) {
+        request.provide_ref::<Backtrace>(&self.0);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_provides_self_and_severity() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound)).with_severity(Severity::Warning);
+    let dyn_err: &dyn Error = &err;
+    assert_eq!(request_ref::<FlatError>(dyn_err), Some(&err));
+    assert_eq!(request_value::<Severity>(dyn_err), Some(Severity::Warning));
+    assert_eq!(
+        request_value::<ErrorKind>(dyn_err),
+        Some(ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn test_forwards_from_original() {
+    let err = FlatError::from_any_retaining(TracedError(Backtrace::disabled()));
+    let dyn_err: &dyn Error = &err;
+    assert!(request_ref::<Backtrace>(dyn_err).is_some());
+    let err = FlatError::from_any(&TracedError(Backtrace::disabled()));
+    let dyn_err: &dyn Error = &err;
+    assert!(request_ref::<Backtrace>(dyn_err).is_none());
+}
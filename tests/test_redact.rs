@@ -0,0 +1,45 @@
+use flat_error::{FlatError, Redactor};
+use pretty_assertions::assert_eq;
+use std::{borrow::Cow, io::Error as IoError};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+struct Paths;
+
+impl Redactor for Paths {
+    fn redact<'a>(&self, message: &'a str) -> Cow<'a, str> {
+        if message.contains('/') {
+            Cow::Owned(
+                message
+                    .split(' ')
+                    .map(|word| {
+                        if word.starts_with('/') {
+                            "<path>"
+                        } else {
+                            word
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        } else {
+            Cow::Borrowed(message)
+        }
+    }
+}
+
+fn chain() -> IoError {
+    IoError::other(FlatError::new("opening /etc/secret.key").context("loading /home/me/app.toml"))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_any_redacted_scrubs_chain() {
+    let err = FlatError::from_any_redacted(&chain(), &Paths);
+    assert_eq!(err.report().to_string(), "loading <path>: opening <path>");
+}
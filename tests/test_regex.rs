@@ -0,0 +1,76 @@
+#![cfg(feature = "regex")]
+
+use flat_error::{assert_flat_matches, FlatError, FlatErrorMatcher};
+use pretty_assertions::assert_eq;
+use regex::Regex;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn refused() -> FlatError {
+    FlatError::from(IoError::new(
+        ErrorKind::ConnectionRefused,
+        "connection to 10.0.0.7:5432 refused",
+    ))
+    .context("loading user 42")
+}
+
+fn address_refused() -> Regex {
+    Regex::new(r"^connection to [\d.]+:\d+ refused$").unwrap()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_matches_pattern() {
+    let err = refused();
+    assert!(err.matches_pattern(&Regex::new(r"^loading user \d+$").unwrap()));
+    assert!(!err.matches_pattern(&address_refused()));
+}
+
+#[test]
+fn test_chain_matches_pattern() {
+    let err = refused();
+    assert!(err.chain_matches_pattern(&address_refused()));
+    assert!(!err.chain_matches_pattern(&Regex::new("timed out").unwrap()));
+}
+
+#[test]
+fn test_matcher_message_matches() {
+    let matcher = FlatErrorMatcher::new()
+        .message_matches(address_refused())
+        .in_chain();
+    assert!(matcher.matches(&refused()));
+    assert!(!FlatErrorMatcher::new()
+        .message_matches(address_refused())
+        .matches(&refused()));
+    assert_eq!(
+        matcher.to_string(),
+        r"message matches `^connection to [\d.]+:\d+ refused$`, in chain"
+    );
+}
+
+#[test]
+fn test_matcher_compares_patterns() {
+    assert_eq!(
+        FlatErrorMatcher::new().message_matches(address_refused()),
+        FlatErrorMatcher::new().message_matches(address_refused())
+    );
+    assert_ne!(
+        FlatErrorMatcher::new().message_matches(address_refused()),
+        FlatErrorMatcher::new().message_matches(Regex::new("refused").unwrap())
+    );
+}
+
+#[test]
+fn test_assert_flat_matches_pattern() {
+    assert_flat_matches!(
+        refused(),
+        message matches Regex::new(r"user \d+").unwrap(),
+        depth: 2,
+    );
+}
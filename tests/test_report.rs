@@ -0,0 +1,130 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn chain() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .context("starting server")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_report_single_line() {
+    assert_eq!(
+        chain().report().to_string(),
+        "starting server: reading config file: entity not found".to_string()
+    );
+}
+
+#[test]
+fn test_report_pretty_multiple() {
+    assert_eq!(
+        chain().report().pretty(true).to_string(),
+        "starting server\n\nCaused by:\n   0: reading config file\n   1: entity not found"
+            .to_string()
+    );
+}
+
+#[test]
+fn test_report_pretty_no_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(
+        err.report().pretty(true).to_string(),
+        "entity not found".to_string()
+    );
+}
+
+#[test]
+fn test_report_debug_is_display() {
+    let report = chain().report().pretty(true);
+    assert_eq!(format!("{report:?}"), report.to_string());
+    assert_eq!(report.into_error(), chain());
+}
+
+#[test]
+fn test_report_pretty_help() {
+    let err = chain().with_help("check the --config option\nor set APP_CONFIG");
+    assert_eq!(
+        err.help(),
+        Some("check the --config option\nor set APP_CONFIG")
+    );
+    assert_eq!(
+        err.clone().report().pretty(true).to_string(),
+        "starting server\n\nCaused by:\n   0: reading config file\n   1: entity not found\n\nHelp: check the --config option\n      or set APP_CONFIG"
+    );
+    assert_eq!(
+        err.report().to_string(),
+        "starting server: reading config file: entity not found"
+    );
+}
+
+#[test]
+fn test_help_from_source() {
+    let err = FlatError::new_static("destination exists")
+        .with_help("try running with --force")
+        .context("copying file");
+    assert_eq!(err.help(), Some("try running with --force"));
+    assert_eq!(err.frame(0).unwrap().help(), None);
+    assert_eq!(
+        err.frame(1).unwrap().help(),
+        Some("try running with --force")
+    );
+    assert_eq!(
+        err.with_help("see the manual").help(),
+        Some("see the manual")
+    );
+}
+
+#[test]
+fn test_builder_help() {
+    let err = FlatError::builder()
+        .message("destination exists")
+        .help("try running with --force")
+        .build();
+    assert_eq!(err.help(), Some("try running with --force"));
+}
+
+#[test]
+fn test_report_pretty_docs_url() {
+    let err = FlatError::new_static("replica lag exceeded")
+        .with_help("retry against the primary")
+        .with_docs_url("https://runbooks.example.com/replica-lag");
+    assert_eq!(
+        err.report().pretty(true).to_string(),
+        "replica lag exceeded\n\nHelp: retry against the primary\n\nSee: https://runbooks.example.com/replica-lag"
+    );
+}
+
+#[test]
+fn test_docs_url_from_source() {
+    let err = FlatError::builder()
+        .message("replica lag exceeded")
+        .docs_url("https://runbooks.example.com/replica-lag")
+        .build()
+        .context("serving query");
+    assert_eq!(
+        err.docs_url(),
+        Some("https://runbooks.example.com/replica-lag")
+    );
+    assert_eq!(err.frame(0).unwrap().docs_url(), None);
+}
+
+#[test]
+fn test_report_pretty_related() {
+    let err = FlatError::new_static("duplicate key `name`")
+        .with_related(FlatError::new_static("first defined at line 3"))
+        .with_related(FlatError::new_static("also defined at line 7"));
+    assert_eq!(
+        err.clone().report().pretty(true).to_string(),
+        "duplicate key `name`\n\nRelated:\n      first defined at line 3\n      also defined at line 7"
+    );
+    assert_eq!(err.report().to_string(), "duplicate key `name`");
+}
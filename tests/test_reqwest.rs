@@ -0,0 +1,26 @@
+#![cfg(feature = "reqwest")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_status_error() {
+    fn check(response: reqwest::Response) -> Result<reqwest::Response, FlatError> {
+        Ok(response.error_for_status()?)
+    }
+    let response = reqwest::Response::from(http::Response::builder().status(404).body("").unwrap());
+    let err = check(response).unwrap_err();
+    assert_eq!(err.original_type_name(), "reqwest::error::Error");
+    assert_eq!(err.fields().collect::<Vec<_>>(), vec![("status", "404")]);
+}
+
+#[test]
+fn test_builder_error() {
+    let err = FlatError::from(reqwest::Client::new().get("not a url").build().unwrap_err());
+    assert_eq!(err.to_string(), "builder error");
+    assert_eq!(err.fields().count(), 0);
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "rusqlite")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use rusqlite::Connection;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn connection() -> Connection {
+    let connection = Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE items (name TEXT NOT NULL, qty INTEGER CONSTRAINT qty_positive CHECK (qty > 0));",
+        )
+        .unwrap();
+    connection
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_check_constraint() {
+    fn insert(connection: &Connection) -> Result<usize, FlatError> {
+        Ok(connection.execute("INSERT INTO items VALUES ('a', 0)", [])?)
+    }
+    let err = insert(&connection()).unwrap_err();
+    assert_eq!(err.original_type_name(), "rusqlite::error::Error");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![
+            ("sqlite_code", "ConstraintViolation"),
+            ("extended_code", "275"),
+            ("constraint", "qty_positive"),
+        ]
+    );
+}
+
+#[test]
+fn test_not_a_constraint() {
+    let err = FlatError::from(
+        connection()
+            .execute("SELECT * FROM missing", [])
+            .unwrap_err(),
+    );
+    assert_eq!(err.field("sqlite_code"), Some("Unknown"));
+    assert_eq!(err.field("constraint"), None);
+}
+
+#[test]
+fn test_not_sqlite_failure() {
+    let err = FlatError::from(rusqlite::Error::QueryReturnedNoRows);
+    assert_eq!(err.fields().count(), 0);
+}
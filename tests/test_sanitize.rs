@@ -0,0 +1,126 @@
+use flat_error::{FlatError, FlattenOptions};
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+struct Raw(&'static str);
+
+impl Debug for Raw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Raw({})", self.0)
+    }
+}
+
+impl Display for Raw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.0)
+    }
+}
+
+impl Error for Raw {}
+
+fn sanitized(message: &'static str) -> String {
+    FlatError::from_any_with(
+        &Raw(message),
+        FlattenOptions::new().with_sanitized_messages(true),
+    )
+    .to_string()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_not_sanitized_when_disabled() {
+    let options = FlattenOptions::new().with_sanitized_messages(false);
+    assert!(!options.sanitize_messages());
+    let err = FlatError::from_any_with(&Raw("line one\nline two"), options);
+    assert_eq!(err.to_string(), "line one\nline two");
+}
+
+#[test]
+fn test_safe_message_unchanged() {
+    assert_eq!(
+        sanitized("file \"a.txt\" not found: C:\\tmp"),
+        "file \"a.txt\" not found: C:\\tmp"
+    );
+    assert_eq!(sanitized("größe überschritten ✓"), "größe überschritten ✓");
+}
+
+#[test]
+fn test_newlines_escaped() {
+    assert_eq!(
+        sanitized("user admin\r\n2024-01-01 INFO login ok\tdone"),
+        r"user admin\r\n2024-01-01 INFO login ok\tdone"
+    );
+}
+
+#[test]
+fn test_control_characters_escaped() {
+    assert_eq!(
+        sanitized("a\0b\x07c\x7fd\u{85}e"),
+        r"a\u{0}b\u{7}c\u{7f}d\u{85}e"
+    );
+    assert_eq!(
+        sanitized("a\u{2028}b\u{202e}c\u{2066}d"),
+        r"a\u{2028}b\u{202e}c\u{2066}d"
+    );
+}
+
+#[test]
+fn test_ansi_sequences_removed() {
+    assert_eq!(sanitized("\x1b[1;31mred\x1b[0m text"), "red text");
+    assert_eq!(sanitized("clear\x1b[2J\x1b[Hscreen"), "clearscreen");
+    assert_eq!(sanitized("\x1b]0;pwned\x07title"), "title");
+    assert_eq!(
+        sanitized("\x1b]8;;http://x\x1b\\link\x1b]8;;\x1b\\"),
+        "link"
+    );
+    assert_eq!(sanitized("reset\x1bcdone"), "resetdone");
+    assert_eq!(sanitized("unterminated \x1b[31"), "unterminated ");
+    assert_eq!(sanitized("trailing \x1b"), "trailing ");
+}
+
+#[test]
+fn test_source_chain_sanitized() {
+    let err = FlatError::from_any_sanitized(&IoError::other(
+        FlatError::new("bad\nrecord").context("loading\x1b[31m data"),
+    ));
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        vec!["loading data", r"bad\nrecord"]
+    );
+}
+
+#[test]
+fn test_sanitized_before_truncation() {
+    let options = FlattenOptions::new()
+        .with_sanitized_messages(true)
+        .with_max_message_len(8);
+    let err = FlatError::from_any_with(&Raw("\x1b[1mone\ntwo three"), options);
+    assert_eq!(err.to_string(), r"one\ntwo…");
+    assert_eq!(err.original_message_len(), Some(14));
+}
+
+#[test]
+fn test_debug_repr_sanitized() {
+    let options = FlattenOptions::new()
+        .with_sanitized_messages(true)
+        .with_debug_capture(true);
+    let err = FlatError::from_any_with(&Raw("a\nb"), options);
+    assert_eq!(err.debug_repr(), Some(r"Raw(a\nb)"));
+}
+
+#[test]
+fn test_sanitized_method() {
+    let err = FlatError::new("one\ntwo").context("three").sanitized();
+    assert_eq!(err.report().to_string(), r"three: one\ntwo");
+}
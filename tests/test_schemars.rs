@@ -0,0 +1,41 @@
+#![cfg(feature = "schemars")]
+
+use flat_error::{FlatError, FlatErrorGroup};
+use pretty_assertions::assert_eq;
+use schemars::schema_for;
+use serde_json::json;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flat_error_schema() {
+    let schema = schema_for!(FlatError).to_value();
+    assert_eq!(schema["title"], "FlatError");
+    assert_eq!(schema["required"], json!(["message", "type"]));
+    assert_eq!(schema["properties"]["sources"]["items"]["$ref"], "#");
+    assert_eq!(schema["properties"]["fields"]["type"], "object");
+    assert_eq!(schema["properties"]["severity"]["$ref"], "#/$defs/Severity");
+    assert_eq!(
+        schema["$defs"]["Severity"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["const"].clone())
+            .collect::<Vec<_>>(),
+        vec![
+            json!("info"),
+            json!("warning"),
+            json!("error"),
+            json!("critical")
+        ]
+    );
+}
+
+#[test]
+fn test_group_schema() {
+    let schema = schema_for!(FlatErrorGroup).to_value();
+    assert_eq!(schema["type"], "array");
+    assert_eq!(schema["items"]["$ref"], "#/$defs/FlatError");
+}
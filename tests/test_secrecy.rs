@@ -0,0 +1,32 @@
+#![cfg(feature = "secrecy")]
+
+use flat_error::{FlatError, REDACTED_FIELD_VALUE};
+use pretty_assertions::assert_eq;
+use secrecy::{SecretBox, SecretString};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_with_secret_field() {
+    let password = SecretString::from("hunter2");
+    let err = FlatError::new("login failed")
+        .with_field("user", "alice")
+        .with_secret_field("password", &password);
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        [("user", "alice"), ("password", "[redacted]")]
+    );
+    assert!(!format!("{err:?}").contains("hunter2"));
+}
+
+#[test]
+fn test_with_secret_field_replaces_value() {
+    let key = SecretBox::new(Box::new([7u8; 32]));
+    let err = FlatError::new("decryption failed")
+        .with_field("key", "0707")
+        .with_secret_field("key", &key);
+    assert_eq!(err.field("key"), Some(REDACTED_FIELD_VALUE));
+    assert_eq!(err.fields().count(), 1);
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "serde")]
+
+use flat_error::{ErrorClass, FlatError, FlatErrorGroup, FlattenOptions, Severity, SourceSpan};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_serialize_all_keys() {
+    let err = FlatError::new("disk full")
+        .with_field("device", "sda1")
+        .with_field("free", "0")
+        .with_message_key("disk.full", [("device", "sda1")])
+        .with_severity(Severity::Critical)
+        .with_help("free some space")
+        .with_docs_url("https://example.com/disk-full")
+        .with_code("E1042");
+    #[allow(unused_mut)]
+    let mut value = serde_json::to_value(&err).unwrap();
+    // The time the error was created, with the timestamp feature, varies; see test_timestamp.
+    #[cfg(feature = "timestamp")]
+    assert!(value
+        .as_object_mut()
+        .unwrap()
+        .remove("timestamp")
+        .unwrap()
+        .is_string());
+    assert_eq!(
+        value,
+        json!({
+            "message": "disk full",
+            "type": "flat_error::FlatError",
+            "severity": "critical",
+            "code": "E1042",
+            "fields": { "device": "sda1", "free": "0" },
+            "message_key": "disk.full",
+            "message_args": { "device": "sda1" },
+            "help": "free some space",
+            "docs_url": "https://example.com/disk-full",
+        })
+    );
+}
+
+#[test]
+fn test_round_trip_chain() {
+    let err = FlatError::new("no space left")
+        .context("disk full")
+        .with_code("E1042")
+        .context("saving file");
+    let json = serde_json::to_string(&err).unwrap();
+    let parsed: FlatError = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, err);
+    assert_eq!(parsed.to_string(), "saving file");
+    assert_eq!(parsed.flat_source().unwrap().code(), Some("E1042"));
+}
+
+#[test]
+fn test_round_trip_branches() {
+    let err = FlatError::join(
+        FlatError::new_static("refused").context("connecting to a"),
+        FlatError::new_static("timed out"),
+    )
+    .context("all endpoints failed");
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(
+        value["sources"][0]["branches"][0]["sources"][0]["message"],
+        "refused"
+    );
+    let parsed: FlatError = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, err);
+}
+
+#[test]
+fn test_round_trip_related() {
+    let err = FlatError::new_static("duplicate key `name`")
+        .with_related(FlatError::new_static("first defined at line 3"));
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["related"][0]["message"], "first defined at line 3");
+    let parsed: FlatError = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, err);
+}
+
+#[test]
+fn test_round_trip_original_type_name() {
+    let err = FlatError::from_any(&"x".parse::<u8>().unwrap_err());
+    let parsed: FlatError = serde_json::from_value(serde_json::to_value(&err).unwrap()).unwrap();
+    assert_eq!(
+        parsed.original_type_name(),
+        "core::num::error::ParseIntError"
+    );
+}
+
+#[test]
+fn test_deserialize_defaults_and_nested_sources() {
+    let err: FlatError = serde_json::from_value(json!({
+        "message": "saving file",
+        "type": "app::Error",
+        "sources": [
+            {
+                "message": "disk full",
+                "type": "app::DiskError",
+                "sources": [{ "message": "no space left", "type": "std::io::Error" }]
+            }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(err.severity(), Severity::Error);
+    assert_eq!(
+        err.display_with(flat_error::DisplayStyle::Compact)
+            .to_string(),
+        "saving file: disk full: no space left"
+    );
+}
+
+#[test]
+fn test_group() {
+    let group: FlatErrorGroup = [FlatError::new("one"), FlatError::new("two")]
+        .into_iter()
+        .collect();
+    let json = serde_json::to_value(&group).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 2);
+    assert_eq!(
+        serde_json::from_value::<FlatErrorGroup>(json).unwrap(),
+        group
+    );
+}
+
+#[test]
+fn test_round_trip_span() {
+    let err = FlatError::new_static("expected `]`").with_span(SourceSpan::new(15, 1, 2, 7));
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(
+        value["span"],
+        json!({"offset": 15, "len": 1, "line": 2, "column": 7})
+    );
+    let parsed: FlatError = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, err);
+}
+
+#[test]
+fn test_round_trip_class() {
+    let err = FlatError::new("busy").with_class(ErrorClass::Unavailable);
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["class"], json!("unavailable"));
+    assert_eq!(value.get("fields"), None);
+    let parsed: FlatError = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.explicit_class(), Some(ErrorClass::Unavailable));
+    assert_eq!(parsed, err);
+}
+
+#[test]
+fn test_round_trip_template() {
+    let err = FlatError::templated("disk {device} full", [("device", "sda1")]);
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["message_template"], json!("disk {device} full"));
+    assert_eq!(value["message_args"], json!({"device": "sda1"}));
+    let parsed: FlatError = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.message_template(), Some("disk {device} full"));
+    assert_eq!(parsed, err);
+}
+
+#[test]
+fn test_round_trip_debug_repr() {
+    let options = FlattenOptions::new().with_debug_capture(true);
+    let err =
+        FlatError::from_any_with(&std::io::Error::from(std::io::ErrorKind::NotFound), options);
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["debug_repr"], json!("Kind(NotFound)"));
+    let parsed: FlatError = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.debug_repr(), Some("Kind(NotFound)"));
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "serde_json")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_syntax_error() {
+    fn parse(s: &str) -> Result<Vec<u32>, FlatError> {
+        Ok(serde_json::from_str(s)?)
+    }
+    let err = parse("[1, 2,]").unwrap_err();
+    assert_eq!(err.to_string(), "trailing comma at line 1 column 7");
+    assert_eq!(err.original_type_name(), "serde_json::error::Error");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("category", "syntax"), ("line", "1"), ("column", "7")]
+    );
+}
+
+#[test]
+fn test_eof_error() {
+    let err = FlatError::from(serde_json::from_str::<Vec<u32>>("[1").unwrap_err());
+    assert_eq!(err.field("category"), Some("eof"));
+}
+
+#[test]
+fn test_error_without_position() {
+    let err = FlatError::from(serde_json::from_value::<u32>(serde_json::json!("one")).unwrap_err());
+    assert_eq!(err.field("category"), Some("data"));
+    assert_eq!(err.field("line"), None);
+    assert_eq!(err.field("column"), None);
+}
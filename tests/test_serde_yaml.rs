@@ -0,0 +1,22 @@
+#![cfg(feature = "serde_yaml")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::collections::BTreeMap;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_error_with_location() {
+    fn parse(s: &str) -> Result<BTreeMap<String, u32>, FlatError> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+    let err = parse("a: 1\nb: two\n").unwrap_err();
+    assert_eq!(err.original_type_name(), "serde_yaml::error::Error");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("line", "2"), ("column", "4"), ("index", "8")]
+    );
+}
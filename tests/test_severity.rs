@@ -0,0 +1,39 @@
+use flat_error::{FlatError, Severity};
+use pretty_assertions::{assert_eq, assert_ne};
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn not_found() -> FlatError {
+    FlatError::from(IoError::from(ErrorKind::NotFound))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_default_severity() {
+    assert_eq!(Severity::default(), Severity::Error);
+    assert_eq!(not_found().severity(), Severity::Error);
+}
+
+#[test]
+fn test_severity_ordering() {
+    assert!(Severity::Info < Severity::Warning);
+    assert!(Severity::Warning < Severity::Error);
+    assert!(Severity::Error < Severity::Critical);
+}
+
+#[test]
+fn test_severity_participates_in_eq() {
+    assert_ne!(not_found().with_severity(Severity::Critical), not_found());
+    assert_eq!(not_found().with_severity(Severity::Error), not_found());
+}
+
+#[test]
+fn test_severity_display() {
+    assert_eq!(Severity::Critical.to_string(), "critical".to_string());
+}
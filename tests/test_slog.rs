@@ -0,0 +1,94 @@
+#![cfg(feature = "slog")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use slog::{error, o, Drain, Key, Logger, OwnedKVList, Record, SerdeValue, Serializer, KV};
+use std::{
+    fmt::Arguments,
+    sync::{Arc, Mutex},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct Capture {
+    nested: bool,
+    values: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+struct Capturing<'a>(&'a Capture);
+
+impl Serializer for Capturing<'_> {
+    fn emit_arguments(&mut self, key: Key, val: &Arguments<'_>) -> slog::Result {
+        self.0
+            .values
+            .lock()
+            .unwrap()
+            .push((key.to_string(), val.to_string()));
+        Ok(())
+    }
+
+    fn emit_serde(&mut self, key: Key, value: &dyn SerdeValue) -> slog::Result {
+        if self.0.nested {
+            let json = serde_json::to_string(value.as_serde()).unwrap();
+            self.0.values.lock().unwrap().push((key.to_string(), json));
+            Ok(())
+        } else {
+            value.serialize_fallback(key, self)
+        }
+    }
+}
+
+impl Drain for Capture {
+    type Ok = ();
+    type Err = slog::Never;
+
+    fn log(&self, record: &Record<'_>, _: &OwnedKVList) -> Result<(), slog::Never> {
+        record.kv().serialize(record, &mut Capturing(self)).unwrap();
+        Ok(())
+    }
+}
+
+fn capture(nested: bool, err: &FlatError) -> Vec<(String, String)> {
+    let drain = Capture {
+        nested,
+        values: Default::default(),
+    };
+    let logger = Logger::root(drain.clone(), o!());
+    error!(logger, "request failed"; "error" => err);
+    let values = drain.values.lock().unwrap().clone();
+    values
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_slog_nested_value() {
+    let err = FlatError::new("disk full")
+        .with_code("E1042")
+        .context("saving file");
+    assert_eq!(
+        capture(true, &err),
+        vec![(
+            "error".to_string(),
+            concat!(
+                r#"[{"message":"saving file","type":"flat_error::FlatError","severity":"error"},"#,
+                r#"{"message":"disk full","type":"flat_error::FlatError","severity":"error","code":"E1042"}]"#
+            )
+            .to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_slog_fallback_value() {
+    let err = FlatError::new("disk full").context("saving file");
+    assert_eq!(
+        capture(false, &err),
+        vec![("error".to_string(), "saving file: disk full".to_string())]
+    );
+}
@@ -0,0 +1,64 @@
+use flat_error::{FlatError, SourceSpan};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_span_from_range() {
+    let source = "port: 80\nhost: [1, 2]\n";
+    assert_eq!(
+        SourceSpan::from_range(source, 15..16),
+        SourceSpan::new(15, 1, 2, 7)
+    );
+    assert_eq!(
+        SourceSpan::from_range(source, 0..4),
+        SourceSpan::new(0, 4, 1, 1)
+    );
+    assert_eq!(SourceSpan::new(15, 1, 2, 7).range(), 15..16);
+}
+
+#[test]
+fn test_span_from_range_counts_characters() {
+    let span = SourceSpan::from_range("名前: [", 8..9);
+    assert_eq!((span.line, span.column), (1, 5));
+    let span = SourceSpan::from_range("名前", 1..2);
+    assert_eq!((span.line, span.column), (1, 1));
+}
+
+#[test]
+fn test_span_from_range_beyond_end() {
+    let span = SourceSpan::from_range("a\nbc", 10..10);
+    assert_eq!(span, SourceSpan::new(10, 0, 2, 3));
+}
+
+#[test]
+fn test_span_from_source() {
+    let err = FlatError::new_static("expected `]`")
+        .with_span(SourceSpan::new(15, 1, 2, 7))
+        .context("parsing config.yaml");
+    assert_eq!(err.span(), Some(SourceSpan::new(15, 1, 2, 7)));
+    assert_eq!(err.frame(0).unwrap().span(), None);
+    assert_eq!(
+        err.frame(1).unwrap().span(),
+        Some(SourceSpan::new(15, 1, 2, 7))
+    );
+    assert_eq!(FlatError::new_static("expected `]`").span(), None);
+}
+
+#[test]
+fn test_span_builder() {
+    let err = FlatError::builder()
+        .message("expected `]`")
+        .span(SourceSpan::new(15, 1, 2, 7))
+        .build();
+    assert_eq!(err.span(), Some(SourceSpan::new(15, 1, 2, 7)));
+}
+
+#[test]
+fn test_span_report_pretty() {
+    let err = FlatError::new_static("expected `]`")
+        .with_span(SourceSpan::new(15, 1, 2, 7))
+        .context("parsing config.yaml");
+    assert_eq!(
+        err.report().pretty(true).to_string(),
+        "parsing config.yaml\n  --> 2:7\n\nCaused by:\n      expected `]`"
+    );
+}
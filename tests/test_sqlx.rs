@@ -0,0 +1,89 @@
+#![cfg(feature = "sqlx")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use sqlx::error::{DatabaseError, ErrorKind};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct UniqueViolation;
+
+impl Display for UniqueViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.message())
+    }
+}
+
+impl Error for UniqueViolation {}
+
+impl DatabaseError for UniqueViolation {
+    fn message(&self) -> &str {
+        "duplicate key value violates unique constraint"
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed("23505"))
+    }
+
+    fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        Some("users_email_key")
+    }
+
+    fn table(&self) -> Option<&str> {
+        Some("users")
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::UniqueViolation
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_database_error() {
+    fn insert() -> Result<(), FlatError> {
+        Err(sqlx::Error::database(UniqueViolation))?
+    }
+    let err = insert().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error returned from database: duplicate key value violates unique constraint"
+    );
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![
+            ("sqlstate", "23505"),
+            ("constraint", "users_email_key"),
+            ("table", "users"),
+        ]
+    );
+}
+
+#[test]
+fn test_other_error() {
+    let err = FlatError::from(sqlx::Error::PoolTimedOut);
+    assert_eq!(err.fields().count(), 0);
+}
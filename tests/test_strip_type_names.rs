@@ -0,0 +1,39 @@
+#![cfg(flat_error_strip_type_names)]
+
+use flat_error::{FlatError, FlatErrorFixed, STRIPPED_TYPE_NAME};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+#[test]
+fn test_from_error_strips_type_name() {
+    let err = FlatError::from("x".parse::<u8>().unwrap_err());
+    assert_eq!(err.original_type_name(), STRIPPED_TYPE_NAME);
+    assert!(err.is_exact::<std::num::ParseIntError>());
+}
+
+#[test]
+fn test_source_chain_strips_type_names() {
+    let err = FlatError::from(IoError::new(
+        ErrorKind::InvalidData,
+        FlatError::new("inner"),
+    ));
+    assert!(err
+        .chain_type_names()
+        .all(|type_name| type_name == STRIPPED_TYPE_NAME));
+    assert_eq!(err.io_kind(), Some(ErrorKind::InvalidData));
+}
+
+#[test]
+fn test_from_display_strips_type_name() {
+    let err = FlatError::from_display(&503_u16);
+    assert_eq!(err.to_string(), "503");
+    assert_eq!(err.original_type_name(), STRIPPED_TYPE_NAME);
+}
+
+#[test]
+fn test_fixed_strips_type_name() {
+    let err: FlatErrorFixed<16> = FlatErrorFixed::from_any(&IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.original_type_name(), STRIPPED_TYPE_NAME);
+    let err: FlatErrorFixed<16> = FlatErrorFixed::new("disk full");
+    assert_eq!(err.original_type_name(), STRIPPED_TYPE_NAME);
+}
@@ -0,0 +1,48 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_templated_renders_arguments() {
+    let err = FlatError::templated(
+        "copying {from} to {to} failed after {tries} tries",
+        [("from", "a.txt"), ("to", "b.txt"), ("tries", "3")],
+    );
+    assert_eq!(
+        err.to_string(),
+        "copying a.txt to b.txt failed after 3 tries"
+    );
+    assert_eq!(
+        err.message_template(),
+        Some("copying {from} to {to} failed after {tries} tries")
+    );
+    assert_eq!(err.message_key(), None);
+}
+
+#[test]
+fn test_templated_escapes_and_missing_arguments() {
+    let err = FlatError::templated("{{literal}} {missing} {n}} {", [("n", 1)]);
+    assert_eq!(err.to_string(), "{literal} {missing} 1} {");
+}
+
+#[test]
+fn test_templated_groups_by_template() {
+    let first = FlatError::templated("no user with id {id}", [("id", 1)]);
+    let second = FlatError::templated("no user with id {id}", [("id", 2)]);
+    assert_ne!(first.to_string(), second.to_string());
+    assert_eq!(first.message_template(), second.message_template());
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_templated_is_retained_by_context() {
+    let err = FlatError::templated("disk {device} full", [("device", "sda1")]).context("saving");
+    assert_eq!(err.message_template(), None);
+    assert_eq!(
+        err.flat_source().unwrap().message_template(),
+        Some("disk {device} full")
+    );
+}
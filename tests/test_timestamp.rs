@@ -0,0 +1,101 @@
+#![cfg(feature = "timestamp")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::{
+    io::{Error as IoError, ErrorKind},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_timestamp_captured_when_flattened() {
+    let before = SystemTime::now();
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    let after = SystemTime::now();
+    let timestamp = err.timestamp().unwrap();
+    assert!(before <= timestamp && timestamp <= after);
+}
+
+#[test]
+fn test_timestamp_of_context_is_source() {
+    let err = FlatError::new("disk full");
+    let timestamp = err.timestamp();
+    assert_eq!(err.context("saving file").timestamp(), timestamp);
+
+    let at = UNIX_EPOCH + Duration::from_secs(1);
+    let err = FlatError::new("disk full")
+        .with_timestamp(at)
+        .context("saving file");
+    assert_eq!(err.timestamp(), Some(at));
+}
+
+#[test]
+fn test_timestamp_not_compared() {
+    let at = UNIX_EPOCH + Duration::from_secs(1);
+    let err = FlatError::new("disk full");
+    assert_eq!(err.clone().with_timestamp(at), err);
+    assert_eq!(err.with_timestamp(at).timestamp(), Some(at));
+}
+
+#[test]
+fn test_timestamp_captured_by_constructors() {
+    let before = SystemTime::now();
+    let errors = [
+        FlatError::new("not ready"),
+        FlatError::from("not ready"),
+        FlatError::join(FlatError::new("first"), FlatError::new("second")),
+        FlatError::from_parts("app::Error", "not ready", None),
+    ];
+    let after = SystemTime::now();
+    for err in errors {
+        let timestamp = err.timestamp().unwrap();
+        assert!(before <= timestamp && timestamp <= after, "{err}");
+    }
+}
+
+#[test]
+fn test_new_static_has_no_timestamp() {
+    assert_eq!(FlatError::new_static("not ready").timestamp(), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_timestamp_serde() {
+    let at = UNIX_EPOCH + Duration::new(1_738_314_900, 125_000_000);
+    let err = FlatError::new("disk full").with_timestamp(at);
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json["timestamp"], "2025-01-31T09:15:00.125Z");
+    let parsed: FlatError = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.timestamp(), Some(at));
+
+    for (text, expected) in [
+        ("1970-01-01T00:00:00Z", UNIX_EPOCH),
+        (
+            "2000-02-29T23:59:59.000001+01:00",
+            UNIX_EPOCH + Duration::new(951_865_199, 1_000),
+        ),
+        (
+            "1969-12-31T23:59:59.5Z",
+            UNIX_EPOCH - Duration::from_millis(500),
+        ),
+    ] {
+        let err: FlatError = serde_json::from_value(serde_json::json!({
+            "message": "m",
+            "type": "t",
+            "timestamp": text,
+        }))
+        .unwrap();
+        assert_eq!(err.timestamp(), Some(expected), "{text}");
+    }
+
+    let result = serde_json::from_value::<FlatError>(serde_json::json!({
+        "message": "m",
+        "type": "t",
+        "timestamp": "2025-02-30T00:00:00Z",
+    }));
+    assert!(result.is_err());
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "tokio")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+use tokio::{
+    runtime::{Builder, Runtime},
+    sync::{mpsc, oneshot, Semaphore},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().build().unwrap()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_join_error_panic() {
+    let err = runtime().block_on(async {
+        let task = tokio::spawn(async { panic!("task failed") });
+        FlatError::from(task.await.unwrap_err())
+    });
+    assert_eq!(err.field("panic"), Some("true"));
+    assert_eq!(err.field("cancelled"), None);
+}
+
+#[test]
+fn test_join_error_cancelled() {
+    let err = runtime().block_on(async {
+        let task = tokio::spawn(std::future::pending::<()>());
+        task.abort();
+        FlatError::from(task.await.unwrap_err())
+    });
+    assert_eq!(err.field("panic"), None);
+    assert_eq!(err.field("cancelled"), Some("true"));
+}
+
+#[test]
+fn test_semaphore_errors() {
+    let semaphore = Arc::new(Semaphore::new(0));
+    let err = FlatError::from(semaphore.try_acquire().unwrap_err());
+    assert_eq!(err.to_string(), "no permits available");
+
+    semaphore.close();
+    let err = runtime().block_on(async { FlatError::from(semaphore.acquire().await.unwrap_err()) });
+    assert_eq!(err.to_string(), "semaphore closed");
+}
+
+#[test]
+fn test_channel_errors() {
+    let (tx, rx) = oneshot::channel::<u8>();
+    drop(tx);
+    let err = runtime().block_on(async { FlatError::from(rx.await.unwrap_err()) });
+    assert_eq!(err.to_string(), "channel closed");
+
+    let (tx, mut rx) = mpsc::channel::<String>(1);
+    let err = FlatError::from(rx.try_recv().unwrap_err());
+    assert_eq!(err.to_string(), "receiving on an empty channel");
+
+    tx.try_send(String::from("a")).unwrap();
+    let err = FlatError::from(tx.try_send(String::from("b")).unwrap_err());
+    assert_eq!(err.to_string(), "no available capacity");
+
+    drop(rx);
+    let err = runtime()
+        .block_on(async { FlatError::from(tx.send(String::from("c")).await.unwrap_err()) });
+    assert_eq!(err.to_string(), "channel closed");
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "toml")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_de_error_with_span() {
+    fn parse(s: &str) -> Result<toml::Table, FlatError> {
+        Ok(toml::from_str(s)?)
+    }
+    let err = parse("a = 1\na = 2\n").unwrap_err();
+    assert_eq!(err.original_type_name(), "toml::de::error::Error");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("span_start", "6"), ("span_end", "7")]
+    );
+}
+
+#[test]
+fn test_ser_error() {
+    let err = FlatError::from(toml::to_string(&1_u32).unwrap_err());
+    assert!(err.fields().next().is_none());
+}
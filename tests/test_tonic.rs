@@ -0,0 +1,50 @@
+#![cfg(feature = "tonic")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+use tonic::{Code, Status};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_from_status() {
+    fn call() -> Result<(), FlatError> {
+        Err(Status::permission_denied("not allowed"))?
+    }
+    let err = call().unwrap_err();
+    assert_eq!(err.to_string(), "not allowed");
+    assert_eq!(err.original_type_name(), "tonic::status::Status");
+    assert_eq!(err.grpc_code(), Code::PermissionDenied);
+}
+
+#[test]
+fn test_status_round_trip() {
+    let status = Status::from(FlatError::from(Status::already_exists("duplicate")));
+    assert_eq!(status.code(), Code::AlreadyExists);
+    assert_eq!(status.message(), "duplicate");
+}
+
+#[test]
+fn test_io_kind_mapping() {
+    let err = FlatError::from(IoError::from(ErrorKind::TimedOut));
+    assert_eq!(Status::from(err).code(), Code::DeadlineExceeded);
+    assert_eq!(Status::from(FlatError::new("failed")).code(), Code::Unknown);
+}
+
+#[test]
+fn test_grpc_code_from_class() {
+    let err = FlatError::new("busy").with_class(flat_error::ErrorClass::Unavailable);
+    assert_eq!(err.grpc_code(), Code::Unavailable);
+}
+
+#[test]
+fn test_grpc_code_from_source() {
+    let err = FlatError::from(IoError::from(ErrorKind::PermissionDenied))
+        .context("opening journal")
+        .context("handling request");
+    assert_eq!(err.grpc_code(), Code::PermissionDenied);
+    assert_eq!(Status::from(err).code(), Code::PermissionDenied);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "tower")]
+
+use flat_error::{
+    tower::{FlattenError, FlattenErrorLayer},
+    FlatError,
+};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+use tower::{service_fn, BoxError, Layer, Service, ServiceExt};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn call<S, Req>(service: S, request: Req) -> Result<S::Response, FlatError>
+where
+    S: Service<Req, Error = FlatError>,
+{
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(service.oneshot(request))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flatten_box_error() {
+    let service = FlattenError::new(service_fn(|_: ()| async {
+        Err::<(), BoxError>(IoError::from(ErrorKind::TimedOut).into())
+    }));
+    let err = call(service, ()).unwrap_err();
+    assert_eq!(err.to_string(), "timed out");
+    assert_eq!(err.flat_source(), None);
+}
+
+#[test]
+fn test_flatten_with_context() {
+    let service = FlattenErrorLayer::new()
+        .with_context(|id: &u32| format!("loading user {id}"))
+        .layer(service_fn(|_: u32| async {
+            Err::<(), _>(FlatError::new("no such user"))
+        }));
+    let err = call(service, 42).unwrap_err();
+    assert_eq!(err.to_string(), "loading user 42");
+    assert_eq!(err.flat_source().unwrap().to_string(), "no such user");
+}
+
+#[test]
+fn test_flatten_passes_response() {
+    let service =
+        FlattenErrorLayer::new().layer(service_fn(|n: u32| async move { Ok::<_, IoError>(n * 2) }));
+    assert_eq!(call(service, 21), Ok(42));
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "tracing")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Level, Metadata, Subscriber,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+type Captured = Vec<(Level, Vec<(String, String)>)>;
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Captured>>);
+
+struct Fields(Vec<(String, String)>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields(Vec::new());
+        event.record(&mut fields);
+        self.0
+            .lock()
+            .unwrap()
+            .push((*event.metadata().level(), fields.0));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_trace_records_fields() {
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || {
+        FlatError::new("disk full")
+            .context("saving file")
+            .with_code("E1042")
+            .trace(Level::WARN);
+    });
+    let events = capture.0.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    let (level, fields) = &events[0];
+    assert_eq!(*level, Level::WARN);
+    #[allow(unused_mut)]
+    let mut fields = fields
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect::<Vec<_>>();
+    // The time the error was created, with the timestamp feature, varies.
+    #[cfg(feature = "timestamp")]
+    assert_eq!(fields.pop().map(|(k, _)| k), Some("error.timestamp"));
+    assert_eq!(
+        fields,
+        vec![
+            ("message", "saving file"),
+            ("error.type", "flat_error::FlatError"),
+            ("error.code", "E1042"),
+            ("error.severity", "error"),
+            ("error.depth", "2"),
+            ("error.chain", "saving file: disk full"),
+        ]
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "tracing-error")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+use tracing_error::ErrorLayer;
+use tracing_subscriber::layer::SubscriberExt;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn in_span<T>(f: impl FnOnce() -> T) -> T {
+    let subscriber = tracing_subscriber::registry().with(ErrorLayer::default());
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("handle_request", id = 42);
+        let _entered = span.enter();
+        f()
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_span_trace_captured() {
+    let err = in_span(|| FlatError::from(IoError::from(ErrorKind::NotFound)));
+    let span_trace = err.span_trace_str().unwrap();
+    assert!(span_trace.contains("handle_request"));
+    assert!(span_trace.contains("id=42"));
+}
+
+#[test]
+fn test_no_span_trace_without_layer() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert_eq!(err.span_trace_str(), None);
+}
+
+#[test]
+fn test_span_trace_from_source() {
+    let err = in_span(|| FlatError::from(IoError::from(ErrorKind::NotFound))).context("loading");
+    assert!(err.span_trace_str().unwrap().contains("handle_request"));
+}
+
+#[test]
+fn test_span_trace_not_compared() {
+    let err = in_span(|| FlatError::from(IoError::from(ErrorKind::NotFound)));
+    assert_eq!(err, FlatError::from(IoError::from(ErrorKind::NotFound)));
+}
+
+#[test]
+fn test_span_trace_in_pretty_report() {
+    let err = in_span(|| FlatError::from(IoError::from(ErrorKind::NotFound)));
+    let report = err.clone().report().pretty(true).to_string();
+    assert!(report.starts_with("entity not found\n\nSpan trace:\n"));
+    assert!(report.contains("handle_request"));
+    assert_eq!(err.report().to_string(), "entity not found");
+}
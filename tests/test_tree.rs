@@ -0,0 +1,123 @@
+use flat_error::{DisplayStyle, FlatError};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn endpoints() -> FlatError {
+    FlatError::join(
+        FlatError::new_static("connection refused").context("connecting to a"),
+        FlatError::new_static("timed out").context("connecting to b"),
+    )
+    .with_branch(FlatError::new_static("no route to host").context("connecting to c"))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_join() {
+    let err = FlatError::join(FlatError::new_static("a"), FlatError::new_static("b"));
+    assert_eq!(err.to_string(), "a; b");
+    assert!(err.flat_source().is_none());
+    assert_eq!(
+        err.branches().map(ToString::to_string).collect::<Vec<_>>(),
+        ["a", "b"]
+    );
+}
+
+#[test]
+fn test_no_branches() {
+    let err = FlatError::new_static("a").context("b");
+    assert_eq!(err.branches().len(), 0);
+    assert_eq!(
+        err.tree_frames()
+            .map(|(depth, frame)| (depth, frame.message()))
+            .collect::<Vec<_>>(),
+        [(0, "b"), (1, "a")]
+    );
+    assert_eq!(err.display_with(DisplayStyle::Tree).to_string(), "b\n└─ a");
+}
+
+#[test]
+fn test_tree_frames() {
+    let err = endpoints().context("all endpoints failed");
+    assert_eq!(
+        err.tree_frames()
+            .map(|(depth, frame)| (depth, frame.message()))
+            .collect::<Vec<_>>(),
+        [
+            (0, "all endpoints failed"),
+            (1, "connecting to a; connecting to b"),
+            (2, "connecting to a"),
+            (3, "connection refused"),
+            (2, "connecting to b"),
+            (3, "timed out"),
+            (2, "connecting to c"),
+            (3, "no route to host"),
+        ]
+    );
+    assert_eq!(err.frame(1).unwrap().branches().len(), 3);
+}
+
+#[test]
+fn test_display_tree_with_source_and_branches() {
+    let err = FlatError::new_static("disk full")
+        .context("writing log")
+        .with_branch(FlatError::new_static("quota exceeded"))
+        .context("shutting down");
+    assert_eq!(
+        err.display_with(DisplayStyle::Tree).to_string(),
+        [
+            "shutting down",
+            "└─ writing log",
+            "   ├─ disk full",
+            "   └─ quota exceeded",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_display_tree_of_joined_errors() {
+    assert_eq!(
+        endpoints().display_with(DisplayStyle::Tree).to_string(),
+        [
+            "connecting to a; connecting to b",
+            "├─ connecting to a",
+            "│  └─ connection refused",
+            "├─ connecting to b",
+            "│  └─ timed out",
+            "└─ connecting to c",
+            "   └─ no route to host",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_branches_are_compared() {
+    assert_eq!(endpoints(), endpoints());
+    assert_ne!(
+        endpoints(),
+        FlatError::join(
+            FlatError::new_static("connection refused").context("connecting to a"),
+            FlatError::new_static("timed out").context("connecting to b"),
+        )
+    );
+}
+
+#[test]
+fn test_debug_branches() {
+    let err = FlatError::join(FlatError::new_static("a"), FlatError::new_static("b"));
+    assert_eq!(
+        format!("{err:?}"),
+        concat!(
+            r#"FlatError { message: "a; b", type: "flat_error::FlatError", branches: ["#,
+            r#"FlatError { message: "a", type: "flat_error::FlatError" }, "#,
+            r#"FlatError { message: "b", type: "flat_error::FlatError" }] }"#
+        )
+    );
+}
@@ -0,0 +1,113 @@
+use flat_error::{FlatError, FlattenOptions};
+use pretty_assertions::assert_eq;
+use std::io::Error as IoError;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_short_messages_are_unchanged() {
+    let options = FlattenOptions::new().with_max_message_len(16);
+    let err = FlatError::from_any_with(&IoError::other("short"), options);
+    assert_eq!(err.to_string(), "short");
+    assert_eq!(err.original_message_len(), None);
+}
+
+#[test]
+fn test_truncates_on_char_boundary() {
+    let options = FlattenOptions::new().with_max_message_len(3);
+    let err = FlatError::from_any_with(&IoError::other("naïve"), options);
+    assert_eq!(err.to_string(), "na…");
+    assert_eq!(err.original_message_len(), Some(6));
+}
+
+#[test]
+fn test_truncated_length_excludes_ellipsis() {
+    for max_len in 0..12 {
+        let options = FlattenOptions::new().with_max_message_len(max_len);
+        let err = FlatError::from_any_with(&IoError::other("naïve café au lait"), options);
+        let message = err.to_string();
+        let kept = message.strip_suffix('…').unwrap();
+        assert!(kept.len() <= max_len);
+        assert!(max_len - kept.len() < 'ï'.len_utf8());
+        assert_eq!(message.len(), kept.len() + '…'.len_utf8());
+    }
+}
+
+#[test]
+fn test_truncates_debug_repr() {
+    let options = FlattenOptions::new()
+        .with_max_message_len(8)
+        .with_debug_capture(true);
+    let err = FlatError::from_any_with(&IoError::other("truncated"), options);
+    assert_eq!(err.to_string(), "truncate…");
+    assert_eq!(err.debug_repr(), Some("Custom {…"));
+}
+
+#[test]
+fn test_truncates_sources() {
+    let inner = FlatError::new("a very long source message").context("outer");
+    let options = FlattenOptions::new().with_max_message_len(6);
+    let err = FlatError::from_any_with(&inner, options);
+    assert_eq!(err.to_string(), "outer");
+    let source = err.flat_source().unwrap();
+    assert_eq!(source.to_string(), "a very…");
+    assert_eq!(source.original_message_len(), Some(26));
+}
+
+fn chain(length: usize) -> FlatError {
+    (1..length).fold(FlatError::new_static("0"), |err, depth| err.context(depth))
+}
+
+#[test]
+fn test_truncate_chain() {
+    let err = chain(6).truncate_chain(2);
+    assert_eq!(err.format_chain(": "), "5: 4: 3: … 3 more");
+    assert_eq!(err.frames().len(), 4);
+}
+
+#[test]
+fn test_truncate_chain_within_depth() {
+    let err = chain(3);
+    assert_eq!(err.clone().truncate_chain(2), err);
+    assert_eq!(
+        err.clone().truncate_chain(0).format_chain(": "),
+        "2: … 2 more"
+    );
+}
+
+#[test]
+fn test_truncate_chain_retains_category() {
+    let err = chain(4).with_category(7_u8).truncate_chain(1);
+    assert_eq!(*err.category(), 7);
+    assert_eq!(err.to_string(), "3");
+    assert_eq!(err.frames().len(), 3);
+}
+
+#[test]
+fn test_keep_root() {
+    let err = chain(5).keep_root();
+    assert_eq!(err.format_chain(": "), "4: 0");
+    assert_eq!(chain(1).keep_root(), chain(1));
+}
+
+#[test]
+fn test_strip_sources() {
+    let err = chain(5).with_code("E1").strip_sources();
+    assert_eq!(err.format_chain(": "), "4");
+    assert_eq!(err.code(), Some("E1"));
+    assert!(err.flat_source().is_none());
+    assert_eq!(chain(1).strip_sources(), chain(1));
+}
+
+#[test]
+fn test_eq_shallow() {
+    assert!(chain(5).eq_shallow(&chain(5)));
+    assert!(chain(5).eq_shallow(&chain(5).strip_sources()));
+    assert!(!chain(5).eq_shallow(&chain(4)));
+    assert!(!chain(5).eq_shallow(&chain(5).with_code("E1")));
+    assert!(!FlatError::new("failed")
+        .with_category(1)
+        .eq_shallow(&FlatError::new("failed").with_category(2)));
+}
@@ -0,0 +1,88 @@
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind},
+    num::ParseIntError,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct LoadError(IoError);
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "could not load")
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_is() {
+    let err = FlatError::from_any(&LoadError(IoError::from(ErrorKind::NotFound)));
+    assert!(err.is::<LoadError>());
+    assert!(!err.is::<IoError>());
+    assert!(!err.is::<ParseIntError>());
+}
+
+#[test]
+fn test_chain_matches_io_source() {
+    let err = FlatError::from_any(&LoadError(IoError::from(ErrorKind::NotFound)));
+    assert_eq!(
+        err.flat_source().map(|e| e.original_type_name()),
+        Some("std::io::error::Error")
+    );
+    assert!(err.chain_matches::<LoadError>());
+    assert!(err.chain_matches::<IoError>());
+    assert!(!err.chain_matches::<ParseIntError>());
+}
+
+#[test]
+fn test_chain_matches_context() {
+    let err = FlatError::from_any(&"x".parse::<u8>().unwrap_err()).context("parsing");
+    assert!(err.is::<FlatError>());
+    assert!(err.chain_matches::<ParseIntError>());
+}
+
+#[test]
+fn test_is_exact() {
+    let err = FlatError::from_error(&LoadError(IoError::from(ErrorKind::NotFound)));
+    assert!(err.is_exact::<LoadError>());
+    assert!(!err.is_exact::<IoError>());
+    assert_eq!(
+        err.flat_source().and_then(|e| e.original_type_id()),
+        Some(std::any::TypeId::of::<IoError>())
+    );
+}
+
+#[test]
+fn test_type_id_not_captured_by_from_any() {
+    let err = FlatError::from_any(&LoadError(IoError::from(ErrorKind::NotFound)));
+    assert_eq!(err.original_type_id(), None);
+    assert!(!err.is_exact::<LoadError>());
+    assert_eq!(
+        err,
+        FlatError::from_error(&LoadError(IoError::from(ErrorKind::NotFound)))
+    );
+}
+
+#[test]
+fn test_type_id_from_impls() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound));
+    assert!(err.is_exact::<IoError>());
+    let err = FlatError::from_any_retaining(IoError::from(ErrorKind::NotFound));
+    assert!(err.is_exact::<IoError>());
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "ufmt")]
+
+use flat_error::{FlatError, FlatErrorFixed};
+use pretty_assertions::assert_eq;
+use ufmt::uwrite;
+
+#[test]
+fn test_fixed_display() {
+    let err: FlatErrorFixed<8> = FlatErrorFixed::new("disk is full");
+    let mut out = String::new();
+    uwrite!(out, "{}", err).unwrap();
+    assert_eq!(out, "disk is ");
+}
+
+#[test]
+fn test_fixed_debug() {
+    let err: FlatErrorFixed<8> = FlatErrorFixed::new("disk is full");
+    let mut out = String::new();
+    uwrite!(out, "{:?}", err).unwrap();
+    assert_eq!(
+        out,
+        format!(
+            r#"FlatErrorFixed {{ original_type_name: "{}", message: "disk is ", truncated: true }}"#,
+            err.original_type_name()
+        )
+    );
+}
+
+#[test]
+fn test_display() {
+    let err = FlatError::new("disk is full").context("saving file");
+    let mut out = String::new();
+    uwrite!(out, "{}", err).unwrap();
+    assert_eq!(out, err.to_string());
+}
+
+#[test]
+fn test_debug_with_source() {
+    let err = FlatError::new("disk is full")
+        .with_code("E1042")
+        .context("saving file");
+    let mut out = String::new();
+    uwrite!(out, "{:?}", err).unwrap();
+    assert_eq!(
+        out,
+        r#"FlatError { message: "saving file", type: "flat_error::FlatError", source: FlatError { message: "disk is full", type: "flat_error::FlatError", code: "E1042" } }"#
+    );
+}
+
+#[test]
+fn test_debug_escapes_message() {
+    let err = FlatError::new(r#"bad "path" C:\tmp"#);
+    let mut out = String::new();
+    uwrite!(out, "{:?}", err).unwrap();
+    assert_eq!(
+        out,
+        r#"FlatError { message: "bad \"path\" C:\\tmp", type: "flat_error::FlatError" }"#
+    );
+}
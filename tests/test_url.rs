@@ -0,0 +1,23 @@
+#![cfg(feature = "url")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use url::Url;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_parse_error_kind() {
+    fn parse(s: &str) -> Result<Url, FlatError> {
+        Ok(Url::parse(s)?)
+    }
+    let err = parse("http://example.com:99999/").unwrap_err();
+    assert_eq!(err.original_type_name(), "url::parser::ParseError");
+    assert_eq!(err.to_string(), "invalid port number");
+    assert_eq!(
+        err.fields().collect::<Vec<_>>(),
+        vec![("kind", "InvalidPort")]
+    );
+}
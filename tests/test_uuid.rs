@@ -0,0 +1,26 @@
+#![cfg(feature = "uuid")]
+
+use flat_error::FlatError;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_invalid_character_position() {
+    fn parse(s: &str) -> Result<Uuid, FlatError> {
+        Ok(Uuid::parse_str(s)?)
+    }
+    let err = parse("67e5504x-10b1-426f-9247-bb680e5fe0c8").unwrap_err();
+    assert_eq!(err.original_type_name(), "uuid::error::Error");
+    assert_eq!(err.fields().collect::<Vec<_>>(), vec![("position", "7")]);
+}
+
+#[test]
+fn test_invalid_length_has_no_position() {
+    let err = FlatError::from(Uuid::parse_str("67e55044").unwrap_err());
+    assert!(err.to_string().starts_with("invalid length"));
+    assert!(err.fields().next().is_none());
+}
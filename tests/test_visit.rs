@@ -0,0 +1,86 @@
+use flat_error::{FlatError, FlatErrorVisitor, FlatFrame};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl FlatErrorVisitor for Recorder {
+    fn visit_frame(&mut self, depth: usize, frame: FlatFrame<'_>) {
+        self.0.push(format!("{depth} {}", frame.message()));
+    }
+
+    fn enter_branch(&mut self, depth: usize, index: usize) {
+        self.0.push(format!("enter {depth}.{index}"));
+    }
+
+    fn leave_branch(&mut self, depth: usize, index: usize) {
+        self.0.push(format!("leave {depth}.{index}"));
+    }
+}
+
+fn tree() -> FlatError {
+    FlatError::join(
+        FlatError::from(IoError::from(ErrorKind::NotFound)).context("reading a"),
+        FlatError::new_static("b"),
+    )
+    .context("loading")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_walk_chain() {
+    let err = FlatError::from(IoError::from(ErrorKind::NotFound))
+        .context("reading config file")
+        .context("starting server");
+    let mut recorder = Recorder::default();
+    err.walk(&mut recorder);
+    assert_eq!(
+        recorder.0,
+        [
+            "0 starting server",
+            "1 reading config file",
+            "2 entity not found"
+        ]
+    );
+}
+
+#[test]
+fn test_walk_branches() {
+    let mut recorder = Recorder::default();
+    tree().walk(&mut recorder);
+    assert_eq!(
+        recorder.0,
+        [
+            "0 loading",
+            "1 reading a; b",
+            "enter 1.0",
+            "2 reading a",
+            "3 entity not found",
+            "leave 1.0",
+            "enter 1.1",
+            "2 b",
+            "leave 1.1",
+        ]
+    );
+}
+
+#[test]
+fn test_walk_matches_tree_frames() {
+    let err = tree();
+    let mut visited = Vec::new();
+    err.walk(&mut |depth, frame: FlatFrame<'_>| visited.push((depth, frame.message().to_string())));
+    assert_eq!(
+        visited,
+        err.tree_frames()
+            .map(|(depth, frame)| (depth, frame.message().to_string()))
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "wire")]
+
+use flat_error::{wire, ErrorClass, FlatError, FlattenOptions, Severity, SourceSpan};
+use pretty_assertions::assert_eq;
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return an encoded error whose branches are nested `depth` deep, each frame having an empty
+/// message and type name and no other details.
+///
+fn nested_payload(depth: usize) -> Vec<u8> {
+    const FRAME_PREFIX: [u8; 13] = [0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    let mut bytes = vec![wire::WIRE_VERSION, 1];
+    for _ in 0..depth {
+        bytes.extend(FRAME_PREFIX);
+        bytes.extend([1, 1]);
+    }
+    bytes.extend(FRAME_PREFIX);
+    bytes.extend([0, 0, 0]);
+    bytes.extend(std::iter::repeat(0).take(depth * 2));
+    bytes
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_round_trip_details() {
+    let err = FlatError::new_static("expected `]`")
+        .with_field("file", "config.yaml")
+        .with_help("close the list")
+        .with_docs_url("https://errors.example.com/E0007")
+        .with_span(SourceSpan::new(15, 1, 2, 7))
+        .with_severity(Severity::Warning)
+        .with_related(FlatError::new("list opened here"))
+        .context("parsing config.yaml")
+        .context("loading configuration");
+    let decoded = wire::decode(&wire::encode(&err)).unwrap();
+    assert_eq!(decoded, err);
+    assert_eq!(decoded.to_string(), "loading configuration");
+    assert_eq!(
+        decoded.flat_source().unwrap().to_string(),
+        "parsing config.yaml"
+    );
+}
+
+#[test]
+fn test_round_trip_template() {
+    let err = FlatError::templated("disk {device} full", [("device", "sda1")]);
+    let decoded = wire::decode(&wire::encode(&err)).unwrap();
+    assert_eq!(decoded.message_template(), Some("disk {device} full"));
+    assert_eq!(decoded, err);
+}
+
+#[test]
+fn test_round_trip_debug_repr() {
+    let options = FlattenOptions::new().with_debug_capture(true);
+    let err =
+        FlatError::from_any_with(&std::io::Error::from(std::io::ErrorKind::NotFound), options);
+    let decoded = wire::decode(&wire::encode(&err)).unwrap();
+    assert_eq!(decoded.debug_repr(), Some("Kind(NotFound)"));
+}
+
+#[test]
+fn test_round_trip_branches() {
+    let err = FlatError::join(FlatError::new("first"), FlatError::new("second"));
+    assert_eq!(wire::decode(&wire::encode(&err)), Ok(err));
+}
+
+#[test]
+fn test_round_trip_class() {
+    let err = FlatError::new("busy")
+        .with_class(ErrorClass::Unavailable)
+        .context("calling billing");
+    let decoded = wire::decode(&wire::encode(&err)).unwrap();
+    assert_eq!(decoded, err);
+    assert_eq!(decoded.class(), ErrorClass::Unavailable);
+    assert_eq!(decoded.flat_source().unwrap().field("class"), None);
+}
+
+#[test]
+fn test_decode_version_1_class_field() {
+    let mut bytes = vec![1, 1, 4];
+    bytes.extend(b"busy");
+    bytes.extend([21]);
+    bytes.extend(b"flat_error::FlatError");
+    bytes.extend([2, 0, 2, 5]);
+    bytes.extend(b"class");
+    bytes.extend([11]);
+    bytes.extend(b"unavailable");
+    bytes.extend([4]);
+    bytes.extend(b"host");
+    bytes.extend([4]);
+    bytes.extend(b"db-1");
+    bytes.extend([0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let err = wire::decode(&bytes).unwrap();
+    assert_eq!(
+        err,
+        FlatError::new("busy")
+            .with_class(ErrorClass::Unavailable)
+            .with_field("host", "db-1")
+    );
+    assert_eq!(err.field("class"), None);
+}
+
+#[test]
+fn test_encoding_is_compact() {
+    let err = FlatError::new_static("disk full");
+    let bytes = wire::encode(&err);
+    assert!(bytes.len() < 49, "{} bytes", bytes.len());
+}
+
+#[test]
+fn test_decode_errors() {
+    let err = wire::decode(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "encoded flat error is empty");
+
+    let err = wire::decode(&[99, 0]).unwrap_err();
+    assert_eq!(err.unsupported_version(), Some(99));
+    assert_eq!(
+        err.to_string(),
+        "encoded flat error has unsupported version 99"
+    );
+
+    let mut bytes = wire::encode(&FlatError::new("disk full"));
+    bytes.truncate(bytes.len() - 2);
+    let err = wire::decode(&bytes).unwrap_err();
+    assert_eq!(err.unsupported_version(), None);
+    assert_eq!(err.to_string(), "encoded flat error is malformed");
+
+    assert!(wire::decode(&[wire::WIRE_VERSION, 0]).is_err());
+}
+
+#[test]
+fn test_decode_trailing_bytes() {
+    let mut bytes = wire::encode(&FlatError::new("disk full"));
+    bytes.push(0);
+    let err = wire::decode(&bytes).unwrap_err();
+    assert_eq!(err.to_string(), "encoded flat error is malformed");
+}
+
+#[test]
+fn test_decode_nesting_limit() {
+    let err = wire::decode(&nested_payload(wire::WIRE_MAX_NESTING)).unwrap();
+    assert_eq!(err.branches().count(), 1);
+
+    let err = wire::decode(&nested_payload(wire::WIRE_MAX_NESTING + 1)).unwrap_err();
+    assert_eq!(err.to_string(), "encoded flat error is malformed");
+
+    let err = wire::decode(&nested_payload(1_000_000)).unwrap_err();
+    assert_eq!(err.to_string(), "encoded flat error is malformed");
+}
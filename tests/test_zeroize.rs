@@ -0,0 +1,65 @@
+#![cfg(feature = "zeroize")]
+
+use flat_error::{FlatError, FlatErrorFixed};
+use pretty_assertions::assert_eq;
+use std::io::{Error as IoError, ErrorKind};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+// ------------------------------------------------------------------------------------------------
+// Test Fixtures
+// ------------------------------------------------------------------------------------------------
+
+fn sensitive() -> FlatError<u8> {
+    FlatError::from(IoError::new(
+        ErrorKind::PermissionDenied,
+        "bad key 0a7f93c2",
+    ))
+    .with_field("key_id", "k-42")
+    .with_code("AUTH-7")
+    .context("decrypting payload")
+    .with_category(3)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_zeroize_error() {
+    let mut err = sensitive();
+    err.zeroize();
+    assert_eq!(err.to_string(), "");
+    assert_eq!(err.code(), None);
+    assert_eq!(err.fields().count(), 0);
+    assert!(err.flat_source().is_none());
+    assert_eq!(err.category(), &3);
+}
+
+#[test]
+fn test_zeroize_leaves_clones() {
+    let err = sensitive();
+    let mut clone = err.clone();
+    clone.zeroize();
+    assert_eq!(err.to_string(), "decrypting payload");
+    assert_eq!(
+        err.chain_messages().collect::<Vec<_>>(),
+        ["decrypting payload", "bad key 0a7f93c2"]
+    );
+    assert_eq!(err.flat_source().unwrap().field("key_id"), Some("k-42"));
+}
+
+#[test]
+fn test_zeroize_on_drop() {
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+    assert_zeroize_on_drop::<FlatError>();
+    assert_zeroize_on_drop::<FlatError<u8>>();
+}
+
+#[test]
+fn test_zeroize_fixed() {
+    let mut err: FlatErrorFixed<16> = FlatErrorFixed::from_any(&"12a".parse::<u8>().unwrap_err());
+    assert!(err.is_truncated());
+    err.zeroize();
+    assert_eq!(err.message(), "");
+    assert!(!err.is_truncated());
+}